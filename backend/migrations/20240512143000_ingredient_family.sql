@@ -0,0 +1,39 @@
+--
+-- Create Ingredient Family table
+--
+DROP TABLE IF EXISTS "ingredient_family" CASCADE;
+DROP SEQUENCE IF EXISTS ingredient_family_id_seq;
+CREATE SEQUENCE ingredient_family_id_seq INCREMENT 1 MINVALUE 1 MAXVALUE 2147483647 CACHE 1;
+
+CREATE TABLE "public"."ingredient_family" (
+    "id" integer DEFAULT nextval('ingredient_family_id_seq') NOT NULL,
+    "user_id" integer,
+    "name" character varying NOT NULL,
+    "unit" character varying,
+    CONSTRAINT "ingredient_family_pkey" PRIMARY KEY ("id")
+) WITH (oids = false);
+
+COMMENT ON TABLE "public"."ingredient_family" IS 'Groups ingredients that are the same product sold in different pack sizes.';
+COMMENT ON COLUMN "public"."ingredient_family"."user_id" IS 'An optional relation to a user. All other families are global.';
+
+ALTER TABLE "public"."ingredient" ADD COLUMN "family_id" integer;
+
+ALTER TABLE ONLY "public"."ingredient_family" ADD CONSTRAINT "ingredient_family_user_id_fkey" FOREIGN KEY (user_id) REFERENCES useraccount(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
+ALTER TABLE ONLY "public"."ingredient" ADD CONSTRAINT "ingredient_family_id_fkey" FOREIGN KEY (family_id) REFERENCES ingredient_family(id) ON UPDATE CASCADE ON DELETE SET NULL NOT DEFERRABLE;
+
+--
+-- Group global ingredients with the same name and unit, but different pack sizes
+--
+INSERT INTO ingredient_family (name, unit)
+    SELECT name, unit FROM ingredient
+    WHERE user_id IS NULL
+    GROUP BY name, unit
+    HAVING COUNT(DISTINCT purchase_quantity) > 1
+    ORDER BY name;
+
+UPDATE ingredient AS i SET family_id = f.id
+    FROM ingredient_family AS f
+    WHERE i.user_id IS NULL
+        AND f.user_id IS NULL
+        AND i.name = f.name
+        AND i.unit IS NOT DISTINCT FROM f.unit;
@@ -0,0 +1,28 @@
+--
+-- Create Plan Template tables
+--
+DROP TABLE IF EXISTS "plan_template" CASCADE;
+DROP SEQUENCE IF EXISTS plan_template_id_seq;
+CREATE SEQUENCE plan_template_id_seq INCREMENT 1 MINVALUE 1 MAXVALUE 2147483647 CACHE 1;
+
+CREATE TABLE "public"."plan_template" (
+    "id" integer DEFAULT nextval('plan_template_id_seq') NOT NULL,
+    "user_id" integer NOT NULL,
+    "name" character varying NOT NULL,
+    CONSTRAINT "plan_template_pkey" PRIMARY KEY ("id")
+) WITH (oids = false);
+
+COMMENT ON TABLE "public"."plan_template" IS 'A named set of recipes, saved from a range of days so it can be planned again.';
+
+DROP TABLE IF EXISTS "plan_template_day" CASCADE;
+CREATE TABLE "public"."plan_template_day" (
+    "template_id" integer NOT NULL,
+    "day_offset" integer NOT NULL,
+    "recipe_id" integer NOT NULL
+) WITH (oids = false);
+
+COMMENT ON COLUMN "public"."plan_template_day"."day_offset" IS 'The number of days after the start of the template the recipe is planned.';
+
+ALTER TABLE ONLY "public"."plan_template" ADD CONSTRAINT "plan_template_user_id_fkey" FOREIGN KEY (user_id) REFERENCES useraccount(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
+ALTER TABLE ONLY "public"."plan_template_day" ADD CONSTRAINT "plan_template_day_template_id_fkey" FOREIGN KEY (template_id) REFERENCES plan_template(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
+ALTER TABLE ONLY "public"."plan_template_day" ADD CONSTRAINT "plan_template_day_recipe_id_fkey" FOREIGN KEY (recipe_id) REFERENCES recipe(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
@@ -0,0 +1,6 @@
+--
+-- Add calendar subscription tokens to user accounts
+--
+ALTER TABLE "public"."useraccount" ADD COLUMN "calendar_token" character varying UNIQUE;
+
+COMMENT ON COLUMN "public"."useraccount"."calendar_token" IS 'A secret used in place of a bearer token to subscribe to the user''s meal plan calendar.';
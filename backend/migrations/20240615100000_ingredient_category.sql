@@ -0,0 +1,1184 @@
+--
+-- Add aisle categories to ingredients
+--
+ALTER TABLE "public"."ingredient" ADD COLUMN "category" character varying;
+
+COMMENT ON COLUMN "public"."ingredient"."category" IS 'The aisle the ingredient is found in, taken from the category it was scraped from.';
+
+--
+-- Create User Aisle table
+--
+DROP TABLE IF EXISTS "user_aisle" CASCADE;
+CREATE TABLE "public"."user_aisle" (
+    "user_id" integer NOT NULL,
+    "category" character varying NOT NULL,
+    "position" integer NOT NULL,
+    CONSTRAINT "user_aisle_pkey" PRIMARY KEY ("user_id", "category")
+) WITH (oids = false);
+
+COMMENT ON TABLE "public"."user_aisle" IS 'A user''s ordering of ingredient categories, matching the layout of their store.';
+
+ALTER TABLE ONLY "public"."user_aisle" ADD CONSTRAINT "user_aisle_user_id_fkey" FOREIGN KEY (user_id) REFERENCES useraccount(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
+
+--
+-- Categorise global ingredients from the aisle each was scraped from
+--
+UPDATE ingredient AS i SET category = c.category
+    FROM (VALUES
+(1,'Butter'),
+(2,'Butter'),
+(3,'Butter'),
+(4,'Butter'),
+(5,'Butter'),
+(6,'Butter'),
+(7,'Butter'),
+(8,'Milk'),
+(9,'Milk'),
+(10,'Milk'),
+(11,'Milk'),
+(12,'Milk'),
+(13,'Milk'),
+(14,'Milk'),
+(15,'Milk'),
+(16,'Milk'),
+(17,'Milk'),
+(18,'Milk'),
+(19,'Milk'),
+(20,'Milk'),
+(21,'Milk'),
+(22,'Milk'),
+(23,'Milk'),
+(24,'Milk'),
+(25,'Milk'),
+(26,'Milk'),
+(27,'Milk'),
+(28,'Milk'),
+(29,'Milk'),
+(30,'Milk'),
+(31,'Meat and Fish Essentials'),
+(32,'Meat and Fish Essentials'),
+(33,'Meat and Fish Essentials'),
+(34,'Meat and Fish Essentials'),
+(35,'Meat and Fish Essentials'),
+(36,'Meat and Fish Essentials'),
+(37,'Meat and Fish Essentials'),
+(38,'Meat and Fish Essentials'),
+(39,'Meat and Fish Essentials'),
+(40,'Meat and Fish Essentials'),
+(41,'Meat and Fish Essentials'),
+(42,'Meat and Fish Essentials'),
+(43,'Meat and Fish Essentials'),
+(44,'Meat and Fish Essentials'),
+(45,'Meat and Fish Essentials'),
+(46,'Meat and Fish Essentials'),
+(47,'Meat and Fish Essentials'),
+(48,'Meat and Fish Essentials'),
+(49,'Meat and Fish Essentials'),
+(50,'Meat and Fish Essentials'),
+(51,'Meat and Fish Essentials'),
+(52,'Meat and Fish Essentials'),
+(53,'Meat and Fish Essentials'),
+(54,'Meat and Fish Essentials'),
+(55,'Meat and Fish Essentials'),
+(56,'Meat and Fish Essentials'),
+(57,'Meat and Fish Essentials'),
+(58,'Meat and Fish Essentials'),
+(59,'Meat and Fish Essentials'),
+(60,'Meat and Fish Essentials'),
+(61,'Meat and Fish Essentials'),
+(62,'Meat and Fish Essentials'),
+(63,'Meat and Fish Essentials'),
+(64,'Meat and Fish Essentials'),
+(65,'Meat and Fish Essentials'),
+(66,'Meat and Fish Essentials'),
+(67,'Meat and Fish Essentials'),
+(68,'Meat and Fish Essentials'),
+(69,'Meat and Fish Essentials'),
+(70,'Meat and Fish Essentials'),
+(71,'Meat and Fish Essentials'),
+(72,'Meat and Fish Essentials'),
+(73,'Meat and Fish Essentials'),
+(74,'Meat and Fish Essentials'),
+(75,'Meat and Fish Essentials'),
+(76,'Meat and Fish Essentials'),
+(77,'Meat and Fish Essentials'),
+(78,'Meat and Fish Essentials'),
+(79,'Meat and Fish Essentials'),
+(80,'Meat and Fish Essentials'),
+(81,'Meat and Fish Essentials'),
+(82,'Meat and Fish Essentials'),
+(83,'Meat and Fish Essentials'),
+(84,'Meat and Fish Essentials'),
+(85,'Meat and Fish Essentials'),
+(86,'Meat and Fish Essentials'),
+(87,'Meat and Fish Essentials'),
+(88,'Meat and Fish Essentials'),
+(89,'Meat and Fish Essentials'),
+(90,'Meat and Fish Essentials'),
+(91,'Meat and Fish Essentials'),
+(92,'Meat and Fish Essentials'),
+(93,'Meat and Fish Essentials'),
+(94,'Meat and Fish Essentials'),
+(95,'Meat and Fish Essentials'),
+(96,'Meat and Fish Essentials'),
+(97,'Meat and Fish Essentials'),
+(98,'Meat and Fish Essentials'),
+(99,'Meat and Fish Essentials'),
+(100,'Meat and Fish Essentials'),
+(101,'Meat and Fish Essentials'),
+(102,'Meat and Fish Essentials'),
+(103,'Meat and Fish Essentials'),
+(104,'Meat and Fish Essentials'),
+(105,'Meat and Fish Essentials'),
+(106,'Meat and Fish Essentials'),
+(107,'Meat and Fish Essentials'),
+(108,'Meat and Fish Essentials'),
+(109,'Meat and Fish Essentials'),
+(110,'Meat and Fish Essentials'),
+(111,'Meat and Fish Essentials'),
+(112,'Meat and Fish Essentials'),
+(113,'Meat and Fish Essentials'),
+(114,'Meat and Fish Essentials'),
+(115,'Meat and Fish Essentials'),
+(116,'Meat and Fish Essentials'),
+(117,'Meat and Fish Essentials'),
+(118,'Meat and Fish Essentials'),
+(119,'Meat and Fish Essentials'),
+(120,'Meat and Fish Essentials'),
+(121,'Meat and Fish Essentials'),
+(122,'Meat and Fish Essentials'),
+(123,'Meat and Fish Essentials'),
+(124,'Meat and Fish Essentials'),
+(125,'Meat and Fish Essentials'),
+(126,'Meat and Fish Essentials'),
+(127,'Meat and Fish Essentials'),
+(128,'Meat and Fish Essentials'),
+(129,'Meat and Fish Essentials'),
+(130,'Meat and Fish Essentials'),
+(131,'Meat and Fish Essentials'),
+(132,'Meat and Fish Essentials'),
+(133,'Meat and Fish Essentials'),
+(134,'Meat and Fish Essentials'),
+(135,'Meat and Fish Essentials'),
+(136,'Meat and Fish Essentials'),
+(137,'Meat and Fish Essentials'),
+(138,'Meat and Fish Essentials'),
+(139,'Meat and Fish Essentials'),
+(140,'Meat and Fish Essentials'),
+(141,'Meat and Fish Essentials'),
+(142,'Meat and Fish Essentials'),
+(143,'Meat and Fish Essentials'),
+(144,'Meat and Fish Essentials'),
+(145,'Meat and Fish Essentials'),
+(146,'Meat and Fish Essentials'),
+(147,'Meat and Fish Essentials'),
+(148,'Meat and Fish Essentials'),
+(149,'Meat and Fish Essentials'),
+(150,'Meat and Fish Essentials'),
+(151,'Meat and Fish Essentials'),
+(152,'Meat and Fish Essentials'),
+(153,'Meat and Fish Essentials'),
+(154,'Meat and Fish Essentials'),
+(155,'Meat and Fish Essentials'),
+(156,'Meat and Fish Essentials'),
+(157,'Meat and Fish Essentials'),
+(158,'Meat and Fish Essentials'),
+(159,'Meat and Fish Essentials'),
+(160,'Meat and Fish Essentials'),
+(161,'Meat and Fish Essentials'),
+(162,'Meat and Fish Essentials'),
+(163,'Meat and Fish Essentials'),
+(164,'Meat and Fish Essentials'),
+(165,'Meat and Fish Essentials'),
+(166,'Meat and Fish Essentials'),
+(167,'Meat and Fish Essentials'),
+(168,'Meat and Fish Essentials'),
+(169,'Meat and Fish Essentials'),
+(170,'Meat and Fish Essentials'),
+(171,'Meat and Fish Essentials'),
+(172,'Meat and Fish Essentials'),
+(173,'Meat and Fish Essentials'),
+(174,'Meat and Fish Essentials'),
+(175,'Meat and Fish Essentials'),
+(176,'Meat and Fish Essentials'),
+(177,'Meat and Fish Essentials'),
+(178,'Meat and Fish Essentials'),
+(179,'Meat and Fish Essentials'),
+(180,'Meat and Fish Essentials'),
+(181,'Meat and Fish Essentials'),
+(182,'Meat and Fish Essentials'),
+(183,'Cheese'),
+(184,'Cheese'),
+(185,'Cheese'),
+(186,'Cheese'),
+(187,'Cheese'),
+(188,'Cheese'),
+(189,'Cheese'),
+(190,'Cheese'),
+(191,'Cheese'),
+(192,'Cheese'),
+(193,'Cheese'),
+(194,'Cheese'),
+(195,'Cheese'),
+(196,'Cheese'),
+(197,'Cheese'),
+(198,'Cheese'),
+(199,'Cheese'),
+(200,'Cheese'),
+(201,'Cheese'),
+(202,'Cheese'),
+(203,'Cheese'),
+(204,'Cheese'),
+(205,'Cheese'),
+(206,'Cheese'),
+(207,'Cheese'),
+(208,'Cheese'),
+(209,'Cheese'),
+(210,'Cheese'),
+(211,'Cheese'),
+(212,'Cheese'),
+(213,'Cheese'),
+(214,'Cheese'),
+(215,'Cheese'),
+(216,'Cheese'),
+(217,'Cheese'),
+(218,'Cheese'),
+(219,'Cheese'),
+(220,'Cheese'),
+(221,'Cheese'),
+(222,'Cheese'),
+(223,'Cheese'),
+(224,'Cheese'),
+(225,'Cheese'),
+(226,'Cheese'),
+(227,'Cheese'),
+(228,'Cheese'),
+(229,'Cheese'),
+(230,'Cheese'),
+(231,'Cheese'),
+(232,'Cheese'),
+(233,'Cheese'),
+(234,'Cheese'),
+(235,'Cheese'),
+(236,'Cheese'),
+(237,'Cheese'),
+(238,'Cheese'),
+(239,'Cheese'),
+(240,'Cheese'),
+(241,'Cheese'),
+(242,'Cheese'),
+(243,'Cheese'),
+(244,'Cheese'),
+(245,'Cheese'),
+(246,'Cheese'),
+(247,'Cheese'),
+(248,'Cheese'),
+(249,'Cheese'),
+(250,'Cheese'),
+(251,'Cheese'),
+(252,'Cheese'),
+(253,'Cheese'),
+(254,'Cheese'),
+(255,'Cheese'),
+(256,'Cheese'),
+(257,'Cheese'),
+(258,'Cheese'),
+(259,'Cheese'),
+(260,'Cheese'),
+(261,'Cheese'),
+(262,'Cheese'),
+(263,'Cheese'),
+(264,'Cheese'),
+(265,'Cheese'),
+(266,'Cheese'),
+(267,'Cheese'),
+(268,'Cheese'),
+(269,'Cheese'),
+(270,'Cheese'),
+(271,'Cheese'),
+(272,'Cheese'),
+(273,'Cheese'),
+(274,'Cheese'),
+(275,'Cheese'),
+(276,'Cheese'),
+(277,'Cheese'),
+(278,'Cheese'),
+(279,'Cheese'),
+(280,'Cheese'),
+(281,'Cheese'),
+(282,'Cheese'),
+(283,'Cheese'),
+(284,'Cheese'),
+(285,'Cheese'),
+(286,'Cheese'),
+(287,'Cheese'),
+(288,'Cheese'),
+(289,'Cheese'),
+(290,'Cheese'),
+(291,'Cheese'),
+(292,'Cheese'),
+(293,'Cheese'),
+(294,'Cheese'),
+(295,'Cheese'),
+(296,'Cheese'),
+(297,'Cheese'),
+(298,'Cheese'),
+(299,'Cheese'),
+(300,'Cheese'),
+(301,'Cheese'),
+(302,'Cheese'),
+(303,'Cheese'),
+(304,'Cheese'),
+(305,'Cheese'),
+(306,'Cheese'),
+(307,'Cheese'),
+(308,'Cheese'),
+(309,'Cheese'),
+(310,'Cheese'),
+(311,'Cheese'),
+(312,'Cheese'),
+(313,'Cheese'),
+(314,'Cheese'),
+(315,'Cheese'),
+(316,'Cheese'),
+(317,'Cheese'),
+(318,'Eggs'),
+(319,'Eggs'),
+(320,'Eggs'),
+(321,'Eggs'),
+(322,'Eggs'),
+(323,'Eggs'),
+(324,'Eggs'),
+(325,'Eggs'),
+(326,'Eggs'),
+(327,'Eggs'),
+(328,'Dairy Free'),
+(329,'Dairy Free'),
+(330,'Dairy Free'),
+(331,'Dairy Free'),
+(332,'Dairy Free'),
+(333,'Dairy Free'),
+(334,'Dairy Free'),
+(335,'Dairy Free'),
+(336,'Dairy Free'),
+(337,'Fresh Fruit'),
+(338,'Fresh Fruit'),
+(339,'Fresh Fruit'),
+(340,'Fresh Fruit'),
+(341,'Fresh Fruit'),
+(342,'Fresh Fruit'),
+(343,'Fresh Fruit'),
+(344,'Fresh Fruit'),
+(345,'Fresh Fruit'),
+(346,'Fresh Fruit'),
+(347,'Fresh Fruit'),
+(348,'Fresh Fruit'),
+(349,'Fresh Fruit'),
+(350,'Fresh Fruit'),
+(351,'Fresh Fruit'),
+(352,'Fresh Fruit'),
+(353,'Fresh Fruit'),
+(354,'Fresh Fruit'),
+(355,'Fresh Fruit'),
+(356,'Fresh Fruit'),
+(357,'Fresh Fruit'),
+(358,'Fresh Fruit'),
+(359,'Fresh Fruit'),
+(360,'Fresh Fruit'),
+(361,'Fresh Fruit'),
+(362,'Fresh Fruit'),
+(363,'Fresh Fruit'),
+(364,'Fresh Fruit'),
+(365,'Fresh Fruit'),
+(366,'Fresh Fruit'),
+(367,'Fresh Fruit'),
+(368,'Fresh Fruit'),
+(369,'Fresh Fruit'),
+(370,'Fresh Fruit'),
+(371,'Fresh Fruit'),
+(372,'Fresh Fruit'),
+(373,'Fresh Fruit'),
+(374,'Fresh Fruit'),
+(375,'Fresh Fruit'),
+(376,'Fresh Fruit'),
+(377,'Fresh Fruit'),
+(378,'Fresh Fruit'),
+(379,'Fresh Fruit'),
+(380,'Fresh Fruit'),
+(381,'Fresh Fruit'),
+(382,'Fresh Fruit'),
+(383,'Fresh Fruit'),
+(384,'Fresh Fruit'),
+(385,'Fresh Fruit'),
+(386,'Fresh Fruit'),
+(387,'Fresh Fruit'),
+(388,'Fresh Fruit'),
+(389,'Fresh Fruit'),
+(390,'Fresh Fruit'),
+(391,'Fresh Fruit'),
+(392,'Fresh Fruit'),
+(393,'Fresh Fruit'),
+(394,'Fresh Fruit'),
+(395,'Fresh Fruit'),
+(396,'Fresh Fruit'),
+(397,'Fresh Fruit'),
+(398,'Fresh Fruit'),
+(399,'Fresh Fruit'),
+(400,'Fresh Fruit'),
+(401,'Fresh Fruit'),
+(402,'Fresh Fruit'),
+(403,'Fresh Fruit'),
+(404,'Fresh Fruit'),
+(405,'Fresh Fruit'),
+(406,'Fresh Fruit'),
+(407,'Fresh Fruit'),
+(408,'Fresh Fruit'),
+(409,'Fresh Fruit'),
+(410,'Fresh Fruit'),
+(411,'Fresh Fruit'),
+(412,'Fresh Fruit'),
+(413,'Fresh Fruit'),
+(414,'Fresh Fruit'),
+(415,'Fresh Fruit'),
+(416,'Fresh Fruit'),
+(417,'Fresh Fruit'),
+(418,'Fresh Fruit'),
+(419,'Fresh Fruit'),
+(420,'Fresh Fruit'),
+(421,'Fresh Fruit'),
+(422,'Fresh Fruit'),
+(423,'Fresh Fruit'),
+(424,'Fresh Fruit'),
+(425,'Fresh Fruit'),
+(426,'Fresh Fruit'),
+(427,'Fresh Fruit'),
+(428,'Fresh Fruit'),
+(429,'Fresh Fruit'),
+(430,'Fresh Fruit'),
+(431,'Fresh Fruit'),
+(432,'Fresh Fruit'),
+(433,'Fresh Fruit'),
+(434,'Fresh Fruit'),
+(435,'Fresh Fruit'),
+(436,'Fresh Fruit'),
+(437,'Fresh Fruit'),
+(438,'Fresh Fruit'),
+(439,'Fresh Fruit'),
+(440,'Fresh Fruit'),
+(441,'Fresh Fruit'),
+(442,'Fresh Fruit'),
+(443,'Fresh Fruit'),
+(444,'Fresh Fruit'),
+(445,'Fresh Fruit'),
+(446,'Fresh Fruit'),
+(447,'Fresh Fruit'),
+(448,'Fresh Fruit'),
+(449,'Fresh Fruit'),
+(450,'Fresh Fruit'),
+(451,'Fresh Fruit'),
+(452,'Fresh Fruit'),
+(453,'Fresh Fruit'),
+(454,'Fresh Fruit'),
+(455,'Fresh Fruit'),
+(456,'Fresh Fruit'),
+(457,'Fresh Fruit'),
+(458,'Fresh Fruit'),
+(459,'Fresh Fruit'),
+(460,'Fresh Vegetables'),
+(461,'Fresh Vegetables'),
+(462,'Fresh Vegetables'),
+(463,'Fresh Vegetables'),
+(464,'Fresh Vegetables'),
+(465,'Fresh Vegetables'),
+(466,'Fresh Vegetables'),
+(467,'Fresh Vegetables'),
+(468,'Fresh Vegetables'),
+(469,'Fresh Vegetables'),
+(470,'Fresh Vegetables'),
+(471,'Fresh Vegetables'),
+(472,'Fresh Vegetables'),
+(473,'Fresh Vegetables'),
+(474,'Fresh Vegetables'),
+(475,'Fresh Vegetables'),
+(476,'Fresh Vegetables'),
+(477,'Fresh Vegetables'),
+(478,'Fresh Vegetables'),
+(479,'Fresh Vegetables'),
+(480,'Fresh Vegetables'),
+(481,'Fresh Vegetables'),
+(482,'Fresh Vegetables'),
+(483,'Fresh Vegetables'),
+(484,'Fresh Vegetables'),
+(485,'Fresh Vegetables'),
+(486,'Fresh Vegetables'),
+(487,'Fresh Vegetables'),
+(488,'Fresh Vegetables'),
+(489,'Fresh Vegetables'),
+(490,'Fresh Vegetables'),
+(491,'Fresh Vegetables'),
+(492,'Fresh Vegetables'),
+(493,'Fresh Vegetables'),
+(494,'Fresh Vegetables'),
+(495,'Fresh Vegetables'),
+(496,'Fresh Vegetables'),
+(497,'Fresh Vegetables'),
+(498,'Fresh Vegetables'),
+(499,'Fresh Vegetables'),
+(500,'Fresh Vegetables'),
+(501,'Fresh Vegetables'),
+(502,'Fresh Vegetables'),
+(503,'Fresh Vegetables'),
+(504,'Fresh Vegetables'),
+(505,'Fresh Vegetables'),
+(506,'Fresh Vegetables'),
+(507,'Fresh Vegetables'),
+(508,'Fresh Vegetables'),
+(509,'Fresh Vegetables'),
+(510,'Fresh Vegetables'),
+(511,'Fresh Vegetables'),
+(512,'Fresh Vegetables'),
+(513,'Fresh Vegetables'),
+(514,'Fresh Vegetables'),
+(515,'Fresh Vegetables'),
+(516,'Fresh Vegetables'),
+(517,'Fresh Vegetables'),
+(518,'Fresh Vegetables'),
+(519,'Fresh Vegetables'),
+(520,'Fresh Vegetables'),
+(521,'Fresh Vegetables'),
+(522,'Fresh Vegetables'),
+(523,'Fresh Vegetables'),
+(524,'Fresh Vegetables'),
+(525,'Fresh Vegetables'),
+(526,'Fresh Vegetables'),
+(527,'Fresh Vegetables'),
+(528,'Fresh Vegetables'),
+(529,'Fresh Vegetables'),
+(530,'Fresh Vegetables'),
+(531,'Fresh Vegetables'),
+(532,'Fresh Vegetables'),
+(533,'Fresh Vegetables'),
+(534,'Fresh Vegetables'),
+(535,'Fresh Vegetables'),
+(536,'Fresh Vegetables'),
+(537,'Fresh Vegetables'),
+(538,'Fresh Vegetables'),
+(539,'Fresh Vegetables'),
+(540,'Fresh Vegetables'),
+(541,'Fresh Vegetables'),
+(542,'Fresh Vegetables'),
+(543,'Fresh Vegetables'),
+(544,'Fresh Vegetables'),
+(545,'Fresh Vegetables'),
+(546,'Fresh Vegetables'),
+(547,'Fresh Vegetables'),
+(548,'Fresh Vegetables'),
+(549,'Fresh Vegetables'),
+(550,'Fresh Vegetables'),
+(551,'Fresh Vegetables'),
+(552,'Fresh Vegetables'),
+(553,'Fresh Vegetables'),
+(554,'Fresh Vegetables'),
+(555,'Fresh Vegetables'),
+(556,'Fresh Vegetables'),
+(557,'Fresh Vegetables'),
+(558,'Fresh Vegetables'),
+(559,'Fresh Vegetables'),
+(560,'Fresh Vegetables'),
+(561,'Fresh Vegetables'),
+(562,'Fresh Vegetables'),
+(563,'Fresh Vegetables'),
+(564,'Fresh Vegetables'),
+(565,'Fresh Vegetables'),
+(566,'Fresh Vegetables'),
+(567,'Fresh Vegetables'),
+(568,'Fresh Vegetables'),
+(569,'Fresh Vegetables'),
+(570,'Fresh Vegetables'),
+(571,'Fresh Vegetables'),
+(572,'Fresh Vegetables'),
+(573,'Fresh Vegetables'),
+(574,'Fresh Vegetables'),
+(575,'Fresh Vegetables'),
+(576,'Fresh Vegetables'),
+(577,'Fresh Vegetables'),
+(578,'Fresh Vegetables'),
+(579,'Fresh Vegetables'),
+(580,'Fresh Vegetables'),
+(581,'Fresh Vegetables'),
+(582,'Fresh Vegetables'),
+(583,'Fresh Vegetables'),
+(584,'Fresh Vegetables'),
+(585,'Fresh Vegetables'),
+(586,'Fresh Vegetables'),
+(587,'Fresh Vegetables'),
+(588,'Fresh Vegetables'),
+(589,'Fresh Vegetables'),
+(590,'Fresh Vegetables'),
+(591,'Fresh Vegetables'),
+(592,'Fresh Vegetables'),
+(593,'Fresh Vegetables'),
+(594,'Fresh Vegetables'),
+(595,'Fresh Vegetables'),
+(596,'Fresh Vegetables'),
+(597,'Fresh Vegetables'),
+(598,'Fresh Vegetables'),
+(599,'Fresh Vegetables'),
+(600,'Fresh Vegetables'),
+(601,'Fresh Vegetables'),
+(602,'Fresh Vegetables'),
+(603,'Fresh Vegetables'),
+(604,'Fresh Vegetables'),
+(605,'Fresh Vegetables'),
+(606,'Fresh Vegetables'),
+(607,'Fresh Vegetables'),
+(608,'Fresh Vegetables'),
+(609,'Fresh Vegetables'),
+(610,'Fresh Vegetables'),
+(611,'Fresh Vegetables'),
+(612,'Fresh Vegetables'),
+(613,'Fresh Vegetables'),
+(614,'Fresh Vegetables'),
+(615,'Fresh Vegetables'),
+(616,'Fresh Vegetables'),
+(617,'Fresh Vegetables'),
+(618,'Fresh Vegetables'),
+(619,'Fresh Vegetables'),
+(620,'Fresh Vegetables'),
+(621,'Fresh Vegetables'),
+(622,'Fresh Vegetables'),
+(623,'Fresh Vegetables'),
+(624,'Fresh Vegetables'),
+(625,'Fresh Vegetables'),
+(626,'Fresh Vegetables'),
+(627,'Fresh Vegetables'),
+(628,'Fresh Vegetables'),
+(629,'Fresh Vegetables'),
+(630,'Fresh Vegetables'),
+(631,'Fresh Vegetables'),
+(632,'Fresh Vegetables'),
+(633,'Fresh Vegetables'),
+(634,'Fresh Vegetables'),
+(635,'Fresh Vegetables'),
+(636,'Fresh Vegetables'),
+(637,'Fresh Vegetables'),
+(638,'Fresh Vegetables'),
+(639,'Fresh Vegetables'),
+(640,'Fresh Vegetables'),
+(641,'Fresh Vegetables'),
+(642,'Fresh Vegetables'),
+(643,'Fresh Vegetables'),
+(644,'Fresh Vegetables'),
+(645,'Fresh Vegetables'),
+(646,'Fresh Vegetables'),
+(647,'Fresh Vegetables'),
+(648,'Fresh Vegetables'),
+(649,'Fresh Vegetables'),
+(650,'Fresh Vegetables'),
+(651,'Fresh Vegetables'),
+(652,'Fresh Vegetables'),
+(653,'Fresh Vegetables'),
+(654,'Fresh Vegetables'),
+(655,'Fresh Vegetables'),
+(656,'Fresh Vegetables'),
+(657,'Fresh Vegetables'),
+(658,'Fresh Vegetables'),
+(659,'Fresh Vegetables'),
+(660,'Fresh Vegetables'),
+(661,'Fresh Vegetables'),
+(662,'Fresh Vegetables'),
+(663,'Fresh Vegetables'),
+(664,'Fresh Vegetables'),
+(665,'Fresh Vegetables'),
+(666,'Fresh Vegetables'),
+(667,'Fresh Vegetables'),
+(668,'Fresh Vegetables'),
+(669,'Fresh Vegetables'),
+(670,'Fresh Vegetables'),
+(671,'Fresh Vegetables'),
+(672,'Fresh Vegetables'),
+(673,'Fresh Vegetables'),
+(674,'Fresh Vegetables'),
+(675,'Fresh Vegetables'),
+(676,'Fresh Vegetables'),
+(677,'Fresh Vegetables'),
+(678,'Fresh Vegetables'),
+(679,'Fresh Vegetables'),
+(680,'Fresh Vegetables'),
+(681,'Fresh Vegetables'),
+(682,'Fresh Vegetables'),
+(683,'Fresh Vegetables'),
+(684,'Fresh Vegetables'),
+(685,'Fresh Vegetables'),
+(686,'Fresh Vegetables'),
+(687,'Fresh Vegetables'),
+(688,'Fresh Vegetables'),
+(689,'Fresh Vegetables'),
+(690,'Fresh Vegetables'),
+(691,'Fresh Vegetables'),
+(692,'Fresh Vegetables'),
+(693,'Fresh Vegetables'),
+(694,'Fresh Vegetables'),
+(695,'Fresh Vegetables'),
+(696,'Fresh Vegetables'),
+(697,'Fresh Vegetables'),
+(698,'Fresh Vegetables'),
+(699,'Fresh Vegetables'),
+(700,'Fresh Vegetables'),
+(701,'Fresh Vegetables'),
+(702,'Fresh Vegetables'),
+(703,'Fresh Vegetables'),
+(704,'Fresh Vegetables'),
+(705,'Fresh Vegetables'),
+(706,'Fresh Vegetables'),
+(707,'Fresh Vegetables'),
+(708,'Fresh Vegetables'),
+(709,'Fresh Vegetables'),
+(710,'Fresh Vegetables'),
+(711,'Fresh Vegetables'),
+(712,'Fresh Vegetables'),
+(713,'Fresh Vegetables'),
+(714,'Fresh Vegetables'),
+(715,'Fresh Vegetables'),
+(716,'Fresh Vegetables'),
+(717,'Fresh Vegetables'),
+(718,'Fresh Vegetables'),
+(719,'Fresh Vegetables'),
+(720,'Fresh Vegetables'),
+(721,'Fresh Vegetables'),
+(722,'Fresh Vegetables'),
+(723,'Fresh Vegetables'),
+(724,'Fresh Vegetables'),
+(725,'Fresh Vegetables'),
+(726,'Fresh Vegetables'),
+(727,'Fresh Vegetables'),
+(728,'Fresh Vegetables'),
+(729,'Fresh Vegetables'),
+(730,'Fresh Vegetables'),
+(731,'Fresh Vegetables'),
+(732,'Fresh Vegetables'),
+(733,'Fresh Vegetables'),
+(734,'Fresh Vegetables'),
+(735,'Fresh Herbs'),
+(736,'Fresh Herbs'),
+(737,'Fresh Herbs'),
+(738,'Fresh Herbs'),
+(739,'Fresh Herbs'),
+(740,'Fresh Herbs'),
+(741,'Fresh Herbs'),
+(742,'Bread'),
+(743,'Bread'),
+(744,'Bread'),
+(745,'Bread'),
+(746,'Bread'),
+(747,'Bread'),
+(748,'Bread'),
+(749,'Bread'),
+(750,'Bread'),
+(751,'Bread'),
+(752,'Bread'),
+(753,'Bread'),
+(754,'Bread'),
+(755,'Bread'),
+(756,'Bread'),
+(757,'Bread'),
+(758,'Bread'),
+(759,'Bread'),
+(760,'Bread'),
+(761,'Bread'),
+(762,'Bread'),
+(763,'Bread'),
+(764,'Bread'),
+(765,'Bread'),
+(766,'Bread'),
+(767,'Bread'),
+(768,'Bread'),
+(769,'Bread'),
+(770,'Bread'),
+(771,'Bread'),
+(772,'Bread'),
+(773,'Bread'),
+(774,'Bread'),
+(775,'Bread'),
+(776,'Bread'),
+(777,'Bread'),
+(778,'Bread'),
+(779,'Bread'),
+(780,'Bread'),
+(781,'Bread'),
+(782,'Bread'),
+(783,'Bread'),
+(784,'Bread'),
+(785,'Bread'),
+(786,'Bread'),
+(787,'Bread'),
+(788,'Bread'),
+(789,'Bread'),
+(790,'Bread'),
+(791,'Bread'),
+(792,'Bread'),
+(793,'Bread'),
+(794,'Bread'),
+(795,'Bread'),
+(796,'Bread'),
+(797,'Bread'),
+(798,'Bread'),
+(799,'Bread'),
+(800,'Bread'),
+(801,'Bread'),
+(802,'Bread'),
+(803,'Bread'),
+(804,'Bread'),
+(805,'Bread'),
+(806,'Bread'),
+(807,'Bread'),
+(808,'Herbs and Spices'),
+(809,'Herbs and Spices'),
+(810,'Herbs and Spices'),
+(811,'Herbs and Spices'),
+(812,'Herbs and Spices'),
+(813,'Herbs and Spices'),
+(814,'Herbs and Spices'),
+(815,'Herbs and Spices'),
+(816,'Herbs and Spices'),
+(817,'Herbs and Spices'),
+(818,'Herbs and Spices'),
+(819,'Herbs and Spices'),
+(820,'Herbs and Spices'),
+(821,'Herbs and Spices'),
+(822,'Herbs and Spices'),
+(823,'Herbs and Spices'),
+(824,'Herbs and Spices'),
+(825,'Herbs and Spices'),
+(826,'Herbs and Spices'),
+(827,'Herbs and Spices'),
+(828,'Herbs and Spices'),
+(829,'Herbs and Spices'),
+(830,'Herbs and Spices'),
+(831,'Herbs and Spices'),
+(832,'Herbs and Spices'),
+(833,'Herbs and Spices'),
+(834,'Herbs and Spices'),
+(835,'Herbs and Spices'),
+(836,'Herbs and Spices'),
+(837,'Herbs and Spices'),
+(838,'Herbs and Spices'),
+(839,'Herbs and Spices'),
+(840,'Herbs and Spices'),
+(841,'Herbs and Spices'),
+(842,'Herbs and Spices'),
+(843,'Herbs and Spices'),
+(844,'Herbs and Spices'),
+(845,'Herbs and Spices'),
+(846,'Herbs and Spices'),
+(847,'Herbs and Spices'),
+(848,'Herbs and Spices'),
+(849,'Herbs and Spices'),
+(850,'Herbs and Spices'),
+(851,'Herbs and Spices'),
+(852,'Herbs and Spices'),
+(853,'Herbs and Spices'),
+(854,'Herbs and Spices'),
+(855,'Herbs and Spices'),
+(856,'Herbs and Spices'),
+(857,'Herbs and Spices'),
+(858,'Herbs and Spices'),
+(859,'Herbs and Spices'),
+(860,'Herbs and Spices'),
+(861,'Herbs and Spices'),
+(862,'Herbs and Spices'),
+(863,'Herbs and Spices'),
+(864,'Herbs and Spices'),
+(865,'Herbs and Spices'),
+(866,'Herbs and Spices'),
+(867,'Herbs and Spices'),
+(868,'Herbs and Spices'),
+(869,'Herbs and Spices'),
+(870,'Herbs and Spices'),
+(871,'Herbs and Spices'),
+(872,'Herbs and Spices'),
+(873,'Herbs and Spices'),
+(874,'Herbs and Spices'),
+(875,'Herbs and Spices'),
+(876,'Herbs and Spices'),
+(877,'Herbs and Spices'),
+(878,'Herbs and Spices'),
+(879,'Herbs and Spices'),
+(880,'Herbs and Spices'),
+(881,'Herbs and Spices'),
+(882,'Herbs and Spices'),
+(883,'Herbs and Spices'),
+(884,'Herbs and Spices'),
+(885,'Herbs and Spices'),
+(886,'Herbs and Spices'),
+(887,'Herbs and Spices'),
+(888,'Herbs and Spices'),
+(889,'Herbs and Spices'),
+(890,'Herbs and Spices'),
+(891,'Herbs and Spices'),
+(892,'Herbs and Spices'),
+(893,'Herbs and Spices'),
+(894,'Herbs and Spices'),
+(895,'Herbs and Spices'),
+(896,'Herbs and Spices'),
+(897,'Herbs and Spices'),
+(898,'Herbs and Spices'),
+(899,'Herbs and Spices'),
+(900,'Pulses and Beans'),
+(901,'Pulses and Beans'),
+(902,'Pulses and Beans'),
+(903,'Pulses and Beans'),
+(904,'Pulses and Beans'),
+(905,'Pulses and Beans'),
+(906,'Pulses and Beans'),
+(907,'Pulses and Beans'),
+(908,'Pulses and Beans'),
+(909,'Pulses and Beans'),
+(910,'Pulses and Beans'),
+(911,'Pulses and Beans'),
+(912,'Pulses and Beans'),
+(913,'Pulses and Beans'),
+(914,'Pulses and Beans'),
+(915,'Pulses and Beans'),
+(916,'Pulses and Beans'),
+(917,'Pulses and Beans'),
+(918,'Pulses and Beans'),
+(919,'Pulses and Beans'),
+(920,'Pulses and Beans'),
+(921,'Pulses and Beans'),
+(922,'Pulses and Beans'),
+(923,'Pulses and Beans'),
+(924,'Pulses and Beans'),
+(925,'Pulses and Beans'),
+(926,'Pulses and Beans'),
+(927,'Pulses and Beans'),
+(928,'Pulses and Beans'),
+(929,'Pulses and Beans'),
+(930,'Pulses and Beans'),
+(931,'Pulses and Beans'),
+(932,'Pulses and Beans'),
+(933,'Pulses and Beans'),
+(934,'Pulses and Beans'),
+(935,'Pulses and Beans'),
+(936,'Pulses and Beans'),
+(937,'Pulses and Beans'),
+(938,'Flour'),
+(939,'Flour'),
+(940,'Flour'),
+(941,'Flour'),
+(942,'Flour'),
+(943,'Flour'),
+(944,'Flour'),
+(945,'Flour'),
+(946,'Flour'),
+(947,'Flour'),
+(948,'Flour'),
+(949,'Flour'),
+(950,'Flour'),
+(951,'Flour'),
+(952,'Flour'),
+(953,'Flour'),
+(954,'Flour'),
+(955,'Flour'),
+(956,'Flour'),
+(957,'Salt'),
+(958,'Salt'),
+(959,'Salt'),
+(960,'Oil'),
+(961,'Oil'),
+(962,'Oil'),
+(963,'Oil'),
+(964,'Oil'),
+(965,'Oil'),
+(966,'Oil'),
+(967,'Oil'),
+(968,'Oil'),
+(969,'Oil'),
+(970,'Oil'),
+(971,'Oil'),
+(972,'Oil'),
+(973,'Oil'),
+(974,'Oil'),
+(975,'Oil'),
+(976,'Oil'),
+(977,'Oil'),
+(978,'Oil'),
+(979,'Oil'),
+(980,'Oil'),
+(981,'Oil'),
+(982,'Oil'),
+(983,'Oil'),
+(984,'Oil'),
+(985,'Oil'),
+(986,'Oil'),
+(987,'Oil'),
+(988,'Oil'),
+(989,'Oil'),
+(990,'Oil'),
+(991,'Oil'),
+(992,'Oil'),
+(993,'Oil'),
+(994,'Oil'),
+(995,'Oil'),
+(996,'Oil'),
+(997,'Oil'),
+(998,'Oil'),
+(999,'Oil'),
+(1000,'Rice'),
+(1001,'Rice'),
+(1002,'Rice'),
+(1003,'Rice'),
+(1004,'Rice'),
+(1005,'Rice'),
+(1006,'Rice'),
+(1007,'Rice'),
+(1008,'Rice'),
+(1009,'Rice'),
+(1010,'Rice'),
+(1011,'Rice'),
+(1012,'Rice'),
+(1013,'Rice'),
+(1014,'Rice'),
+(1015,'Rice'),
+(1016,'Rice'),
+(1017,'Rice'),
+(1018,'Rice'),
+(1019,'Rice'),
+(1020,'Rice'),
+(1021,'Rice'),
+(1022,'Rice'),
+(1023,'Rice'),
+(1024,'Rice'),
+(1025,'Rice'),
+(1026,'Rice'),
+(1027,'Pasta'),
+(1028,'Pasta'),
+(1029,'Pasta'),
+(1030,'Pasta'),
+(1031,'Pasta'),
+(1032,'Pasta'),
+(1033,'Pasta'),
+(1034,'Pasta'),
+(1035,'Pasta'),
+(1036,'Pasta'),
+(1037,'Pasta'),
+(1038,'Pasta'),
+(1039,'Pasta'),
+(1040,'Pasta'),
+(1041,'Pasta'),
+(1042,'Pasta'),
+(1043,'Pasta'),
+(1044,'Pasta'),
+(1045,'Pasta'),
+(1046,'Pasta'),
+(1047,'Pasta'),
+(1048,'Pasta'),
+(1049,'Pasta'),
+(1050,'Pasta'),
+(1051,'Pasta'),
+(1052,'Pasta'),
+(1053,'Pasta'),
+(1054,'Pasta'),
+(1055,'Pasta'),
+(1056,'Pasta'),
+(1057,'Pasta'),
+(1058,'Pasta'),
+(1059,'Pasta'),
+(1060,'Pasta'),
+(1061,'Pasta'),
+(1062,'Pasta'),
+(1063,'Pasta'),
+(1064,'Pasta'),
+(1065,'Pasta'),
+(1066,'Pasta'),
+(1067,'Pasta'),
+(1068,'Pasta'),
+(1069,'Pasta'),
+(1070,'Noodles'),
+(1071,'Noodles'),
+(1072,'Noodles'),
+(1073,'Noodles'),
+(1074,'Noodles'),
+(1075,'Noodles'),
+(1076,'Noodles'),
+(1077,'Stock'),
+(1078,'Stock'),
+(1079,'Stock'),
+(1080,'Stock'),
+(1081,'Stock'),
+(1082,'Stock'),
+(1083,'Stock'),
+(1084,'Stock'),
+(1085,'Baking Essentials'),
+(1086,'Baking Essentials'),
+(1087,'Baking Essentials'),
+(1088,'Baking Essentials'),
+(1089,'Baking Essentials'),
+(1090,'Baking Essentials'),
+(1091,'Baking Essentials'),
+(1092,'Baking Essentials'),
+(1093,'Baking Essentials'),
+(1094,'Baking Essentials'),
+(1095,'Baking Essentials'),
+(1096,'Baking Essentials'),
+(1097,'Baking Essentials'),
+(1098,'Baking Essentials'),
+(1099,'Baking Essentials'),
+(1100,'Baking Essentials'),
+(1101,'Baking Essentials'),
+(1102,'Baking Essentials'),
+(1103,'Baking Essentials'),
+(1104,'Baking Essentials'),
+(1105,'Baking Essentials'),
+(1106,'Baking Essentials'),
+(1107,'Baking Essentials'),
+(1108,'Baking Essentials'),
+(1109,'Baking Essentials'),
+(1110,'Baking Essentials'),
+(1111,'Baking Essentials'),
+(1112,'Baking Essentials'),
+(1113,'Baking Essentials'),
+(1114,'Baking Essentials'),
+(1115,'Baking Essentials'),
+(1116,'Baking Essentials'),
+(1117,'Baking Essentials'),
+(1118,'Baking Essentials'),
+(1119,'Baking Essentials'),
+(1120,'Baking Essentials'),
+(1121,'Baking Essentials'),
+(1122,'Baking Essentials'),
+(1123,'Baking Essentials'),
+(1124,'Baking Essentials'),
+(1125,'Baking Essentials'),
+(1126,'Baking Essentials'),
+(1127,'Baking Essentials'),
+(1128,'Baking Essentials'),
+(1129,'Baking Essentials'),
+(1130,'Baking Essentials'),
+(1131,'Baking Essentials'),
+(1132,'Baking Essentials'),
+(1133,'Baking Essentials'),
+(1134,'Baking Essentials'),
+(1135,'Baking Essentials'),
+(1136,'Baking Essentials'),
+(1137,'Baking Essentials'),
+(1138,'Baking Essentials'),
+(1139,'Baking Essentials'),
+(1140,'Baking Essentials'),
+(1141,'Packaged Tomatoes'),
+(1142,'Packaged Tomatoes'),
+(1143,'Packaged Tomatoes'),
+(1144,'Packaged Tomatoes'),
+(1145,'Packaged Tomatoes'),
+(1146,'Packaged Tomatoes'),
+(1147,'Packaged Tomatoes'),
+(1148,'Packaged Tomatoes'),
+(1149,'Packaged Tomatoes'),
+(1150,'Packaged Tomatoes'),
+(1151,'Packaged Tomatoes'),
+(1152,'Packaged Tomatoes'),
+(1153,'Packaged Tomatoes'),
+(1154,'Packaged Tomatoes'),
+(1155,'Packaged Tomatoes')
+    ) AS c(id, category)
+    WHERE i.id = c.id AND i.user_id IS NULL;
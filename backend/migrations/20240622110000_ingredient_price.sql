@@ -0,0 +1,1043 @@
+--
+-- Add prices to ingredients
+--
+ALTER TABLE "public"."ingredient" ADD COLUMN "price" integer;
+
+COMMENT ON COLUMN "public"."ingredient"."price" IS 'The retail price of one purchase quantity, in pence.';
+
+--
+-- Create Ingredient Price table
+--
+DROP TABLE IF EXISTS "ingredient_price" CASCADE;
+CREATE TABLE "public"."ingredient_price" (
+    "user_id" integer NOT NULL,
+    "ingredient_id" integer NOT NULL,
+    "price" integer NOT NULL,
+    CONSTRAINT "ingredient_price_pkey" PRIMARY KEY ("user_id", "ingredient_id")
+) WITH (oids = false);
+
+COMMENT ON TABLE "public"."ingredient_price" IS 'A user''s own price for an ingredient, used in place of the retail price.';
+
+ALTER TABLE ONLY "public"."ingredient_price" ADD CONSTRAINT "ingredient_price_user_id_fkey" FOREIGN KEY (user_id) REFERENCES useraccount(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
+ALTER TABLE ONLY "public"."ingredient_price" ADD CONSTRAINT "ingredient_price_ingredient_id_fkey" FOREIGN KEY (ingredient_id) REFERENCES ingredient(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
+
+--
+-- Price global ingredients from the scraped retail prices
+--
+UPDATE ingredient AS i SET price = p.price
+    FROM (VALUES
+(1,169),
+(2,169),
+(3,335),
+(4,300),
+(5,300),
+(6,250),
+(7,240),
+(8,145),
+(9,120),
+(10,145),
+(11,145),
+(12,215),
+(13,120),
+(14,120),
+(15,190),
+(16,200),
+(17,90),
+(18,200),
+(19,135),
+(20,215),
+(21,155),
+(22,90),
+(23,190),
+(24,90),
+(25,155),
+(26,135),
+(27,135),
+(28,155),
+(29,100),
+(30,135),
+(31,349),
+(32,450),
+(33,235),
+(34,442),
+(35,649),
+(36,515),
+(37,175),
+(38,325),
+(39,200),
+(40,200),
+(41,499),
+(42,200),
+(43,239),
+(44,285),
+(45,249),
+(46,550),
+(48,200),
+(49,425),
+(51,275),
+(52,325),
+(53,875),
+(54,426),
+(55,200),
+(57,219),
+(58,250),
+(59,385),
+(60,250),
+(61,325),
+(62,216),
+(64,350),
+(65,259),
+(66,259),
+(67,325),
+(68,110),
+(70,360),
+(71,283),
+(72,400),
+(73,450),
+(74,300),
+(76,524),
+(77,550),
+(78,200),
+(79,395),
+(80,660),
+(81,450),
+(82,290),
+(83,379),
+(85,199),
+(86,110),
+(87,216),
+(88,175),
+(89,200),
+(90,475),
+(91,270),
+(93,330),
+(95,300),
+(96,69),
+(98,338),
+(99,475),
+(100,225),
+(101,325),
+(102,199),
+(104,350),
+(105,450),
+(106,400),
+(108,675),
+(109,160),
+(110,216),
+(111,229),
+(113,325),
+(114,475),
+(115,650),
+(116,570),
+(117,275),
+(118,325),
+(120,500),
+(121,425),
+(122,600),
+(126,250),
+(127,500),
+(129,475),
+(130,349),
+(131,200),
+(132,375),
+(134,370),
+(135,450),
+(138,375),
+(139,450),
+(140,300),
+(141,545),
+(142,600),
+(143,233),
+(144,525),
+(145,500),
+(146,525),
+(147,575),
+(148,110),
+(150,325),
+(152,650),
+(153,525),
+(155,200),
+(156,216),
+(157,200),
+(158,450),
+(159,725),
+(161,550),
+(163,550),
+(164,200),
+(165,290),
+(166,110),
+(167,425),
+(168,525),
+(170,110),
+(171,625),
+(172,480),
+(175,400),
+(176,650),
+(180,625),
+(181,400),
+(182,800),
+(183,300),
+(184,230),
+(185,210),
+(186,300),
+(187,300),
+(188,340),
+(189,255),
+(190,130),
+(191,255),
+(192,300),
+(193,265),
+(194,285),
+(195,165),
+(196,130),
+(197,300),
+(198,145),
+(199,169),
+(200,280),
+(201,445),
+(202,177),
+(203,130),
+(204,380),
+(205,430),
+(206,176),
+(207,250),
+(208,300),
+(209,445),
+(210,180),
+(211,300),
+(212,255),
+(214,280),
+(215,140),
+(216,255),
+(217,140),
+(219,320),
+(220,120),
+(221,275),
+(222,390),
+(223,255),
+(224,255),
+(226,240),
+(228,280),
+(229,177),
+(230,265),
+(231,250),
+(232,265),
+(233,445),
+(234,315),
+(235,235),
+(236,320),
+(237,180),
+(239,225),
+(240,250),
+(241,225),
+(242,275),
+(244,250),
+(245,170),
+(246,300),
+(247,125),
+(248,290),
+(249,325),
+(250,320),
+(251,380),
+(252,280),
+(253,410),
+(254,265),
+(255,235),
+(256,255),
+(257,255),
+(258,155),
+(259,199),
+(260,235),
+(261,210),
+(262,265),
+(263,140),
+(264,265),
+(265,380),
+(266,200),
+(267,175),
+(268,410),
+(269,265),
+(270,265),
+(271,225),
+(272,300),
+(273,355),
+(274,235),
+(275,300),
+(276,320),
+(277,410),
+(278,265),
+(279,400),
+(280,375),
+(281,215),
+(282,340),
+(283,280),
+(284,232),
+(286,375),
+(287,265),
+(289,255),
+(290,365),
+(291,355),
+(293,660),
+(294,235),
+(295,275),
+(296,245),
+(297,320),
+(298,235),
+(299,200),
+(300,395),
+(301,200),
+(302,265),
+(303,250),
+(304,270),
+(307,570),
+(308,255),
+(309,380),
+(310,400),
+(311,450),
+(312,280),
+(313,325),
+(314,250),
+(315,400),
+(316,450),
+(317,225),
+(318,315),
+(319,185),
+(320,265),
+(321,150),
+(322,265),
+(323,320),
+(324,250),
+(325,270),
+(326,450),
+(327,250),
+(328,85),
+(329,145),
+(330,99),
+(331,99),
+(332,145),
+(333,125),
+(334,125),
+(335,99),
+(336,125),
+(338,78),
+(339,180),
+(340,200),
+(341,180),
+(342,190),
+(343,125),
+(344,170),
+(345,180),
+(346,30),
+(347,100),
+(348,375),
+(349,170),
+(350,315),
+(351,100),
+(352,225),
+(353,230),
+(354,280),
+(355,135),
+(356,24),
+(357,170),
+(358,290),
+(359,225),
+(360,160),
+(361,190),
+(363,189),
+(364,150),
+(365,150),
+(366,200),
+(367,230),
+(368,40),
+(369,129),
+(370,55),
+(371,95),
+(373,230),
+(375,110),
+(376,120),
+(377,180),
+(378,130),
+(379,200),
+(380,260),
+(381,130),
+(382,375),
+(383,235),
+(384,35),
+(385,200),
+(386,170),
+(387,150),
+(388,80),
+(389,220),
+(390,30),
+(391,279),
+(393,50),
+(394,260),
+(395,315),
+(396,150),
+(397,220),
+(398,200),
+(399,250),
+(400,135),
+(401,170),
+(403,40),
+(404,125),
+(405,30),
+(407,30),
+(408,250),
+(409,350),
+(410,35),
+(411,250),
+(412,350),
+(413,175),
+(414,250),
+(415,135),
+(416,275),
+(419,200),
+(421,210),
+(422,140),
+(424,260),
+(425,135),
+(426,210),
+(427,260),
+(428,135),
+(429,320),
+(430,250),
+(431,235),
+(432,235),
+(433,250),
+(434,100),
+(435,130),
+(440,250),
+(442,135),
+(443,235),
+(444,140),
+(445,150),
+(446,235),
+(447,250),
+(448,235),
+(449,125),
+(450,235),
+(451,235),
+(452,250),
+(453,235),
+(454,135),
+(455,300),
+(457,235),
+(458,170),
+(460,89),
+(462,225),
+(463,65),
+(464,179),
+(465,59),
+(466,150),
+(467,89),
+(468,59),
+(469,190),
+(472,120),
+(473,135),
+(474,109),
+(475,110),
+(476,69),
+(477,120),
+(479,120),
+(480,135),
+(481,69),
+(483,95),
+(486,125),
+(487,24),
+(488,100),
+(489,100),
+(490,95),
+(491,110),
+(494,110),
+(495,52),
+(496,150),
+(497,150),
+(498,59),
+(499,129),
+(500,160),
+(501,160),
+(502,69),
+(503,119),
+(504,55),
+(505,100),
+(506,115),
+(507,155),
+(508,85),
+(510,62),
+(511,52),
+(512,50),
+(513,52),
+(514,115),
+(515,185),
+(516,149),
+(519,170),
+(521,59),
+(523,95),
+(524,150),
+(526,99),
+(528,110),
+(529,199),
+(530,140),
+(531,100),
+(532,110),
+(533,60),
+(534,50),
+(535,150),
+(536,75),
+(537,130),
+(538,230),
+(539,140),
+(540,135),
+(542,100),
+(543,160),
+(544,52),
+(545,160),
+(546,120),
+(547,125),
+(548,200),
+(549,65),
+(550,85),
+(551,125),
+(552,135),
+(553,140),
+(554,80),
+(555,160),
+(556,220),
+(557,160),
+(558,270),
+(559,75),
+(560,80),
+(561,140),
+(562,75),
+(563,139),
+(564,160),
+(565,100),
+(566,135),
+(567,52),
+(568,40),
+(569,150),
+(570,80),
+(571,165),
+(572,190),
+(573,160),
+(575,150),
+(576,210),
+(577,80),
+(578,100),
+(579,180),
+(581,175),
+(582,60),
+(583,140),
+(584,107),
+(585,150),
+(586,160),
+(587,200),
+(588,100),
+(589,74),
+(590,125),
+(593,160),
+(594,175),
+(595,175),
+(596,59),
+(597,125),
+(598,140),
+(599,150),
+(600,190),
+(601,121),
+(602,120),
+(603,140),
+(605,180),
+(606,260),
+(607,140),
+(608,160),
+(609,100),
+(610,52),
+(611,125),
+(612,135),
+(613,110),
+(614,255),
+(615,240),
+(616,150),
+(618,50),
+(619,95),
+(620,65),
+(621,175),
+(622,170),
+(623,160),
+(624,215),
+(625,150),
+(626,130),
+(627,125),
+(628,155),
+(629,140),
+(630,140),
+(631,125),
+(632,185),
+(633,250),
+(634,60),
+(635,200),
+(636,160),
+(637,275),
+(638,150),
+(640,200),
+(641,110),
+(642,180),
+(643,200),
+(644,135),
+(646,130),
+(647,225),
+(648,120),
+(649,95),
+(650,150),
+(651,235),
+(652,52),
+(653,130),
+(654,150),
+(655,250),
+(656,160),
+(657,175),
+(659,120),
+(661,225),
+(662,60),
+(663,60),
+(664,215),
+(666,175),
+(667,135),
+(668,160),
+(669,99),
+(670,65),
+(671,140),
+(672,230),
+(674,275),
+(675,225),
+(676,130),
+(677,60),
+(678,150),
+(680,120),
+(681,140),
+(682,180),
+(683,150),
+(685,210),
+(686,90),
+(687,120),
+(688,120),
+(689,170),
+(690,120),
+(691,150),
+(692,150),
+(693,160),
+(695,200),
+(696,225),
+(697,160),
+(698,160),
+(699,150),
+(700,100),
+(701,150),
+(702,150),
+(703,175),
+(704,200),
+(705,200),
+(706,200),
+(707,160),
+(709,160),
+(710,70),
+(711,215),
+(712,200),
+(714,150),
+(715,200),
+(716,75),
+(717,225),
+(718,225),
+(719,160),
+(720,160),
+(721,150),
+(723,110),
+(724,120),
+(725,200),
+(726,250),
+(727,225),
+(728,150),
+(730,220),
+(731,235),
+(732,180),
+(734,175),
+(735,120),
+(736,120),
+(737,160),
+(738,160),
+(740,160),
+(741,85),
+(742,120),
+(743,75),
+(744,75),
+(745,75),
+(746,180),
+(747,180),
+(748,160),
+(749,125),
+(750,75),
+(751,210),
+(752,180),
+(753,120),
+(754,99),
+(755,180),
+(756,100),
+(757,150),
+(758,210),
+(759,180),
+(760,80),
+(761,85),
+(762,100),
+(763,125),
+(764,210),
+(765,120),
+(766,210),
+(767,85),
+(768,150),
+(769,130),
+(770,145),
+(772,115),
+(773,115),
+(774,140),
+(775,260),
+(776,75),
+(777,65),
+(778,80),
+(779,210),
+(780,110),
+(781,210),
+(782,150),
+(783,180),
+(784,169),
+(785,65),
+(786,190),
+(788,85),
+(789,50),
+(790,155),
+(792,120),
+(793,190),
+(794,315),
+(796,115),
+(797,110),
+(798,40),
+(799,315),
+(800,65),
+(801,155),
+(802,80),
+(804,275),
+(806,210),
+(807,100),
+(808,110),
+(809,110),
+(810,110),
+(811,110),
+(812,110),
+(813,110),
+(814,110),
+(815,110),
+(816,110),
+(817,110),
+(818,110),
+(819,110),
+(820,125),
+(821,125),
+(822,110),
+(823,110),
+(824,110),
+(825,250),
+(826,110),
+(827,110),
+(828,110),
+(829,110),
+(830,110),
+(831,125),
+(832,125),
+(833,110),
+(834,470),
+(835,125),
+(836,110),
+(837,125),
+(838,110),
+(839,110),
+(840,110),
+(841,225),
+(842,125),
+(843,125),
+(844,110),
+(845,110),
+(846,165),
+(847,110),
+(848,110),
+(849,110),
+(850,110),
+(851,110),
+(852,245),
+(853,125),
+(854,140),
+(855,110),
+(856,180),
+(857,110),
+(858,125),
+(859,250),
+(860,110),
+(861,110),
+(862,155),
+(863,140),
+(864,110),
+(865,125),
+(866,245),
+(867,110),
+(868,110),
+(869,110),
+(870,110),
+(871,110),
+(872,110),
+(873,240),
+(874,110),
+(875,110),
+(876,110),
+(877,110),
+(878,110),
+(879,110),
+(880,140),
+(881,315),
+(883,125),
+(884,125),
+(885,125),
+(886,155),
+(887,155),
+(888,125),
+(889,125),
+(890,125),
+(891,110),
+(892,125),
+(893,125),
+(894,125),
+(895,125),
+(897,155),
+(898,110),
+(899,320),
+(900,49),
+(901,49),
+(902,48),
+(903,48),
+(904,49),
+(905,55),
+(906,100),
+(907,105),
+(908,95),
+(909,50),
+(910,105),
+(911,54),
+(912,260),
+(913,59),
+(914,105),
+(915,95),
+(916,105),
+(917,105),
+(918,59),
+(919,190),
+(920,190),
+(921,50),
+(922,105),
+(923,50),
+(924,250),
+(925,190),
+(926,300),
+(927,210),
+(928,190),
+(929,100),
+(930,250),
+(931,190),
+(932,250),
+(933,190),
+(934,190),
+(935,145),
+(936,190),
+(937,150),
+(938,79),
+(939,79),
+(940,130),
+(941,45),
+(942,245),
+(943,130),
+(944,45),
+(945,160),
+(946,150),
+(947,135),
+(948,160),
+(949,150),
+(950,160),
+(951,135),
+(952,100),
+(953,135),
+(954,75),
+(955,110),
+(956,160),
+(957,120),
+(958,175),
+(959,200),
+(960,185),
+(961,840),
+(962,215),
+(963,780),
+(964,575),
+(965,240),
+(966,650),
+(967,200),
+(968,360),
+(969,700),
+(970,520),
+(971,1610),
+(972,330),
+(973,350),
+(974,330),
+(975,430),
+(976,270),
+(977,190),
+(978,175),
+(979,350),
+(980,780),
+(981,750),
+(982,330),
+(983,160),
+(984,220),
+(985,1540),
+(986,645),
+(987,350),
+(988,575),
+(989,840),
+(990,430),
+(991,200),
+(992,200),
+(993,160),
+(994,160),
+(995,770),
+(996,770),
+(997,410),
+(998,300),
+(999,995),
+(1000,185),
+(1001,240),
+(1002,125),
+(1003,92),
+(1004,120),
+(1005,240),
+(1006,185),
+(1007,365),
+(1008,69),
+(1009,63),
+(1010,235),
+(1011,160),
+(1012,60),
+(1013,150),
+(1014,240),
+(1015,190),
+(1016,125),
+(1017,735),
+(1018,115),
+(1019,325),
+(1020,150),
+(1021,150),
+(1022,350),
+(1023,170),
+(1024,390),
+(1025,320),
+(1026,235),
+(1029,75),
+(1035,75),
+(1044,75),
+(1052,200),
+(1054,72),
+(1058,75),
+(1063,275),
+(1067,200),
+(1070,115),
+(1071,100),
+(1072,115),
+(1073,115),
+(1074,115),
+(1075,100),
+(1076,100),
+(1077,100),
+(1078,100),
+(1079,100),
+(1080,240),
+(1081,240),
+(1082,160),
+(1083,240),
+(1084,250),
+(1085,99),
+(1086,109),
+(1087,195),
+(1088,99),
+(1089,149),
+(1090,185),
+(1091,90),
+(1092,89),
+(1093,120),
+(1094,410),
+(1095,90),
+(1096,375),
+(1097,170),
+(1098,430),
+(1099,275),
+(1100,395),
+(1101,255),
+(1102,180),
+(1103,61),
+(1104,100),
+(1105,155),
+(1106,160),
+(1107,345),
+(1108,230),
+(1109,59),
+(1110,245),
+(1111,260),
+(1112,500),
+(1113,200),
+(1114,180),
+(1115,175),
+(1116,175),
+(1117,285),
+(1118,175),
+(1119,175),
+(1120,300),
+(1121,175),
+(1122,175),
+(1123,195),
+(1124,200),
+(1125,195),
+(1126,120),
+(1127,195),
+(1128,240),
+(1129,240),
+(1130,275),
+(1131,195),
+(1132,200),
+(1133,300),
+(1134,150),
+(1135,275),
+(1136,200),
+(1137,600),
+(1138,450),
+(1139,130),
+(1140,235),
+(1141,59),
+(1142,55),
+(1143,49),
+(1144,40),
+(1145,49),
+(1146,110),
+(1147,170),
+(1148,55),
+(1149,300),
+(1150,40),
+(1151,125),
+(1152,135),
+(1153,100),
+(1154,160),
+(1155,110)
+    ) AS p(id, price)
+    WHERE i.id = p.id AND i.user_id IS NULL;
@@ -0,0 +1,17 @@
+--
+-- Add nutrition per 100g/ml to ingredients
+--
+ALTER TABLE "public"."ingredient"
+    ADD COLUMN "energy" double precision,
+    ADD COLUMN "protein" double precision,
+    ADD COLUMN "fat" double precision,
+    ADD COLUMN "carbohydrate" double precision,
+    ADD COLUMN "fibre" double precision,
+    ADD COLUMN "salt" double precision;
+
+COMMENT ON COLUMN "public"."ingredient"."energy" IS 'Energy per 100g/ml, in kcal. Nutrition is only known if this is present.';
+COMMENT ON COLUMN "public"."ingredient"."protein" IS 'Protein per 100g/ml, in grams.';
+COMMENT ON COLUMN "public"."ingredient"."fat" IS 'Fat per 100g/ml, in grams.';
+COMMENT ON COLUMN "public"."ingredient"."carbohydrate" IS 'Carbohydrate per 100g/ml, in grams.';
+COMMENT ON COLUMN "public"."ingredient"."fibre" IS 'Fibre per 100g/ml, in grams.';
+COMMENT ON COLUMN "public"."ingredient"."salt" IS 'Salt per 100g/ml, in grams.';
@@ -0,0 +1,129 @@
+--
+-- Add allergen and diet flags to ingredients
+--
+ALTER TABLE "public"."ingredient"
+    ADD COLUMN "allergens" character varying[] DEFAULT '{}' NOT NULL,
+    ADD COLUMN "vegetarian" boolean DEFAULT true NOT NULL,
+    ADD COLUMN "vegan" boolean DEFAULT true NOT NULL;
+
+COMMENT ON COLUMN "public"."ingredient"."allergens" IS 'The allergens the ingredient contains, e.g. gluten or dairy.';
+COMMENT ON COLUMN "public"."ingredient"."vegetarian" IS 'False if the ingredient contains meat or fish.';
+COMMENT ON COLUMN "public"."ingredient"."vegan" IS 'False if the ingredient contains any animal product.';
+
+--
+-- Create Diet Profile table
+--
+DROP TABLE IF EXISTS "diet_profile" CASCADE;
+CREATE TABLE "public"."diet_profile" (
+    "user_id" integer NOT NULL,
+    "vegetarian" boolean DEFAULT false NOT NULL,
+    "vegan" boolean DEFAULT false NOT NULL,
+    "allergens" character varying[] DEFAULT '{}' NOT NULL,
+    CONSTRAINT "diet_profile_pkey" PRIMARY KEY ("user_id")
+) WITH (oids = false);
+
+COMMENT ON TABLE "public"."diet_profile" IS 'The diet a user follows and the allergens they avoid. Planned recipes are checked against it.';
+
+ALTER TABLE ONLY "public"."diet_profile" ADD CONSTRAINT "diet_profile_user_id_fkey" FOREIGN KEY (user_id) REFERENCES useraccount(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
+
+--
+-- Flag global ingredients from their category and name. These are deliberately cautious - a
+-- warning about an ingredient that is actually safe is better than a missed one.
+--
+UPDATE ingredient SET vegetarian = false, vegan = false
+    WHERE user_id IS NULL AND (
+        (category = 'Meat and Fish Essentials' AND name NOT ILIKE '%yorkshire pudding%')
+        OR (category = 'Stock' AND (name ILIKE '%chicken%' OR name ILIKE '%beef%'))
+        OR name ILIKE '%prawn%'
+    );
+
+UPDATE ingredient SET vegan = false
+    WHERE user_id IS NULL AND (
+        category IN ('Butter', 'Milk', 'Cheese', 'Eggs')
+        OR name ILIKE '%cream%'
+        OR name ILIKE '%buttermilk%'
+        OR name ILIKE '%milk chocolate%'
+        OR name ILIKE '%milk chips%'
+        OR name ILIKE '%meringue%'
+        OR name ~* '\meggs?\M'
+        OR name ILIKE '%honey%'
+        OR name ILIKE '%yorkshire pudding%'
+        OR name ILIKE '%parsley sauce%'
+    );
+
+UPDATE ingredient SET allergens = array_append(allergens, 'dairy')
+    WHERE user_id IS NULL AND (
+        category IN ('Butter', 'Milk', 'Cheese')
+        OR name ILIKE '%cream%'
+        OR name ILIKE '%buttermilk%'
+        OR name ILIKE '%milk chocolate%'
+        OR name ILIKE '%milk chips%'
+        OR name ILIKE '%yorkshire pudding%'
+        OR name ILIKE '%parsley sauce%'
+    );
+
+UPDATE ingredient SET allergens = array_append(allergens, 'egg')
+    WHERE user_id IS NULL AND (
+        category = 'Eggs'
+        OR name ~* '\meggs?\M'
+        OR name ILIKE '%meringue%'
+        OR name ILIKE '%yorkshire pudding%'
+    );
+
+UPDATE ingredient SET allergens = array_append(allergens, 'gluten')
+    WHERE user_id IS NULL AND name NOT ILIKE '%gluten free%' AND (
+        (category = 'Flour' AND name NOT ILIKE '%corn%' AND name NOT ILIKE '%rice%' AND name NOT ILIKE '%gram%')
+        OR category IN ('Bread', 'Pasta')
+        OR (name ILIKE '%noodle%' AND name NOT ILIKE '%rice%')
+        OR name ILIKE '%breaded%'
+        OR name ILIKE '%battered%'
+        OR name ILIKE '%yorkshire pudding%'
+        OR name ILIKE '%couscous%'
+        OR name ~* '\moats\M'
+        OR name ILIKE '%sponge%'
+        OR name ILIKE '%fishcake%'
+        OR name ILIKE '%stock cube%'
+    );
+
+UPDATE ingredient SET allergens = array_append(allergens, 'fish')
+    WHERE user_id IS NULL AND (
+        name ILIKE '%salmon%'
+        OR name ILIKE '%mackerel%'
+        OR name ~* '\mcod\M'
+        OR name ILIKE '%sea bass%'
+        OR name ILIKE '%trout%'
+        OR name ILIKE '%tuna%'
+        OR name ILIKE '%anchov%'
+        OR name ILIKE '%sardine%'
+        OR name ILIKE '%fishcake%'
+    );
+
+UPDATE ingredient SET allergens = array_append(allergens, 'crustaceans')
+    WHERE user_id IS NULL AND name ILIKE '%prawn%';
+
+UPDATE ingredient SET allergens = array_append(allergens, 'peanuts')
+    WHERE user_id IS NULL AND name ILIKE '%peanut%';
+
+UPDATE ingredient SET allergens = array_append(allergens, 'nuts')
+    WHERE user_id IS NULL AND (
+        name ILIKE '%almond%'
+        OR name ILIKE '%walnut%'
+        OR name ILIKE '%hazelnut%'
+        OR name ILIKE '%cashew%'
+        OR name ILIKE '%pecan%'
+        OR name ILIKE '%pistachio%'
+        OR name ILIKE '%mixed nut%'
+        OR name ILIKE '%marzipan%'
+    );
+
+UPDATE ingredient SET allergens = array_append(allergens, 'soya')
+    WHERE user_id IS NULL AND (name ILIKE '%soya%' OR name ILIKE '%soy sauce%' OR name ILIKE '%tofu%');
+
+UPDATE ingredient SET allergens = array_append(allergens, 'sesame')
+    WHERE user_id IS NULL AND (name ILIKE '%sesame%' OR name ILIKE '%tahini%');
+
+UPDATE ingredient SET allergens = array_append(allergens, 'mustard')
+    WHERE user_id IS NULL AND name ILIKE '%mustard%';
+
+UPDATE ingredient SET allergens = array_append(allergens, 'celery')
+    WHERE user_id IS NULL AND (name ILIKE '%celery%' OR name ILIKE '%celeriac%');
@@ -0,0 +1,23 @@
+--
+-- Convert recipe steps from plain strings to structured steps
+--
+UPDATE recipe SET steps = (
+    SELECT COALESCE(json_agg(
+        CASE WHEN json_typeof(step.value) = 'string' THEN
+            json_build_object(
+                'section', NULL,
+                'text', step.value #>> '{}',
+                'duration', NULL,
+                'timer', false,
+                'ingredients', '[]'::json
+            )
+        ELSE step.value END
+        ORDER BY step.position
+    ), '[]'::json)
+    FROM json_array_elements(recipe.steps) WITH ORDINALITY AS step(value, position)
+)
+WHERE json_typeof(steps) = 'array';
+
+UPDATE recipe SET steps = '[]'::json WHERE json_typeof(steps) <> 'array';
+
+COMMENT ON COLUMN "public"."recipe"."steps" IS 'A JSON array of steps, each with text, an optional section heading, duration and timer, and the ingredients it uses.';
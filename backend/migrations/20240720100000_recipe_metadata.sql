@@ -0,0 +1,17 @@
+--
+-- Add timings and provenance to recipes
+--
+ALTER TABLE "public"."recipe" ADD COLUMN "prep_time" integer;
+ALTER TABLE "public"."recipe" ADD COLUMN "cook_time" integer;
+ALTER TABLE "public"."recipe" ADD COLUMN "total_time" integer;
+ALTER TABLE "public"."recipe" ADD COLUMN "source_url" character varying;
+ALTER TABLE "public"."recipe" ADD COLUMN "notes" text;
+ALTER TABLE "public"."recipe" ADD COLUMN "yield_description" character varying;
+ALTER TABLE "public"."recipe" ADD COLUMN "difficulty" character varying;
+
+COMMENT ON COLUMN "public"."recipe"."prep_time" IS 'Preparation time in minutes.';
+COMMENT ON COLUMN "public"."recipe"."cook_time" IS 'Cooking time in minutes.';
+COMMENT ON COLUMN "public"."recipe"."total_time" IS 'Total time in minutes, including any resting. Defaults to prep_time + cook_time.';
+COMMENT ON COLUMN "public"."recipe"."source_url" IS 'Where the recipe came from, if anywhere.';
+COMMENT ON COLUMN "public"."recipe"."yield_description" IS 'A free-text description of what the recipe makes, e.g. "1 large loaf".';
+COMMENT ON COLUMN "public"."recipe"."difficulty" IS 'One of easy, medium or hard.';
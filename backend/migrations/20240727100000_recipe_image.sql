@@ -0,0 +1,23 @@
+--
+-- Create Recipe Image table
+--
+DROP TABLE IF EXISTS "recipe_image" CASCADE;
+DROP SEQUENCE IF EXISTS recipe_image_id_seq;
+CREATE SEQUENCE recipe_image_id_seq INCREMENT 1 MINVALUE 1 MAXVALUE 2147483647 CACHE 1;
+
+CREATE TABLE "public"."recipe_image" (
+    "id" integer DEFAULT nextval('recipe_image_id_seq') NOT NULL,
+    "recipe_id" integer NOT NULL,
+    "step_index" integer,
+    "key" character varying NOT NULL DEFAULT replace(gen_random_uuid()::text, '-', ''),
+    "extension" character varying NOT NULL,
+    "created_at" timestamp NOT NULL DEFAULT NOW(),
+    CONSTRAINT "recipe_image_pkey" PRIMARY KEY ("id")
+) WITH (oids = false);
+
+COMMENT ON TABLE "public"."recipe_image" IS 'A photo of a recipe, or of one of its steps.';
+COMMENT ON COLUMN "public"."recipe_image"."step_index" IS 'The index of the step the photo shows, or NULL for a photo of the whole recipe.';
+COMMENT ON COLUMN "public"."recipe_image"."key" IS 'A random name the image and its thumbnail are stored under.';
+COMMENT ON COLUMN "public"."recipe_image"."extension" IS 'The file extension of the original image, which determines its content type.';
+
+ALTER TABLE ONLY "public"."recipe_image" ADD CONSTRAINT "recipe_image_recipe_id_fkey" FOREIGN KEY (recipe_id) REFERENCES recipe(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
@@ -0,0 +1,11 @@
+--
+-- Allow recipe lines to use another recipe, e.g. a sauce or dough
+--
+ALTER TABLE "public"."recipe_ingredient" ALTER COLUMN "ingredient_id" DROP NOT NULL;
+ALTER TABLE "public"."recipe_ingredient" ADD COLUMN "sub_recipe_id" integer;
+ALTER TABLE "public"."recipe_ingredient" ADD CONSTRAINT "recipe_ingredient_line_check" CHECK ((ingredient_id IS NULL) <> (sub_recipe_id IS NULL));
+
+COMMENT ON COLUMN "public"."recipe_ingredient"."sub_recipe_id" IS 'A recipe used as an ingredient. Set instead of ingredient_id.';
+COMMENT ON COLUMN "public"."recipe_ingredient"."quantity" IS 'Quantity in the ingredient''s unit, or the number of portions of the sub-recipe.';
+
+ALTER TABLE ONLY "public"."recipe_ingredient" ADD CONSTRAINT "recipe_ingredient_sub_recipe_id_fkey" FOREIGN KEY (sub_recipe_id) REFERENCES recipe(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
@@ -0,0 +1,10 @@
+--
+-- Allow planned meals to be eaten from the leftovers of a meal cooked on an earlier day
+--
+ALTER TABLE "public"."day" ADD COLUMN "portions" integer DEFAULT 1 NOT NULL;
+ALTER TABLE "public"."day" ADD COLUMN "leftovers_of" integer;
+
+COMMENT ON COLUMN "public"."day"."portions" IS 'The number of portions eaten at this meal.';
+COMMENT ON COLUMN "public"."day"."leftovers_of" IS 'The cooked meal this one is eaten from. Null if the recipe is cooked for this meal.';
+
+ALTER TABLE ONLY "public"."day" ADD CONSTRAINT "day_leftovers_of_fkey" FOREIGN KEY (leftovers_of) REFERENCES day(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
@@ -0,0 +1,19 @@
+--
+-- Record whether planned meals were cooked, and how much of each ingredient they actually used
+--
+ALTER TABLE "public"."day" ADD COLUMN "status" character varying(16);
+
+COMMENT ON COLUMN "public"."day"."status" IS 'Either cooked or skipped once the meal has happened. Null while it is still planned.';
+
+DROP TABLE IF EXISTS "day_usage" CASCADE;
+CREATE TABLE "public"."day_usage" (
+    "day_id" integer NOT NULL,
+    "ingredient_id" integer NOT NULL,
+    "quantity" integer NOT NULL,
+    CONSTRAINT "day_usage_pkey" PRIMARY KEY ("day_id", "ingredient_id")
+) WITH (oids = false);
+
+COMMENT ON TABLE "public"."day_usage" IS 'The amount of an ingredient a cooked meal actually used, where it differed from the recipe.';
+
+ALTER TABLE ONLY "public"."day_usage" ADD CONSTRAINT "day_usage_day_id_fkey" FOREIGN KEY (day_id) REFERENCES day(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
+ALTER TABLE ONLY "public"."day_usage" ADD CONSTRAINT "day_usage_ingredient_id_fkey" FOREIGN KEY (ingredient_id) REFERENCES ingredient(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
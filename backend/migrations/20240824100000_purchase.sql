@@ -0,0 +1,20 @@
+--
+-- Record what was actually bought on each shopping trip
+--
+DROP TABLE IF EXISTS "purchase" CASCADE;
+DROP SEQUENCE IF EXISTS purchase_id_seq;
+CREATE SEQUENCE purchase_id_seq INCREMENT 1 MINVALUE 1 MAXVALUE 2147483647 CACHE 1;
+
+CREATE TABLE "public"."purchase" (
+    "id" integer DEFAULT nextval('purchase_id_seq') NOT NULL,
+    "user_id" integer NOT NULL,
+    "ingredient_id" integer NOT NULL,
+    "quantity" integer NOT NULL,
+    "date" date NOT NULL,
+    CONSTRAINT "purchase_pkey" PRIMARY KEY ("id")
+) WITH (oids = false);
+
+COMMENT ON COLUMN "public"."purchase"."quantity" IS 'The total amount bought, in the ingredient''s unit.';
+
+ALTER TABLE ONLY "public"."purchase" ADD CONSTRAINT "purchase_ingredient_id_fkey" FOREIGN KEY (ingredient_id) REFERENCES ingredient(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
+ALTER TABLE ONLY "public"."purchase" ADD CONSTRAINT "purchase_user_id_fkey" FOREIGN KEY (user_id) REFERENCES useraccount(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
@@ -0,0 +1,120 @@
+--
+-- Record when synced rows last changed
+--
+ALTER TABLE "public"."recipe" ADD COLUMN "updated_at" timestamptz DEFAULT now() NOT NULL;
+ALTER TABLE "public"."ingredient" ADD COLUMN "updated_at" timestamptz DEFAULT now() NOT NULL;
+ALTER TABLE "public"."ingredient_price" ADD COLUMN "updated_at" timestamptz DEFAULT now() NOT NULL;
+ALTER TABLE "public"."day" ADD COLUMN "updated_at" timestamptz DEFAULT now() NOT NULL;
+ALTER TABLE "public"."ingredientschedule" ADD COLUMN "updated_at" timestamptz DEFAULT now() NOT NULL;
+
+CREATE FUNCTION set_updated_at() RETURNS trigger AS $$
+BEGIN
+    NEW.updated_at = now();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER "recipe_updated_at" BEFORE UPDATE ON "public"."recipe" FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+CREATE TRIGGER "ingredient_updated_at" BEFORE UPDATE ON "public"."ingredient" FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+CREATE TRIGGER "ingredient_price_updated_at" BEFORE UPDATE ON "public"."ingredient_price" FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+CREATE TRIGGER "day_updated_at" BEFORE UPDATE ON "public"."day" FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+CREATE TRIGGER "ingredientschedule_updated_at" BEFORE UPDATE ON "public"."ingredientschedule" FOR EACH ROW EXECUTE FUNCTION set_updated_at();
+
+--
+-- A recipe changes with its ingredients and images, and a planned meal with its recorded usage
+--
+CREATE FUNCTION touch_recipe() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        UPDATE recipe SET updated_at = now() WHERE id = OLD.recipe_id;
+    ELSE
+        UPDATE recipe SET updated_at = now() WHERE id = NEW.recipe_id;
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE FUNCTION touch_day() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        UPDATE day SET updated_at = now() WHERE id = OLD.day_id;
+    ELSE
+        UPDATE day SET updated_at = now() WHERE id = NEW.day_id;
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER "recipe_ingredient_touch_recipe" AFTER INSERT OR UPDATE OR DELETE ON "public"."recipe_ingredient" FOR EACH ROW EXECUTE FUNCTION touch_recipe();
+CREATE TRIGGER "recipe_image_touch_recipe" AFTER INSERT OR UPDATE OR DELETE ON "public"."recipe_image" FOR EACH ROW EXECUTE FUNCTION touch_recipe();
+CREATE TRIGGER "day_usage_touch_day" AFTER INSERT OR UPDATE OR DELETE ON "public"."day_usage" FOR EACH ROW EXECUTE FUNCTION touch_day();
+
+--
+-- Create Tombstone table
+--
+DROP TABLE IF EXISTS "tombstone" CASCADE;
+DROP SEQUENCE IF EXISTS tombstone_id_seq;
+CREATE SEQUENCE tombstone_id_seq INCREMENT 1 MINVALUE 1 MAXVALUE 2147483647 CACHE 1;
+
+CREATE TABLE "public"."tombstone" (
+    "id" integer DEFAULT nextval('tombstone_id_seq') NOT NULL,
+    "user_id" integer NOT NULL,
+    "entity" character varying(16) NOT NULL,
+    "entity_key" character varying NOT NULL,
+    "deleted_at" timestamptz DEFAULT now() NOT NULL,
+    CONSTRAINT "tombstone_pkey" PRIMARY KEY ("id")
+) WITH (oids = false);
+
+COMMENT ON TABLE "public"."tombstone" IS 'Something a user deleted, so that clients can be told to delete it too. There is no foreign key to the user, as tombstones are written while a deleted user''s rows cascade away.';
+COMMENT ON COLUMN "public"."tombstone"."entity" IS 'What was deleted: recipe, ingredient, ingredient_price, day or schedule.';
+COMMENT ON COLUMN "public"."tombstone"."entity_key" IS 'The ID of what was deleted, or the date for a day.';
+
+--
+-- Write tombstones as rows are deleted
+--
+CREATE FUNCTION record_recipe_deletion() RETURNS trigger AS $$
+BEGIN
+    INSERT INTO tombstone (user_id, entity, entity_key) VALUES (OLD.user_id, 'recipe', OLD.id::text);
+    -- The recipe's planned meals are deleted after it, when their owner can no longer be found.
+    INSERT INTO tombstone (user_id, entity, entity_key)
+        SELECT DISTINCT OLD.user_id, 'day', date::text FROM day WHERE recipe_id = OLD.id;
+    RETURN OLD;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE FUNCTION record_day_deletion() RETURNS trigger AS $$
+BEGIN
+    INSERT INTO tombstone (user_id, entity, entity_key)
+        SELECT user_id, 'day', OLD.date::text FROM recipe WHERE id = OLD.recipe_id;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE FUNCTION record_ingredient_deletion() RETURNS trigger AS $$
+BEGIN
+    IF OLD.user_id IS NOT NULL THEN
+        INSERT INTO tombstone (user_id, entity, entity_key) VALUES (OLD.user_id, 'ingredient', OLD.id::text);
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE FUNCTION record_ingredient_price_deletion() RETURNS trigger AS $$
+BEGIN
+    INSERT INTO tombstone (user_id, entity, entity_key) VALUES (OLD.user_id, 'ingredient_price', OLD.ingredient_id::text);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE FUNCTION record_schedule_deletion() RETURNS trigger AS $$
+BEGIN
+    INSERT INTO tombstone (user_id, entity, entity_key) VALUES (OLD.user_id, 'schedule', OLD.id::text);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER "recipe_tombstone" BEFORE DELETE ON "public"."recipe" FOR EACH ROW EXECUTE FUNCTION record_recipe_deletion();
+CREATE TRIGGER "day_tombstone" AFTER DELETE ON "public"."day" FOR EACH ROW EXECUTE FUNCTION record_day_deletion();
+CREATE TRIGGER "ingredient_tombstone" AFTER DELETE ON "public"."ingredient" FOR EACH ROW EXECUTE FUNCTION record_ingredient_deletion();
+CREATE TRIGGER "ingredient_price_tombstone" AFTER DELETE ON "public"."ingredient_price" FOR EACH ROW EXECUTE FUNCTION record_ingredient_price_deletion();
+CREATE TRIGGER "ingredientschedule_tombstone" AFTER DELETE ON "public"."ingredientschedule" FOR EACH ROW EXECUTE FUNCTION record_schedule_deletion();
@@ -0,0 +1,29 @@
+--
+-- Give recipes a version, so that updates made to an older version can be rejected
+--
+ALTER TABLE "public"."recipe" ADD COLUMN "version" integer DEFAULT 1 NOT NULL;
+
+COMMENT ON COLUMN "public"."recipe"."version" IS 'Incremented each time the recipe is updated.';
+
+--
+-- Create Idempotency Key table
+--
+DROP TABLE IF EXISTS "idempotency_key" CASCADE;
+CREATE TABLE "public"."idempotency_key" (
+    "user_id" integer NOT NULL,
+    "key" character varying NOT NULL,
+    "method" character varying(8) NOT NULL,
+    "path" character varying NOT NULL,
+    "request_hash" character varying(64) NOT NULL,
+    "status" smallint,
+    "content_type" character varying,
+    "body" bytea,
+    "created_at" timestamptz DEFAULT now() NOT NULL,
+    CONSTRAINT "idempotency_key_pkey" PRIMARY KEY ("user_id", "key")
+) WITH (oids = false);
+
+COMMENT ON TABLE "public"."idempotency_key" IS 'The response to a write made with an Idempotency-Key header, replayed if the write is retried.';
+COMMENT ON COLUMN "public"."idempotency_key"."request_hash" IS 'A SHA-256 hash of the request body, so that a key can''t be reused for a different request.';
+COMMENT ON COLUMN "public"."idempotency_key"."status" IS 'The response status, or null while the request is still being handled.';
+
+ALTER TABLE ONLY "public"."idempotency_key" ADD CONSTRAINT "idempotency_key_user_id_fkey" FOREIGN KEY (user_id) REFERENCES useraccount(id) ON UPDATE CASCADE ON DELETE CASCADE NOT DEFERRABLE;
@@ -0,0 +1,17 @@
+--
+-- Plan each recipe at most once a day, so that retried or concurrent requests can't plan it twice
+--
+UPDATE "public"."day" AS leftovers SET leftovers_of = (
+    SELECT min(kept.id) FROM day AS kept
+        INNER JOIN day AS cooked ON cooked.recipe_id = kept.recipe_id AND cooked.date = kept.date
+    WHERE cooked.id = leftovers.leftovers_of
+)
+WHERE leftovers.leftovers_of IS NOT NULL;
+
+DELETE FROM "public"."day" AS duplicate
+    USING day AS kept
+    WHERE duplicate.recipe_id = kept.recipe_id
+        AND duplicate.date = kept.date
+        AND duplicate.id > kept.id;
+
+CREATE UNIQUE INDEX "day_recipe_id_date" ON "public"."day" USING btree ("recipe_id", "date");
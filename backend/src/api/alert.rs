@@ -0,0 +1,180 @@
+use serde::Serialize;
+use std::{error::Error, time::Duration};
+use tracing::{event, Level};
+
+use super::{
+    day,
+    ingredient::Ingredient,
+    notify::Notifier,
+    recipe,
+    utils::{add_days, day_diff, today},
+};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// The most recipes suggested to use up each expiring ingredient.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// An ingredient held in surplus that will soon go off, with recipes that could use it up.
+#[derive(Serialize, Clone, Debug, PartialEq, ToSchema, TS)]
+pub struct ExpiringIngredient {
+    pub ingredient: Ingredient,
+    pub quantity: i32,
+    /// The date the ingredient is expected to have gone off by.
+    pub expires_on: String,
+    pub suggested_recipes: Vec<RecipeSuggestion>,
+}
+
+/// A recipe that uses an expiring ingredient, and how much of it the recipe uses.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq, ToSchema, TS)]
+pub struct RecipeSuggestion {
+    pub recipe_id: i32,
+    pub name: String,
+    pub quantity: i32,
+}
+
+/// Lists the surplus ingredients that will go off within `within` days of `date`, soonest first.
+/// Each comes with the user's recipes that would use up the most of it.
+pub async fn expiring(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    date: &str,
+    within: i32,
+) -> Result<Vec<ExpiringIngredient>, Box<dyn Error>> {
+    let recipes = recipe::query_multiple(pool, user_id).await?;
+
+    // Check far enough back to catch surplus of any ingredient the recipes use
+    let days_to_check = recipes
+        .iter()
+        .flat_map(|r| r.all_ingredients())
+        .map(|i| i.ingredient.life)
+        .max()
+        .unwrap_or(0);
+    let (surplus, families) = day::query_surplus(pool, user_id, date, days_to_check).await?;
+
+    let mut expiring = vec![];
+    for held in surplus.into_values().filter(|h| h.quantity > 0) {
+        let expires_on = add_days(&held.bought_on, held.ingredient.life)?;
+        if day_diff(&expires_on, date)? > within {
+            continue;
+        }
+
+        let mut suggested_recipes: Vec<RecipeSuggestion> = recipes
+            .iter()
+            .filter_map(|recipe| {
+                let quantity: i32 = recipe
+                    .all_ingredients()
+                    .iter()
+                    .filter(|i| families.resolve(&i.ingredient).id == held.ingredient.id)
+                    .map(|i| i.quantity)
+                    .sum();
+                (quantity > 0).then(|| RecipeSuggestion {
+                    recipe_id: recipe.id,
+                    name: recipe.name.clone(),
+                    quantity,
+                })
+            })
+            .collect();
+        // Prefer recipes that use up as much of the surplus as possible
+        suggested_recipes.sort_by(|a, b| {
+            b.quantity
+                .min(held.quantity)
+                .cmp(&a.quantity.min(held.quantity))
+                .then(a.recipe_id.cmp(&b.recipe_id))
+        });
+        suggested_recipes.truncate(MAX_SUGGESTIONS);
+
+        expiring.push(ExpiringIngredient {
+            ingredient: held.ingredient,
+            quantity: held.quantity,
+            expires_on,
+            suggested_recipes,
+        });
+    }
+
+    expiring.sort_by(|a, b| {
+        a.expires_on
+            .cmp(&b.expires_on)
+            .then(a.ingredient.name.cmp(&b.ingredient.name))
+    });
+    Ok(expiring)
+}
+
+/// Writes a digest of expiring ingredients as a subject and plain text body, or returns `None` if
+/// nothing is expiring.
+pub fn digest(expiring: &[ExpiringIngredient]) -> Option<(String, String)> {
+    if expiring.is_empty() {
+        return None;
+    }
+    let subject = match expiring.len() {
+        1 => format!("{} is about to go off", expiring[0].ingredient.name),
+        count => format!("{} ingredients are about to go off", count),
+    };
+
+    let mut body = String::new();
+    for item in expiring {
+        let quantity = match &item.ingredient.unit {
+            Some(unit) => format!("{}{}", item.quantity, unit),
+            None => item.quantity.to_string(),
+        };
+        body += &format!(
+            "{} {}, by {}\n",
+            quantity, item.ingredient.name, item.expires_on
+        );
+        for suggestion in &item.suggested_recipes {
+            body += &format!("- Use it in {}\n", suggestion.name);
+        }
+    }
+    Some((subject, body))
+}
+
+/// Sends every user with ingredients going off within `within` days of `date` a digest of them.
+/// Returns the number of digests sent.
+pub async fn send_digests(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    notifier: &dyn Notifier,
+    date: &str,
+    within: i32,
+) -> Result<usize, Box<dyn Error>> {
+    let users = sqlx::query!("SELECT id FROM useraccount ORDER BY id")
+        .fetch_all(pool)
+        .await?;
+
+    let mut sent = 0;
+    for user in users {
+        let expiring = expiring(pool, user.id, date, within).await?;
+        if let Some((subject, body)) = digest(&expiring) {
+            notifier
+                .send(user.id, &subject, &body)
+                .await
+                .map_err(|e| e.to_string())?;
+            sent += 1;
+        }
+    }
+    Ok(sent)
+}
+
+/// Sends digests every `interval`, starting immediately. Failures are logged, and retried at the
+/// next interval.
+pub async fn schedule_digests(
+    pool: sqlx::Pool<sqlx::Postgres>,
+    notifier: std::sync::Arc<dyn Notifier>,
+    interval: Duration,
+    within: i32,
+) {
+    let mut timer = tokio::time::interval(interval);
+    loop {
+        timer.tick().await;
+        let date = today().map_err(|e| e.to_string());
+        let result = match date {
+            Ok(date) => send_digests(&pool, notifier.as_ref(), &date, within)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(sent) => event!(Level::INFO, "sent {} expiry digests", sent),
+            Err(e) => event!(Level::ERROR, "failed to send expiry digests: {}", e),
+        }
+    }
+}
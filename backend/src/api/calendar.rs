@@ -0,0 +1,250 @@
+use serde::Serialize;
+use std::error::Error;
+use time::{format_description::FormatItem, macros::format_description, OffsetDateTime};
+
+use super::{
+    day::{self, Day},
+    recipe::Recipe,
+    utils::{add_days, sub_days, today},
+};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// How far either side of today a subscribed calendar shows planned meals.
+const FEED_DAYS_BEFORE: i32 = 28;
+const FEED_DAYS_AFTER: i32 = 56;
+
+/// The longest a line in an iCalendar file may be, in octets, before it must be folded.
+const MAX_LINE_LENGTH: usize = 75;
+
+/// The format of a UTC date and time in an iCalendar file.
+const STAMP_FORMAT: &[FormatItem<'_>] =
+    format_description!("[year][month][day]T[hour][minute][second]Z");
+
+/// A user's calendar subscription token, and the path it can be fetched from without a bearer token.
+#[derive(Serialize, Debug, ToSchema, TS)]
+pub struct CalendarToken {
+    pub token: String,
+    pub path: String,
+}
+
+impl CalendarToken {
+    fn new(token: String) -> Self {
+        CalendarToken {
+            path: format!("/calendar/{}/meals.ics", token),
+            token,
+        }
+    }
+}
+
+/// Exports the meals planned between a range of dates as an iCalendar file.
+pub async fn export_range(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    from: String,
+    to: String,
+) -> Result<String, Box<dyn Error>> {
+    let days = day::query_range(pool, user_id, &from, &to).await?;
+    let stamp = OffsetDateTime::now_utc().format(&STAMP_FORMAT)?;
+    Ok(to_ical(&days, &stamp))
+}
+
+/// Exports the meals planned around today for the user with a subscription token.
+/// Returns `None` if no user has the token.
+pub async fn export_feed(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    token: String,
+) -> Result<Option<String>, Box<dyn Error>> {
+    let user = sqlx::query!(
+        "SELECT id FROM useraccount WHERE calendar_token = $1",
+        token
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    let Some(user) = user else {
+        return Ok(None);
+    };
+
+    let today = today()?;
+    let from = sub_days(&today, FEED_DAYS_BEFORE)?;
+    let to = add_days(&today, FEED_DAYS_AFTER)?;
+    Ok(Some(export_range(pool, user.id, from, to).await?))
+}
+
+/// Creates a new subscription token for a user, replacing any previous token.
+pub async fn create_token(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+) -> Result<CalendarToken, sqlx::Error> {
+    // Two random UUIDs give a token that can't reasonably be guessed
+    let row = sqlx::query!(
+        "UPDATE useraccount
+		SET calendar_token = replace(gen_random_uuid()::text || gen_random_uuid()::text, '-', '')
+		WHERE id = $1
+		RETURNING calendar_token as \"calendar_token!\"",
+        user_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(CalendarToken::new(row.calendar_token))
+}
+
+/// Removes a user's subscription token, so their calendar can no longer be fetched without a bearer token.
+pub async fn delete_token(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE useraccount SET calendar_token = NULL WHERE id = $1",
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Converts a set of days into an iCalendar file, with an all-day event for each planned recipe
+/// and meal of leftovers. Each event is stamped with the time the file was generated, in UTC.
+pub fn to_ical(days: &[Day], stamp: &str) -> String {
+    let mut lines: Vec<String> = vec![
+        "BEGIN:VCALENDAR".into(),
+        "VERSION:2.0".into(),
+        "PRODID:-//lembas//Meal Plan//EN".into(),
+        "CALSCALE:GREGORIAN".into(),
+        "X-WR-CALNAME:Meal Plan".into(),
+    ];
+
+    for day in days {
+        let start = day.date.replace('-', "");
+        let end = add_days(&day.date, 1)
+            .map(|d| d.replace('-', ""))
+            .unwrap_or(start.clone());
+
+        for recipe in &day.recipes {
+            lines.push("BEGIN:VEVENT".into());
+            lines.push(format!("UID:{}-{}@lembas", start, recipe.id));
+            lines.push(format!("DTSTAMP:{}", stamp));
+            lines.push(format!("DTSTART;VALUE=DATE:{}", start));
+            lines.push(format!("DTEND;VALUE=DATE:{}", end));
+            lines.push(format!("SUMMARY:{}", escape_text(&recipe.name)));
+            lines.push(format!("DESCRIPTION:{}", escape_text(&describe(recipe))));
+            lines.push("END:VEVENT".into());
+        }
+
+        for leftovers in &day.leftovers {
+            lines.push("BEGIN:VEVENT".into());
+            lines.push(format!(
+                "UID:{}-{}-leftovers@lembas",
+                start, leftovers.recipe.id
+            ));
+            lines.push(format!("DTSTAMP:{}", stamp));
+            lines.push(format!("DTSTART;VALUE=DATE:{}", start));
+            lines.push(format!("DTEND;VALUE=DATE:{}", end));
+            lines.push(format!(
+                "SUMMARY:{}",
+                escape_text(&format!("{} (leftovers)", leftovers.recipe.name))
+            ));
+            lines.push(format!(
+                "DESCRIPTION:{}",
+                escape_text(&format!("Cooked on {}.", leftovers.cooked_on))
+            ));
+            lines.push("END:VEVENT".into());
+        }
+    }
+
+    lines.push("END:VCALENDAR".into());
+
+    lines
+        .iter()
+        .map(|l| fold_line(l))
+        .collect::<Vec<String>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+/// Lists a recipe's ingredients and steps as plain text. Ingredients include those of any
+/// sub-recipes, as on the shopping list.
+fn describe(recipe: &Recipe) -> String {
+    let mut description = String::from("Ingredients:\n");
+    for ingredient in recipe.all_ingredients() {
+        let quantity = match &ingredient.ingredient.unit {
+            Some(unit) => format!("{}{}", ingredient.quantity, unit),
+            None => ingredient.quantity.to_string(),
+        };
+        description += &format!("- {} {}\n", quantity, ingredient.ingredient.name);
+    }
+
+    let steps = recipe.step_text();
+    if !steps.is_empty() {
+        description += "\nMethod:\n";
+        for (i, step) in steps.iter().enumerate() {
+            description += &format!("{}. {}\n", i + 1, step);
+        }
+    }
+
+    description.trim_end().to_string()
+}
+
+/// Escapes text for use in an iCalendar property value.
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Splits a line longer than 75 octets onto several lines, each continuation starting with a space.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+
+    for c in line.chars() {
+        if length + c.len_utf8() > MAX_LINE_LENGTH {
+            folded += "\r\n ";
+            // The leading space counts towards the continuation's length
+            length = 1;
+        }
+        folded.push(c);
+        length += c.len_utf8();
+    }
+
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_text() {
+        let escaped = escape_text("Salt, pepper; oil\\butter\nmix");
+        assert_eq!(escaped, "Salt\\, pepper\\; oil\\\\butter\\nmix");
+    }
+
+    #[test]
+    fn test_fold_line() {
+        let line = "A".repeat(100);
+        let folded = fold_line(&line);
+        let lines: Vec<&str> = folded.split("\r\n").collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].len(), 75);
+        assert_eq!(lines[1], format!(" {}", "A".repeat(25)));
+    }
+
+    #[test]
+    fn test_fold_line_multibyte() {
+        // Each character is two octets, so can't be split evenly at 75
+        let line = "é".repeat(40);
+        let folded = fold_line(&line);
+
+        for line in folded.split("\r\n") {
+            assert!(line.len() <= 75);
+        }
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+}
@@ -1,25 +1,191 @@
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, error::Error, time::Duration};
+use sqlx::{types, QueryBuilder};
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt,
+    time::Duration,
+};
+use time::Date;
 
 use super::{
-    ingredient::{self, Ingredient, IngredientQuantity},
-    recipe::Recipe,
-    utils::{day_diff, parse_date, sub_days},
+    diet::{self, Allergen, DietWarning},
+    images,
+    ingredient::{self, Families, Ingredient, IngredientQuantity, PackQuantity},
+    nutrition::Nutrition,
+    page::{Column, InvalidPage, Listing, Page, PageParams},
+    purchase::{self, Purchase},
+    recipe::{self, Difficulty, Recipe, RecipeMetadata, Step},
+    utils::{add_days, day_diff, parse_date, sub_days},
 };
+use ts_rs::TS;
+use utoipa::ToSchema;
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Clone, Debug, ToSchema, TS)]
 pub struct Day {
     pub date: String,
+    /// The recipes cooked on the day.
     pub recipes: Vec<Recipe>,
+    /// Meals eaten from recipes cooked on earlier days. These need no shopping.
+    pub leftovers: Vec<PlannedLeftovers>,
+    /// Whether each of the day's recipes was cooked or skipped, for those that have happened.
+    pub records: Vec<MealRecord>,
+    /// The nutrition of one portion of each of the day's recipes and leftovers.
+    pub nutrition: Nutrition,
+    /// Warnings for any of the day's recipes that don't suit the user's diet profile.
+    pub warnings: Vec<DietWarning>,
 }
 
 impl Day {
     pub fn new(date: String, recipes: Vec<Recipe>) -> Self {
-        Day { date, recipes }
+        let mut day = Day {
+            date,
+            recipes: vec![],
+            leftovers: vec![],
+            records: vec![],
+            nutrition: Nutrition::default(),
+            warnings: vec![],
+        };
+        for recipe in recipes {
+            day.add_recipe(recipe);
+        }
+        day
+    }
+
+    /// Adds a recipe to the day, updating its nutrition.
+    pub fn add_recipe(&mut self, recipe: Recipe) {
+        self.recipes.push(recipe);
+        self.update_nutrition();
+    }
+
+    /// Adds a meal of leftovers to the day, updating its nutrition.
+    pub fn add_leftovers(&mut self, leftovers: PlannedLeftovers) {
+        self.leftovers.push(leftovers);
+        self.update_nutrition();
+    }
+
+    /// The recipes that were or will be cooked on the day, leaving out any that were skipped.
+    /// Where a meal used different amounts to its recipe, the recipe's ingredients are replaced
+    /// with what was actually used.
+    pub fn meals(&self) -> Vec<Recipe> {
+        self.recipes
+            .iter()
+            .filter_map(|recipe| {
+                let record = self.records.iter().find(|r| r.recipe_id == recipe.id);
+                match record {
+                    Some(record) if record.status == MealStatus::Skipped => None,
+                    Some(record) if !record.used.is_empty() => {
+                        Some(with_usage(recipe, &record.used))
+                    }
+                    _ => Some(recipe.clone()),
+                }
+            })
+            .collect()
     }
+
+    fn update_nutrition(&mut self) {
+        self.nutrition = self
+            .recipes
+            .iter()
+            .chain(self.leftovers.iter().map(|l| &l.recipe))
+            .map(|r| r.nutrition.per_portion)
+            .sum::<Nutrition>()
+            .round();
+    }
+}
+
+/// Replaces the quantities of a recipe's ingredients with the amounts actually used. Sub-recipes
+/// are expanded, as the amounts recorded are of the ingredients they contain.
+fn with_usage(recipe: &Recipe, used: &[UsedQuantity]) -> Recipe {
+    let mut recipe = recipe.clone();
+    recipe.ingredients = recipe
+        .all_ingredients()
+        .into_iter()
+        .map(|mut ingredient| {
+            if let Some(usage) = used.iter().find(|u| u.id == ingredient.ingredient.id) {
+                ingredient.quantity = usage.quantity;
+            }
+            ingredient
+        })
+        .collect();
+    recipe.sub_recipes = vec![];
+    recipe
+}
+
+/// Whether a planned recipe has been cooked yet.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, ToSchema, TS)]
+#[serde(rename_all = "lowercase")]
+pub enum MealStatus {
+    Planned,
+    Cooked,
+    Skipped,
 }
 
+impl MealStatus {
+    /// Parses a status stored in the database. Meals without one are still planned.
+    pub fn parse(value: Option<&str>) -> MealStatus {
+        match value {
+            Some("cooked") => MealStatus::Cooked,
+            Some("skipped") => MealStatus::Skipped,
+            _ => MealStatus::Planned,
+        }
+    }
+
+    /// The name the status is stored under, if it isn't planned.
+    pub fn as_str(&self) -> Option<&'static str> {
+        match self {
+            MealStatus::Planned => None,
+            MealStatus::Cooked => Some("cooked"),
+            MealStatus::Skipped => Some("skipped"),
+        }
+    }
+}
+
+/// The amount of an ingredient a cooked meal actually used.
+/// - `id` - the ID of the ingredient
+/// - `quantity` - the amount used
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, ToSchema, TS)]
+pub struct UsedQuantity {
+    pub id: i32,
+    pub quantity: i32,
+}
+
+/// What happened to a planned recipe, and any ingredients it used different amounts of.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq, ToSchema, TS)]
+pub struct MealRecord {
+    pub recipe_id: i32,
+    pub status: MealStatus,
+    pub used: Vec<UsedQuantity>,
+}
+
+/// Defines the input for recording whether a planned recipe was cooked.
+/// - `status` - whether the recipe was cooked or skipped, or `planned` to clear the record
+/// - `used` - the amounts of any ingredients that differed from the recipe
+#[derive(Deserialize, Debug, ToSchema, TS)]
+pub struct MealRecordInput {
+    pub status: MealStatus,
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub used: Vec<UsedQuantity>,
+}
+
+/// A meal eaten from the leftovers of a recipe cooked on an earlier day.
+#[derive(Serialize, Clone, Debug, PartialEq, ToSchema, TS)]
+pub struct PlannedLeftovers {
+    pub recipe: Recipe,
+    /// The date the recipe was cooked.
+    pub cooked_on: String,
+    /// The number of portions eaten.
+    pub portions: i32,
+    /// The portions still left once this meal has been eaten.
+    pub remaining_portions: i32,
+}
+
+/// Ingredients lasting this many days or fewer are considered perishable.
+const PERISHABLE_LIFE: i32 = 7;
+
 /// Contains mapped ingredient lists for quick modification.
+#[derive(Clone)]
 pub struct MappedShoppingList {
     /// Tracks surplus of ingredients from previous weeks. Reduced as recipes are added.
     surplus: HashMap<i32, IngredientQuantity>,
@@ -27,42 +193,61 @@ pub struct MappedShoppingList {
     ingredients: HashMap<i32, IngredientPurchaseQuantity>,
     /// Stores additional scheduled ingredients
     scheduled_ingredients: HashMap<i32, IngredientPurchaseQuantity>,
+    /// The ingredient families used to merge pack sizes and choose what to buy
+    families: Families,
+    /// Tracks what is left of each purchase once the recipe it was bought for is made
+    leftovers: Vec<Leftover>,
+}
+
+/// The remainder of a purchase made for a single recipe on a given date.
+#[derive(Clone, Debug)]
+struct Leftover {
+    date: String,
+    recipe_id: i32,
+    recipe_name: String,
+    ingredient: Ingredient,
+    quantity: i32,
 }
 
 impl MappedShoppingList {
     /// Takes a map of initially available ingredients, to which recipes can be added.
-    pub fn new(surplus: HashMap<i32, IngredientQuantity>) -> Self {
+    pub fn new(surplus: HashMap<i32, IngredientQuantity>, families: Families) -> Self {
         MappedShoppingList {
             surplus,
             ingredients: HashMap::new(),
             scheduled_ingredients: HashMap::new(),
+            families,
+            leftovers: vec![],
         }
     }
 
     /// Adds a recipe's ingredients to the existing list. An existing surplus is also passed in,
     /// with ingredients only being purchased if the surplus does not cover the amount
-    /// required by the recipe.
-    pub fn add_recipe(&mut self, recipe: Recipe) {
-        for ingredient_quantity in recipe.ingredients {
-            let existing_surplus = self.surplus.get_mut(&ingredient_quantity.ingredient.id);
+    /// required by the recipe. Ingredients belonging to a family are merged into a single entry.
+    /// Anything left over from a purchase is recorded against the recipe and date it was made on,
+    /// and used by later recipes in the list before it expires. Sub-recipes are expanded into
+    /// their ingredients, scaled to the portions used.
+    pub fn add_recipe(&mut self, date: &str, recipe: Recipe) -> Result<(), Box<dyn Error>> {
+        for ingredient_quantity in recipe.all_ingredients() {
+            let ingredient = self.families.resolve(&ingredient_quantity.ingredient);
+            let existing_surplus = self.surplus.get_mut(&ingredient.id);
 
             let entry = self
                 .ingredients
-                .entry(ingredient_quantity.ingredient.id)
+                .entry(ingredient.id)
                 // If no entry exists, insert a new one with the existing surplus set to the ingredient's initial surplus value.
                 .or_insert(match existing_surplus {
                     Some(surplus) => {
-                        let mut entry =
-                            IngredientPurchaseQuantity::new(ingredient_quantity.ingredient.clone());
+                        let mut entry = IngredientPurchaseQuantity::new(ingredient.clone());
                         entry.set_existing_surplus(surplus.quantity);
                         entry
                     }
-                    None => IngredientPurchaseQuantity::new(ingredient_quantity.ingredient.clone()),
+                    None => IngredientPurchaseQuantity::new(ingredient.clone()),
                 });
 
             // Calculate if we need to purchase more by subtracting the amount of the ingredient
             // we have left from the amount required for this recipe
-            let needed_quantity = match self.surplus.get_mut(&ingredient_quantity.ingredient.id) {
+            let needed_quantity = match self.surplus.get_mut(&ingredient.id) {
                 Some(existing_surplus) => {
                     existing_surplus.quantity -= ingredient_quantity.quantity;
                     let mut purchase = 0;
@@ -75,12 +260,100 @@ impl MappedShoppingList {
                 None => ingredient_quantity.quantity,
             };
 
-            // Increase the amount to be purchased
-            entry.add_puchase_quantity(needed_quantity);
+            // Use what's left from earlier purchases in the list before buying more
+            let needed_quantity =
+                use_leftovers(&mut self.leftovers, ingredient.id, date, needed_quantity)?;
+
+            // Increase the amount to be purchased, choosing the packs that waste the least
+            let packs = self.families.choose_packs(&ingredient, needed_quantity);
+            let purchased: i32 = packs.iter().map(PackQuantity::quantity).sum();
+            entry.add_packs(packs);
+
+            if purchased > needed_quantity {
+                self.leftovers.push(Leftover {
+                    date: date.into(),
+                    recipe_id: recipe.id,
+                    recipe_name: recipe.name.clone(),
+                    ingredient: ingredient.clone(),
+                    quantity: purchased - needed_quantity,
+                });
+            }
 
             // Increase the exact amount used
             entry.add_quantity(ingredient_quantity.quantity);
         }
+        Ok(())
+    }
+
+    /// Uses up leftovers with meals planned after the list, oldest first, skipping any leftovers
+    /// that will have expired by then. Whatever remains is recorded as each ingredient's
+    /// projected waste.
+    pub fn project_waste(&mut self, later_days: &[Day]) -> Result<(), Box<dyn Error>> {
+        for day in later_days {
+            for ingredient_quantity in day.meals().iter().flat_map(|r| r.all_ingredients()) {
+                let ingredient = self.families.resolve(&ingredient_quantity.ingredient);
+                use_leftovers(
+                    &mut self.leftovers,
+                    ingredient.id,
+                    &day.date,
+                    ingredient_quantity.quantity,
+                )?;
+            }
+        }
+
+        for entry in self.ingredients.values_mut() {
+            entry.projected_waste = self
+                .leftovers
+                .iter()
+                .filter(|l| l.ingredient.id == entry.ingredient.id)
+                .map(|l| l.quantity)
+                .sum();
+        }
+        Ok(())
+    }
+
+    /// Totals the surplus and leftovers still held, measured in packs. Perishable ingredients are
+    /// weighted more heavily, as anything held is more likely to expire before it is used.
+    pub fn held_stock(&self) -> f64 {
+        let surplus = self.surplus.values().map(|s| (&s.ingredient, s.quantity));
+        let leftovers = self.leftovers.iter().map(|l| (&l.ingredient, l.quantity));
+
+        surplus
+            .chain(leftovers)
+            .filter(|(ingredient, _)| ingredient.purchase_quantity > 0)
+            .map(|(ingredient, quantity)| {
+                let packs = quantity as f64 / ingredient.purchase_quantity as f64;
+                packs * PERISHABLE_LIFE as f64 / ingredient.life.max(PERISHABLE_LIFE) as f64
+            })
+            .sum()
+    }
+
+    /// Totals the projected waste caused by each recipe, ranked from most to least wasteful.
+    pub fn recipe_waste(&self) -> Vec<RecipeWaste> {
+        let mut recipes: Vec<RecipeWaste> = vec![];
+        for leftover in self.leftovers.iter().filter(|l| l.quantity > 0) {
+            let index = match recipes
+                .iter()
+                .position(|r| r.recipe_id == leftover.recipe_id)
+            {
+                Some(index) => index,
+                None => {
+                    recipes.push(RecipeWaste::new(
+                        leftover.recipe_id,
+                        leftover.recipe_name.clone(),
+                    ));
+                    recipes.len() - 1
+                }
+            };
+            recipes[index].add_leftover(leftover);
+        }
+
+        recipes.sort_by(|a, b| {
+            b.packs_wasted
+                .total_cmp(&a.packs_wasted)
+                .then(a.recipe_id.cmp(&b.recipe_id))
+        });
+        recipes
     }
 
     /// Add a single purchase quantity of the given ingredient to the scheduled ingredient list.
@@ -89,39 +362,200 @@ impl MappedShoppingList {
             .scheduled_ingredients
             .entry(ingredient.id)
             .or_insert(IngredientPurchaseQuantity::new(ingredient.clone()));
-        entry.add_puchase_quantity(ingredient.purchase_quantity);
+        entry.add_packs(vec![PackQuantity::new(ingredient.clone(), 1)]);
         // Increase the exact amount used
         entry.add_quantity(ingredient.purchase_quantity);
     }
 }
 
 /// A more usable version of `ShoppingList` that is returned by the API.
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Debug, ToSchema, TS)]
 pub struct ShoppingList {
     pub ingredients: Vec<IngredientPurchaseQuantity>,
     pub scheduled_ingredients: Vec<IngredientPurchaseQuantity>,
+    /// The estimated cost of everything on the list, in pence. Unpriced items are left out.
+    pub estimated_cost: i32,
 }
 
-impl Into<ShoppingList> for MappedShoppingList {
-    fn into(self) -> ShoppingList {
+impl ShoppingList {
+    /// Sorts the list into the order of the aisles in the user's store. Ingredients without a
+    /// category, or in an aisle the user hasn't ordered, come last.
+    pub fn sort_by_aisle(&mut self, aisles: &[String]) {
+        let position = |item: &IngredientPurchaseQuantity| {
+            item.ingredient
+                .category
+                .as_ref()
+                .and_then(|c| aisles.iter().position(|a| a == c))
+                .unwrap_or(aisles.len())
+        };
+        self.ingredients.sort_by_key(position);
+        self.scheduled_ingredients.sort_by_key(position);
+    }
+}
+
+impl From<MappedShoppingList> for ShoppingList {
+    fn from(val: MappedShoppingList) -> Self {
+        let mut ingredients: Vec<IngredientPurchaseQuantity> =
+            val.ingredients.values().cloned().collect();
+        ingredients.sort_by_key(|a| a.ingredient.id);
+        let mut scheduled_ingredients: Vec<IngredientPurchaseQuantity> =
+            val.scheduled_ingredients.values().cloned().collect();
+        scheduled_ingredients.sort_by_key(|a| a.ingredient.id);
+        let estimated_cost = ingredients
+            .iter()
+            .chain(scheduled_ingredients.iter())
+            .filter_map(|i| i.estimated_cost)
+            .sum();
+
         ShoppingList {
-            ingredients: self.ingredients.values().into_iter().cloned().collect(),
-            scheduled_ingredients: self
-                .scheduled_ingredients
-                .values()
-                .into_iter()
-                .cloned()
-                .collect(),
+            ingredients,
+            scheduled_ingredients,
+            estimated_cost,
+        }
+    }
+}
+
+/// Uses up to `needed` of an ingredient from leftovers that are still in date on `date`, oldest
+/// first. Returns the amount that couldn't be covered.
+fn use_leftovers(
+    leftovers: &mut [Leftover],
+    ingredient_id: i32,
+    date: &str,
+    needed: i32,
+) -> Result<i32, Box<dyn Error>> {
+    let mut needed = needed;
+    for leftover in leftovers
+        .iter_mut()
+        .filter(|l| l.ingredient.id == ingredient_id)
+    {
+        // Skip the leftover if it's likely to be out of date
+        if needed == 0 || leftover.ingredient.life <= day_diff(date, &leftover.date)? {
+            continue;
         }
+        let used = needed.min(leftover.quantity);
+        leftover.quantity -= used;
+        needed -= used;
     }
+    Ok(needed)
+}
+
+/// A recipe, along with how much of it is covered by surplus.
+#[derive(Serialize, Clone, Debug, ToSchema, TS)]
+pub struct CookableRecipe {
+    pub recipe: Recipe,
+    /// The average fraction of each ingredient covered by surplus, between 0 and 1.
+    pub coverage: f64,
+    /// The ingredients surplus doesn't cover, and what would need to be bought.
+    pub missing: Vec<MissingIngredient>,
 }
 
-#[derive(Serialize, Clone, Debug)]
+impl CookableRecipe {
+    /// Takes a recipe and the shopping list entries for making it from surplus.
+    fn new(recipe: Recipe, entries: Vec<IngredientPurchaseQuantity>) -> Self {
+        let mut coverage = 0.0;
+        let mut missing = vec![];
+
+        for entry in &entries {
+            // Ingredients that can't be bought, such as water, are assumed to be available
+            if entry.used_quantity == 0 || entry.ingredient.purchase_quantity == 0 {
+                coverage += 1.0;
+                continue;
+            }
+
+            let covered = entry.existing_surplus.min(entry.used_quantity);
+            coverage += f64::from(covered) / f64::from(entry.used_quantity);
+
+            if covered < entry.used_quantity {
+                missing.push(MissingIngredient {
+                    ingredient: entry.ingredient.clone(),
+                    quantity: entry.used_quantity - covered,
+                    purchase_quantity: entry.purchase_quantity,
+                    packs: entry.packs.clone(),
+                });
+            }
+        }
+
+        if !entries.is_empty() {
+            coverage /= entries.len() as f64;
+        }
+
+        CookableRecipe {
+            recipe,
+            coverage,
+            missing,
+        }
+    }
+}
+
+/// An ingredient a recipe needs more of than is available in surplus.
+/// - `quantity` - the amount not covered by surplus
+/// - `purchase_quantity` - the amount that would need to be bought to cover it
+#[derive(Serialize, Clone, Debug, ToSchema, TS)]
+pub struct MissingIngredient {
+    pub ingredient: Ingredient,
+    pub quantity: i32,
+    pub purchase_quantity: i32,
+    pub packs: Vec<PackQuantity>,
+}
+
+/// The waste a planned recipe is projected to cause, from buying more than it needs.
+#[derive(Serialize, Clone, Debug, ToSchema, TS)]
+pub struct RecipeWaste {
+    pub recipe_id: i32,
+    pub name: String,
+    /// The dates the recipe is planned on that cause waste
+    pub dates: Vec<String>,
+    /// The wasted quantity of each ingredient
+    pub ingredients: Vec<IngredientQuantity>,
+    /// The waste measured in packs, allowing ingredients with different units to be compared
+    pub packs_wasted: f64,
+}
+
+impl RecipeWaste {
+    fn new(recipe_id: i32, name: String) -> Self {
+        RecipeWaste {
+            recipe_id,
+            name,
+            dates: vec![],
+            ingredients: vec![],
+            packs_wasted: 0.0,
+        }
+    }
+
+    fn add_leftover(&mut self, leftover: &Leftover) {
+        if !self.dates.contains(&leftover.date) {
+            self.dates.push(leftover.date.clone());
+        }
+        match self
+            .ingredients
+            .iter_mut()
+            .find(|i| i.ingredient.id == leftover.ingredient.id)
+        {
+            Some(existing) => existing.quantity += leftover.quantity,
+            None => self.ingredients.push(IngredientQuantity::new(
+                leftover.ingredient.clone(),
+                leftover.quantity,
+            )),
+        }
+        if leftover.ingredient.purchase_quantity > 0 {
+            self.packs_wasted +=
+                leftover.quantity as f64 / leftover.ingredient.purchase_quantity as f64;
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug, ToSchema, TS)]
 pub struct IngredientPurchaseQuantity {
-    ingredient: Ingredient,
+    pub ingredient: Ingredient,
     pub existing_surplus: i32,
     pub used_quantity: i32,
     pub purchase_quantity: i32,
+    /// The packs that make up `purchase_quantity`.
+    pub packs: Vec<PackQuantity>,
+    /// The amount purchased that is expected to expire before it is used.
+    pub projected_waste: i32,
+    /// The estimated cost of the packs to buy, in pence. Absent if any pack is unpriced.
+    pub estimated_cost: Option<i32>,
 }
 
 impl IngredientPurchaseQuantity {
@@ -129,18 +563,34 @@ impl IngredientPurchaseQuantity {
     /// Initialises an IngredientPurchaseQuantity wih 0 used and purchase quantities.
     pub fn new(ingredient: Ingredient) -> Self {
         IngredientPurchaseQuantity {
-            ingredient: ingredient,
+            ingredient,
             existing_surplus: 0,
             used_quantity: 0,
             purchase_quantity: 0,
+            packs: vec![],
+            projected_waste: 0,
+            estimated_cost: Some(0),
         }
     }
 
-    /// Increase the amount of ingredient to be purchased. This scales w.r.t the minimum purchase
-    /// quantity.
-    pub fn add_puchase_quantity(&mut self, purchase_quantity: i32) {
-        let scaled_purchase_quantity = self.ingredient.scale_purchase_quantity(purchase_quantity);
-        self.purchase_quantity += scaled_purchase_quantity;
+    /// Increase the amount of ingredient to be purchased by a set of packs.
+    pub fn add_packs(&mut self, packs: Vec<PackQuantity>) {
+        for pack in packs {
+            self.purchase_quantity += pack.quantity();
+            match self
+                .packs
+                .iter_mut()
+                .find(|p| p.ingredient.id == pack.ingredient.id)
+            {
+                Some(existing) => existing.count += pack.count,
+                None => self.packs.push(pack),
+            }
+        }
+        self.estimated_cost = self
+            .packs
+            .iter()
+            .map(|p| p.ingredient.price.map(|price| price * p.count))
+            .sum();
     }
 
     /// Increase the quantity of the ingredient to be used by an exact amount
@@ -156,49 +606,152 @@ impl IngredientPurchaseQuantity {
 /// Defines the input for adding a recipe to a day.
 /// - `recipe_id` - the ID of the recipe to be added
 /// - `date` - a string representing the date the recipe should be added to (YYYY-MM-DD)
-#[derive(Deserialize)]
+/// - `portions` - the number of portions eaten at the meal, one if absent
+/// - `leftovers_from` - the date the recipe was cooked, if the meal is eaten from its leftovers
+#[derive(Deserialize, Debug, ToSchema, TS)]
 pub struct DayInput {
     pub recipe_id: i32,
     pub date: String,
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub portions: Option<i32>,
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub leftovers_from: Option<String>,
+}
+
+/// Returned when a meal can't be planned, with a description of the problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDay(pub String);
+
+impl fmt::Display for InvalidDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for InvalidDay {}
+
+/// How lists of days can be sorted and paged through. Each date appears once.
+pub const LISTING: Listing = Listing {
+    key: "date",
+    sortable: &[],
+    columns: &[Column {
+        field: "date",
+        sql: "d.date",
+        text: false,
+    }],
+};
+
+/// Fetches a page of the days with planned meals between a range of dates, picked out in the
+/// query.
+pub async fn query_page(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    from: &str,
+    to: &str,
+    params: &PageParams,
+) -> Result<Result<Page, InvalidPage>, Box<dyn Error>> {
+    let keyset = match LISTING.keyset(params) {
+        Ok(Some(keyset)) => keyset,
+        Ok(None) => return Ok(LISTING.page(query_range(pool, user_id, from, to).await?, params)),
+        Err(e) => return Ok(Err(e)),
+    };
+
+    let mut query = QueryBuilder::new(
+        "SELECT d.date FROM (
+			SELECT DISTINCT to_char(day.date, 'YYYY-MM-DD') AS date
+			FROM day INNER JOIN recipe AS r ON day.recipe_id = r.id
+			WHERE r.user_id = ",
+    );
+    query.push_bind(user_id);
+    query.push(" AND day.date BETWEEN ");
+    query.push_bind(parse_date(from)?);
+    query.push(" AND ");
+    query.push_bind(parse_date(to)?);
+    query.push(") AS d WHERE d.date IS NOT NULL");
+    keyset.push_to(&mut query);
+    let dates: Vec<String> = query.build_query_scalar().fetch_all(pool).await?;
+
+    // The page's dates follow on from one another, so they're all of those in its range.
+    let mut days = match (dates.iter().min(), dates.iter().max()) {
+        (Some(first), Some(last)) => query_range(pool, user_id, first, last).await?,
+        _ => vec![],
+    };
+    days.sort_by_key(|d| dates.iter().position(|date| *date == d.date));
+    Ok(LISTING.keyset_page(days, &keyset, params))
 }
 
 /// Queries any planned meals between a range of dates.
 pub async fn query_range(
     pool: &sqlx::Pool<sqlx::Postgres>,
     user_id: i32,
-    from: &String,
-    to: &String,
+    from: &str,
+    to: &str,
 ) -> Result<Vec<Day>, Box<dyn Error>> {
     let rows = sqlx::query!(
         "SELECT
+			day.id as day_id,
 			day.date,
+			day.status,
+			day.portions,
+			cooked.date as \"cooked_on?\",
+			r.portions - cooked.portions - (
+				SELECT SUM(l.portions) FROM day AS l
+				WHERE l.leftovers_of = cooked.id AND (l.date, l.id) <= (day.date, day.id)
+			) as remaining_portions,
 			r.id as recipe_id, 
 			r.name as recipe_name,
 			r.portions as recipe_portions, 
-			r.steps as recipe_steps,
+			r.steps as \"recipe_steps: types::Json<Vec<Step>>\",
+			r.prep_time,
+			r.cook_time,
+			r.total_time,
+			r.source_url,
+			r.notes,
+			r.yield_description,
+			r.difficulty,
+			r.version,
+			to_char(r.created_at, 'YYYY-MM-DD\"T\"HH24:MI:SS') as \"created_at!\",
             
-			i.id as ingredient_id,
+			i.id as \"ingredient_id?\",
             i.user_id,
-			i.name as ingredient_name,
+			i.name as \"ingredient_name?\",
 			i.unit as ingredient_unit,
-			i.minimum_quantity as ingredient_minimum_quantity,
-			i.purchase_quantity as ingredient_purchase_quantity,
-			i.life as ingredient_life,
-			ri.quantity as ingredient_quantity
+			i.minimum_quantity as \"ingredient_minimum_quantity?\",
+			i.purchase_quantity as \"ingredient_purchase_quantity?\",
+			i.life as \"ingredient_life?\",
+			i.family_id as ingredient_family_id,
+			i.category as ingredient_category,
+			COALESCE(p.price, i.price) as ingredient_price,
+			i.energy,
+			i.protein,
+			i.fat,
+			i.carbohydrate,
+			i.fibre,
+			i.salt,
+			i.allergens as \"allergens?\",
+			i.vegetarian as \"vegetarian?\",
+			i.vegan as \"vegan?\",
+			ri.quantity as \"ingredient_quantity?\"
 		FROM day 
+			LEFT JOIN day as cooked
+				ON day.leftovers_of = cooked.id
 			LEFT JOIN recipe as r 
 				ON day.recipe_id = r.id
 			LEFT JOIN recipe_ingredient as ri 
-				ON r.id = ri.recipe_id
+				ON r.id = ri.recipe_id AND ri.ingredient_id IS NOT NULL
 			LEFT JOIN ingredient as i 
 				ON ri.ingredient_id = i.id
+			LEFT JOIN ingredient_price as p
+				ON p.ingredient_id = i.id AND p.user_id = r.user_id
 		WHERE r.user_id = $1
         AND day.date BETWEEN $2 AND $3
-		ORDER BY day.date ASC
+		ORDER BY day.date ASC, day.id ASC
 		",
         user_id,
-        parse_date(&from)?,
-        parse_date(&to)?
+        parse_date(from)?,
+        parse_date(to)?
     )
     .fetch_all(pool)
     .await?;
@@ -206,33 +759,81 @@ pub async fn query_range(
     let mut recipe_map = HashMap::<i32, Recipe>::new();
 
     for row in rows.iter() {
+        // Checks the hashmap for the current recipe ID, creating a new entry if one does not exist
+        let entry = recipe_map.entry(row.recipe_id).or_insert(
+            Recipe::new(
+                row.recipe_id,
+                row.recipe_name.clone(),
+                row.recipe_portions,
+                row.recipe_steps.0.clone(),
+                vec![],
+            )
+            .with_metadata(RecipeMetadata {
+                prep_time: row.prep_time,
+                cook_time: row.cook_time,
+                total_time: row.total_time,
+                source_url: row.source_url.clone(),
+                notes: row.notes.clone(),
+                yield_description: row.yield_description.clone(),
+                difficulty: Difficulty::parse(row.difficulty.clone()),
+            })
+            .with_version(row.version)
+            .with_created_at(row.created_at.clone()),
+        );
+
+        // A recipe made only of sub-recipes has a single row with no ingredient.
+        let Some(ingredient_id) = row.ingredient_id else {
+            continue;
+        };
         let ingredient = Ingredient::new(
-            row.ingredient_id,
-            row.ingredient_name.clone(),
+            ingredient_id,
+            row.ingredient_name.clone().unwrap_or_default(),
             row.user_id,
             row.ingredient_unit.clone(),
-            row.ingredient_minimum_quantity,
-            row.ingredient_purchase_quantity,
-            row.ingredient_life,
+            row.ingredient_minimum_quantity.unwrap_or_default(),
+            row.ingredient_purchase_quantity.unwrap_or_default(),
+            row.ingredient_life.unwrap_or_default(),
+        )
+        .with_family(row.ingredient_family_id)
+        .with_category(row.ingredient_category.clone())
+        .with_price(row.ingredient_price)
+        .with_nutrition(Nutrition::from_columns(
+            row.energy,
+            row.protein,
+            row.fat,
+            row.carbohydrate,
+            row.fibre,
+            row.salt,
+        ))
+        .with_diet(
+            Allergen::parse_all(&row.allergens.clone().unwrap_or_default()),
+            row.vegetarian.unwrap_or_default(),
+            row.vegan.unwrap_or_default(),
         );
 
-        let ingredient_quantity = IngredientQuantity::new(ingredient, row.ingredient_quantity);
-
-        // Checks the hashmap for the current recipe ID, creating a new entry if one does not exist
-        let entry = recipe_map.entry(row.recipe_id).or_insert(Recipe::new(
-            row.recipe_id,
-            row.recipe_name.clone(),
-            row.recipe_portions,
-            row.recipe_steps.clone(),
-            vec![],
-        ));
-
+        let ingredient_quantity =
+            IngredientQuantity::new(ingredient, row.ingredient_quantity.unwrap_or_default());
         if !entry.ingredients.contains(&ingredient_quantity) {
-            entry.ingredients.push(ingredient_quantity);
+            entry.add_ingredient(ingredient_quantity);
         }
     }
 
+    recipe::attach_sub_recipes(pool, user_id, recipe_map.values_mut()).await?;
+    images::attach(pool, recipe_map.values_mut()).await?;
+
+    // Amounts actually used by any cooked meals in the range
+    let day_ids: Vec<i32> = rows.iter().map(|row| row.day_id).collect();
+    let usage = sqlx::query!(
+        "SELECT day_id, ingredient_id, quantity FROM day_usage
+		WHERE day_id = ANY($1)
+		ORDER BY ingredient_id",
+        &day_ids
+    )
+    .fetch_all(pool)
+    .await?;
+
     // Group the list of day/recipe combinations into a map of dates to lists of recipes.
+    // Meals eaten from leftovers are kept apart, as they need no ingredients of their own.
     let mut day_map = HashMap::<String, Day>::new();
 
     for row in rows {
@@ -241,33 +842,194 @@ pub async fn query_range(
             let entry = day_map
                 .entry(row.date.to_string())
                 .or_insert(Day::new(row.date.to_string(), vec![]));
-            if !entry.recipes.contains(recipe) {
-                entry.recipes.push(recipe.clone());
+            match row.cooked_on {
+                Some(cooked_on) => {
+                    let leftovers = PlannedLeftovers {
+                        recipe: recipe.clone(),
+                        cooked_on: cooked_on.to_string(),
+                        portions: row.portions,
+                        remaining_portions: row.remaining_portions.unwrap_or_default() as i32,
+                    };
+                    if !entry.leftovers.contains(&leftovers) {
+                        entry.add_leftovers(leftovers);
+                    }
+                }
+                None => {
+                    if !entry.recipes.contains(recipe) {
+                        entry.add_recipe(recipe.clone());
+                    }
+                    let status = MealStatus::parse(row.status.as_deref());
+                    let recorded = entry.records.iter().any(|r| r.recipe_id == recipe.id);
+                    if status != MealStatus::Planned && !recorded {
+                        entry.records.push(MealRecord {
+                            recipe_id: recipe.id,
+                            status,
+                            used: usage
+                                .iter()
+                                .filter(|u| u.day_id == row.day_id)
+                                .map(|u| UsedQuantity {
+                                    id: u.ingredient_id,
+                                    quantity: u.quantity,
+                                })
+                                .collect(),
+                        });
+                    }
+                }
             }
         }
     }
 
-    let mut days: Vec<Day> = day_map.values().into_iter().cloned().collect();
+    // Warn about any planned recipes that don't suit the user's diet.
+    let profile = diet::query_profile(pool, user_id).await?;
+    for day in day_map.values_mut() {
+        day.warnings = day
+            .recipes
+            .iter()
+            .filter_map(|r| profile.check(r))
+            .collect();
+    }
+
+    let mut days: Vec<Day> = day_map.values().cloned().collect();
     days.sort_by(|a, b| a.date.cmp(&b.date));
     Ok(days)
 }
 
+/// Checks that a meal eaten from leftovers follows a meal of the same recipe, and that enough
+/// portions of it are left.
+pub async fn check_leftovers<'e>(
+    executor: impl sqlx::PgExecutor<'e>,
+    user_id: i32,
+    day: &DayInput,
+) -> Result<Result<(), InvalidDay>, sqlx::Error> {
+    let portions = day.portions.unwrap_or(1);
+    if portions < 1 {
+        return Ok(Err(InvalidDay(
+            "At least one portion must be eaten.".into(),
+        )));
+    }
+    let Some(leftovers_from) = &day.leftovers_from else {
+        return Ok(Ok(()));
+    };
+    let (Ok(date), Ok(cooked_on)) = (parse_date(&day.date), parse_date(leftovers_from)) else {
+        return Ok(Err(InvalidDay(
+            "Dates must be in the format YYYY-MM-DD.".into(),
+        )));
+    };
+    if cooked_on >= date {
+        return Ok(Err(InvalidDay(
+            "Leftovers can only be eaten after the day they were cooked.".into(),
+        )));
+    }
+
+    let cooked = sqlx::query!(
+        "SELECT r.portions - cooked.portions - COALESCE((
+			SELECT SUM(l.portions) FROM day AS l WHERE l.leftovers_of = cooked.id
+		), 0) as \"remaining_portions!\"
+		FROM day AS cooked
+			INNER JOIN recipe AS r ON r.id = cooked.recipe_id
+		WHERE r.user_id = $1 AND r.id = $2 AND cooked.date = $3 AND cooked.leftovers_of IS NULL
+		ORDER BY cooked.id
+		LIMIT 1",
+        user_id,
+        day.recipe_id,
+        cooked_on
+    )
+    .fetch_optional(executor)
+    .await?;
+
+    Ok(match cooked {
+        None => Err(InvalidDay(format!(
+            "The recipe isn't cooked on {}.",
+            leftovers_from
+        ))),
+        Some(cooked) if cooked.remaining_portions < portions as i64 => Err(InvalidDay(format!(
+            "Only {} portions of the recipe are left.",
+            cooked.remaining_portions.max(0)
+        ))),
+        Some(_) => Ok(()),
+    })
+}
+
 // Creates a new day/recipe entry. Also verifies the passed user_id owns the recipe.
+// Meals eaten from leftovers are linked to the first meal of the recipe on `leftovers_from`.
 pub async fn create(
     pool: &sqlx::Pool<sqlx::Postgres>,
     user_id: i32,
     day: DayInput,
 ) -> Result<(), Box<dyn Error>> {
     let date = parse_date(&day.date)?;
+    let cooked_on = day.leftovers_from.as_deref().map(parse_date).transpose()?;
+    let portions = day.portions.unwrap_or(1);
+    insert(pool, user_id, day.recipe_id, date, portions, cooked_on).await?;
 
-    sqlx::query!(
-        "INSERT INTO day (recipe_id, date) SELECT $1, $2
+    Ok(())
+}
+
+/// Plans a recipe on a date, if the user owns it and it isn't already planned then, so that a
+/// retried request doesn't plan it twice. Returns the number of meals planned.
+///
+/// Each recipe can only be planned once a day, so every insert into `day` skips recipes that are
+/// already planned on their date with `ON CONFLICT DO NOTHING`.
+async fn insert<'e>(
+    executor: impl sqlx::PgExecutor<'e>,
+    user_id: i32,
+    recipe_id: i32,
+    date: Date,
+    portions: i32,
+    cooked_on: Option<Date>,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query!(
+        "INSERT INTO day (recipe_id, date, portions, leftovers_of) 
+		SELECT $1, $2, $4, (
+			SELECT cooked.id FROM day AS cooked
+			WHERE cooked.recipe_id = $1 AND cooked.date = $5 AND cooked.leftovers_of IS NULL
+			ORDER BY cooked.id
+			LIMIT 1
+		)
 		WHERE EXISTS (
 			SELECT id FROM recipe WHERE user_id = $3 AND id = $1
-		)",
-        day.recipe_id,
+		)
+		ON CONFLICT (recipe_id, date) DO NOTHING",
+        recipe_id,
         date,
-        user_id
+        user_id,
+        portions,
+        cooked_on
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// Adds several recipes to days at once. Either all are added, or none are.
+/// Each recipe is cooked on its day, so `leftovers_from` is not used.
+pub async fn create_many(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    days: Vec<DayInput>,
+) -> Result<(), Box<dyn Error>> {
+    let mut recipe_ids: Vec<i32> = vec![];
+    let mut dates: Vec<Date> = vec![];
+    let mut portions: Vec<i32> = vec![];
+    for day in days {
+        recipe_ids.push(day.recipe_id);
+        dates.push(parse_date(&day.date)?);
+        portions.push(day.portions.unwrap_or(1));
+    }
+
+    sqlx::query!(
+        "INSERT INTO day (recipe_id, date, portions)
+		SELECT d.recipe_id, d.date, d.portions
+		FROM UNNEST($1::integer[], $2::date[], $4::integer[]) AS d(recipe_id, date, portions)
+		WHERE EXISTS (
+			SELECT id FROM recipe WHERE user_id = $3 AND id = d.recipe_id
+		)
+		ON CONFLICT (recipe_id, date) DO NOTHING",
+        &recipe_ids,
+        &dates,
+        user_id,
+        &portions
     )
     .execute(pool)
     .await?;
@@ -283,7 +1045,19 @@ pub async fn delete_day_recipe(
     recipe_id: i32,
 ) -> Result<(), Box<dyn Error>> {
     let date = parse_date(&date)?;
-    sqlx::query!(
+    remove(pool, user_id, recipe_id, date).await?;
+
+    Ok(())
+}
+
+/// Removes a user's recipe from a date. Returns the number of meals removed.
+async fn remove<'e>(
+    executor: impl sqlx::PgExecutor<'e>,
+    user_id: i32,
+    recipe_id: i32,
+    date: Date,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query!(
         "DELETE FROM day 
 		WHERE recipe_id = $1 
 			AND date = $2 
@@ -294,29 +1068,371 @@ pub async fn delete_day_recipe(
         date,
         user_id
     )
-    .execute(pool)
+    .execute(executor)
     .await?;
 
-    Ok(())
+    Ok(result.rows_affected())
+}
+
+/// The most operations a single batch can hold.
+pub const MAX_BATCH_SIZE: usize = 100;
+
+/// One change to a meal plan, made as part of a batch.
+#[derive(Deserialize, Debug, ToSchema, TS)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PlanOperation {
+    /// Plans a recipe on a date, the same as creating a day.
+    Add(DayInput),
+    /// Moves a recipe from one date to another.
+    Move {
+        recipe_id: i32,
+        from: String,
+        to: String,
+    },
+    /// Removes a recipe from a date.
+    Delete { recipe_id: i32, date: String },
+}
+
+/// What happened to each operation in a batch.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, ToSchema, TS)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum OperationResult {
+    /// The operation succeeded. It is still undone if a later one fails.
+    Ok,
+    /// The operation failed, so the whole batch was undone.
+    Failed { error: String },
+    /// The operation wasn't tried, as an earlier one failed.
+    Skipped,
+}
+
+/// The outcome of a batch of changes to a meal plan.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, ToSchema, TS)]
+pub struct BatchResult {
+    /// True if every operation succeeded and the batch was saved.
+    pub applied: bool,
+    /// The result of each operation, in the order they were given.
+    pub results: Vec<OperationResult>,
+}
+
+/// Applies a list of changes to a user's meal plan in order. Either all of them are made, or, if
+/// any fails, none are.
+pub async fn apply_batch(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    operations: Vec<PlanOperation>,
+) -> Result<Result<BatchResult, InvalidDay>, sqlx::Error> {
+    if operations.len() > MAX_BATCH_SIZE {
+        return Ok(Err(InvalidDay(format!(
+            "A batch can hold at most {} operations.",
+            MAX_BATCH_SIZE
+        ))));
+    }
+
+    let mut tx = pool.begin().await?;
+    let mut results = vec![];
+    let mut failed = false;
+    for operation in operations {
+        if failed {
+            results.push(OperationResult::Skipped);
+            continue;
+        }
+        match apply_operation(&mut tx, user_id, operation).await? {
+            Ok(()) => results.push(OperationResult::Ok),
+            Err(e) => {
+                failed = true;
+                results.push(OperationResult::Failed { error: e.0 });
+            }
+        }
+    }
+
+    if failed {
+        tx.rollback().await?;
+    } else {
+        tx.commit().await?;
+    }
+    Ok(Ok(BatchResult {
+        applied: !failed,
+        results,
+    }))
+}
+
+async fn apply_operation(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    user_id: i32,
+    operation: PlanOperation,
+) -> Result<Result<(), InvalidDay>, sqlx::Error> {
+    let invalid_date = || {
+        Ok(Err(InvalidDay(
+            "Dates must be in the format YYYY-MM-DD.".into(),
+        )))
+    };
+    let not_planned = |date: &str| {
+        Ok(Err(InvalidDay(format!(
+            "The recipe isn't planned on {}.",
+            date
+        ))))
+    };
+
+    match operation {
+        PlanOperation::Add(day) => {
+            if let Err(e) = check_leftovers(&mut **tx, user_id, &day).await? {
+                return Ok(Err(e));
+            }
+            let Ok(date) = parse_date(&day.date) else {
+                return invalid_date();
+            };
+            let cooked_on = day.leftovers_from.as_deref().map(parse_date);
+            let Ok(cooked_on) = cooked_on.transpose() else {
+                return invalid_date();
+            };
+            let portions = day.portions.unwrap_or(1);
+            if insert(&mut **tx, user_id, day.recipe_id, date, portions, cooked_on).await? == 0 {
+                return Ok(Err(InvalidDay(format!(
+                    "The recipe doesn't exist, or is already planned on {}.",
+                    day.date
+                ))));
+            }
+        }
+        PlanOperation::Move {
+            recipe_id,
+            from,
+            to,
+        } => {
+            let (Ok(from_date), Ok(to_date)) = (parse_date(&from), parse_date(&to)) else {
+                return invalid_date();
+            };
+            let planned = sqlx::query_scalar!(
+                "SELECT EXISTS (
+					SELECT id FROM day WHERE recipe_id = $1 AND date = $2
+				) as \"planned!\"",
+                recipe_id,
+                to_date
+            )
+            .fetch_one(&mut **tx)
+            .await?;
+            if planned && from_date != to_date {
+                return Ok(Err(InvalidDay(format!(
+                    "The recipe is already planned on {}.",
+                    to
+                ))));
+            }
+            let moved = sqlx::query!(
+                "UPDATE day SET date = $3
+				WHERE recipe_id = $1
+					AND date = $2
+					AND EXISTS (
+						SELECT id FROM recipe WHERE user_id = $4 AND id = $1
+					)",
+                recipe_id,
+                from_date,
+                to_date,
+                user_id
+            )
+            .execute(&mut **tx)
+            .await?;
+            if moved.rows_affected() == 0 {
+                return not_planned(&from);
+            }
+
+            // Leftovers must still be eaten after the meal they came from.
+            let out_of_order = sqlx::query_scalar!(
+                "SELECT EXISTS (
+					SELECT leftovers.id FROM day AS leftovers
+						INNER JOIN day AS cooked ON leftovers.leftovers_of = cooked.id
+					WHERE cooked.recipe_id = $1 AND leftovers.date <= cooked.date
+				) as \"out_of_order!\"",
+                recipe_id
+            )
+            .fetch_one(&mut **tx)
+            .await?;
+            if out_of_order {
+                return Ok(Err(InvalidDay(
+                    "Leftovers can only be eaten after the day they were cooked.".into(),
+                )));
+            }
+        }
+        PlanOperation::Delete { recipe_id, date } => {
+            let Ok(parsed) = parse_date(&date) else {
+                return invalid_date();
+            };
+            if remove(&mut **tx, user_id, recipe_id, parsed).await? == 0 {
+                return not_planned(&date);
+            }
+        }
+    }
+
+    Ok(Ok(()))
+}
+
+/// Records whether a user's planned recipe was cooked on a date, and how much of each ingredient
+/// it actually used. Returns `false` if the recipe wasn't planned on the date.
+pub async fn record_meal(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    date: String,
+    recipe_id: i32,
+    record: MealRecordInput,
+) -> Result<Result<bool, InvalidDay>, Box<dyn Error>> {
+    let Ok(date) = parse_date(&date) else {
+        return Ok(Err(InvalidDay(
+            "Dates must be in the format YYYY-MM-DD.".into(),
+        )));
+    };
+    if record.status != MealStatus::Cooked && !record.used.is_empty() {
+        return Ok(Err(InvalidDay(
+            "Amounts used can only be recorded for cooked meals.".into(),
+        )));
+    }
+    if record.used.iter().any(|u| u.quantity < 0) {
+        return Ok(Err(InvalidDay("Amounts used can't be negative.".into())));
+    }
+    if !record.used.is_empty() {
+        let recipe = match recipe::query_single(pool, user_id, recipe_id).await {
+            Ok(recipe) => recipe,
+            Err(sqlx::Error::RowNotFound) => return Ok(Ok(false)),
+            Err(e) => return Err(e.into()),
+        };
+        let ingredients = recipe.all_ingredients();
+        if let Some(unused) = record
+            .used
+            .iter()
+            .find(|u| !ingredients.iter().any(|i| i.ingredient.id == u.id))
+        {
+            return Ok(Err(InvalidDay(format!(
+                "Ingredient {} isn't used by the recipe.",
+                unused.id
+            ))));
+        }
+    }
+
+    let mut tx = pool.begin().await?;
+
+    let updated = sqlx::query!(
+        "UPDATE day SET status = $4
+		WHERE recipe_id = $1
+			AND date = $2
+			AND leftovers_of IS NULL
+			AND EXISTS (
+				SELECT id FROM recipe WHERE user_id = $3 AND id = $1
+			)
+		RETURNING id",
+        recipe_id,
+        date,
+        user_id,
+        record.status.as_str()
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+    if updated.is_empty() {
+        return Ok(Ok(false));
+    }
+
+    let day_ids: Vec<i32> = updated.iter().map(|row| row.id).collect();
+    sqlx::query!("DELETE FROM day_usage WHERE day_id = ANY($1)", &day_ids)
+        .execute(&mut *tx)
+        .await?;
+
+    let ingredient_ids: Vec<i32> = record.used.iter().map(|u| u.id).collect();
+    let quantities: Vec<i32> = record.used.iter().map(|u| u.quantity).collect();
+    sqlx::query!(
+        "INSERT INTO day_usage (day_id, ingredient_id, quantity)
+		SELECT d.id, u.ingredient_id, u.quantity
+		FROM UNNEST($1::integer[]) AS d(id)
+			CROSS JOIN UNNEST($2::integer[], $3::integer[]) AS u(ingredient_id, quantity)
+		ON CONFLICT (day_id, ingredient_id) DO UPDATE SET quantity = EXCLUDED.quantity",
+        &day_ids,
+        &ingredient_ids,
+        &quantities
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(Ok(true))
 }
 
 /// Generates a shopping list from a range of days. Surplus from previous weeks
 /// is totalled and subtracted from each ingredient's purchase amount.
-/// Expired ingredients will not be counted.
+/// Expired ingredients will not be counted. Pack sizes of the same product are
+/// merged, and bought in the combination that wastes the least. Meals eaten from
+/// leftovers are cooked earlier, so add nothing to the list.
 pub async fn build_list_for_range(
     pool: &sqlx::Pool<sqlx::Postgres>,
     user_id: i32,
     from: String,
     to: String,
 ) -> Result<ShoppingList, Box<dyn Error>> {
+    let shopping_list = build_mapped_list(pool, user_id, &from, &to).await?;
+
+    let mut list: ShoppingList = shopping_list.into();
+    list.sort_by_aisle(&ingredient::query_aisles(pool, user_id).await?);
+    Ok(list)
+}
+
+/// Ranks the recipes planned in a range of days by the waste they are projected to cause.
+pub async fn waste_for_range(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    from: String,
+    to: String,
+) -> Result<Vec<RecipeWaste>, Box<dyn Error>> {
+    let shopping_list = build_mapped_list(pool, user_id, &from, &to).await?;
+    Ok(shopping_list.recipe_waste())
+}
+
+/// Ranks the user's recipes by how much of them could be made on `date` from the surplus
+/// left over from earlier meals.
+pub async fn cookable_recipes(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    date: String,
+) -> Result<Vec<CookableRecipe>, Box<dyn Error>> {
+    let recipes = recipe::query_multiple(pool, user_id).await?;
+
+    // Check far enough back to catch surplus of any ingredient the recipes use
+    let days_to_check = recipes
+        .iter()
+        .flat_map(|r| r.all_ingredients())
+        .map(|i| i.ingredient.life)
+        .max()
+        .unwrap_or(0);
+    let list = start_list(pool, user_id, &date, days_to_check).await?;
+
+    let mut cookable = vec![];
+    for recipe in recipes {
+        // Make each recipe on its own, and see what would need to be bought
+        let mut trial = list.clone();
+        trial.add_recipe(&date, recipe.clone())?;
+        let trial: ShoppingList = trial.into();
+        cookable.push(CookableRecipe::new(recipe, trial.ingredients));
+    }
+
+    cookable.sort_by(|a, b| {
+        b.coverage
+            .total_cmp(&a.coverage)
+            .then(a.missing.len().cmp(&b.missing.len()))
+            .then(a.recipe.id.cmp(&b.recipe.id))
+    });
+    Ok(cookable)
+}
+
+/// Builds the mapped shopping list for a range of days, projecting waste from any meals
+/// planned after the range.
+async fn build_mapped_list(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    from: &str,
+    to: &str,
+) -> Result<MappedShoppingList, Box<dyn Error>> {
     // Days in the current range
-    let days = query_range(&pool, user_id, &from, &to).await?;
+    let days = query_range(pool, user_id, from, to).await?;
 
     // Get the longest possible ingredient lifetime
     let ingredients: Vec<IngredientQuantity> = days
         .iter()
-        .flat_map(|day| day.recipes.clone())
-        .flat_map(|r| r.ingredients.clone())
+        .flat_map(|day| day.meals())
+        .flat_map(|r| r.all_ingredients())
         .collect();
 
     // Get the age of the oldest used ingredient - defaults to 0.
@@ -325,28 +1441,24 @@ pub async fn build_list_for_range(
         .max_by(|a, b| a.ingredient.life.cmp(&b.ingredient.life))
         .map_or(0, |i| i.ingredient.life);
 
-    let surplus_check_start = sub_days(&from, days_to_check)?;
-    let surplus_check_end = sub_days(&from, 1)?;
-
-    // Query enough days to catch all potential non-expired surplus
-    let surplus_check_days =
-        query_range(&pool, user_id, &surplus_check_start, &surplus_check_end).await?;
-
-    // Get the currently available surplus
-    let surplus = get_surplus(surplus_check_days, &from)?;
-
-    let mut shopping_list = MappedShoppingList::new(surplus);
+    let mut shopping_list = start_list(pool, user_id, from, days_to_check).await?;
     for day in days {
-        for recipe in day.recipes {
-            shopping_list.add_recipe(recipe);
+        for recipe in day.meals() {
+            shopping_list.add_recipe(&day.date, recipe)?;
         }
     }
 
+    // Meals planned after the range can use up leftovers before they expire
+    let waste_check_start = add_days(to, 1)?;
+    let waste_check_end = add_days(to, days_to_check)?;
+    let waste_check_days = query_range(pool, user_id, &waste_check_start, &waste_check_end).await?;
+    shopping_list.project_waste(&waste_check_days)?;
+
     // Query the user's ingredient purchasing schedule
-    let schedule = ingredient::query_scheduled(&pool, user_id).await?;
+    let schedule = ingredient::query_scheduled(pool, user_id).await?;
     // Check each day in the range for scheduled ingredient occurences and add them.
-    let mut day = parse_date(&from)?;
-    let target = parse_date(&to);
+    let mut day = parse_date(from)?;
+    let target = parse_date(to);
     while day <= target? {
         for item in schedule.clone().into_iter() {
             let age = day_diff(&day.to_string(), &item.start_date)?;
@@ -357,48 +1469,177 @@ pub async fn build_list_for_range(
         day += Duration::from_secs(60 * 60 * 24);
     }
 
-    // Sort and return the list, after conversion into the response type
-    let mut list: ShoppingList = shopping_list.into();
-    list.ingredients
-        .sort_by(|a, b| a.ingredient.id.cmp(&b.ingredient.id));
-    Ok(list)
+    Ok(shopping_list)
+}
+
+/// Starts an empty shopping list on `from`, holding the surplus that is still in date from
+/// the `days_to_check` days before it.
+pub async fn start_list(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    from: &str,
+    days_to_check: i32,
+) -> Result<MappedShoppingList, Box<dyn Error>> {
+    let (surplus, families) = query_surplus(pool, user_id, from, days_to_check).await?;
+    let surplus = surplus
+        .into_iter()
+        .map(|(id, held)| (id, IngredientQuantity::new(held.ingredient, held.quantity)))
+        .collect();
+
+    Ok(MappedShoppingList::new(surplus, families))
+}
+
+/// Finds the surplus that is still in date on `from`, left over from the `days_to_check` days
+/// before it. Also returns the user's ingredient families, which the surplus is totalled by.
+pub async fn query_surplus(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    from: &str,
+    days_to_check: i32,
+) -> Result<(HashMap<i32, HeldSurplus>, Families), Box<dyn Error>> {
+    let surplus_check_start = sub_days(from, days_to_check)?;
+    let surplus_check_end = sub_days(from, 1)?;
+
+    // Query enough days to catch all potential non-expired surplus
+    let surplus_check_days =
+        query_range(pool, user_id, &surplus_check_start, &surplus_check_end).await?;
+
+    // Families allow pack sizes of the same product to be bought interchangeably
+    let families = Families::new(ingredient::query_families(pool, user_id).await?);
+
+    // Anything recorded as bought over the same days
+    let purchases =
+        purchase::query_range(pool, user_id, &surplus_check_start, &surplus_check_end).await?;
+
+    // Get the currently available surplus
+    let surplus = get_surplus(surplus_check_days, &purchases, from, &families)?;
+
+    Ok((surplus, families))
+}
+
+/// An amount of an ingredient left over, and the date the last of it was bought.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeldSurplus {
+    pub ingredient: Ingredient,
+    pub quantity: i32,
+    pub bought_on: String,
 }
 
 /// Takes a set of days and an end date, and returns the set of ingredients left over from those days
-/// that are still in date.
+/// that are still in date. Family members are totalled against their family. Skipped meals use
+/// nothing, and cooked meals use the amounts recorded against them where they differ from the
+/// recipe. Ingredients with recorded purchases over the same days are totalled from those;
+/// otherwise, purchases are inferred from the packs needed to cover each meal. Older stock is
+/// assumed to be used first, so whatever is left of an ingredient is from its last purchase.
 pub fn get_surplus(
     days: Vec<Day>,
-    end_date: &String,
-) -> Result<HashMap<i32, IngredientQuantity>, Box<dyn Error>> {
+    purchases: &[Purchase],
+    end_date: &str,
+    families: &Families,
+) -> Result<HashMap<i32, HeldSurplus>, Box<dyn Error>> {
     // Maps ingredient IDs to the quantity available in surplus
-    let mut surplus: HashMap<i32, IngredientQuantity> = HashMap::new();
+    let mut surplus: HashMap<i32, HeldSurplus> = HashMap::new();
+
+    let tracked: HashSet<i32> = purchases
+        .iter()
+        .map(|p| families.resolve(&p.ingredient).id)
+        .collect();
+    let mut purchases = purchases.iter().peekable();
 
     // Check each ingredient of each day
     for day in days {
-        for ingredient in day.recipes.iter().flat_map(|r| r.ingredients.clone()) {
-            // Skip the ingredient if it's likely to be out of date
-            if ingredient.ingredient.life <= day_diff(&end_date, &day.date)? {
-                continue;
-            }
+        // Anything bought by the day can be used by its meals
+        while let Some(purchase) = purchases.next_if(|p| p.date <= day.date) {
+            add_purchase(&mut surplus, purchase, end_date, families)?;
+        }
+
+        let meals = day.meals();
+        for recipe in &day.recipes {
+            // Skipped meals use nothing, though their ingredients were still bought
+            let used = meals
+                .iter()
+                .find(|m| m.id == recipe.id)
+                .map(Recipe::all_ingredients)
+                .unwrap_or_default();
+
+            for ingredient in recipe.all_ingredients() {
+                // Skip the ingredient if it's likely to be out of date
+                if ingredient.ingredient.life <= day_diff(end_date, &day.date)? {
+                    continue;
+                }
 
-            let entry = surplus
-                .entry(ingredient.ingredient.id)
-                .or_insert(IngredientQuantity::new(ingredient.ingredient.clone(), 0));
+                let resolved = families.resolve(&ingredient.ingredient);
+                let entry = surplus.entry(resolved.id).or_insert(HeldSurplus {
+                    ingredient: resolved.clone(),
+                    quantity: 0,
+                    bought_on: day.date.clone(),
+                });
+                let used_quantity = used
+                    .iter()
+                    .find(|u| u.ingredient.id == ingredient.ingredient.id)
+                    .map_or(0, |u| u.quantity);
 
-            // Reduce the surplus by the amount used
-            entry.quantity -= ingredient.quantity;
+                // Recorded purchases are all that's held. Anything used beyond them must have
+                // come from stock bought earlier.
+                if tracked.contains(&resolved.id) {
+                    entry.quantity = (entry.quantity - used_quantity).max(0);
+                    continue;
+                }
 
-            // If the quantity is less than 0, assume more of the ingredient was purchased.
-            if entry.quantity < 0 {
-                // The minumum purchase required to cover the deficit
-                let amount_purchased = ingredient
-                    .ingredient
-                    .scale_purchase_quantity(entry.quantity.abs());
+                // If the surplus doesn't cover the recipe, assume more of the ingredient was
+                // purchased, in the packs with the least waste that cover the deficit.
+                if entry.quantity < ingredient.quantity {
+                    entry.quantity +=
+                        purchase(families, &resolved, ingredient.quantity - entry.quantity);
+                    entry.bought_on = day.date.clone();
+                }
 
-                entry.quantity += amount_purchased;
+                // Reduce the surplus by the amount actually used
+                entry.quantity -= used_quantity;
+
+                // Using more than the recipe called for means even more was purchased
+                if entry.quantity < 0 {
+                    entry.quantity += purchase(families, &resolved, entry.quantity.abs());
+                    entry.bought_on = day.date.clone();
+                }
             }
         }
     }
 
+    for purchase in purchases {
+        add_purchase(&mut surplus, purchase, end_date, families)?;
+    }
+
     Ok(surplus)
 }
+
+/// Adds a recorded purchase to the surplus, unless it will have expired by `end_date`.
+fn add_purchase(
+    surplus: &mut HashMap<i32, HeldSurplus>,
+    purchase: &Purchase,
+    end_date: &str,
+    families: &Families,
+) -> Result<(), Box<dyn Error>> {
+    if purchase.ingredient.life <= day_diff(end_date, &purchase.date)? {
+        return Ok(());
+    }
+    let resolved = families.resolve(&purchase.ingredient);
+    let entry = surplus.entry(resolved.id).or_insert(HeldSurplus {
+        ingredient: resolved.clone(),
+        quantity: 0,
+        bought_on: purchase.date.clone(),
+    });
+    entry.quantity += purchase.quantity;
+    entry.bought_on = purchase.date.clone();
+    Ok(())
+}
+
+/// The amount bought when purchasing the packs of an ingredient that cover a quantity with the
+/// least waste.
+fn purchase(families: &Families, ingredient: &Ingredient, quantity: i32) -> i32 {
+    families
+        .choose_packs(ingredient, quantity)
+        .iter()
+        .map(PackQuantity::quantity)
+        .sum()
+}
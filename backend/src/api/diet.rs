@@ -0,0 +1,207 @@
+use serde::{
+    de::{value, IntoDeserializer},
+    Deserialize, Serialize,
+};
+
+use super::{ingredient::IngredientQuantity, recipe::Recipe};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// An allergen an ingredient may contain, following the UK's 14 major allergens.
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ToSchema, TS,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Allergen {
+    Celery,
+    Gluten,
+    Crustaceans,
+    Egg,
+    Fish,
+    Lupin,
+    Dairy,
+    Molluscs,
+    Mustard,
+    Nuts,
+    Peanuts,
+    Sesame,
+    Soya,
+    Sulphites,
+}
+
+impl Allergen {
+    /// Parses a list of allergens stored in the database, ignoring any that aren't recognised.
+    pub fn parse_all(values: &[String]) -> Vec<Allergen> {
+        let mut allergens: Vec<Allergen> = values
+            .iter()
+            .filter_map(|v| {
+                let deserializer: value::StrDeserializer<value::Error> =
+                    v.as_str().into_deserializer();
+                Allergen::deserialize(deserializer).ok()
+            })
+            .collect();
+        allergens.sort();
+        allergens.dedup();
+        allergens
+    }
+
+    /// The name the allergen is stored under.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Allergen::Celery => "celery",
+            Allergen::Gluten => "gluten",
+            Allergen::Crustaceans => "crustaceans",
+            Allergen::Egg => "egg",
+            Allergen::Fish => "fish",
+            Allergen::Lupin => "lupin",
+            Allergen::Dairy => "dairy",
+            Allergen::Molluscs => "molluscs",
+            Allergen::Mustard => "mustard",
+            Allergen::Nuts => "nuts",
+            Allergen::Peanuts => "peanuts",
+            Allergen::Sesame => "sesame",
+            Allergen::Soya => "soya",
+            Allergen::Sulphites => "sulphites",
+        }
+    }
+
+    /// Converts a list of allergens to the names they are stored under.
+    pub fn to_strings(allergens: &[Allergen]) -> Vec<String> {
+        allergens.iter().map(|a| a.as_str().to_string()).collect()
+    }
+}
+
+/// The diet a recipe suits, derived from its ingredients.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq, ToSchema, TS)]
+pub struct RecipeDiet {
+    /// Every allergen found in the recipe's ingredients.
+    pub allergens: Vec<Allergen>,
+    pub vegetarian: bool,
+    pub vegan: bool,
+}
+
+impl Default for RecipeDiet {
+    fn default() -> Self {
+        RecipeDiet {
+            allergens: vec![],
+            vegetarian: true,
+            vegan: true,
+        }
+    }
+}
+
+impl RecipeDiet {
+    /// Classifies a recipe from its ingredients. A recipe is only vegetarian or vegan if all of
+    /// its ingredients are.
+    pub fn classify(ingredients: &[IngredientQuantity]) -> Self {
+        let mut allergens: Vec<Allergen> = ingredients
+            .iter()
+            .flat_map(|i| i.ingredient.allergens.iter().copied())
+            .collect();
+        allergens.sort();
+        allergens.dedup();
+
+        RecipeDiet {
+            allergens,
+            vegetarian: ingredients.iter().all(|i| i.ingredient.vegetarian),
+            vegan: ingredients.iter().all(|i| i.ingredient.vegan),
+        }
+    }
+}
+
+/// The diet a user follows and the allergens they avoid.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default, ToSchema, TS)]
+pub struct DietProfile {
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub vegetarian: bool,
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub vegan: bool,
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub allergens: Vec<Allergen>,
+}
+
+impl DietProfile {
+    /// Checks a recipe against the profile, returning a warning if the recipe doesn't suit it.
+    pub fn check(&self, recipe: &Recipe) -> Option<DietWarning> {
+        let allergens: Vec<Allergen> = recipe
+            .diet
+            .allergens
+            .iter()
+            .filter(|a| self.allergens.contains(a))
+            .copied()
+            .collect();
+        // Vegans are also vegetarian.
+        let not_vegetarian = (self.vegetarian || self.vegan) && !recipe.diet.vegetarian;
+        let not_vegan = self.vegan && !recipe.diet.vegan;
+
+        if allergens.is_empty() && !not_vegetarian && !not_vegan {
+            return None;
+        }
+        Some(DietWarning {
+            recipe_id: recipe.id,
+            recipe_name: recipe.name.clone(),
+            allergens,
+            not_vegetarian,
+            not_vegan,
+        })
+    }
+}
+
+/// A warning that a planned recipe doesn't suit the user's diet profile.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq, ToSchema, TS)]
+pub struct DietWarning {
+    pub recipe_id: i32,
+    pub recipe_name: String,
+    /// The allergens in the recipe that the user avoids.
+    pub allergens: Vec<Allergen>,
+    pub not_vegetarian: bool,
+    pub not_vegan: bool,
+}
+
+/// Fetches a user's diet profile. Users without a profile have no restrictions.
+pub async fn query_profile(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+) -> Result<DietProfile, sqlx::Error> {
+    let row = sqlx::query!(
+        "SELECT vegetarian, vegan, allergens FROM diet_profile WHERE user_id = $1",
+        user_id
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row
+        .map(|row| DietProfile {
+            vegetarian: row.vegetarian,
+            vegan: row.vegan,
+            allergens: Allergen::parse_all(&row.allergens),
+        })
+        .unwrap_or_default())
+}
+
+/// Replaces a user's diet profile.
+pub async fn update_profile(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    profile: DietProfile,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "INSERT INTO diet_profile (user_id, vegetarian, vegan, allergens)
+		VALUES ($1, $2, $3, $4)
+		ON CONFLICT (user_id) DO UPDATE SET
+			vegetarian = EXCLUDED.vegetarian,
+			vegan = EXCLUDED.vegan,
+			allergens = EXCLUDED.allergens",
+        user_id,
+        profile.vegetarian,
+        profile.vegan,
+        &Allergen::to_strings(&profile.allergens)
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
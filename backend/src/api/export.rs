@@ -0,0 +1,265 @@
+use serde::Deserialize;
+
+use super::{
+    day::{IngredientPurchaseQuantity, ShoppingList},
+    ingredient::PackQuantity,
+};
+use utoipa::ToSchema;
+
+/// The formats a shopping list can be exported in.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ListFormat {
+    #[default]
+    Json,
+    Text,
+    Csv,
+    Md,
+    Html,
+}
+
+impl ListFormat {
+    /// The value of the `Content-Type` header for an export in this format.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ListFormat::Json => "application/json",
+            ListFormat::Text => "text/plain; charset=utf-8",
+            ListFormat::Csv => "text/csv; charset=utf-8",
+            ListFormat::Md => "text/markdown; charset=utf-8",
+            ListFormat::Html => "text/html; charset=utf-8",
+        }
+    }
+}
+
+/// Renders a shopping list in a human readable format. JSON lists are returned as-is by the API,
+/// so are rendered as plain text here.
+pub fn render(list: &ShoppingList, format: ListFormat) -> String {
+    match format {
+        ListFormat::Json | ListFormat::Text => to_text(list),
+        ListFormat::Csv => to_csv(list),
+        ListFormat::Md => to_markdown(list),
+        ListFormat::Html => to_html(list),
+    }
+}
+
+/// Pairs each section's title with its items, skipping empty sections.
+fn sections(list: &ShoppingList) -> Vec<(&'static str, &Vec<IngredientPurchaseQuantity>)> {
+    [
+        ("Ingredients", &list.ingredients),
+        ("Scheduled", &list.scheduled_ingredients),
+    ]
+    .into_iter()
+    .filter(|(_, items)| !items.is_empty())
+    .collect()
+}
+
+/// Splits a section's items into consecutive runs from the same aisle. Aisles are only named if
+/// at least one item in the section has a category.
+fn aisles(
+    items: &[IngredientPurchaseQuantity],
+) -> Vec<(Option<String>, Vec<&IngredientPurchaseQuantity>)> {
+    let named = items.iter().any(|i| i.ingredient.category.is_some());
+    let mut aisles: Vec<(Option<String>, Vec<&IngredientPurchaseQuantity>)> = vec![];
+
+    for item in items {
+        let aisle = match &item.ingredient.category {
+            Some(category) => Some(category.clone()),
+            None if named => Some("Other".into()),
+            None => None,
+        };
+        match aisles.last_mut() {
+            Some((last, items)) if *last == aisle => items.push(item),
+            _ => aisles.push((aisle, vec![item])),
+        }
+    }
+    aisles
+}
+
+fn to_text(list: &ShoppingList) -> String {
+    let mut text = String::new();
+    for (title, items) in sections(list) {
+        text += &format!("{}\n", title);
+        for (aisle, items) in aisles(items) {
+            if let Some(aisle) = aisle {
+                text += &format!("-- {} --\n", aisle);
+            }
+            for item in items {
+                text += &format!("- {}: {}", item.ingredient.name, describe_purchase(item));
+                if let Some(note) = surplus_note(item) {
+                    text += &format!(" ({})", note);
+                }
+                text += "\n";
+            }
+        }
+        text += "\n";
+    }
+    text.trim_end().to_string() + "\n"
+}
+
+fn to_markdown(list: &ShoppingList) -> String {
+    let mut markdown = String::from("# Shopping List\n\n");
+    for (title, items) in sections(list) {
+        markdown += &format!("## {}\n\n", title);
+        for (aisle, items) in aisles(items) {
+            if let Some(aisle) = aisle {
+                markdown += &format!("### {}\n\n", escape_markdown(&aisle));
+            }
+            for item in items {
+                markdown += &format!(
+                    "- [ ] **{}**: {}",
+                    escape_markdown(&item.ingredient.name),
+                    describe_purchase(item)
+                );
+                if let Some(note) = surplus_note(item) {
+                    markdown += &format!(" _({})_", note);
+                }
+                markdown += "\n";
+            }
+            markdown += "\n";
+        }
+    }
+    markdown.trim_end().to_string() + "\n"
+}
+
+fn to_csv(list: &ShoppingList) -> String {
+    let mut csv = String::from(
+        "section,aisle,ingredient,unit,purchase_quantity,packs,used_quantity,existing_surplus\n",
+    );
+    for (title, items) in sections(list) {
+        for item in items {
+            let row = [
+                title.to_string(),
+                item.ingredient.category.clone().unwrap_or_default(),
+                item.ingredient.name.clone(),
+                item.ingredient.unit.clone().unwrap_or_default(),
+                item.purchase_quantity.to_string(),
+                describe_packs(&item.packs),
+                item.used_quantity.to_string(),
+                item.existing_surplus.to_string(),
+            ];
+            let row: Vec<String> = row.iter().map(|field| escape_csv(field)).collect();
+            csv += &format!("{}\n", row.join(","));
+        }
+    }
+    csv
+}
+
+fn to_html(list: &ShoppingList) -> String {
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Shopping List</title>\n\
+        <style>body { font-family: sans-serif; } li { list-style: none; margin: 0.25em 0; } \
+        .note { color: #666; }</style>\n</head>\n<body>\n<h1>Shopping List</h1>\n",
+    );
+    for (title, items) in sections(list) {
+        html += &format!("<h2>{}</h2>\n", title);
+        for (aisle, items) in aisles(items) {
+            if let Some(aisle) = aisle {
+                html += &format!("<h3>{}</h3>\n", escape_html(&aisle));
+            }
+            html += "<ul>\n";
+            for item in items {
+                html += &format!(
+                    "<li>&#9744; <strong>{}</strong>: {}",
+                    escape_html(&item.ingredient.name),
+                    escape_html(&describe_purchase(item))
+                );
+                if let Some(note) = surplus_note(item) {
+                    html += &format!(" <span class=\"note\">({})</span>", escape_html(&note));
+                }
+                html += "</li>\n";
+            }
+            html += "</ul>\n";
+        }
+    }
+    html += "</body>\n</html>\n";
+    html
+}
+
+/// Describes what to buy, e.g. "2 × 250 g". Items covered by surplus have nothing to buy.
+fn describe_purchase(item: &IngredientPurchaseQuantity) -> String {
+    if item.purchase_quantity == 0 {
+        return "nothing to buy".into();
+    }
+    if item.packs.is_empty() {
+        return format_quantity(item.purchase_quantity, &item.ingredient.unit);
+    }
+    describe_packs(&item.packs)
+}
+
+/// Describes a set of packs, largest first, e.g. "1 × 2270 ml + 1 × 1130 ml".
+fn describe_packs(packs: &[PackQuantity]) -> String {
+    packs
+        .iter()
+        .map(|p| {
+            format!(
+                "{} × {}",
+                p.count,
+                format_quantity(p.ingredient.purchase_quantity, &p.ingredient.unit)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(" + ")
+}
+
+/// Notes how much of an item is already available from previous meals.
+fn surplus_note(item: &IngredientPurchaseQuantity) -> Option<String> {
+    if item.existing_surplus <= 0 {
+        return None;
+    }
+    Some(format!(
+        "{} already in surplus",
+        format_quantity(item.existing_surplus, &item.ingredient.unit)
+    ))
+}
+
+/// Formats a quantity with its unit, if it has one.
+fn format_quantity(quantity: i32, unit: &Option<String>) -> String {
+    match unit {
+        Some(unit) => format!("{} {}", quantity, unit),
+        None => quantity.to_string(),
+    }
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        if "\\`*_[]#<>".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_csv() {
+        assert_eq!(escape_csv("Salt"), "Salt");
+        assert_eq!(escape_csv("Salt, sea"), "\"Salt, sea\"");
+        assert_eq!(escape_csv("12\" pizza"), "\"12\"\" pizza\"");
+    }
+
+    #[test]
+    fn test_format_quantity() {
+        assert_eq!(format_quantity(250, &Some("g".into())), "250 g");
+        assert_eq!(format_quantity(10, &None), "10");
+    }
+}
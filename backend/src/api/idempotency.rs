@@ -0,0 +1,139 @@
+/// How long a key is remembered for, in hours. Retries after this are handled as new requests.
+pub const KEY_LIFETIME_HOURS: i32 = 24;
+
+/// How long a key is held for a request that hasn't finished, in seconds. If the request never
+/// finishes, e.g. because the client disconnected part way through, a retry can take the key over
+/// after this.
+pub const CLAIM_LEASE_SECONDS: i32 = 60;
+
+/// A response stored against an idempotency key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredResponse {
+    pub status: u16,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+/// What to do with a request made with an idempotency key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Claim {
+    /// The key hasn't been used, and is now held for this request.
+    New,
+    /// A request with the key is still being handled.
+    InProgress,
+    /// The request has already been handled. It shouldn't be run again.
+    Replay(StoredResponse),
+    /// The key has already been used for a different request.
+    Mismatch,
+}
+
+/// A request made with an idempotency key, identified by its method, path and a hash of its body.
+pub struct KeyedRequest<'a> {
+    pub key: &'a str,
+    pub method: &'a str,
+    pub path: &'a str,
+    pub request_hash: &'a str,
+}
+
+/// Holds an idempotency key for a request, unless it has already been used. Keys held by
+/// requests that didn't finish within the lease are released first.
+pub async fn claim(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    request: &KeyedRequest<'_>,
+) -> Result<Claim, sqlx::Error> {
+    sqlx::query!(
+        "DELETE FROM idempotency_key
+		WHERE user_id = $1 AND (created_at < now() - make_interval(hours => $2)
+			OR (status IS NULL AND created_at < now() - make_interval(secs => $3)))",
+        user_id,
+        KEY_LIFETIME_HOURS,
+        f64::from(CLAIM_LEASE_SECONDS)
+    )
+    .execute(pool)
+    .await?;
+
+    let inserted = sqlx::query!(
+        "INSERT INTO idempotency_key (user_id, key, method, path, request_hash)
+		VALUES ($1, $2, $3, $4, $5)
+		ON CONFLICT (user_id, key) DO NOTHING
+		RETURNING key",
+        user_id,
+        request.key,
+        request.method,
+        request.path,
+        request.request_hash
+    )
+    .fetch_optional(pool)
+    .await?;
+    if inserted.is_some() {
+        return Ok(Claim::New);
+    }
+
+    let Some(row) = sqlx::query!(
+        "SELECT method, path, request_hash, status, content_type, body
+		FROM idempotency_key WHERE user_id = $1 AND key = $2",
+        user_id,
+        request.key
+    )
+    .fetch_optional(pool)
+    .await?
+    else {
+        // The key expired between the insert and the select.
+        return Ok(Claim::InProgress);
+    };
+
+    if row.method != request.method
+        || row.path != request.path
+        || row.request_hash != request.request_hash
+    {
+        return Ok(Claim::Mismatch);
+    }
+    Ok(match row.status {
+        Some(status) => Claim::Replay(StoredResponse {
+            status: status as u16,
+            content_type: row.content_type,
+            body: row.body.unwrap_or_default(),
+        }),
+        None => Claim::InProgress,
+    })
+}
+
+/// Stores the response to a request, to be replayed if it is retried.
+pub async fn complete(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    key: &str,
+    response: &StoredResponse,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE idempotency_key SET status = $3, content_type = $4, body = $5
+		WHERE user_id = $1 AND key = $2",
+        user_id,
+        key,
+        response.status as i16,
+        response.content_type,
+        response.body
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Forgets a key, so that a request that failed can be retried.
+pub async fn release(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    key: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "DELETE FROM idempotency_key WHERE user_id = $1 AND key = $2",
+        user_id,
+        key
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
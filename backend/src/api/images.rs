@@ -0,0 +1,344 @@
+use std::{collections::HashMap, fmt, io::Cursor};
+
+use image::{
+    error::ImageError,
+    imageops::FilterType,
+    io::{Limits, Reader},
+    ImageFormat, ImageOutputFormat,
+};
+use serde::Serialize;
+
+use super::{
+    recipe::Recipe,
+    storage::{self, ImageStorage, StorageError},
+};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// The largest image that can be uploaded, in bytes.
+pub const MAX_IMAGE_SIZE: usize = 5 * 1024 * 1024;
+
+/// The longest edge of an image that can be uploaded, in pixels. A small file can still decode
+/// to a huge image, so this is checked before any pixels are decoded.
+pub const MAX_IMAGE_DIMENSION: u32 = 8192;
+
+/// The most memory decoding an upload may use, in bytes. This is enough for an RGBA image of
+/// the largest dimensions.
+const MAX_DECODE_ALLOC: u64 = 256 * 1024 * 1024;
+
+/// The longest edge of a generated thumbnail, in pixels.
+const THUMBNAIL_SIZE: u32 = 320;
+
+/// A photo attached to a recipe, or to one of its steps.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq, ToSchema, TS)]
+pub struct RecipeImage {
+    pub id: i32,
+    /// The index of the step the photo shows, if it isn't of the whole recipe.
+    pub step: Option<i32>,
+    pub url: String,
+    pub thumbnail_url: String,
+}
+
+impl RecipeImage {
+    fn new(id: i32, step: Option<i32>, key: &str, extension: &str) -> Self {
+        RecipeImage {
+            id,
+            step,
+            url: format!("/images/{}", image_name(key, extension)),
+            thumbnail_url: format!("/images/{}", thumbnail_name(key)),
+        }
+    }
+}
+
+/// The name an original image is stored under.
+fn image_name(key: &str, extension: &str) -> String {
+    format!("{}.{}", key, extension)
+}
+
+/// The name a thumbnail is stored under. Thumbnails are always JPEGs.
+fn thumbnail_name(key: &str) -> String {
+    format!("{}-thumb.jpg", key)
+}
+
+/// The image types that can be uploaded, with the extension each is stored under.
+const SUPPORTED_TYPES: [(&str, &str, ImageFormat); 3] = [
+    ("image/jpeg", "jpg", ImageFormat::Jpeg),
+    ("image/png", "png", ImageFormat::Png),
+    ("image/webp", "webp", ImageFormat::WebP),
+];
+
+/// Returns the content type to serve a stored image with, based on its name.
+pub fn content_type_of(name: &str) -> Option<&'static str> {
+    let extension = name.rsplit_once('.')?.1;
+    SUPPORTED_TYPES
+        .iter()
+        .find(|(_, e, _)| *e == extension)
+        .map(|(content_type, _, _)| *content_type)
+}
+
+/// Returned when an upload isn't an image that can be stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidImage {
+    UnsupportedType(String),
+    TooLarge(usize),
+    /// The image is wider or taller than `MAX_IMAGE_DIMENSION`.
+    TooManyPixels,
+    /// The file couldn't be decoded as the type it claimed to be.
+    Unreadable,
+}
+
+impl fmt::Display for InvalidImage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidImage::UnsupportedType(content_type) => write!(
+                f,
+                "Images of type '{}' aren't supported. Use JPEG, PNG or WebP.",
+                content_type
+            ),
+            InvalidImage::TooLarge(size) => write!(
+                f,
+                "The image is {} bytes, but can be at most {} bytes.",
+                size, MAX_IMAGE_SIZE
+            ),
+            InvalidImage::TooManyPixels => write!(
+                f,
+                "The image can be at most {} pixels wide or high.",
+                MAX_IMAGE_DIMENSION
+            ),
+            InvalidImage::Unreadable => write!(f, "The image could not be read."),
+        }
+    }
+}
+
+impl std::error::Error for InvalidImage {}
+
+/// An uploaded image that has been checked and thumbnailed, ready to store.
+#[derive(Debug)]
+pub struct PreparedImage {
+    extension: &'static str,
+    content_type: &'static str,
+    data: Vec<u8>,
+    thumbnail: Vec<u8>,
+}
+
+impl PreparedImage {
+    /// Checks an upload's type and size, makes sure it decodes as the type it claims to be, and
+    /// generates a JPEG thumbnail. Decoding is done on a blocking thread, within limits on the
+    /// image's dimensions and the memory used.
+    pub async fn new(content_type: &str, data: Vec<u8>) -> Result<Self, InvalidImage> {
+        let Some((content_type, extension, format)) = SUPPORTED_TYPES
+            .iter()
+            .find(|(t, _, _)| *t == content_type)
+            .copied()
+        else {
+            return Err(InvalidImage::UnsupportedType(content_type.into()));
+        };
+        if data.len() > MAX_IMAGE_SIZE {
+            return Err(InvalidImage::TooLarge(data.len()));
+        }
+
+        // A decoder that panics on a malformed file has failed to read it
+        let (data, thumbnail) = tokio::task::spawn_blocking(move || {
+            let thumbnail = make_thumbnail(&data, format)?;
+            Ok((data, thumbnail))
+        })
+        .await
+        .map_err(|_| InvalidImage::Unreadable)??;
+
+        Ok(PreparedImage {
+            extension,
+            content_type,
+            data,
+            thumbnail,
+        })
+    }
+}
+
+/// Decodes an image and encodes a JPEG thumbnail of it.
+fn make_thumbnail(data: &[u8], format: ImageFormat) -> Result<Vec<u8>, InvalidImage> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_IMAGE_DIMENSION);
+    limits.max_image_height = Some(MAX_IMAGE_DIMENSION);
+    limits.max_alloc = Some(MAX_DECODE_ALLOC);
+
+    let mut reader = Reader::with_format(Cursor::new(data), format);
+    reader.limits(limits);
+    let image = reader.decode().map_err(|e| match e {
+        ImageError::Limits(_) => InvalidImage::TooManyPixels,
+        _ => InvalidImage::Unreadable,
+    })?;
+
+    let mut thumbnail = Cursor::new(vec![]);
+    image
+        .resize(THUMBNAIL_SIZE, THUMBNAIL_SIZE, FilterType::Triangle)
+        .to_rgb8()
+        .write_to(&mut thumbnail, ImageOutputFormat::Jpeg(80))
+        .map_err(|_| InvalidImage::Unreadable)?;
+    Ok(thumbnail.into_inner())
+}
+
+/// Returns the number of steps in a user's recipe, or `None` if they have no such recipe.
+pub async fn step_count(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    recipe_id: i32,
+) -> Result<Option<i32>, sqlx::Error> {
+    let row = sqlx::query!(
+        "SELECT json_array_length(steps) as \"step_count!\"
+		FROM recipe WHERE id = $1 AND user_id = $2",
+        recipe_id,
+        user_id
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(|row| row.step_count))
+}
+
+/// Stores an image and its thumbnail, then attaches it to a recipe.
+pub async fn upload(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    storage: &dyn ImageStorage,
+    recipe_id: i32,
+    step: Option<i32>,
+    image: PreparedImage,
+) -> Result<RecipeImage, StorageError> {
+    let mut tx = pool.begin().await?;
+
+    let row = sqlx::query!(
+        "INSERT INTO recipe_image (recipe_id, step_index, extension)
+		VALUES ($1, $2, $3)
+		RETURNING id, key",
+        recipe_id,
+        step,
+        image.extension
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    // The row is only committed once both files are stored, so a failed upload leaves nothing
+    // pointing at missing files.
+    storage
+        .put(
+            &image_name(&row.key, image.extension),
+            image.content_type,
+            image.data,
+        )
+        .await?;
+    storage
+        .put(&thumbnail_name(&row.key), "image/jpeg", image.thumbnail)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(RecipeImage::new(row.id, step, &row.key, image.extension))
+}
+
+/// Removes an image from a user's recipe, along with its stored files.
+/// Returns `false` if there was no such image.
+pub async fn delete(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    storage: &dyn ImageStorage,
+    user_id: i32,
+    recipe_id: i32,
+    image_id: i32,
+) -> Result<bool, StorageError> {
+    let row = sqlx::query!(
+        "DELETE FROM recipe_image AS ri
+		USING recipe AS r
+		WHERE ri.recipe_id = r.id AND r.user_id = $1 AND r.id = $2 AND ri.id = $3
+		RETURNING ri.key, ri.extension",
+        user_id,
+        recipe_id,
+        image_id
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(false);
+    };
+    storage
+        .delete(&image_name(&row.key, &row.extension))
+        .await?;
+    storage.delete(&thumbnail_name(&row.key)).await?;
+    Ok(true)
+}
+
+/// Removes the stored files of every image attached to a user's recipe. The rows themselves are
+/// removed with the recipe.
+pub async fn delete_files(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    storage: &dyn ImageStorage,
+    user_id: i32,
+    recipe_id: i32,
+) -> Result<(), StorageError> {
+    let rows = sqlx::query!(
+        "SELECT ri.key, ri.extension
+		FROM recipe_image AS ri
+			INNER JOIN recipe AS r ON ri.recipe_id = r.id
+		WHERE r.user_id = $1 AND r.id = $2",
+        user_id,
+        recipe_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for row in rows {
+        storage
+            .delete(&image_name(&row.key, &row.extension))
+            .await?;
+        storage.delete(&thumbnail_name(&row.key)).await?;
+    }
+    Ok(())
+}
+
+/// Fetches a stored image by the name in its URL, along with the content type to serve it with.
+/// Names that could never have been stored, such as ones containing a path, aren't found.
+pub async fn fetch(
+    storage: &dyn ImageStorage,
+    name: &str,
+) -> Result<Option<(&'static str, Vec<u8>)>, StorageError> {
+    if !storage::is_valid_key(name) {
+        return Ok(None);
+    }
+    let Some(content_type) = content_type_of(name) else {
+        return Ok(None);
+    };
+    Ok(storage.get(name).await?.map(|data| (content_type, data)))
+}
+
+/// Fills in the images of each recipe, in the order they were uploaded.
+pub async fn attach<'a>(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    recipes: impl IntoIterator<Item = &'a mut Recipe>,
+) -> Result<(), sqlx::Error> {
+    let recipes: Vec<&mut Recipe> = recipes.into_iter().collect();
+    let recipe_ids: Vec<i32> = recipes.iter().map(|r| r.id).collect();
+    let rows = sqlx::query!(
+        "SELECT id, recipe_id, step_index, key, extension
+		FROM recipe_image
+		WHERE recipe_id = ANY($1)
+		ORDER BY created_at, id",
+        &recipe_ids
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut images = HashMap::<i32, Vec<RecipeImage>>::new();
+    for row in rows {
+        images
+            .entry(row.recipe_id)
+            .or_default()
+            .push(RecipeImage::new(
+                row.id,
+                row.step_index,
+                &row.key,
+                &row.extension,
+            ));
+    }
+    for recipe in recipes {
+        recipe.images = images.remove(&recipe.id).unwrap_or_default();
+    }
+    Ok(())
+}
@@ -1,10 +1,18 @@
-use std::error::Error;
+use std::{collections::HashMap, error::Error};
 
-use super::utils::parse_date;
+use super::{
+    diet::Allergen,
+    nutrition::Nutrition,
+    page::{Column, InvalidPage, Listing, Page, PageParams},
+    utils::parse_date,
+};
 use serde::{Deserialize, Serialize};
+use sqlx::QueryBuilder;
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 /// Represents an ingredient.
-#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Clone, PartialEq, ToSchema, TS)]
 pub struct Ingredient {
     /// The ingredient's unique ID
     pub id: i32,
@@ -20,6 +28,23 @@ pub struct Ingredient {
     pub purchase_quantity: i32,
     /// The estimated shelf life of the ingredient
     pub life: i32,
+    /// Present if the ingredient is one of several pack sizes of the same product.
+    pub family_id: Option<i32>,
+    /// The aisle the ingredient is found in, e.g. "Fresh Vegetables"
+    pub category: Option<String>,
+    /// The price of one purchase quantity in pence, if known. A user's own price takes the place
+    /// of the retail price.
+    pub price: Option<i32>,
+    /// The price of a single unit (e.g. 1g) in pence, derived from `price`.
+    pub price_per_unit: Option<f64>,
+    /// Nutrition per 100g/ml, if known.
+    pub nutrition: Option<Nutrition>,
+    /// The allergens the ingredient contains.
+    pub allergens: Vec<Allergen>,
+    /// False if the ingredient contains meat or fish.
+    pub vegetarian: bool,
+    /// False if the ingredient contains any animal product.
+    pub vegan: bool,
 }
 
 impl Ingredient {
@@ -40,9 +65,71 @@ impl Ingredient {
             minimum_quantity,
             purchase_quantity,
             life,
+            family_id: None,
+            category: None,
+            price: None,
+            price_per_unit: None,
+            nutrition: None,
+            allergens: vec![],
+            vegetarian: true,
+            vegan: true,
         }
     }
 
+    /// Sets the family this ingredient belongs to.
+    pub fn with_family(mut self, family_id: Option<i32>) -> Self {
+        self.family_id = family_id;
+        self
+    }
+
+    /// Sets the aisle category this ingredient is found in.
+    pub fn with_category(mut self, category: Option<String>) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// Sets the price of one purchase quantity, in pence.
+    pub fn with_price(mut self, price: Option<i32>) -> Self {
+        self.price = price;
+        self.price_per_unit = match self.purchase_quantity {
+            0 => None,
+            quantity => price.map(|p| p as f64 / quantity as f64),
+        };
+        self
+    }
+
+    /// Sets the ingredient's nutrition per 100g/ml.
+    pub fn with_nutrition(mut self, nutrition: Option<Nutrition>) -> Self {
+        self.nutrition = nutrition;
+        self
+    }
+
+    /// Sets the allergens the ingredient contains, and whether it suits vegetarians and vegans.
+    pub fn with_diet(mut self, allergens: Vec<Allergen>, vegetarian: bool, vegan: bool) -> Self {
+        self.allergens = allergens;
+        self.vegetarian = vegetarian;
+        self.vegan = vegan;
+        self
+    }
+
+    /// The nutrition in `quantity` of this ingredient. Only ingredients measured in grams or
+    /// millilitres can be converted from their nutrition per 100g/ml.
+    pub fn nutrition_of(&self, quantity: i32) -> Option<Nutrition> {
+        match self.unit.as_deref() {
+            Some("g" | "ml") => self.nutrition.map(|n| n.scale(quantity as f64 / 100.0)),
+            _ => None,
+        }
+    }
+
+    /// The estimated cost of using `quantity` of this ingredient, in pence. Ingredients that can't
+    /// be bought, such as water, cost nothing.
+    pub fn cost_of(&self, quantity: i32) -> Option<f64> {
+        if self.purchase_quantity == 0 {
+            return Some(0.0);
+        }
+        self.price_per_unit.map(|p| p * quantity as f64)
+    }
+
     /// Takes an arbitrary amount and scales it to the minimum purchase of this ingredient needed to cover it.
     pub fn scale_purchase_quantity(&self, quantity: i32) -> i32 {
         (quantity as f64 / self.purchase_quantity as f64).ceil() as i32 * self.purchase_quantity
@@ -50,36 +137,162 @@ impl Ingredient {
 }
 
 /// An input to create a new ingredient.
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema, TS)]
 pub struct IngredientInput {
     name: String,
+    #[ts(optional = nullable)]
     unit: Option<String>,
     pub minimum_quantity: i32,
     pub purchase_quantity: i32,
     pub life: i32,
+    /// An optional family to add the ingredient to as another pack size.
+    #[ts(optional = nullable)]
+    pub family_id: Option<i32>,
+    /// An optional aisle category, used to group shopping lists.
+    #[ts(optional = nullable)]
+    pub category: Option<String>,
+    /// An optional price for one purchase quantity, in pence.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub price: Option<i32>,
+    /// Optional nutrition per 100g/ml.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub nutrition: Option<Nutrition>,
+    /// The allergens the ingredient contains.
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub allergens: Vec<Allergen>,
+    #[serde(default = "default_true")]
+    #[ts(as = "Option<_>", optional)]
+    pub vegetarian: bool,
+    #[serde(default = "default_true")]
+    #[ts(as = "Option<_>", optional)]
+    pub vegan: bool,
 }
 
-/// Creates a new ingredient against a user.
+fn default_true() -> bool {
+    true
+}
+
+/// True if an ingredient can be put in a family, which must be global or the user's own.
+async fn family_usable(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    family_id: Option<i32>,
+) -> Result<bool, sqlx::Error> {
+    let Some(family_id) = family_id else {
+        return Ok(true);
+    };
+    let row = sqlx::query!(
+        "SELECT id FROM ingredient_family WHERE id = $1 AND (user_id = $2 OR user_id IS NULL)",
+        family_id,
+        user_id
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.is_some())
+}
+
+/// Creates a new ingredient against a user. Returns false if the ingredient's family isn't
+/// one the user can use.
 pub async fn create(
     pool: &sqlx::Pool<sqlx::Postgres>,
     user_id: i32,
     ingredient: IngredientInput,
-) -> Result<(), sqlx::Error> {
+) -> Result<bool, sqlx::Error> {
+    if !family_usable(pool, user_id, ingredient.family_id).await? {
+        return Ok(false);
+    }
+    let nutrition = ingredient.nutrition;
     sqlx::query!(
-        "INSERT INTO ingredient (user_id, name, unit, minimum_quantity, purchase_quantity, life) 
-		SELECT $1, $2, $3, $4, $5, $6
+        "INSERT INTO ingredient (user_id, name, unit, minimum_quantity, purchase_quantity, life, family_id, category, price,
+			energy, protein, fat, carbohydrate, fibre, salt, allergens, vegetarian, vegan) 
+		SELECT $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18
 		RETURNING id",
         user_id,
         ingredient.name,
         ingredient.unit,
         ingredient.minimum_quantity,
         ingredient.purchase_quantity,
-        ingredient.life
+        ingredient.life,
+        ingredient.family_id,
+        ingredient.category,
+        ingredient.price,
+        nutrition.map(|n| n.energy),
+        nutrition.map(|n| n.protein),
+        nutrition.map(|n| n.fat),
+        nutrition.map(|n| n.carbohydrate),
+        nutrition.map(|n| n.fibre),
+        nutrition.map(|n| n.salt),
+        &Allergen::to_strings(&ingredient.allergens),
+        ingredient.vegetarian,
+        ingredient.vegan
     )
     .fetch_one(pool)
     .await?;
 
-    Ok(())
+    Ok(true)
+}
+
+/// Updates one of a user's ingredients, such as to change its aisle category. Returns false
+/// if the ingredient's family isn't one the user can use.
+pub async fn update(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    ingredient_id: i32,
+    ingredient: IngredientInput,
+) -> Result<bool, sqlx::Error> {
+    if !family_usable(pool, user_id, ingredient.family_id).await? {
+        return Ok(false);
+    }
+    let nutrition = ingredient.nutrition;
+    // Users shouldn't be able to edit system ingredients.
+    sqlx::query!(
+        "UPDATE ingredient SET
+			name = $3,
+			unit = $4,
+			minimum_quantity = $5,
+			purchase_quantity = $6,
+			life = $7,
+			family_id = $8,
+			category = $9,
+			price = $10,
+			energy = $11,
+			protein = $12,
+			fat = $13,
+			carbohydrate = $14,
+			fibre = $15,
+			salt = $16,
+			allergens = $17,
+			vegetarian = $18,
+			vegan = $19
+		WHERE id = $1 AND user_id = $2",
+        ingredient_id,
+        user_id,
+        ingredient.name,
+        ingredient.unit,
+        ingredient.minimum_quantity,
+        ingredient.purchase_quantity,
+        ingredient.life,
+        ingredient.family_id,
+        ingredient.category,
+        ingredient.price,
+        nutrition.map(|n| n.energy),
+        nutrition.map(|n| n.protein),
+        nutrition.map(|n| n.fat),
+        nutrition.map(|n| n.carbohydrate),
+        nutrition.map(|n| n.fibre),
+        nutrition.map(|n| n.salt),
+        &Allergen::to_strings(&ingredient.allergens),
+        ingredient.vegetarian,
+        ingredient.vegan
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(true)
 }
 
 /// Creates a new ingredient against a user.
@@ -100,9 +313,81 @@ pub async fn delete(
     Ok(())
 }
 
+/// How lists of ingredients can be sorted and paged through.
+pub const LISTING: Listing = Listing {
+    key: "id",
+    sortable: &["name", "category", "life", "price", "price_per_unit"],
+    columns: &[
+        Column {
+            field: "id",
+            sql: "i.id",
+            text: false,
+        },
+        Column {
+            field: "name",
+            sql: "i.name",
+            text: true,
+        },
+        Column {
+            field: "category",
+            sql: "i.category",
+            text: true,
+        },
+        Column {
+            field: "life",
+            sql: "i.life",
+            text: false,
+        },
+        Column {
+            field: "price",
+            sql: "COALESCE(p.price, i.price)",
+            text: false,
+        },
+    ],
+};
+
 pub async fn query_multiple(
     pool: &sqlx::Pool<sqlx::Postgres>,
     user_id: i32,
+) -> Result<Vec<Ingredient>, sqlx::Error> {
+    query_listed(pool, user_id, None).await
+}
+
+/// Fetches a page of the ingredients a user can see. Pages sorted by a column are picked out in
+/// the query, and the rest are cut from the whole list.
+pub async fn query_page(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    params: &PageParams,
+) -> Result<Result<Page, InvalidPage>, sqlx::Error> {
+    let keyset = match LISTING.keyset(params) {
+        Ok(Some(keyset)) => keyset,
+        Ok(None) => return Ok(LISTING.page(query_multiple(pool, user_id).await?, params)),
+        Err(e) => return Ok(Err(e)),
+    };
+
+    let mut query = QueryBuilder::new(
+        "SELECT i.id FROM ingredient AS i
+			LEFT JOIN ingredient_price AS p ON p.ingredient_id = i.id AND p.user_id = ",
+    );
+    query.push_bind(user_id);
+    query.push(" WHERE (i.user_id IS NULL OR i.user_id = ");
+    query.push_bind(user_id);
+    query.push(")");
+    keyset.push_to(&mut query);
+    let ids: Vec<i32> = query.build_query_scalar().fetch_all(pool).await?;
+
+    let mut ingredients = query_listed(pool, user_id, Some(&ids)).await?;
+    ingredients.sort_by_key(|i| ids.iter().position(|id| *id == i.id));
+    Ok(LISTING.keyset_page(ingredients, &keyset, params))
+}
+
+/// Fetches the ingredients a user can see, their own first and then the most used. If IDs are
+/// given, only those ingredients are fetched.
+async fn query_listed(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    ids: Option<&[i32]>,
 ) -> Result<Vec<Ingredient>, sqlx::Error> {
     let rows = sqlx::query!(
         "SELECT
@@ -113,29 +398,60 @@ pub async fn query_multiple(
 		i.minimum_quantity,
 		i.purchase_quantity,
 		i.life,
+		i.family_id,
+		i.category,
+		COALESCE(p.price, i.price) as price,
+		i.energy,
+		i.protein,
+		i.fat,
+		i.carbohydrate,
+		i.fibre,
+		i.salt,
+		i.allergens,
+		i.vegetarian,
+		i.vegan,
 		COUNT(ri.ingredient_id)
 	  	FROM ingredient as i
 	  		LEFT JOIN recipe_ingredient as ri ON i.id = ri.ingredient_id
-	  	WHERE i.user_id IS NULL OR i.user_id = $1
-	  	GROUP BY i.id
+	  		LEFT JOIN ingredient_price as p ON p.ingredient_id = i.id AND p.user_id = $1
+	  	WHERE (i.user_id IS NULL OR i.user_id = $1)
+	  		AND ($2::integer[] IS NULL OR i.id = ANY($2))
+	  	GROUP BY i.id, p.price
 	  	ORDER BY i.user_id DESC, count DESC
 		",
-        user_id
+        user_id,
+        ids
     )
     .fetch_all(pool)
     .await?;
 
     let mut ingredients = Vec::new();
     for row in rows {
-        let ingredient = Ingredient {
-            id: row.id,
-            user_id: row.user_id,
-            name: row.name,
-            unit: row.unit,
-            minimum_quantity: row.minimum_quantity,
-            purchase_quantity: row.purchase_quantity,
-            life: row.life,
-        };
+        let ingredient = Ingredient::new(
+            row.id,
+            row.name,
+            row.user_id,
+            row.unit,
+            row.minimum_quantity,
+            row.purchase_quantity,
+            row.life,
+        )
+        .with_family(row.family_id)
+        .with_category(row.category)
+        .with_price(row.price)
+        .with_nutrition(Nutrition::from_columns(
+            row.energy,
+            row.protein,
+            row.fat,
+            row.carbohydrate,
+            row.fibre,
+            row.salt,
+        ))
+        .with_diet(
+            Allergen::parse_all(&row.allergens),
+            row.vegetarian,
+            row.vegan,
+        );
         ingredients.push(ingredient);
     }
 
@@ -155,11 +471,24 @@ pub async fn query_user(
 		i.minimum_quantity,
 		i.purchase_quantity,
 		i.life,
+		i.family_id,
+		i.category,
+		COALESCE(p.price, i.price) as price,
+		i.energy,
+		i.protein,
+		i.fat,
+		i.carbohydrate,
+		i.fibre,
+		i.salt,
+		i.allergens,
+		i.vegetarian,
+		i.vegan,
 		COUNT(ri.ingredient_id)
 	  	FROM ingredient as i
 	  		LEFT JOIN recipe_ingredient as ri ON i.id = ri.ingredient_id
+	  		LEFT JOIN ingredient_price as p ON p.ingredient_id = i.id AND p.user_id = $1
 	  	WHERE i.user_id = $1
-	  	GROUP BY i.id
+	  	GROUP BY i.id, p.price
 	  	ORDER BY i.user_id DESC, count DESC
 		",
         user_id
@@ -169,15 +498,31 @@ pub async fn query_user(
 
     let mut ingredients = Vec::new();
     for row in rows {
-        let ingredient = Ingredient {
-            id: row.id,
-            user_id: row.user_id,
-            name: row.name,
-            unit: row.unit,
-            minimum_quantity: row.minimum_quantity,
-            purchase_quantity: row.purchase_quantity,
-            life: row.life,
-        };
+        let ingredient = Ingredient::new(
+            row.id,
+            row.name,
+            row.user_id,
+            row.unit,
+            row.minimum_quantity,
+            row.purchase_quantity,
+            row.life,
+        )
+        .with_family(row.family_id)
+        .with_category(row.category)
+        .with_price(row.price)
+        .with_nutrition(Nutrition::from_columns(
+            row.energy,
+            row.protein,
+            row.fat,
+            row.carbohydrate,
+            row.fibre,
+            row.salt,
+        ))
+        .with_diet(
+            Allergen::parse_all(&row.allergens),
+            row.vegetarian,
+            row.vegan,
+        );
         ingredients.push(ingredient);
     }
 
@@ -186,7 +531,7 @@ pub async fn query_user(
 
 /// Represents a recurring purchase of an ingredient derived from a start date
 /// and interval in days.
-#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Clone, PartialEq, ToSchema, TS)]
 pub struct ScheduledIngredient {
     pub id: i32,
     pub ingredient: Ingredient,
@@ -210,10 +555,24 @@ pub async fn query_scheduled(
 			i.unit,
 			i.minimum_quantity, 
 			i.purchase_quantity,
-			i.life 
+			i.life,
+			i.family_id,
+			i.category,
+			COALESCE(p.price, i.price) as price,
+			i.energy,
+			i.protein,
+			i.fat,
+			i.carbohydrate,
+			i.fibre,
+			i.salt,
+			i.allergens,
+			i.vegetarian,
+			i.vegan
 		FROM ingredientschedule AS ins
 			LEFT JOIN ingredient AS i
 			ON i.id = ins.ingredient_id
+			LEFT JOIN ingredient_price AS p
+			ON p.ingredient_id = i.id AND p.user_id = ins.user_id
 		WHERE ins.user_id = $1",
         user_id
     )
@@ -231,6 +590,22 @@ pub async fn query_scheduled(
             row.minimum_quantity,
             row.purchase_quantity,
             row.life,
+        )
+        .with_family(row.family_id)
+        .with_category(row.category)
+        .with_price(row.price)
+        .with_nutrition(Nutrition::from_columns(
+            row.energy,
+            row.protein,
+            row.fat,
+            row.carbohydrate,
+            row.fibre,
+            row.salt,
+        ))
+        .with_diet(
+            Allergen::parse_all(&row.allergens),
+            row.vegetarian,
+            row.vegan,
         );
         items.push(ScheduledIngredient {
             id: row.id,
@@ -243,7 +618,7 @@ pub async fn query_scheduled(
 }
 /// Represents an API input to create a recurring purchase of an ingredient derived from a start date
 /// and interval in days.
-#[derive(Debug, serde::Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[derive(Debug, serde::Deserialize, Serialize, Clone, PartialEq, Eq, ToSchema, TS)]
 pub struct ScheduledIngredientInput {
     ingredient_id: i32,
     pub start_date: String,
@@ -295,7 +670,7 @@ pub async fn delete_scheduled(
 /// Represents an ingredient in the context of a recipe
 /// - `ingredient` - The ingredient the quantity is relevant to
 /// - `quantity` - The quantity of the ingredient
-#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Clone, PartialEq, ToSchema, TS)]
 pub struct IngredientQuantity {
     pub ingredient: Ingredient,
     pub quantity: i32,
@@ -316,28 +691,568 @@ pub async fn search(
     query: String,
 ) -> Result<Vec<Ingredient>, sqlx::Error> {
     let escaped = format!("%{}%", query);
-    sqlx::query_as!(
-        Ingredient,
+    let rows = sqlx::query!(
         "SELECT 
-            id, 
-            user_id, 
-            name, 
-            unit, 
-            minimum_quantity, 
-            purchase_quantity, 
-            life 
+            i.id, 
+            i.user_id, 
+            i.name, 
+            i.unit, 
+            i.minimum_quantity, 
+            i.purchase_quantity, 
+            i.life,
+            i.family_id,
+            i.category,
+            COALESCE(p.price, i.price) as price,
+            i.energy,
+            i.protein,
+            i.fat,
+            i.carbohydrate,
+            i.fibre,
+            i.salt,
+            i.allergens,
+            i.vegetarian,
+            i.vegan
         FROM 
-            ingredient 
+            ingredient as i
+            LEFT JOIN ingredient_price as p ON p.ingredient_id = i.id AND p.user_id = $2
         WHERE 
-            LOWER(name) LIKE LOWER($1) AND (user_id IS NULL OR user_id = $2)
+            LOWER(i.name) LIKE LOWER($1) AND (i.user_id IS NULL OR i.user_id = $2)
         ORDER BY
-            user_id ASC
+            i.user_id ASC
             ",
         escaped,
         user_id
     )
     .fetch_all(pool)
-    .await
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            Ingredient::new(
+                row.id,
+                row.name,
+                row.user_id,
+                row.unit,
+                row.minimum_quantity,
+                row.purchase_quantity,
+                row.life,
+            )
+            .with_family(row.family_id)
+            .with_category(row.category)
+            .with_price(row.price)
+            .with_nutrition(Nutrition::from_columns(
+                row.energy,
+                row.protein,
+                row.fat,
+                row.carbohydrate,
+                row.fibre,
+                row.salt,
+            ))
+            .with_diet(
+                Allergen::parse_all(&row.allergens),
+                row.vegetarian,
+                row.vegan,
+            )
+        })
+        .collect())
+}
+
+/// Queries the order of the aisles in a user's store. Aisles the user has ordered come first,
+/// followed by any other ingredient categories in the order they were scraped.
+pub async fn query_aisles(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+) -> Result<Vec<String>, sqlx::Error> {
+    let mut aisles: Vec<String> = sqlx::query!(
+        "SELECT category FROM user_aisle WHERE user_id = $1 ORDER BY position ASC",
+        user_id
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|row| row.category)
+    .collect();
+
+    let categories = sqlx::query!(
+        "SELECT category as \"category!\" FROM ingredient
+		WHERE category IS NOT NULL AND (user_id IS NULL OR user_id = $1)
+		GROUP BY category
+		ORDER BY MIN(id) ASC",
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for row in categories {
+        if !aisles.contains(&row.category) {
+            aisles.push(row.category);
+        }
+    }
+
+    Ok(aisles)
+}
+
+/// Replaces the order of the aisles in a user's store.
+pub async fn update_aisles(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    aisles: Vec<String>,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query!("DELETE FROM user_aisle WHERE user_id = $1", user_id)
+        .execute(&mut *tx)
+        .await?;
+
+    // Positions are taken from the order of the input
+    sqlx::query!(
+        "INSERT INTO user_aisle (user_id, category, position)
+			SELECT $1, aisle.category, aisle.position
+			FROM UNNEST($2::varchar[]) WITH ORDINALITY AS aisle(category, position)
+		ON CONFLICT DO NOTHING",
+        user_id,
+        &aisles
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// An input to set a user's own price for an ingredient.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema, TS)]
+pub struct PriceInput {
+    /// The price of one purchase quantity in pence, or null to go back to the retail price.
+    pub price: Option<i32>,
+}
+
+/// Sets the price a user pays for an ingredient, overriding its retail price. Clearing the price
+/// removes the override.
+pub async fn update_price(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    ingredient_id: i32,
+    input: PriceInput,
+) -> Result<(), sqlx::Error> {
+    match input.price {
+        Some(price) => {
+            sqlx::query!(
+                "INSERT INTO ingredient_price (user_id, ingredient_id, price)
+				SELECT $1, i.id, $3 FROM ingredient AS i
+				WHERE i.id = $2 AND (i.user_id IS NULL OR i.user_id = $1)
+				ON CONFLICT (user_id, ingredient_id) DO UPDATE SET price = EXCLUDED.price",
+                user_id,
+                ingredient_id,
+                price
+            )
+            .execute(pool)
+            .await?;
+        }
+        None => {
+            sqlx::query!(
+                "DELETE FROM ingredient_price WHERE user_id = $1 AND ingredient_id = $2",
+                user_id,
+                ingredient_id
+            )
+            .execute(pool)
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Represents a product that is sold in several pack sizes, each of which is stored as a
+/// separate ingredient (e.g. 1130ml and 2270ml of whole milk).
+#[derive(Debug, Serialize, Clone, PartialEq, ToSchema, TS)]
+pub struct IngredientFamily {
+    /// The family's unique ID
+    pub id: i32,
+    /// Present if the family is assigned to a user.
+    pub user_id: Option<i32>,
+    /// The name of the product
+    pub name: String,
+    /// The unit shared by every pack size in the family
+    pub unit: Option<String>,
+    /// The pack sizes available, ordered from smallest to largest.
+    pub members: Vec<Ingredient>,
+}
+
+impl IngredientFamily {
+    /// Builds a single ingredient that stands in for every member of the family. Quantities
+    /// are aggregated against this ingredient, and only split into packs when purchased.
+    pub fn as_ingredient(&self) -> Ingredient {
+        let id = self.members.iter().map(|m| m.id).min().unwrap_or_default();
+        let minimum_quantity = self
+            .members
+            .iter()
+            .map(|m| m.minimum_quantity)
+            .min()
+            .unwrap_or_default();
+        let purchase_quantity = self
+            .members
+            .iter()
+            .map(|m| m.purchase_quantity)
+            .filter(|q| *q > 0)
+            .min()
+            .unwrap_or_default();
+        let life = self
+            .members
+            .iter()
+            .map(|m| m.life)
+            .min()
+            .unwrap_or_default();
+        let category = self.members.iter().find_map(|m| m.category.clone());
+        let nutrition = self.members.iter().find_map(|m| m.nutrition);
+        let mut allergens: Vec<Allergen> = self
+            .members
+            .iter()
+            .flat_map(|m| m.allergens.iter().copied())
+            .collect();
+        allergens.sort();
+        allergens.dedup();
+        let vegetarian = self.members.iter().all(|m| m.vegetarian);
+        let vegan = self.members.iter().all(|m| m.vegan);
+        // Members are ordered by pack size, so this is the price of the smallest pack.
+        let price = self
+            .members
+            .iter()
+            .find(|m| m.purchase_quantity == purchase_quantity)
+            .and_then(|m| m.price);
+
+        Ingredient::new(
+            id,
+            self.name.clone(),
+            self.user_id,
+            self.unit.clone(),
+            minimum_quantity,
+            purchase_quantity,
+            life,
+        )
+        .with_family(Some(self.id))
+        .with_category(category)
+        .with_price(price)
+        .with_nutrition(nutrition)
+        .with_diet(allergens, vegetarian, vegan)
+    }
+}
+
+/// A lookup of the ingredient families available to a user.
+///
+/// Recipes keep the ID of whichever pack size they were written with, rather than the ID of its
+/// family. This is equivalent to referencing the family: lists, surplus and plans resolve each
+/// member to the family's stand-in ingredient before totalling or matching, and that ingredient
+/// always takes the smallest member ID, so every member lands on the same entry. The member a
+/// recipe names only changes how the recipe itself is shown.
+#[derive(Debug, Default, Clone)]
+pub struct Families {
+    families: HashMap<i32, IngredientFamily>,
+}
+
+impl Families {
+    pub fn new(families: Vec<IngredientFamily>) -> Self {
+        Families {
+            families: families.into_iter().map(|f| (f.id, f)).collect(),
+        }
+    }
+
+    /// Returns the family-level ingredient for a family member, or a copy of the ingredient
+    /// if it doesn't belong to a family.
+    pub fn resolve(&self, ingredient: &Ingredient) -> Ingredient {
+        match ingredient.family_id.and_then(|id| self.families.get(&id)) {
+            Some(family) => family.as_ingredient(),
+            None => ingredient.clone(),
+        }
+    }
+
+    /// Returns the ID of the family-level ingredient for an ingredient ID, or the ID itself
+    /// if it isn't a member of a family. Two IDs resolve to the same ID if they are pack sizes
+    /// of the same family.
+    pub fn resolve_id(&self, ingredient_id: i32) -> i32 {
+        self.families
+            .values()
+            .find(|f| f.members.iter().any(|m| m.id == ingredient_id))
+            .map(|f| f.as_ingredient().id)
+            .unwrap_or(ingredient_id)
+    }
+
+    /// Chooses the packs of an ingredient to buy to cover `quantity`. Any pack size in the
+    /// ingredient's family may be used.
+    pub fn choose_packs(&self, ingredient: &Ingredient, quantity: i32) -> Vec<PackQuantity> {
+        match ingredient.family_id.and_then(|id| self.families.get(&id)) {
+            Some(family) => choose_packs(&family.members, quantity),
+            None => choose_packs(std::slice::from_ref(ingredient), quantity),
+        }
+    }
+}
+
+/// A number of packs of a single ingredient.
+#[derive(Debug, Serialize, Clone, PartialEq, ToSchema, TS)]
+pub struct PackQuantity {
+    pub ingredient: Ingredient,
+    pub count: i32,
+}
+
+impl PackQuantity {
+    pub fn new(ingredient: Ingredient, count: i32) -> Self {
+        PackQuantity { ingredient, count }
+    }
+
+    /// The total quantity of ingredient in these packs.
+    pub fn quantity(&self) -> i32 {
+        self.ingredient.purchase_quantity * self.count
+    }
+}
+
+/// The largest table `choose_packs` will build. Pack sizes with a small common divisor and a
+/// large quantity would otherwise need a table of millions of entries.
+const MAX_PACK_TABLE: usize = 100_000;
+
+/// Chooses the combination of packs that covers `quantity` with the least waste. Where
+/// several combinations waste the same amount, the one with the fewest packs is chosen.
+/// Packs with a purchase quantity of 0 can't be bought and are ignored.
+pub fn choose_packs(packs: &[Ingredient], quantity: i32) -> Vec<PackQuantity> {
+    let packs: Vec<&Ingredient> = packs.iter().filter(|p| p.purchase_quantity > 0).collect();
+    if quantity <= 0 || packs.is_empty() {
+        return vec![];
+    }
+
+    // With a single pack size, the least waste is found by rounding up to a whole pack.
+    if let [pack] = packs.as_slice() {
+        let count = pack.scale_purchase_quantity(quantity) / pack.purchase_quantity;
+        return vec![PackQuantity::new((*pack).clone(), count)];
+    }
+
+    // Work in multiples of the greatest common divisor of the pack sizes to keep the table small.
+    let step = packs.iter().fold(0, |acc, p| gcd(acc, p.purchase_quantity));
+    let sizes: Vec<usize> = packs
+        .iter()
+        .map(|p| (p.purchase_quantity / step) as usize)
+        .collect();
+    let target = (quantity as f64 / step as f64).ceil() as usize;
+    let largest = *sizes.iter().max().unwrap_or(&1);
+    if target + largest > MAX_PACK_TABLE {
+        return choose_largest_packs(packs, quantity);
+    }
+
+    // fewest[t] holds the fewest packs totalling exactly t, and the last pack added to reach it.
+    // No optimal total can exceed the target by a whole pack, which bounds the table.
+    let mut fewest: Vec<Option<(i32, usize)>> = vec![None; target + largest];
+    fewest[0] = Some((0, 0));
+    for total in 1..fewest.len() {
+        fewest[total] = sizes
+            .iter()
+            .enumerate()
+            .filter(|(_, size)| **size <= total)
+            .filter_map(|(i, size)| fewest[total - size].map(|(count, _)| (count + 1, i)))
+            .min_by_key(|(count, _)| *count);
+    }
+
+    let Some(mut total) = (target..fewest.len()).find(|t| fewest[*t].is_some()) else {
+        return vec![];
+    };
+
+    // Walk back through the table to recover the packs used.
+    let mut counts = vec![0; packs.len()];
+    while total > 0 {
+        if let Some((_, pack)) = fewest[total] {
+            counts[pack] += 1;
+            total -= sizes[pack];
+        }
+    }
+
+    let mut chosen: Vec<PackQuantity> = packs
+        .into_iter()
+        .zip(counts)
+        .filter(|(_, count)| *count > 0)
+        .map(|(pack, count)| PackQuantity::new(pack.clone(), count))
+        .collect();
+    chosen.sort_by_key(|p| -p.ingredient.purchase_quantity);
+    chosen
+}
+
+/// Covers `quantity` with as many of the largest packs as fit, then the next largest and so on,
+/// topping up with one of the smallest packs. This may waste more than `choose_packs`, but
+/// doesn't depend on the size of the quantity.
+fn choose_largest_packs(mut packs: Vec<&Ingredient>, quantity: i32) -> Vec<PackQuantity> {
+    packs.sort_by_key(|p| -p.purchase_quantity);
+
+    let mut remaining = quantity;
+    let mut chosen: Vec<PackQuantity> = vec![];
+    for pack in &packs {
+        let count = remaining / pack.purchase_quantity;
+        if count > 0 {
+            chosen.push(PackQuantity::new((*pack).clone(), count));
+            remaining -= count * pack.purchase_quantity;
+        }
+    }
+
+    if remaining > 0 {
+        let smallest = packs[packs.len() - 1];
+        match chosen.iter_mut().find(|p| p.ingredient.id == smallest.id) {
+            Some(chosen) => chosen.count += 1,
+            None => chosen.push(PackQuantity::new(smallest.clone(), 1)),
+        }
+    }
+    chosen
+}
+
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Fetches all ingredient families visible to a user, along with their pack sizes.
+pub async fn query_families(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+) -> Result<Vec<IngredientFamily>, sqlx::Error> {
+    let rows = sqlx::query!(
+        "SELECT
+			f.id as family_id,
+			f.user_id as family_user_id,
+			f.name as family_name,
+			f.unit as family_unit,
+
+			i.id,
+			i.user_id,
+			i.name,
+			i.unit,
+			i.minimum_quantity,
+			i.purchase_quantity,
+			i.life,
+			i.category,
+			COALESCE(p.price, i.price) as price,
+			i.energy,
+			i.protein,
+			i.fat,
+			i.carbohydrate,
+			i.fibre,
+			i.salt,
+			i.allergens,
+			i.vegetarian,
+			i.vegan
+		FROM ingredient_family AS f
+			INNER JOIN ingredient AS i ON i.family_id = f.id
+			LEFT JOIN ingredient_price AS p ON p.ingredient_id = i.id AND p.user_id = $1
+		WHERE (f.user_id IS NULL OR f.user_id = $1)
+			AND (i.user_id IS NULL OR i.user_id = $1)
+		ORDER BY f.id ASC, i.purchase_quantity ASC",
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut families: Vec<IngredientFamily> = vec![];
+    for row in rows {
+        let ingredient = Ingredient::new(
+            row.id,
+            row.name,
+            row.user_id,
+            row.unit,
+            row.minimum_quantity,
+            row.purchase_quantity,
+            row.life,
+        )
+        .with_family(Some(row.family_id))
+        .with_category(row.category)
+        .with_price(row.price)
+        .with_nutrition(Nutrition::from_columns(
+            row.energy,
+            row.protein,
+            row.fat,
+            row.carbohydrate,
+            row.fibre,
+            row.salt,
+        ))
+        .with_diet(
+            Allergen::parse_all(&row.allergens),
+            row.vegetarian,
+            row.vegan,
+        );
+
+        // Rows are ordered by family, so members of the same family are adjacent.
+        match families.last_mut() {
+            Some(family) if family.id == row.family_id => family.members.push(ingredient),
+            _ => families.push(IngredientFamily {
+                id: row.family_id,
+                user_id: row.family_user_id,
+                name: row.family_name,
+                unit: row.family_unit,
+                members: vec![ingredient],
+            }),
+        }
+    }
+
+    Ok(families)
+}
+
+/// An input to group a user's ingredients into a family.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema, TS)]
+pub struct IngredientFamilyInput {
+    name: String,
+    #[ts(optional = nullable)]
+    unit: Option<String>,
+    /// The IDs of the user's ingredients that make up the family.
+    pub ingredient_ids: Vec<i32>,
+}
+
+/// Creates a new family against a user, and adds the given ingredients to it. Only the user's
+/// own ingredients can be added. Returns the ID of the new family.
+pub async fn create_family(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    family: IngredientFamilyInput,
+) -> Result<i32, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let created = sqlx::query!(
+        "INSERT INTO ingredient_family (user_id, name, unit)
+		VALUES ($1, $2, $3)
+		RETURNING id",
+        user_id,
+        family.name,
+        family.unit
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "UPDATE ingredient SET family_id = $1 WHERE id = ANY($2) AND user_id = $3",
+        created.id,
+        &family.ingredient_ids,
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(created.id)
+}
+
+/// Deletes a user's family. Its members are kept as standalone ingredients.
+pub async fn delete_family(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    family_id: i32,
+) -> Result<(), sqlx::Error> {
+    // Users shouldn't be able to delete system families.
+    sqlx::query!(
+        "DELETE FROM ingredient_family WHERE id = $1 AND user_id = $2",
+        family_id,
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -357,4 +1272,52 @@ mod tests {
         let scaled_purchase = ingredient.scale_purchase_quantity(140);
         assert_eq!(scaled_purchase, 150);
     }
+
+    #[test]
+    fn test_choose_packs_single_size() {
+        let ingredient = Ingredient::new(1, "Test Ingredient".into(), None, None, 50, 50, 7);
+        let packs = choose_packs(std::slice::from_ref(&ingredient), 140);
+        assert_eq!(packs, vec![PackQuantity::new(ingredient, 3)]);
+    }
+
+    #[test]
+    fn test_choose_packs_least_waste() {
+        let small = Ingredient::new(1, "Milk".into(), None, Some("ml".into()), 1, 1130, 7);
+        let large = Ingredient::new(2, "Milk".into(), None, Some("ml".into()), 1, 2270, 7);
+
+        // Three small packs (3390ml) waste less than a large and a small pack (3400ml).
+        let packs = choose_packs(&[small.clone(), large.clone()], 3000);
+        assert_eq!(packs, vec![PackQuantity::new(small.clone(), 3)]);
+
+        // A large pack (2270ml) wastes less than two small packs (2260ml) when 2265ml is needed.
+        let packs = choose_packs(&[small, large.clone()], 2265);
+        assert_eq!(packs, vec![PackQuantity::new(large, 1)]);
+    }
+
+    #[test]
+    fn test_choose_packs_prefers_fewer_packs() {
+        let small = Ingredient::new(1, "Butter".into(), None, Some("g".into()), 1, 250, 60);
+        let large = Ingredient::new(2, "Butter".into(), None, Some("g".into()), 1, 500, 60);
+        let packs = choose_packs(&[small, large.clone()], 400);
+        assert_eq!(packs, vec![PackQuantity::new(large, 1)]);
+    }
+
+    #[test]
+    fn test_choose_packs_large_table() {
+        let small = Ingredient::new(1, "Rice".into(), None, Some("g".into()), 1, 999, 365);
+        let large = Ingredient::new(2, "Rice".into(), None, Some("g".into()), 1, 1000, 365);
+
+        // The pack sizes share no divisor, so the table would need a million entries.
+        let packs = choose_packs(&[small.clone(), large.clone()], 1_000_500);
+        assert_eq!(
+            packs,
+            vec![PackQuantity::new(large, 1000), PackQuantity::new(small, 1)]
+        );
+    }
+
+    #[test]
+    fn test_choose_packs_unpurchasable() {
+        let ingredient = Ingredient::new(1, "Water".into(), None, Some("g".into()), 0, 0, 100);
+        assert!(choose_packs(&[ingredient], 375).is_empty());
+    }
 }
@@ -1,5 +1,18 @@
+pub mod alert;
+pub mod calendar;
 pub mod day;
 pub mod db;
+pub mod diet;
+pub mod export;
+pub mod idempotency;
+pub mod images;
 pub mod ingredient;
+pub mod notify;
+pub mod nutrition;
+pub mod page;
+pub mod plan;
+pub mod purchase;
 pub mod recipe;
+pub mod storage;
+pub mod sync;
 pub mod utils;
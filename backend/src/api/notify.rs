@@ -0,0 +1,59 @@
+use std::{
+    env,
+    error::Error,
+    path::PathBuf,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+
+pub type NotifyError = Box<dyn Error + Send + Sync>;
+
+/// Somewhere messages to users can be sent, such as an email gateway.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    /// Sends a message to a user.
+    async fn send(&self, user_id: i32, subject: &str, body: &str) -> Result<(), NotifyError>;
+}
+
+/// Chooses a notifier from the environment. `NOTIFIER` may be `none` (the default), which sends
+/// nothing, or `file`, which writes each message to a file under `NOTIFY_DIR`.
+pub fn from_env() -> Result<Option<Arc<dyn Notifier>>, Box<dyn Error>> {
+    match env::var("NOTIFIER").unwrap_or("none".into()).as_str() {
+        "none" => Ok(None),
+        "file" => Ok(Some(Arc::new(FileNotifier::new(
+            env::var("NOTIFY_DIR").unwrap_or("outbox".into()),
+        )))),
+        other => Err(format!("unknown notifier '{}'", other))?,
+    }
+}
+
+/// Writes each message to its own file in a directory, formatted as a plain text email. Stands in
+/// for a mail server, so messages can be checked or picked up by another process.
+pub struct FileNotifier {
+    root: PathBuf,
+}
+
+impl FileNotifier {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        FileNotifier { root: root.into() }
+    }
+}
+
+#[async_trait]
+impl Notifier for FileNotifier {
+    async fn send(&self, user_id: i32, subject: &str, body: &str) -> Result<(), NotifyError> {
+        tokio::fs::create_dir_all(&self.root).await?;
+        let sent = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        let name = format!("{}-{}.eml", user_id, sent.as_nanos());
+        let message = format!(
+            "X-Lembas-User: {}\r\nSubject: {}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{}",
+            user_id,
+            subject,
+            body.replace('\n', "\r\n")
+        );
+        tokio::fs::write(self.root.join(name), message).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use std::{iter::Sum, ops::Add};
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Nutrition per 100g/ml of an ingredient, or the total nutrition of a recipe or day.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default, ToSchema, TS)]
+pub struct Nutrition {
+    /// Energy in kcal
+    pub energy: f64,
+    /// Protein in grams
+    pub protein: f64,
+    /// Fat in grams
+    pub fat: f64,
+    /// Carbohydrate in grams
+    pub carbohydrate: f64,
+    /// Fibre in grams
+    pub fibre: f64,
+    /// Salt in grams
+    pub salt: f64,
+}
+
+impl Nutrition {
+    /// Builds nutrition from nullable database columns. Nutrition is only known if energy is
+    /// present; any other missing values are taken as 0.
+    pub fn from_columns(
+        energy: Option<f64>,
+        protein: Option<f64>,
+        fat: Option<f64>,
+        carbohydrate: Option<f64>,
+        fibre: Option<f64>,
+        salt: Option<f64>,
+    ) -> Option<Self> {
+        Some(Nutrition {
+            energy: energy?,
+            protein: protein.unwrap_or_default(),
+            fat: fat.unwrap_or_default(),
+            carbohydrate: carbohydrate.unwrap_or_default(),
+            fibre: fibre.unwrap_or_default(),
+            salt: salt.unwrap_or_default(),
+        })
+    }
+
+    /// Multiplies every value by `factor`.
+    pub fn scale(&self, factor: f64) -> Self {
+        Nutrition {
+            energy: self.energy * factor,
+            protein: self.protein * factor,
+            fat: self.fat * factor,
+            carbohydrate: self.carbohydrate * factor,
+            fibre: self.fibre * factor,
+            salt: self.salt * factor,
+        }
+    }
+
+    /// Rounds every value to one decimal place, for display.
+    pub fn round(&self) -> Self {
+        let round = |v: f64| (v * 10.0).round() / 10.0;
+        Nutrition {
+            energy: round(self.energy),
+            protein: round(self.protein),
+            fat: round(self.fat),
+            carbohydrate: round(self.carbohydrate),
+            fibre: round(self.fibre),
+            salt: round(self.salt),
+        }
+    }
+}
+
+impl Add for Nutrition {
+    type Output = Nutrition;
+
+    fn add(self, other: Nutrition) -> Nutrition {
+        Nutrition {
+            energy: self.energy + other.energy,
+            protein: self.protein + other.protein,
+            fat: self.fat + other.fat,
+            carbohydrate: self.carbohydrate + other.carbohydrate,
+            fibre: self.fibre + other.fibre,
+            salt: self.salt + other.salt,
+        }
+    }
+}
+
+impl Sum for Nutrition {
+    fn sum<I: Iterator<Item = Nutrition>>(iter: I) -> Self {
+        iter.fold(Nutrition::default(), |acc, n| acc + n)
+    }
+}
+
+/// The nutrition of a whole recipe and of a single portion.
+#[derive(Debug, Serialize, Clone, PartialEq, Default, ToSchema, TS)]
+pub struct RecipeNutrition {
+    pub total: Nutrition,
+    pub per_portion: Nutrition,
+    /// The IDs of ingredients without nutrition data, or measured in a unit that can't be
+    /// converted to grams or millilitres. These are left out of the totals.
+    pub unknown_ingredients: Vec<i32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nutrition_scale_and_sum() {
+        let flour = Nutrition {
+            energy: 341.0,
+            protein: 10.0,
+            fat: 1.3,
+            carbohydrate: 70.0,
+            fibre: 3.1,
+            salt: 0.01,
+        };
+        let total: Nutrition = vec![flour.scale(2.5), flour.scale(0.5)].into_iter().sum();
+        assert_eq!(total.round(), flour.scale(3.0).round());
+        assert_eq!(total.round().energy, 1023.0);
+    }
+
+    #[test]
+    fn test_nutrition_from_columns() {
+        assert_eq!(
+            Nutrition::from_columns(None, Some(1.0), None, None, None, None),
+            None
+        );
+        assert_eq!(
+            Nutrition::from_columns(Some(100.0), None, None, None, None, None),
+            Some(Nutrition {
+                energy: 100.0,
+                ..Default::default()
+            })
+        );
+    }
+}
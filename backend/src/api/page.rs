@@ -0,0 +1,399 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::{Postgres, QueryBuilder};
+use std::{cmp::Ordering, error::Error, fmt};
+use utoipa::IntoParams;
+
+/// The most items a single page can hold.
+pub const MAX_LIMIT: usize = 500;
+
+/// Query parameters accepted by list endpoints. All are optional: without a `limit` or `cursor`
+/// the whole list is returned, as it always has been.
+#[derive(Deserialize, Debug, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PageParams {
+    /// The most items to return.
+    pub limit: Option<usize>,
+    /// Where to carry on from, as given in the link to the previous page's next page.
+    pub cursor: Option<String>,
+    /// The field to sort by, prefixed with `-` for descending order, e.g. `-total_time`.
+    pub sort: Option<String>,
+    /// A comma-separated list of the fields to include in each item, e.g. `id,name`.
+    pub fields: Option<String>,
+}
+
+/// Describes how the items of a list endpoint can be sorted and paged through.
+pub struct Listing {
+    /// The field that tells items apart. Ties between items with the same sort value are broken
+    /// by it, and paged lists are sorted by it unless something else is asked for.
+    pub key: &'static str,
+    /// The fields the list can be sorted by. Each must serialise to a number, string or null.
+    pub sortable: &'static [&'static str],
+    /// The key and sortable fields that are read straight from a column. Pages of lists sorted
+    /// by one of these are picked out in the query; others are cut from the whole list.
+    pub columns: &'static [Column],
+}
+
+/// A field of a list's items that is read straight from a column of the list's query.
+pub struct Column {
+    pub field: &'static str,
+    /// The SQL expression the field is read from.
+    pub sql: &'static str,
+    /// Whether the field holds text, which is sorted ignoring case as in `compare_values`.
+    pub text: bool,
+}
+
+impl Column {
+    /// The expressions the column is sorted by.
+    fn order_by(&self) -> Vec<String> {
+        match self.text {
+            true => vec![
+                format!("lower({}) COLLATE \"C\"", self.sql),
+                format!("{} COLLATE \"C\"", self.sql),
+            ],
+            false => vec![self.sql.to_string()],
+        }
+    }
+
+    /// Adds a value to compare with the expressions the column is sorted by.
+    fn push_value(&self, query: &mut QueryBuilder<'_, Postgres>, value: &Value) {
+        match value {
+            Value::String(s) if self.text => {
+                query.push("lower(");
+                query.push_bind(s.clone());
+                query.push("), ");
+                query.push_bind(s.clone())
+            }
+            Value::String(s) => query.push_bind(s.clone()),
+            Value::Number(n) => match n.as_i64() {
+                Some(n) => query.push_bind(n),
+                None => query.push_bind(n.as_f64()),
+            },
+            Value::Bool(b) => query.push_bind(*b),
+            _ => query.push("NULL"),
+        };
+    }
+}
+
+/// The part of a list to pick out in its query: the items after the cursor, in order, up to one
+/// more than the limit so that it's known whether there's a page after them.
+pub struct Keyset<'a> {
+    sort: Sort<'a>,
+    column: &'static Column,
+    key: &'static Column,
+    /// The sort value and key of the item the page starts after.
+    after: Option<(Value, Value)>,
+    limit: Option<usize>,
+}
+
+impl Keyset<'_> {
+    /// Adds the conditions, order and limit that pick out the page to a query of the list, which
+    /// must already have a `WHERE` clause.
+    pub fn push_to(&self, query: &mut QueryBuilder<'_, Postgres>) {
+        let columns = match self.column.field == self.key.field {
+            true => vec![self.key],
+            false => vec![self.column, self.key],
+        };
+        let (comparison, order) = match self.sort.descending {
+            true => ("<", "DESC NULLS LAST"),
+            false => (">", "ASC NULLS FIRST"),
+        };
+
+        if let Some((value, key)) = &self.after {
+            // Nulls sort first, so after a null only nulls with later keys are left, and after
+            // anything else only other values. Descending lists are the other way around.
+            let (test, join) = match (value.is_null(), self.sort.descending) {
+                (true, false) => ("IS NOT NULL", "OR"),
+                (false, false) => ("IS NOT NULL", "AND"),
+                (true, true) => ("IS NULL", "AND"),
+                (false, true) => ("IS NULL", "OR"),
+            };
+            let compared = match value.is_null() || columns.len() == 1 {
+                true => vec![(self.key, key)],
+                false => vec![(self.column, value), (self.key, key)],
+            };
+            let sorted: Vec<String> = compared.iter().flat_map(|(c, _)| c.order_by()).collect();
+            query.push(format!(
+                " AND ({} {} {} ({}) {} (",
+                self.column.sql,
+                test,
+                join,
+                sorted.join(", "),
+                comparison
+            ));
+            for (i, (column, value)) in compared.iter().enumerate() {
+                if i > 0 {
+                    query.push(", ");
+                }
+                column.push_value(query, value);
+            }
+            query.push("))");
+        }
+
+        let sorted: Vec<String> = columns
+            .iter()
+            .flat_map(|c| c.order_by())
+            .map(|e| format!("{} {}", e, order))
+            .collect();
+        query.push(format!(" ORDER BY {}", sorted.join(", ")));
+        if let Some(limit) = self.limit {
+            query.push(" LIMIT ");
+            query.push_bind(limit as i64 + 1);
+        }
+    }
+}
+
+/// One page of a list, with the cursor of the page after it if there are more items.
+#[derive(Debug)]
+pub struct Page {
+    pub items: Vec<Value>,
+    pub next_cursor: Option<String>,
+}
+
+/// Returned when a list can't be paged as asked, with a description of the problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidPage(pub String);
+
+impl fmt::Display for InvalidPage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for InvalidPage {}
+
+/// The order a list is sorted in.
+struct Sort<'a> {
+    field: &'a str,
+    descending: bool,
+}
+
+impl Sort<'_> {
+    /// The `sort` parameter this order is written as, which each cursor records.
+    fn param(&self) -> String {
+        match self.descending {
+            true => format!("-{}", self.field),
+            false => self.field.to_string(),
+        }
+    }
+}
+
+impl Listing {
+    /// Sorts a list, cuts out the page asked for and removes any fields that weren't asked for.
+    ///
+    /// Cursors hold the sort value and key of the last item on a page, so the next page starts
+    /// after that item even if items have been added or removed since.
+    pub fn page<T: Serialize>(
+        &self,
+        items: Vec<T>,
+        params: &PageParams,
+    ) -> Result<Page, InvalidPage> {
+        check_limit(params)?;
+        let mut items = to_values(items)?;
+
+        let paged = params.limit.is_some() || params.cursor.is_some();
+        let sort = match (&params.sort, paged) {
+            (Some(sort), _) => Some(self.parse_sort(sort)?),
+            (None, true) => Some(Sort {
+                field: self.key,
+                descending: false,
+            }),
+            (None, false) => None,
+        };
+
+        if let Some(sort) = &sort {
+            items.sort_by(|a, b| self.compare(sort, a, b));
+        }
+        if let (Some(cursor), Some(sort)) = (&params.cursor, &sort) {
+            let after = self.decode_cursor(sort, cursor)?;
+            let start =
+                items.partition_point(|item| self.compare(sort, item, &after) != Ordering::Greater);
+            items.drain(..start);
+        }
+
+        Ok(self.finish(items, sort.as_ref(), params))
+    }
+
+    /// Works out how to pick out the page asked for in the list's query. There's nothing to pick
+    /// out if the list isn't paged, and it can't be if it's sorted by a field that isn't read from
+    /// a column: then the whole list should be fetched and given to [`Listing::page`].
+    pub fn keyset<'a>(&self, params: &'a PageParams) -> Result<Option<Keyset<'a>>, InvalidPage> {
+        check_limit(params)?;
+        if params.limit.is_none() && params.cursor.is_none() {
+            return Ok(None);
+        }
+        let sort = self.parse_sort(params.sort.as_deref().unwrap_or(self.key))?;
+        let (Some(column), Some(key)) = (self.column(sort.field), self.column(self.key)) else {
+            return Ok(None);
+        };
+
+        let after = match &params.cursor {
+            Some(cursor) => {
+                let after = self.decode_cursor(&sort, cursor)?;
+                let (value, key) = (&after[sort.field], &after[self.key]);
+                if !matches!(key, Value::Number(_) | Value::String(_))
+                    || matches!(value, Value::Array(_) | Value::Object(_))
+                {
+                    return Err(InvalidPage("The cursor is invalid.".into()));
+                }
+                Some((value.clone(), key.clone()))
+            }
+            None => None,
+        };
+        Ok(Some(Keyset {
+            sort,
+            column,
+            key,
+            after,
+            limit: params.limit,
+        }))
+    }
+
+    /// Makes a page of the items picked out by a [`Keyset`], which must be in the order fetched.
+    pub fn keyset_page<T: Serialize>(
+        &self,
+        items: Vec<T>,
+        keyset: &Keyset,
+        params: &PageParams,
+    ) -> Result<Page, InvalidPage> {
+        Ok(self.finish(to_values(items)?, Some(&keyset.sort), params))
+    }
+
+    /// Cuts a sorted list that starts after the cursor down to the limit, and removes any fields
+    /// that weren't asked for.
+    fn finish(&self, mut items: Vec<Value>, sort: Option<&Sort>, params: &PageParams) -> Page {
+        let mut next_cursor = None;
+        if let (Some(limit), Some(sort)) = (params.limit, sort) {
+            if items.len() > limit {
+                items.truncate(limit);
+                next_cursor = items.last().map(|last| self.encode_cursor(sort, last));
+            }
+        }
+
+        if let Some(fields) = &params.fields {
+            let fields: Vec<&str> = fields.split(',').map(str::trim).collect();
+            for item in items.iter_mut() {
+                if let Value::Object(map) = item {
+                    map.retain(|k, _| fields.contains(&k.as_str()));
+                }
+            }
+        }
+
+        Page { items, next_cursor }
+    }
+
+    fn column(&self, field: &str) -> Option<&'static Column> {
+        self.columns.iter().find(|c| c.field == field)
+    }
+
+    fn parse_sort<'a>(&self, sort: &'a str) -> Result<Sort<'a>, InvalidPage> {
+        let (field, descending) = match sort.strip_prefix('-') {
+            Some(field) => (field, true),
+            None => (sort, false),
+        };
+        if field != self.key && !self.sortable.contains(&field) {
+            let fields: Vec<&str> = [self.key].iter().chain(self.sortable).copied().collect();
+            return Err(InvalidPage(format!(
+                "Lists can't be sorted by '{}'. Use one of: {}.",
+                field,
+                fields.join(", ")
+            )));
+        }
+        Ok(Sort { field, descending })
+    }
+
+    /// Orders two items by the sort field, then by key.
+    fn compare(&self, sort: &Sort, a: &Value, b: &Value) -> Ordering {
+        let ordering = compare_values(&a[sort.field], &b[sort.field])
+            .then_with(|| compare_values(&a[self.key], &b[self.key]));
+        match sort.descending {
+            true => ordering.reverse(),
+            false => ordering,
+        }
+    }
+
+    fn encode_cursor(&self, sort: &Sort, item: &Value) -> String {
+        let cursor = json!([sort.param(), item[sort.field], item[self.key]]);
+        hex::encode(cursor.to_string())
+    }
+
+    /// Reads a cursor back into a stand-in item holding only the sort field and key, which can be
+    /// compared with the others.
+    fn decode_cursor(&self, sort: &Sort, cursor: &str) -> Result<Value, InvalidPage> {
+        let invalid = || InvalidPage("The cursor is invalid.".into());
+        let cursor: Value = hex::decode(cursor)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or_else(invalid)?;
+        let Some([param, value, key]) = cursor.as_array().map(Vec::as_slice) else {
+            return Err(invalid());
+        };
+        if param.as_str() != Some(sort.param().as_str()) {
+            return Err(InvalidPage(
+                "The cursor belongs to a list with a different sort order.".into(),
+            ));
+        }
+
+        let mut after = json!({});
+        after[self.key] = key.clone();
+        after[sort.field] = value.clone();
+        Ok(after)
+    }
+}
+
+fn check_limit(params: &PageParams) -> Result<(), InvalidPage> {
+    match params.limit.is_some_and(|l| l == 0 || l > MAX_LIMIT) {
+        true => Err(InvalidPage(format!(
+            "The limit must be between 1 and {}.",
+            MAX_LIMIT
+        ))),
+        false => Ok(()),
+    }
+}
+
+fn to_values<T: Serialize>(items: Vec<T>) -> Result<Vec<Value>, InvalidPage> {
+    items
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<_, _>>()
+        .map_err(|e| InvalidPage(e.to_string()))
+}
+
+/// Orders sort values: nulls first, then booleans, numbers and strings. Strings are compared
+/// ignoring case, so that names sort the way people expect.
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    fn rank(value: &Value) -> u8 {
+        match value {
+            Value::Null => 0,
+            Value::Bool(_) => 1,
+            Value::Number(_) => 2,
+            Value::String(_) => 3,
+            Value::Array(_) | Value::Object(_) => 4,
+        }
+    }
+
+    match (a, b) {
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::String(a), Value::String(b)) => a
+            .to_lowercase()
+            .cmp(&b.to_lowercase())
+            .then_with(|| a.cmp(b)),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+/// Builds the link to the next page of a list, from the URI of the current one.
+pub fn next_link(path: &str, query: Option<&str>, cursor: &str) -> String {
+    let mut params: Vec<&str> = query
+        .unwrap_or_default()
+        .split('&')
+        .filter(|p| !p.is_empty() && !p.starts_with("cursor="))
+        .collect();
+    let cursor = format!("cursor={}", cursor);
+    params.push(&cursor);
+    format!("<{}?{}>; rel=\"next\"", path, params.join("&"))
+}
@@ -0,0 +1,564 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, error::Error};
+
+use super::{
+    day::{self, Day, DayInput, MappedShoppingList, ShoppingList},
+    diet,
+    ingredient::{self, Families},
+    recipe::{self, Recipe},
+    utils::{add_days, day_diff, parse_date, sub_days},
+};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Defines the constraints used to generate a meal plan.
+/// - `from` and `to` - the range of dates to fill (YYYY-MM-DD, inclusive)
+/// - `meals_per_day` - the number of meals each day should have, including any already planned
+/// - `no_repeat_days` - a recipe won't be planned again within this many days of its last use
+/// - `required_recipes` - recipes that must appear at least once
+/// - `excluded_recipes` - recipes that must not appear
+/// - `required_ingredients` - ingredients that at least one planned recipe must use
+/// - `excluded_ingredients` - ingredients that no planned recipe may use
+///
+/// Ingredients match any other pack size in their family.
+#[derive(Deserialize, Debug, ToSchema, TS)]
+pub struct PlanInput {
+    pub from: String,
+    pub to: String,
+    #[serde(default = "default_meals_per_day")]
+    #[ts(as = "Option<_>", optional)]
+    pub meals_per_day: i32,
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub no_repeat_days: i32,
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub required_recipes: Vec<i32>,
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub excluded_recipes: Vec<i32>,
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub required_ingredients: Vec<i32>,
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub excluded_ingredients: Vec<i32>,
+}
+
+fn default_meals_per_day() -> i32 {
+    1
+}
+
+/// A generated meal plan. Nothing is saved until the draft is accepted.
+#[derive(Serialize, Debug, ToSchema, TS)]
+pub struct PlanDraft {
+    /// The recipes generated for each day. Meals that were already planned are not included.
+    pub days: Vec<Day>,
+    /// The shopping list for the range if the draft is accepted, excluding scheduled ingredients.
+    pub shopping_list: ShoppingList,
+    /// Any requirements that neither the draft nor the meals already planned meet.
+    pub unmet: UnmetRequirements,
+}
+
+/// The required recipes and ingredients a plan leaves out. A required recipe can't be planned
+/// if it is excluded, doesn't suit the user's diet profile or doesn't exist. A required
+/// ingredient can't be met if no recipe that can be planned uses it. Either may also be left
+/// out if there aren't enough meals to fit every requirement.
+#[derive(Serialize, Debug, Default, PartialEq, ToSchema, TS)]
+pub struct UnmetRequirements {
+    pub recipes: Vec<i32>,
+    pub ingredients: Vec<i32>,
+}
+
+/// Fills a range of days with recipes from the user's collection. Recipes are chosen one meal
+/// at a time, picking whichever leaves the least stock held afterwards - this favours recipes
+/// that use up existing surplus, or that share perishable ingredients with earlier meals.
+/// Recipes that would get a diet warning are never planned.
+pub async fn generate(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    input: PlanInput,
+) -> Result<PlanDraft, Box<dyn Error>> {
+    let profile = diet::query_profile(pool, user_id).await?;
+    // Ingredients are compared by family, so requiring or excluding one pack size covers them all
+    let families = Families::new(ingredient::query_families(pool, user_id).await?);
+    let excluded_ingredients = resolve_ids(&families, &input.excluded_ingredients);
+    let required_ingredients = resolve_ids(&families, &input.required_ingredients);
+    let recipes: Vec<Recipe> = recipe::query_multiple(pool, user_id)
+        .await?
+        .into_iter()
+        .filter(|r| !input.excluded_recipes.contains(&r.id))
+        .filter(|r| !uses_any(r, &families, &excluded_ingredients))
+        .filter(|r| profile.check(r).is_none())
+        .collect();
+
+    // Meals already planned in the range count towards each day's meals. Skipped meals don't
+    // count, and recorded meals count with what they actually used. Meals of leftovers take up
+    // a slot too, though they need no ingredients.
+    let planned: Vec<(String, Vec<Recipe>, i32)> =
+        day::query_range(pool, user_id, &input.from, &input.to)
+            .await?
+            .into_iter()
+            .map(|d| (d.date.clone(), d.meals(), d.leftovers.len() as i32))
+            .collect();
+
+    // Check far enough back to catch any surplus the plan could use
+    let days_to_check = recipes
+        .iter()
+        .chain(planned.iter().flat_map(|(_, meals, _)| meals.iter()))
+        .flat_map(|r| r.all_ingredients())
+        .map(|i| i.ingredient.life)
+        .max()
+        .unwrap_or(0);
+    let mut list = day::start_list(pool, user_id, &input.from, days_to_check).await?;
+
+    // Recipes made shortly before the range still count towards repeats
+    let mut last_used: HashMap<i32, String> = HashMap::new();
+    if input.no_repeat_days > 0 {
+        let history_start = sub_days(&input.from, input.no_repeat_days)?;
+        let history_end = sub_days(&input.from, 1)?;
+        for day in day::query_range(pool, user_id, &history_start, &history_end).await? {
+            for recipe in day.recipes {
+                last_used.insert(recipe.id, day.date.clone());
+            }
+        }
+    }
+
+    // Requirements already met by planned meals don't need to be planned again
+    let planned_recipes: Vec<&Recipe> = planned
+        .iter()
+        .flat_map(|(_, meals, _)| meals.iter())
+        .collect();
+    let mut unmet_recipes: Vec<i32> = input
+        .required_recipes
+        .iter()
+        .filter(|id| recipes.iter().any(|r| r.id == **id))
+        .filter(|id| !planned_recipes.iter().any(|r| r.id == **id))
+        .cloned()
+        .collect();
+    let mut unmet_ingredients: Vec<i32> = required_ingredients
+        .iter()
+        .filter(|id| recipes.iter().any(|r| uses_any(r, &families, &[**id])))
+        .filter(|id| {
+            !planned_recipes
+                .iter()
+                .any(|r| uses_any(r, &families, &[**id]))
+        })
+        .cloned()
+        .collect();
+
+    // Work out how many meals need to be planned on each date
+    let mut open_slots: Vec<(String, Vec<Recipe>, i32)> = vec![];
+    let mut date = input.from.clone();
+    while day_diff(&input.to, &date)? >= 0 {
+        let (existing, leftovers) = planned
+            .iter()
+            .find(|(planned_date, _, _)| *planned_date == date)
+            .map(|(_, meals, leftovers)| (meals.clone(), *leftovers))
+            .unwrap_or_default();
+        let open = (input.meals_per_day - existing.len() as i32 - leftovers).max(0);
+        open_slots.push((date.clone(), existing, open));
+        date = add_days(&date, 1)?;
+    }
+    let mut remaining_slots: i32 = open_slots.iter().map(|(_, _, open)| open).sum();
+
+    let mut draft: Vec<Day> = vec![];
+    for (date, existing, open) in open_slots {
+        for recipe in existing {
+            last_used.insert(recipe.id, date.clone());
+            list.add_recipe(&date, recipe)?;
+        }
+
+        let mut chosen: Vec<Recipe> = vec![];
+        for _ in 0..open {
+            // Skip recipes made too recently
+            let mut eligible: Vec<&Recipe> = vec![];
+            for recipe in &recipes {
+                let repeated = match last_used.get(&recipe.id) {
+                    Some(used) => day_diff(&date, used)? < input.no_repeat_days,
+                    None => false,
+                };
+                if !repeated {
+                    eligible.push(recipe);
+                }
+            }
+
+            // Once there are only enough meals left to meet the requirements, only plan
+            // recipes that meet one.
+            let unmet = (unmet_recipes.len() + unmet_ingredients.len()) as i32;
+            if remaining_slots <= unmet {
+                let meeting: Vec<&Recipe> = eligible
+                    .iter()
+                    .filter(|r| {
+                        unmet_recipes.contains(&r.id) || uses_any(r, &families, &unmet_ingredients)
+                    })
+                    .cloned()
+                    .collect();
+                if !meeting.is_empty() {
+                    eligible = meeting;
+                }
+            }
+            remaining_slots -= 1;
+
+            if let Some(recipe) = choose_recipe(&list, &date, &eligible)? {
+                unmet_recipes.retain(|id| *id != recipe.id);
+                unmet_ingredients.retain(|id| !uses_any(&recipe, &families, &[*id]));
+                last_used.insert(recipe.id, date.clone());
+                list.add_recipe(&date, recipe.clone())?;
+                chosen.push(recipe);
+            }
+        }
+
+        if !chosen.is_empty() {
+            draft.push(Day::new(date, chosen));
+        }
+    }
+
+    // Report whatever is still missing from the draft and the meals already planned
+    let all_planned: Vec<&Recipe> = planned_recipes
+        .into_iter()
+        .chain(draft.iter().flat_map(|d| d.recipes.iter()))
+        .collect();
+    let unmet = UnmetRequirements {
+        recipes: input
+            .required_recipes
+            .iter()
+            .filter(|id| !all_planned.iter().any(|r| r.id == **id))
+            .cloned()
+            .collect(),
+        ingredients: input
+            .required_ingredients
+            .iter()
+            .filter(|id| {
+                let resolved = families.resolve_id(**id);
+                !all_planned
+                    .iter()
+                    .any(|r| uses_any(r, &families, &[resolved]))
+            })
+            .cloned()
+            .collect(),
+    };
+
+    list.project_waste(&[])?;
+    let mut shopping_list: ShoppingList = list.into();
+    shopping_list.sort_by_aisle(&ingredient::query_aisles(pool, user_id).await?);
+
+    Ok(PlanDraft {
+        days: draft,
+        shopping_list,
+        unmet,
+    })
+}
+
+/// Scores each candidate by the change in stock held once it is added to the list, returning
+/// the candidate that leaves the least. Ties go to the lowest recipe ID.
+fn choose_recipe(
+    list: &MappedShoppingList,
+    date: &str,
+    candidates: &[&Recipe],
+) -> Result<Option<Recipe>, Box<dyn Error>> {
+    let held = list.held_stock();
+    let mut best: Option<(f64, &Recipe)> = None;
+
+    for recipe in candidates {
+        let mut trial = list.clone();
+        trial.add_recipe(date, (*recipe).clone())?;
+        let score = trial.held_stock() - held;
+
+        let better = match best {
+            Some((best_score, best_recipe)) => {
+                score < best_score || (score == best_score && recipe.id < best_recipe.id)
+            }
+            None => true,
+        };
+        if better {
+            best = Some((score, recipe));
+        }
+    }
+
+    Ok(best.map(|(_, recipe)| recipe.clone()))
+}
+
+/// Resolves ingredient IDs to their family-level IDs, for comparison with `uses_any`.
+fn resolve_ids(families: &Families, ingredient_ids: &[i32]) -> Vec<i32> {
+    ingredient_ids
+        .iter()
+        .map(|id| families.resolve_id(*id))
+        .collect()
+}
+
+/// True if the recipe uses any of the given ingredients, which must already be resolved to
+/// their families. A recipe using any pack size in a family uses the family.
+fn uses_any(recipe: &Recipe, families: &Families, ingredient_ids: &[i32]) -> bool {
+    recipe
+        .all_ingredients()
+        .iter()
+        .any(|i| ingredient_ids.contains(&families.resolve_id(i.ingredient.id)))
+}
+
+/// Accepts a draft plan, adding each of its recipes to the user's days in one go.
+pub async fn accept(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    days: Vec<DayInput>,
+) -> Result<(), Box<dyn Error>> {
+    day::create_many(pool, user_id, days).await
+}
+
+/// A named set of recipes saved from a range of days, which can be planned again from any date.
+#[derive(Serialize, Debug, ToSchema, TS)]
+pub struct PlanTemplate {
+    pub id: i32,
+    pub name: String,
+    pub days: Vec<TemplateDay>,
+}
+
+/// A recipe in a template, planned `day_offset` days after the template's start.
+#[derive(Serialize, Debug, ToSchema, TS)]
+pub struct TemplateDay {
+    pub day_offset: i32,
+    pub recipe_id: i32,
+    pub recipe_name: String,
+}
+
+/// Defines the input for saving a range of days as a template.
+/// - `name` - the display name of the template
+/// - `from` and `to` - the range of days to save (YYYY-MM-DD, inclusive)
+#[derive(Deserialize, Debug, ToSchema, TS)]
+pub struct TemplateInput {
+    pub name: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// Defines the input for renaming a template.
+#[derive(Deserialize, Debug, ToSchema, TS)]
+pub struct TemplateRename {
+    pub name: String,
+}
+
+/// Defines the input for applying a template, starting on `start_date` (YYYY-MM-DD).
+#[derive(Deserialize, Debug, ToSchema, TS)]
+pub struct ApplyInput {
+    pub start_date: String,
+}
+
+/// Defines the input for copying a range of days, such as last week, to start on `start_date`.
+#[derive(Deserialize, Debug, ToSchema, TS)]
+pub struct CopyInput {
+    pub from: String,
+    pub to: String,
+    pub start_date: String,
+}
+
+/// Queries all of a user's templates, along with their recipes.
+pub async fn query_templates(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+) -> Result<Vec<PlanTemplate>, sqlx::Error> {
+    let rows = sqlx::query!(
+        "SELECT
+			t.id,
+			t.name,
+			d.day_offset as \"day_offset?\",
+			r.id as \"recipe_id?\",
+			r.name as \"recipe_name?\"
+		FROM plan_template AS t
+			LEFT JOIN plan_template_day AS d ON d.template_id = t.id
+			LEFT JOIN recipe AS r ON r.id = d.recipe_id
+		WHERE t.user_id = $1
+		ORDER BY t.id ASC, d.day_offset ASC, r.id ASC",
+        user_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut templates: Vec<PlanTemplate> = vec![];
+    for row in rows {
+        // Rows are ordered by template, so days of the same template are adjacent.
+        if templates.last().is_none_or(|t| t.id != row.id) {
+            templates.push(PlanTemplate {
+                id: row.id,
+                name: row.name,
+                days: vec![],
+            });
+        }
+
+        if let (Some(day_offset), Some(recipe_id), Some(recipe_name)) =
+            (row.day_offset, row.recipe_id, row.recipe_name)
+        {
+            if let Some(template) = templates.last_mut() {
+                template.days.push(TemplateDay {
+                    day_offset,
+                    recipe_id,
+                    recipe_name,
+                });
+            }
+        }
+    }
+
+    Ok(templates)
+}
+
+/// Saves the recipes planned in a range of days as a template. Returns its generated ID.
+pub async fn create_template(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    template: TemplateInput,
+) -> Result<i32, Box<dyn Error>> {
+    let from = parse_date(&template.from)?;
+    let to = parse_date(&template.to)?;
+
+    let mut tx = pool.begin().await?;
+
+    let inserted = sqlx::query!(
+        "INSERT INTO plan_template (user_id, name) VALUES ($1, $2) RETURNING id",
+        user_id,
+        template.name
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    // Store each recipe against the number of days since the start of the range. Meals eaten
+    // from leftovers are left out, as they need no planning of their own.
+    sqlx::query!(
+        "INSERT INTO plan_template_day (template_id, day_offset, recipe_id)
+			SELECT $1, day.date - $2::date, day.recipe_id
+			FROM day
+				INNER JOIN recipe ON recipe.id = day.recipe_id
+			WHERE recipe.user_id = $3 AND day.date BETWEEN $2 AND $4 AND day.leftovers_of IS NULL",
+        inserted.id,
+        from,
+        user_id,
+        to
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(inserted.id)
+}
+
+/// Renames one of a user's templates.
+pub async fn rename_template(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    template_id: i32,
+    rename: TemplateRename,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE plan_template SET name = $3 WHERE id = $1 AND user_id = $2",
+        template_id,
+        user_id,
+        rename.name
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Deletes one of a user's templates. Days planned from it are kept.
+pub async fn delete_template(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    template_id: i32,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "DELETE FROM plan_template WHERE id = $1 AND user_id = $2",
+        template_id,
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Plans every recipe in a template, offset from a new start date.
+pub async fn apply_template(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    template_id: i32,
+    apply: ApplyInput,
+) -> Result<(), Box<dyn Error>> {
+    let start_date = parse_date(&apply.start_date)?;
+
+    // A single statement, so either every recipe is planned or none are
+    sqlx::query!(
+        "INSERT INTO day (recipe_id, date)
+			SELECT d.recipe_id, $2::date + d.day_offset
+			FROM plan_template_day AS d
+				INNER JOIN plan_template AS t ON t.id = d.template_id
+			WHERE t.id = $1 AND t.user_id = $3
+			ON CONFLICT (recipe_id, date) DO NOTHING",
+        template_id,
+        start_date,
+        user_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Copies the recipes planned in a range of days, such as last week, to start on a new date.
+/// Meals eaten from leftovers are copied along with the meal they were cooked at, if it is also
+/// in the range.
+pub async fn copy_days(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    copy: CopyInput,
+) -> Result<(), Box<dyn Error>> {
+    let from = parse_date(&copy.from)?;
+    let to = parse_date(&copy.to)?;
+    let start_date = parse_date(&copy.start_date)?;
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query!(
+        "INSERT INTO day (recipe_id, date, portions)
+			SELECT day.recipe_id, $3::date + (day.date - $1::date), day.portions
+			FROM day
+				INNER JOIN recipe ON recipe.id = day.recipe_id
+			WHERE recipe.user_id = $4 AND day.date BETWEEN $1 AND $2 AND day.leftovers_of IS NULL
+			ON CONFLICT (recipe_id, date) DO NOTHING",
+        from,
+        to,
+        start_date,
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Link each copied meal of leftovers to the newest copy of the meal it was cooked at
+    sqlx::query!(
+        "INSERT INTO day (recipe_id, date, portions, leftovers_of)
+			SELECT day.recipe_id, $3::date + (day.date - $1::date), day.portions, (
+				SELECT copied.id FROM day AS copied
+				WHERE copied.recipe_id = day.recipe_id
+					AND copied.date = $3::date + (cooked.date - $1::date)
+					AND copied.leftovers_of IS NULL
+				ORDER BY copied.id DESC
+				LIMIT 1
+			)
+			FROM day
+				INNER JOIN day AS cooked ON cooked.id = day.leftovers_of
+				INNER JOIN recipe ON recipe.id = day.recipe_id
+			WHERE recipe.user_id = $4
+				AND day.date BETWEEN $1 AND $2
+				AND cooked.date BETWEEN $1 AND $2
+			ON CONFLICT (recipe_id, date) DO NOTHING",
+        from,
+        to,
+        start_date,
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fmt};
+use time::Date;
+
+use super::{diet::Allergen, ingredient::Ingredient, nutrition::Nutrition, utils::parse_date};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// An amount of an ingredient bought on a shopping trip.
+#[derive(Serialize, Clone, Debug, PartialEq, ToSchema, TS)]
+pub struct Purchase {
+    pub id: i32,
+    pub ingredient: Ingredient,
+    pub quantity: i32,
+    pub date: String,
+}
+
+/// Identifies an ingredient in a completed shopping list. The rest of the ingredient is ignored.
+#[derive(Deserialize, Debug, ToSchema, TS)]
+pub struct IngredientId {
+    pub id: i32,
+}
+
+/// An entry of a completed shopping list, with the amount that was actually bought.
+/// - `ingredient` - the ingredient bought, of which only the ID is needed
+/// - `purchase_quantity` - the total amount bought, which may differ from the amount suggested
+/// - `date` - the date it was bought, if it differs from the trip's
+#[derive(Deserialize, Debug, ToSchema, TS)]
+pub struct PurchasedIngredient {
+    pub ingredient: IngredientId,
+    pub purchase_quantity: i32,
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub date: Option<String>,
+}
+
+/// A shopping list sent back once the shopping has been done. Takes the same shape as the
+/// `ShoppingList` it was built from, so the list can be edited and returned as is.
+#[derive(Deserialize, Debug, ToSchema, TS)]
+pub struct ShoppingTrip {
+    /// The date of the trip (YYYY-MM-DD).
+    pub date: String,
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub ingredients: Vec<PurchasedIngredient>,
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub scheduled_ingredients: Vec<PurchasedIngredient>,
+}
+
+/// Returned when a shopping trip can't be recorded, with a description of the problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidTrip(pub String);
+
+impl fmt::Display for InvalidTrip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for InvalidTrip {}
+
+/// Records everything bought on a shopping trip. Items with nothing bought are left out.
+/// Either every purchase is recorded, or none are.
+pub async fn complete_trip(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    trip: ShoppingTrip,
+) -> Result<Result<(), InvalidTrip>, sqlx::Error> {
+    let mut ingredient_ids: Vec<i32> = vec![];
+    let mut quantities: Vec<i32> = vec![];
+    let mut dates: Vec<Date> = vec![];
+
+    let items = trip
+        .ingredients
+        .iter()
+        .chain(trip.scheduled_ingredients.iter());
+    for item in items.filter(|i| i.purchase_quantity != 0) {
+        if item.purchase_quantity < 0 {
+            return Ok(Err(InvalidTrip(
+                "Purchased quantities can't be negative.".into(),
+            )));
+        }
+        let Ok(date) = parse_date(item.date.as_ref().unwrap_or(&trip.date)) else {
+            return Ok(Err(InvalidTrip(
+                "Dates must be in the format YYYY-MM-DD.".into(),
+            )));
+        };
+        ingredient_ids.push(item.ingredient.id);
+        quantities.push(item.purchase_quantity);
+        dates.push(date);
+    }
+
+    // Only global ingredients and the user's own can be bought
+    let unknown = sqlx::query!(
+        "SELECT u.id as \"id!\" FROM UNNEST($1::integer[]) AS u(id)
+		WHERE NOT EXISTS (
+			SELECT i.id FROM ingredient AS i
+			WHERE i.id = u.id AND (i.user_id IS NULL OR i.user_id = $2)
+		)
+		LIMIT 1",
+        &ingredient_ids,
+        user_id
+    )
+    .fetch_optional(pool)
+    .await?;
+    if let Some(unknown) = unknown {
+        return Ok(Err(InvalidTrip(format!(
+            "Ingredient {} doesn't exist.",
+            unknown.id
+        ))));
+    }
+
+    sqlx::query!(
+        "INSERT INTO purchase (user_id, ingredient_id, quantity, date)
+		SELECT $1, p.ingredient_id, p.quantity, p.date
+		FROM UNNEST($2::integer[], $3::integer[], $4::date[]) AS p(ingredient_id, quantity, date)",
+        user_id,
+        &ingredient_ids,
+        &quantities,
+        &dates
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(Ok(()))
+}
+
+/// Queries the purchases a user made between a range of dates, oldest first.
+pub async fn query_range(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    from: &str,
+    to: &str,
+) -> Result<Vec<Purchase>, Box<dyn Error>> {
+    let rows = sqlx::query!(
+        "SELECT
+			pu.id,
+			pu.quantity,
+			pu.date,
+
+			i.id as ingredient_id,
+			i.name,
+            i.user_id,
+			i.unit,
+			i.minimum_quantity, 
+			i.purchase_quantity,
+			i.life,
+			i.family_id,
+			i.category,
+			COALESCE(p.price, i.price) as price,
+			i.energy,
+			i.protein,
+			i.fat,
+			i.carbohydrate,
+			i.fibre,
+			i.salt,
+			i.allergens,
+			i.vegetarian,
+			i.vegan
+		FROM purchase AS pu
+			INNER JOIN ingredient AS i
+			ON i.id = pu.ingredient_id
+			LEFT JOIN ingredient_price AS p
+			ON p.ingredient_id = i.id AND p.user_id = pu.user_id
+		WHERE pu.user_id = $1 AND pu.date BETWEEN $2 AND $3
+		ORDER BY pu.date ASC, pu.id ASC",
+        user_id,
+        parse_date(from)?,
+        parse_date(to)?
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut purchases = vec![];
+    for row in rows {
+        let ingredient = Ingredient::new(
+            row.ingredient_id,
+            row.name,
+            row.user_id,
+            row.unit,
+            row.minimum_quantity,
+            row.purchase_quantity,
+            row.life,
+        )
+        .with_family(row.family_id)
+        .with_category(row.category)
+        .with_price(row.price)
+        .with_nutrition(Nutrition::from_columns(
+            row.energy,
+            row.protein,
+            row.fat,
+            row.carbohydrate,
+            row.fibre,
+            row.salt,
+        ))
+        .with_diet(
+            Allergen::parse_all(&row.allergens),
+            row.vegetarian,
+            row.vegan,
+        );
+        purchases.push(Purchase {
+            id: row.id,
+            ingredient,
+            quantity: row.quantity,
+            date: row.date.to_string(),
+        });
+    }
+    Ok(purchases)
+}
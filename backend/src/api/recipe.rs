@@ -1,11 +1,22 @@
-use serde::{Deserialize, Serialize};
-use sqlx::types;
-use std::{collections::HashMap, error::Error};
+use serde::{
+    de::{value, IntoDeserializer},
+    Deserialize, Serialize,
+};
+use sqlx::{types, QueryBuilder};
+use std::{collections::HashMap, error::Error, fmt};
 
-use super::ingredient::{Ingredient, IngredientQuantity};
+use super::{
+    diet::{Allergen, RecipeDiet},
+    images::{self, RecipeImage},
+    ingredient::{Ingredient, IngredientQuantity},
+    nutrition::{Nutrition, RecipeNutrition},
+    page::{Column, InvalidPage, Listing, Page, PageParams},
+};
+use ts_rs::TS;
+use utoipa::{IntoParams, ToSchema};
 
 /// Represents a recipe with fully populated ingredient data.
-#[derive(Debug, Serialize, Clone, Eq)]
+#[derive(Debug, Serialize, Clone, ToSchema, TS)]
 pub struct Recipe {
     /// The recipe's unique ID.
     pub id: i32,
@@ -13,10 +24,30 @@ pub struct Recipe {
     pub name: String,
     /// The number of portions this recipe makes.
     portions: i32,
-    /// A JSON array of strings representing the recipe's steps.
-    steps: types::JsonValue,
+    /// The recipe's method, in order.
+    pub steps: Vec<Step>,
     /// A list of the recipe's ingredients.
     pub ingredients: Vec<IngredientQuantity>,
+    /// The estimated cost of the ingredients used, in pence. Absent if any ingredient is unpriced.
+    pub cost: Option<i32>,
+    /// The estimated cost of a single portion, in pence.
+    pub cost_per_portion: Option<i32>,
+    /// The nutrition of the whole recipe and of a single portion.
+    pub nutrition: RecipeNutrition,
+    /// The allergens in the recipe, and whether it suits vegetarians and vegans.
+    pub diet: RecipeDiet,
+    /// Timings and provenance of the recipe.
+    #[serde(flatten)]
+    pub metadata: RecipeMetadata,
+    /// Photos of the recipe and its steps.
+    pub images: Vec<RecipeImage>,
+    /// Other recipes used in this one, such as a sauce or dough.
+    pub sub_recipes: Vec<SubRecipe>,
+    /// Goes up by one each time the recipe is updated. Updates may give the version they were
+    /// made from, so that they don't overwrite a newer one.
+    pub version: i32,
+    /// When the recipe was added, as YYYY-MM-DDTHH:MM:SS.
+    pub created_at: String,
 }
 
 impl PartialEq for Recipe {
@@ -30,36 +61,379 @@ impl Recipe {
         id: i32,
         name: String,
         portions: i32,
-        steps: types::JsonValue,
+        steps: Vec<Step>,
         ingredients: Vec<IngredientQuantity>,
     ) -> Self {
-        Recipe {
+        let mut recipe = Recipe {
             id,
             name,
             portions,
             steps,
             ingredients,
+            cost: None,
+            cost_per_portion: None,
+            nutrition: RecipeNutrition::default(),
+            diet: RecipeDiet::default(),
+            metadata: RecipeMetadata::default(),
+            images: vec![],
+            sub_recipes: vec![],
+            version: 1,
+            created_at: String::new(),
+        };
+        recipe.summarise();
+        recipe
+    }
+
+    /// Sets the recipe's timings and provenance.
+    pub fn with_metadata(mut self, metadata: RecipeMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    pub fn with_version(mut self, version: i32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets when the recipe was added.
+    pub fn with_created_at(mut self, created_at: String) -> Self {
+        self.created_at = created_at;
+        self
+    }
+
+    /// Adds an ingredient to the recipe, updating its cost, nutrition and diet.
+    pub fn add_ingredient(&mut self, ingredient: IngredientQuantity) {
+        self.ingredients.push(ingredient);
+        self.summarise();
+    }
+
+    /// Recalculates everything derived from the recipe's ingredients, including those of its
+    /// sub-recipes.
+    fn summarise(&mut self) {
+        self.calculate_cost();
+        self.calculate_nutrition();
+        self.diet = RecipeDiet::classify(&self.all_ingredients());
+    }
+
+    /// Returns the recipe's own ingredients along with those of its sub-recipes, expanded
+    /// recursively and scaled to the number of portions used. Scaled quantities are rounded up,
+    /// so there is always enough to make each sub-recipe.
+    pub fn all_ingredients(&self) -> Vec<IngredientQuantity> {
+        let mut ingredients = self.ingredients.clone();
+        for sub_recipe in &self.sub_recipes {
+            let scale = sub_recipe.portions as f64 / sub_recipe.recipe.portions.max(1) as f64;
+            for quantity in sub_recipe.recipe.all_ingredients() {
+                let scaled = (quantity.quantity as f64 * scale).ceil() as i32;
+                match ingredients
+                    .iter_mut()
+                    .find(|i| i.ingredient.id == quantity.ingredient.id)
+                {
+                    Some(existing) => existing.quantity += scaled,
+                    None => ingredients.push(IngredientQuantity::new(quantity.ingredient, scaled)),
+                }
+            }
+        }
+        ingredients
+    }
+
+    /// Estimates the cost of the recipe from the price per unit of its ingredients. Only the
+    /// amount used is costed, rather than the whole packs bought.
+    fn calculate_cost(&mut self) {
+        let cost: Option<f64> = self
+            .all_ingredients()
+            .iter()
+            .map(|i| i.ingredient.cost_of(i.quantity))
+            .sum();
+        self.cost = cost.map(|c| c.round() as i32);
+        self.cost_per_portion = match self.portions {
+            0 => None,
+            portions => cost.map(|c| (c / portions as f64).round() as i32),
+        };
+    }
+
+    /// Totals the nutrition of the recipe's ingredients, from the quantity of each used.
+    fn calculate_nutrition(&mut self) {
+        let mut total = Nutrition::default();
+        let mut unknown_ingredients = vec![];
+        for quantity in &self.all_ingredients() {
+            match quantity.ingredient.nutrition_of(quantity.quantity) {
+                Some(nutrition) => total = total + nutrition,
+                None => unknown_ingredients.push(quantity.ingredient.id),
+            }
+        }
+        let per_portion = total.scale(1.0 / self.portions.max(1) as f64);
+
+        self.nutrition = RecipeNutrition {
+            total: total.round(),
+            per_portion: per_portion.round(),
+            unknown_ingredients,
+        };
+    }
+
+    /// Returns the text of each of the recipe's steps, in order.
+    pub fn step_text(&self) -> Vec<String> {
+        self.steps.iter().map(|s| s.text.clone()).collect()
+    }
+}
+
+/// Another recipe used as part of a recipe, measured in portions.
+#[derive(Debug, Serialize, Clone, ToSchema, TS)]
+pub struct SubRecipe {
+    pub recipe_id: i32,
+    pub name: String,
+    /// The number of portions of the sub-recipe used.
+    pub portions: i32,
+    /// The sub-recipe itself, with its own sub-recipes filled in.
+    #[serde(skip)]
+    pub recipe: Box<Recipe>,
+}
+
+/// How hard a recipe is to make.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema, TS)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    /// Parses a difficulty stored in the database, ignoring any that aren't recognised.
+    pub fn parse(value: Option<String>) -> Option<Difficulty> {
+        let deserializer: value::StrDeserializer<value::Error> =
+            value.as_deref()?.into_deserializer();
+        Difficulty::deserialize(deserializer).ok()
+    }
+
+    /// The name the difficulty is stored under.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+        }
+    }
+}
+
+/// Optional timings and provenance of a recipe. Times are in minutes.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, ToSchema, TS)]
+pub struct RecipeMetadata {
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub prep_time: Option<i32>,
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub cook_time: Option<i32>,
+    /// The time from start to finish, including any resting or proving. Defaults to the sum of
+    /// the prep and cook times.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub total_time: Option<i32>,
+    /// Where the recipe came from.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub source_url: Option<String>,
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub notes: Option<String>,
+    /// What the recipe makes, e.g. "1 large loaf" or "12 cookies".
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub yield_description: Option<String>,
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub difficulty: Option<Difficulty>,
+}
+
+impl RecipeMetadata {
+    /// The total time to store for the recipe: either the one given, or the prep and cook times
+    /// added together if either is known.
+    pub fn total_time(&self) -> Option<i32> {
+        match (self.total_time, self.prep_time, self.cook_time) {
+            (Some(total), _, _) => Some(total),
+            (None, None, None) => None,
+            (None, prep, cook) => Some(prep.unwrap_or(0) + cook.unwrap_or(0)),
+        }
+    }
+
+    /// Checks that times are positive and consistent, and that the source is a web address.
+    fn validate(&self) -> Result<(), InvalidRecipe> {
+        for (name, time) in [
+            ("prep", self.prep_time),
+            ("cook", self.cook_time),
+            ("total", self.total_time),
+        ] {
+            if time.is_some_and(|t| t < 0) {
+                return Err(InvalidRecipe(format!("The {name} time can't be negative.")));
+            }
+        }
+        if let Some(total) = self.total_time {
+            if total < self.prep_time.unwrap_or(0) + self.cook_time.unwrap_or(0) {
+                return Err(InvalidRecipe(
+                    "The total time can't be less than the prep and cook times.".into(),
+                ));
+            }
+        }
+        if let Some(url) = &self.source_url {
+            if !(url.starts_with("http://") || url.starts_with("https://")) {
+                return Err(InvalidRecipe(
+                    "The source URL must be an http or https address.".into(),
+                ));
+            }
         }
+        Ok(())
+    }
+}
+
+/// Filters applied when listing recipes.
+#[derive(Deserialize, Debug, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RecipeFilter {
+    /// Only include recipes that take at most this many minutes in total.
+    pub max_time: Option<i32>,
+}
+
+/// A single step of a recipe's method.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema, TS)]
+pub struct Step {
+    /// A heading that starts a new section of the method, e.g. "For the sauce".
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub section: Option<String>,
+    /// The instruction itself.
+    pub text: String,
+    /// How long the step takes, in seconds.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub duration: Option<i32>,
+    /// True if a timer should be offered for the step's duration.
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub timer: bool,
+    /// The recipe's ingredients used in this step.
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub ingredients: Vec<StepIngredient>,
+}
+
+/// A reference to one of the recipe's ingredients from a step.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema, TS)]
+pub struct StepIngredient {
+    pub ingredient_id: i32,
+    /// The amount of the ingredient used in the step.
+    pub quantity: i32,
+}
+
+/// Returned when a recipe input can't be saved, with a description of the problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidRecipe(pub String);
+
+impl fmt::Display for InvalidRecipe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
     }
 }
 
+impl Error for InvalidRecipe {}
+
 /// An input to insert a recipe into the database.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, ToSchema, TS)]
 pub struct RecipeInput {
     /// The display name of the recipe.
     pub name: String,
     /// The number of portions the recipe makes.
     pub portions: i32,
-    /// A JSON array of strings representing the recipe's steps.
-    pub steps: types::JsonValue,
+    /// The recipe's method, in order.
+    pub steps: Vec<Step>,
     /// Represents the ingredients of the recipe as relations on ID.
     pub ingredients: Vec<RecipeIngredientInput>,
+    /// Other recipes used in this one.
+    #[serde(default)]
+    #[ts(as = "Option<_>", optional)]
+    pub sub_recipes: Vec<SubRecipeInput>,
+    /// Timings and provenance of the recipe.
+    #[serde(flatten)]
+    pub metadata: RecipeMetadata,
+    /// When updating, the version of the recipe the changes were made to. If the recipe has been
+    /// updated since, the changes are rejected rather than overwriting it.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub version: Option<i32>,
+}
+
+impl RecipeInput {
+    /// Checks the recipe's steps and metadata are well formed. Steps may only refer to the
+    /// recipe's own ingredients, and can't use more of an ingredient than the recipe does.
+    pub fn validate(&self) -> Result<(), InvalidRecipe> {
+        self.metadata.validate()?;
+
+        if self.ingredients.is_empty() && self.sub_recipes.is_empty() {
+            return Err(InvalidRecipe(
+                "A recipe must have at least one ingredient or sub-recipe.".into(),
+            ));
+        }
+        if self.sub_recipes.iter().any(|s| s.portions <= 0) {
+            return Err(InvalidRecipe(
+                "Sub-recipes must use a positive number of portions.".into(),
+            ));
+        }
+
+        let mut used = HashMap::<i32, i32>::new();
+
+        for (i, step) in self.steps.iter().enumerate() {
+            let number = i + 1;
+            if step.text.trim().is_empty() {
+                return Err(InvalidRecipe(format!("Step {number} has no text.")));
+            }
+            if step.section.as_ref().is_some_and(|s| s.trim().is_empty()) {
+                return Err(InvalidRecipe(format!(
+                    "Step {number} has an empty section."
+                )));
+            }
+            if step.duration.is_some_and(|d| d <= 0) {
+                return Err(InvalidRecipe(format!(
+                    "Step {number} must have a positive duration."
+                )));
+            }
+            if step.timer && step.duration.is_none() {
+                return Err(InvalidRecipe(format!(
+                    "Step {number} has a timer but no duration."
+                )));
+            }
+            for ingredient in &step.ingredients {
+                if ingredient.quantity <= 0 {
+                    return Err(InvalidRecipe(format!(
+                        "Step {number} must use a positive quantity of each ingredient."
+                    )));
+                }
+                *used.entry(ingredient.ingredient_id).or_default() += ingredient.quantity;
+            }
+        }
+
+        for (ingredient_id, quantity) in used {
+            match self.ingredients.iter().find(|i| i.id == ingredient_id) {
+                None => {
+                    return Err(InvalidRecipe(format!(
+                        "Ingredient {ingredient_id} is used in a step but not in the recipe."
+                    )))
+                }
+                Some(input) if quantity > input.quantity => {
+                    return Err(InvalidRecipe(format!(
+                        "The steps use more of ingredient {ingredient_id} than the recipe does."
+                    )))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Represents a single recipe/ingredient relation, with quantity.
 /// - `id` - The unique ID of the ingredient
 /// - `quantity` - The amount of the ingredient used in whatever recipe the relation is linked to
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, ToSchema, TS)]
 pub struct RecipeIngredientInput {
     pub id: i32,
     pub quantity: i32,
@@ -74,35 +448,177 @@ impl RecipeIngredientInput {
     }
 }
 
+/// Represents the use of another of the user's recipes, in portions.
+#[derive(Deserialize, Debug, ToSchema, TS)]
+pub struct SubRecipeInput {
+    pub recipe_id: i32,
+    pub portions: i32,
+}
+
+/// How lists of recipes can be sorted and paged through.
+pub const LISTING: Listing = Listing {
+    key: "id",
+    sortable: &[
+        "name",
+        "portions",
+        "cost",
+        "cost_per_portion",
+        "prep_time",
+        "cook_time",
+        "total_time",
+        "created_at",
+    ],
+    columns: &[
+        Column {
+            field: "id",
+            sql: "r.id",
+            text: false,
+        },
+        Column {
+            field: "name",
+            sql: "r.name",
+            text: true,
+        },
+        Column {
+            field: "portions",
+            sql: "r.portions",
+            text: false,
+        },
+        Column {
+            field: "prep_time",
+            sql: "r.prep_time",
+            text: false,
+        },
+        Column {
+            field: "cook_time",
+            sql: "r.cook_time",
+            text: false,
+        },
+        Column {
+            field: "total_time",
+            sql: "r.total_time",
+            text: false,
+        },
+        Column {
+            field: "created_at",
+            sql: CREATED_AT_SQL,
+            text: false,
+        },
+    ],
+};
+
+/// Reads a recipe's `created_at` as the text it is given as, which sorts in time order.
+const CREATED_AT_SQL: &str = "to_char(r.created_at, 'YYYY-MM-DD\"T\"HH24:MI:SS')";
+
 pub async fn query_multiple(
     pool: &sqlx::Pool<sqlx::Postgres>,
     user_id: i32,
+) -> Result<Vec<Recipe>, sqlx::Error> {
+    query_filtered(pool, user_id, &RecipeFilter::default()).await
+}
+
+/// Fetches the user's recipes that match a filter.
+pub async fn query_filtered(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    filter: &RecipeFilter,
+) -> Result<Vec<Recipe>, sqlx::Error> {
+    let mut recipes = query_own_ingredients(pool, user_id, filter, None).await?;
+    attach_sub_recipes(pool, user_id, &mut recipes).await?;
+    images::attach(pool, &mut recipes).await?;
+    Ok(recipes)
+}
+
+/// Fetches a page of the user's recipes that match a filter. Pages sorted by a column are picked
+/// out in the query, and the rest are cut from the whole list.
+pub async fn query_page(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    filter: &RecipeFilter,
+    params: &PageParams,
+) -> Result<Result<Page, InvalidPage>, sqlx::Error> {
+    let keyset = match LISTING.keyset(params) {
+        Ok(Some(keyset)) => keyset,
+        Ok(None) => return Ok(LISTING.page(query_filtered(pool, user_id, filter).await?, params)),
+        Err(e) => return Ok(Err(e)),
+    };
+
+    let mut query = QueryBuilder::new("SELECT r.id FROM recipe AS r WHERE r.user_id = ");
+    query.push_bind(user_id);
+    if let Some(max_time) = filter.max_time {
+        query.push(" AND r.total_time <= ");
+        query.push_bind(max_time);
+    }
+    keyset.push_to(&mut query);
+    let ids: Vec<i32> = query.build_query_scalar().fetch_all(pool).await?;
+
+    let mut recipes = query_own_ingredients(pool, user_id, filter, Some(&ids)).await?;
+    attach_sub_recipes(pool, user_id, &mut recipes).await?;
+    images::attach(pool, &mut recipes).await?;
+    recipes.sort_by_key(|r| ids.iter().position(|id| *id == r.id));
+    Ok(LISTING.keyset_page(recipes, &keyset, params))
+}
+
+/// Fetches the user's recipes that match a filter, with only their own ingredients. If IDs are
+/// given, only those recipes are fetched.
+async fn query_own_ingredients(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    filter: &RecipeFilter,
+    ids: Option<&[i32]>,
 ) -> Result<Vec<Recipe>, sqlx::Error> {
     let rows = sqlx::query!(
         "SELECT
 			r.id as recipe_id, 
 			r.name as recipe_name,
 			r.portions as recipe_portions, 
-			r.steps as recipe_steps,
+			r.steps as \"recipe_steps: types::Json<Vec<Step>>\",
+			r.prep_time,
+			r.cook_time,
+			r.total_time,
+			r.source_url,
+			r.notes,
+			r.yield_description,
+			r.difficulty,
+			r.version,
+			to_char(r.created_at, 'YYYY-MM-DD\"T\"HH24:MI:SS') as \"created_at!\",
 
-			i.id as ingredient_id,
+			i.id as \"ingredient_id?\",
             i.user_id,
-			i.name as ingredient_name,
+			i.name as \"ingredient_name?\",
 			i.unit as ingredient_unit,
-			i.minimum_quantity as ingredient_minimum_quantity,
-			i.purchase_quantity as ingredient_purchase_quantity,
-			i.life as ingredient_life,
+			i.minimum_quantity as \"ingredient_minimum_quantity?\",
+			i.purchase_quantity as \"ingredient_purchase_quantity?\",
+			i.life as \"ingredient_life?\",
+			i.family_id as ingredient_family_id,
+			i.category as ingredient_category,
+			COALESCE(p.price, i.price) as ingredient_price,
+			i.energy,
+			i.protein,
+			i.fat,
+			i.carbohydrate,
+			i.fibre,
+			i.salt,
+			i.allergens as \"allergens?\",
+			i.vegetarian as \"vegetarian?\",
+			i.vegan as \"vegan?\",
 
-			ri.quantity as ingredient_quantity
+			ri.quantity as \"ingredient_quantity?\"
 		FROM recipe as r
 			LEFT JOIN recipe_ingredient as ri 
-				ON r.id = ri.recipe_id
+				ON r.id = ri.recipe_id AND ri.ingredient_id IS NOT NULL
 			LEFT JOIN ingredient as i 
 				ON ri.ingredient_id = i.id
-		WHERE r.user_id = $1 AND ingredient_id IS NOT NULL
+			LEFT JOIN ingredient_price as p
+				ON p.ingredient_id = i.id AND p.user_id = r.user_id
+		WHERE r.user_id = $1
+			AND ($2::integer IS NULL OR r.total_time <= $2)
+			AND ($3::integer[] IS NULL OR r.id = ANY($3))
 		ORDER BY r.id DESC
 		",
-        user_id
+        user_id,
+        filter.max_time,
+        ids
     )
     .fetch_all(pool)
     .await?;
@@ -111,32 +627,65 @@ pub async fn query_multiple(
     let mut recipe_map = HashMap::<i32, Recipe>::new();
 
     for row in rows {
-        let recipe = Recipe {
-            id: row.recipe_id,
-            name: row.recipe_name,
-            portions: row.recipe_portions,
-            steps: row.recipe_steps,
-            ingredients: vec![],
-        };
+        let recipe = Recipe::new(
+            row.recipe_id,
+            row.recipe_name,
+            row.recipe_portions,
+            row.recipe_steps.0,
+            vec![],
+        )
+        .with_metadata(RecipeMetadata {
+            prep_time: row.prep_time,
+            cook_time: row.cook_time,
+            total_time: row.total_time,
+            source_url: row.source_url,
+            notes: row.notes,
+            yield_description: row.yield_description,
+            difficulty: Difficulty::parse(row.difficulty),
+        })
+        .with_version(row.version)
+        .with_created_at(row.created_at);
+
+        // Checks the hashmap for the current recipe ID, creating a new entry if one does not exist
+        let entry = recipe_map.entry(row.recipe_id).or_insert(recipe);
 
+        // A recipe made only of sub-recipes has a single row with no ingredient.
+        let Some(ingredient_id) = row.ingredient_id else {
+            continue;
+        };
         let ingredient = Ingredient::new(
-            row.ingredient_id,
-            row.ingredient_name,
+            ingredient_id,
+            row.ingredient_name.unwrap_or_default(),
             row.user_id,
             row.ingredient_unit,
-            row.ingredient_minimum_quantity,
-            row.ingredient_purchase_quantity,
-            row.ingredient_life,
+            row.ingredient_minimum_quantity.unwrap_or_default(),
+            row.ingredient_purchase_quantity.unwrap_or_default(),
+            row.ingredient_life.unwrap_or_default(),
+        )
+        .with_family(row.ingredient_family_id)
+        .with_category(row.ingredient_category)
+        .with_price(row.ingredient_price)
+        .with_nutrition(Nutrition::from_columns(
+            row.energy,
+            row.protein,
+            row.fat,
+            row.carbohydrate,
+            row.fibre,
+            row.salt,
+        ))
+        .with_diet(
+            Allergen::parse_all(&row.allergens.unwrap_or_default()),
+            row.vegetarian.unwrap_or_default(),
+            row.vegan.unwrap_or_default(),
         );
 
-        let ingredient_quantity = IngredientQuantity::new(ingredient, row.ingredient_quantity);
-
-        // Checks the hashmap for the current recipe ID, creating a new entry if one does not exist
-        let entry = recipe_map.entry(row.recipe_id).or_insert(recipe);
-        entry.ingredients.push(ingredient_quantity);
+        entry.add_ingredient(IngredientQuantity::new(
+            ingredient,
+            row.ingredient_quantity.unwrap_or_default(),
+        ));
     }
-    let mut recipes: Vec<Recipe> = recipe_map.values().into_iter().cloned().collect();
-    recipes.sort_by(|a, b| a.id.cmp(&b.id));
+    let mut recipes: Vec<Recipe> = recipe_map.values().cloned().collect();
+    recipes.sort_by_key(|a| a.id);
     // Collect the map values - we can safely discard the keys
     Ok(recipes)
 }
@@ -151,20 +700,43 @@ pub async fn query_single(
 			r.id, 
 			r.name,
 			r.portions, 
-			r.steps,
+			r.steps as \"steps: types::Json<Vec<Step>>\",
+			r.prep_time,
+			r.cook_time,
+			r.total_time,
+			r.source_url,
+			r.notes,
+			r.yield_description,
+			r.difficulty,
+			r.version,
+			to_char(r.created_at, 'YYYY-MM-DD\"T\"HH24:MI:SS') as \"created_at!\",
 
-			i.id as ingredient_id,
-			i.name as ingredient_name,
+			i.id as \"ingredient_id?\",
+			i.name as \"ingredient_name?\",
             i.user_id,
 			i.unit as ingredient_unit,
-			i.minimum_quantity as ingredient_minimum_quantity,
-			i.purchase_quantity as ingredient_purchase_quantity,
-			i.life as ingredient_life,
-			ri.quantity as ingredient_quantity
+			i.minimum_quantity as \"ingredient_minimum_quantity?\",
+			i.purchase_quantity as \"ingredient_purchase_quantity?\",
+			i.life as \"ingredient_life?\",
+			i.family_id as ingredient_family_id,
+			i.category as ingredient_category,
+			COALESCE(p.price, i.price) as ingredient_price,
+			i.energy,
+			i.protein,
+			i.fat,
+			i.carbohydrate,
+			i.fibre,
+			i.salt,
+			i.allergens as \"allergens?\",
+			i.vegetarian as \"vegetarian?\",
+			i.vegan as \"vegan?\",
+			ri.quantity as \"ingredient_quantity?\"
 		FROM recipe as r
-			LEFT JOIN recipe_ingredient as ri ON r.id = ri.recipe_id
+			LEFT JOIN recipe_ingredient as ri
+				ON r.id = ri.recipe_id AND ri.ingredient_id IS NOT NULL
 			LEFT JOIN ingredient as i ON ri.ingredient_id = i.id
-		WHERE r.user_id = $1 AND r.id = $2 AND ingredient_id IS NOT NULL
+			LEFT JOIN ingredient_price as p ON p.ingredient_id = i.id AND p.user_id = r.user_id
+		WHERE r.user_id = $1 AND r.id = $2
 		",
         user_id,
         recipe_id
@@ -173,39 +745,217 @@ pub async fn query_single(
     .await?;
 
     // If a row is present, the recipe exists. If not, return None
-    match rows.get(0) {
+    match rows.first() {
         Some(first_row) => {
-            let mut recipe = Recipe {
-                id: first_row.id,
-                name: first_row.name.clone(),
-                portions: first_row.portions,
-                steps: first_row.steps.clone(),
-                ingredients: vec![],
-            };
+            let mut recipe = Recipe::new(
+                first_row.id,
+                first_row.name.clone(),
+                first_row.portions,
+                first_row.steps.0.clone(),
+                vec![],
+            )
+            .with_metadata(RecipeMetadata {
+                prep_time: first_row.prep_time,
+                cook_time: first_row.cook_time,
+                total_time: first_row.total_time,
+                source_url: first_row.source_url.clone(),
+                notes: first_row.notes.clone(),
+                yield_description: first_row.yield_description.clone(),
+                difficulty: Difficulty::parse(first_row.difficulty.clone()),
+            })
+            .with_version(first_row.version)
+            .with_created_at(first_row.created_at.clone());
 
+            // A recipe made only of sub-recipes has a single row with no ingredient.
             for row in rows {
+                let Some(ingredient_id) = row.ingredient_id else {
+                    continue;
+                };
                 let ingredient = Ingredient::new(
-                    row.ingredient_id,
-                    row.ingredient_name,
+                    ingredient_id,
+                    row.ingredient_name.unwrap_or_default(),
                     row.user_id,
                     row.ingredient_unit,
-                    row.ingredient_minimum_quantity,
-                    row.ingredient_purchase_quantity,
-                    row.ingredient_life,
+                    row.ingredient_minimum_quantity.unwrap_or_default(),
+                    row.ingredient_purchase_quantity.unwrap_or_default(),
+                    row.ingredient_life.unwrap_or_default(),
+                )
+                .with_family(row.ingredient_family_id)
+                .with_category(row.ingredient_category)
+                .with_price(row.ingredient_price)
+                .with_nutrition(Nutrition::from_columns(
+                    row.energy,
+                    row.protein,
+                    row.fat,
+                    row.carbohydrate,
+                    row.fibre,
+                    row.salt,
+                ))
+                .with_diet(
+                    Allergen::parse_all(&row.allergens.unwrap_or_default()),
+                    row.vegetarian.unwrap_or_default(),
+                    row.vegan.unwrap_or_default(),
                 );
 
-                let ingredient_quantity =
-                    IngredientQuantity::new(ingredient, row.ingredient_quantity);
-
-                // Checks the hashmap for the current recipe ID, creating a new entry if one does not exist
-                recipe.ingredients.push(ingredient_quantity);
+                recipe.add_ingredient(IngredientQuantity::new(
+                    ingredient,
+                    row.ingredient_quantity.unwrap_or_default(),
+                ));
             }
+            attach_sub_recipes(pool, user_id, [&mut recipe]).await?;
+            images::attach(pool, [&mut recipe]).await?;
             Ok(recipe)
         }
         None => Err(sqlx::Error::RowNotFound),
     }
 }
 
+/// Fills in the sub-recipes of each recipe, expanding them recursively. A sub-recipe that would
+/// lead back to a recipe already being expanded is left out, so cycles can't recurse forever.
+pub async fn attach_sub_recipes<'a>(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    recipes: impl IntoIterator<Item = &'a mut Recipe>,
+) -> Result<(), sqlx::Error> {
+    let recipes: Vec<&mut Recipe> = recipes.into_iter().collect();
+    let recipe_ids: Vec<i32> = recipes.iter().map(|r| r.id).collect();
+
+    // Only the sub-recipes reachable from the recipes given are needed
+    let links = sqlx::query!(
+        "WITH RECURSIVE reachable(id) AS (
+			SELECT UNNEST($2::integer[])
+			UNION
+			SELECT ri.sub_recipe_id FROM recipe_ingredient AS ri
+				INNER JOIN reachable ON ri.recipe_id = reachable.id
+			WHERE ri.sub_recipe_id IS NOT NULL
+		)
+		SELECT ri.recipe_id, ri.sub_recipe_id as \"sub_recipe_id!\", ri.quantity
+		FROM recipe_ingredient AS ri
+			INNER JOIN reachable ON ri.recipe_id = reachable.id
+			INNER JOIN recipe AS r ON ri.recipe_id = r.id
+		WHERE r.user_id = $1 AND ri.sub_recipe_id IS NOT NULL
+		ORDER BY ri.sub_recipe_id",
+        user_id,
+        &recipe_ids
+    )
+    .fetch_all(pool)
+    .await?;
+    if links.is_empty() {
+        return Ok(());
+    }
+
+    let mut sub_recipes = HashMap::<i32, Vec<(i32, i32)>>::new();
+    for link in &links {
+        sub_recipes
+            .entry(link.recipe_id)
+            .or_default()
+            .push((link.sub_recipe_id, link.quantity));
+    }
+    let library_ids: Vec<i32> = links.iter().map(|link| link.sub_recipe_id).collect();
+    let library: HashMap<i32, Recipe> =
+        query_own_ingredients(pool, user_id, &RecipeFilter::default(), Some(&library_ids))
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r))
+            .collect();
+
+    for recipe in recipes {
+        let mut path = vec![recipe.id];
+        recipe.sub_recipes = expand_sub_recipes(recipe.id, &library, &sub_recipes, &mut path);
+        recipe.summarise();
+    }
+    Ok(())
+}
+
+/// Builds the sub-recipes of a recipe from the user's recipes and the links between them.
+/// `path` holds the recipes being expanded, from the outermost in.
+fn expand_sub_recipes(
+    recipe_id: i32,
+    library: &HashMap<i32, Recipe>,
+    links: &HashMap<i32, Vec<(i32, i32)>>,
+    path: &mut Vec<i32>,
+) -> Vec<SubRecipe> {
+    let mut expanded = vec![];
+    for (sub_recipe_id, portions) in links.get(&recipe_id).into_iter().flatten() {
+        let Some(sub_recipe) = library.get(sub_recipe_id) else {
+            continue;
+        };
+        if path.contains(sub_recipe_id) {
+            continue;
+        }
+
+        let mut sub_recipe = sub_recipe.clone();
+        path.push(sub_recipe.id);
+        sub_recipe.sub_recipes = expand_sub_recipes(sub_recipe.id, library, links, path);
+        path.pop();
+        sub_recipe.summarise();
+
+        expanded.push(SubRecipe {
+            recipe_id: sub_recipe.id,
+            name: sub_recipe.name.clone(),
+            portions: *portions,
+            recipe: Box::new(sub_recipe),
+        });
+    }
+    expanded
+}
+
+/// Checks that a recipe's sub-recipes belong to the user, and that none of them include the
+/// recipe itself, directly or through their own sub-recipes. `recipe_id` is `None` for a recipe
+/// that hasn't been created yet, which nothing can include.
+pub async fn check_sub_recipes(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    recipe_id: Option<i32>,
+    recipe: &RecipeInput,
+) -> Result<Result<(), InvalidRecipe>, sqlx::Error> {
+    let mut sub_recipe_ids: Vec<i32> = recipe.sub_recipes.iter().map(|s| s.recipe_id).collect();
+    sub_recipe_ids.sort();
+    sub_recipe_ids.dedup();
+    if sub_recipe_ids.is_empty() {
+        return Ok(Ok(()));
+    }
+
+    let owned = sqlx::query!(
+        "SELECT COUNT(*) as \"count!\" FROM recipe WHERE user_id = $1 AND id = ANY($2)",
+        user_id,
+        &sub_recipe_ids
+    )
+    .fetch_one(pool)
+    .await?;
+    if owned.count != sub_recipe_ids.len() as i64 {
+        return Ok(Err(InvalidRecipe(
+            "Sub-recipes must be one of your own recipes.".into(),
+        )));
+    }
+
+    let Some(recipe_id) = recipe_id else {
+        return Ok(Ok(()));
+    };
+    // Follow sub-recipes down from the new ones, looking for the recipe being saved.
+    let cycle = sqlx::query!(
+        "WITH RECURSIVE reachable(id) AS (
+			SELECT UNNEST($2::integer[])
+			UNION
+			SELECT ri.sub_recipe_id
+			FROM recipe_ingredient AS ri
+				INNER JOIN reachable ON ri.recipe_id = reachable.id
+			WHERE ri.sub_recipe_id IS NOT NULL
+		)
+		SELECT EXISTS (SELECT 1 FROM reachable WHERE id = $1) as \"cycle!\"",
+        recipe_id,
+        &sub_recipe_ids
+    )
+    .fetch_one(pool)
+    .await?;
+    if cycle.cycle {
+        return Ok(Err(InvalidRecipe(
+            "A recipe can't include itself, directly or through its sub-recipes.".into(),
+        )));
+    }
+    Ok(Ok(()))
+}
+
 /// Creates a recipe in the database. Returns it's generated ID
 pub async fn create(
     pool: &sqlx::Pool<sqlx::Postgres>,
@@ -225,42 +975,79 @@ pub async fn create(
         .map(RecipeIngredientInput::get_quantity)
         .collect();
 
+    let mut tx = pool.begin().await?;
+
     // A slightly complex query:
     // 1. Insert a row into the recipe table, returning its new ID
     // 2. Insert all ingredient and quantity IDs into the recipe_ingredient table,
     //    using the postgres UNNEST function. Much quicker than iterating over ingredients.
+    // 3. Return the new ID, which is there even if the recipe is made only of sub-recipes.
     let insertion = sqlx::query!(
         "WITH recipe AS (
-			INSERT INTO recipe (user_id, name, portions, steps)
-			VALUES ($1, $2, $3, $4)
+			INSERT INTO recipe (
+				user_id, name, portions, steps, prep_time, cook_time, total_time,
+				source_url, notes, yield_description, difficulty
+			)
+			VALUES ($1, $2, $3, $4, $7, $8, $9, $10, $11, $12, $13)
 			RETURNING id
+		), ingredients AS (
+			INSERT INTO recipe_ingredient (recipe_id, ingredient_id, quantity)
+				SELECT recipe.id, ingredient.id, ingredient.quantity 
+				FROM recipe, UNNEST($5::integer[], $6::integer[]) as ingredient(id, quantity)
 		)
-		INSERT INTO recipe_ingredient (recipe_id, ingredient_id, quantity)
-			SELECT recipe.id, ingredient.id, ingredient.quantity 
-			FROM recipe, UNNEST($5::integer[], $6::integer[]) as ingredient(id, quantity)
-		RETURNING recipe_id
+		SELECT id as recipe_id FROM recipe
 	",
         user_id,
         recipe.name,
         recipe.portions,
-        recipe.steps,
+        types::Json(&recipe.steps) as _,
         &ingredient_ids,
-        &ingredient_quantities
+        &ingredient_quantities,
+        recipe.metadata.prep_time,
+        recipe.metadata.cook_time,
+        recipe.metadata.total_time(),
+        recipe.metadata.source_url,
+        recipe.metadata.notes,
+        recipe.metadata.yield_description,
+        recipe.metadata.difficulty.map(|d| d.as_str())
     )
-    .fetch_one(pool)
+    .fetch_one(&mut *tx)
     .await?;
 
+    insert_sub_recipes(&mut tx, insertion.recipe_id, &recipe.sub_recipes).await?;
+    tx.commit().await?;
+
     // Return the ID of the created recipe
     Ok(insertion.recipe_id)
 }
 
-/// Creates a recipe in the database. Returns it's generated ID
+/// Returned when an update was made to an older version of a recipe than the one stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleRecipe {
+    pub version: i32,
+    pub current_version: i32,
+}
+
+impl fmt::Display for StaleRecipe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The recipe has been changed since version {}, and is now at version {}.",
+            self.version, self.current_version
+        )
+    }
+}
+
+impl Error for StaleRecipe {}
+
+/// Updates a recipe, moving it on to its next version. If the update gives the version it was
+/// made to and that is no longer the latest, nothing is changed.
 pub async fn update(
     pool: &sqlx::Pool<sqlx::Postgres>,
     user_id: i32,
     recipe_id: i32,
     recipe: RecipeInput,
-) -> Result<(), Box<dyn Error>> {
+) -> Result<Result<(), StaleRecipe>, Box<dyn Error>> {
     // Extract vectors of ingredient ID and quantity from the recipe struct
     let ingredient_ids: Vec<i32> = recipe
         .ingredients
@@ -277,22 +1064,65 @@ pub async fn update(
     let mut tx = pool.begin().await?;
 
     // Update the recipes table
-    sqlx::query!(
+    let updated = sqlx::query!(
         "
 		UPDATE recipe SET
 			name = $2,
 			portions = $3,
-			steps = $4
-		WHERE id = $1 AND user_id = $5
-		
+			steps = $4,
+			prep_time = $6,
+			cook_time = $7,
+			total_time = $8,
+			source_url = $9,
+			notes = $10,
+			yield_description = $11,
+			difficulty = $12,
+			version = version + 1
+		WHERE id = $1 AND user_id = $5 AND ($13::integer IS NULL OR version = $13)
+		RETURNING id
 	",
         recipe_id,
         recipe.name,
         recipe.portions,
-        recipe.steps,
-        user_id
+        types::Json(&recipe.steps) as _,
+        user_id,
+        recipe.metadata.prep_time,
+        recipe.metadata.cook_time,
+        recipe.metadata.total_time(),
+        recipe.metadata.source_url,
+        recipe.metadata.notes,
+        recipe.metadata.yield_description,
+        recipe.metadata.difficulty.map(|d| d.as_str()),
+        recipe.version
     )
-    .execute(&mut **(&mut tx))
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    if updated.is_none() {
+        let current_version = sqlx::query_scalar!(
+            "SELECT version FROM recipe WHERE id = $1 AND user_id = $2",
+            recipe_id,
+            user_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+        // Updating a recipe that doesn't exist does nothing.
+        return Ok(match (recipe.version, current_version) {
+            (Some(version), Some(current_version)) => Err(StaleRecipe {
+                version,
+                current_version,
+            }),
+            _ => Ok(()),
+        });
+    }
+
+    // Photos of steps that no longer exist become photos of the whole recipe
+    sqlx::query!(
+        "UPDATE recipe_image SET step_index = NULL WHERE recipe_id = $1 AND step_index >= $2",
+        recipe_id,
+        recipe.steps.len() as i32
+    )
+    .execute(&mut *tx)
     .await?;
 
     // Delete existing ingredients
@@ -300,7 +1130,7 @@ pub async fn update(
         "DELETE FROM recipe_ingredient WHERE recipe_id = $1	",
         recipe_id,
     )
-    .execute(&mut **(&mut tx))
+    .execute(&mut *tx)
     .await?;
 
     // Insert new ingredient list
@@ -312,11 +1142,35 @@ pub async fn update(
         &ingredient_ids,
         &ingredient_quantities
     )
-    .execute(&mut **(&mut tx))
+    .execute(&mut *tx)
     .await?;
 
+    insert_sub_recipes(&mut tx, recipe_id, &recipe.sub_recipes).await?;
     tx.commit().await?;
 
+    Ok(Ok(()))
+}
+
+/// Adds sub-recipe lines to a recipe, as part of a transaction that creates or updates it.
+async fn insert_sub_recipes(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    recipe_id: i32,
+    sub_recipes: &[SubRecipeInput],
+) -> Result<(), sqlx::Error> {
+    let sub_recipe_ids: Vec<i32> = sub_recipes.iter().map(|s| s.recipe_id).collect();
+    let portions: Vec<i32> = sub_recipes.iter().map(|s| s.portions).collect();
+
+    sqlx::query!(
+        "INSERT INTO recipe_ingredient (recipe_id, sub_recipe_id, quantity)
+			SELECT $1, sub_recipe.id, sub_recipe.portions
+			FROM UNNEST($2::integer[], $3::integer[]) as sub_recipe(id, portions)",
+        recipe_id,
+        &sub_recipe_ids,
+        &portions
+    )
+    .execute(&mut **tx)
+    .await?;
+
     Ok(())
 }
 
@@ -0,0 +1,216 @@
+use serde::Serialize;
+use std::{collections::HashSet, error::Error, fmt};
+use time::OffsetDateTime;
+
+use super::{
+    day::{self, Day},
+    ingredient::{self, Ingredient, ScheduledIngredient},
+    recipe::{self, Recipe},
+};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Everything of a user's that has changed since they last synced.
+#[derive(Serialize, Debug, ToSchema, TS)]
+pub struct SyncChanges {
+    /// Pass this as `since` on the next sync to fetch only what changes after this one.
+    pub token: String,
+    pub recipes: Changes<Recipe, i32>,
+    pub ingredients: Changes<Ingredient, i32>,
+    /// Days are identified by their date.
+    pub days: Changes<Day, String>,
+    pub schedule: Changes<ScheduledIngredient, i32>,
+}
+
+/// Items of one kind that have been created or updated, and the keys of any that were deleted.
+#[derive(Serialize, Debug, ToSchema, TS)]
+pub struct Changes<T, K> {
+    pub updated: Vec<T>,
+    pub deleted: Vec<K>,
+}
+
+/// Returned when a sync token wasn't given out by the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidToken;
+
+impl fmt::Display for InvalidToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The sync token is invalid. Sync without one to fetch everything."
+        )
+    }
+}
+
+impl Error for InvalidToken {}
+
+/// Reads the time a sync token was given out at. Tokens are microseconds since the Unix epoch.
+pub fn parse_token(token: &str) -> Result<OffsetDateTime, InvalidToken> {
+    token
+        .parse::<i128>()
+        .ok()
+        .and_then(|micros| OffsetDateTime::from_unix_timestamp_nanos(micros * 1000).ok())
+        .ok_or(InvalidToken)
+}
+
+fn token(time: OffsetDateTime) -> String {
+    (time.unix_timestamp_nanos() / 1000).to_string()
+}
+
+/// Fetches the recipes, ingredients, days and scheduled ingredients that have changed since a
+/// sync token was given out, or all of them if there is no token. Changes made while the token
+/// was given out may be sent again on the next sync.
+///
+/// Recipes are only marked as changed by edits to themselves, not by price changes to the
+/// ingredients they use, so clients should recalculate costs from synced ingredients.
+pub async fn changes(
+    pool: &sqlx::Pool<sqlx::Postgres>,
+    user_id: i32,
+    since: Option<OffsetDateTime>,
+) -> Result<SyncChanges, Box<dyn Error>> {
+    // Rows are stamped with the start of the transaction that wrote them, which may commit after
+    // this sync has read. The token is taken from the oldest transaction still open, before any
+    // changes are read, so that the next sync picks up whatever those transactions write.
+    let now = sqlx::query_scalar!(
+        "SELECT LEAST(now(), (
+			SELECT min(xact_start) FROM pg_stat_activity WHERE datname = current_database()
+		)) as \"now!\""
+    )
+    .fetch_one(pool)
+    .await?;
+    let full_sync = since.is_none();
+    let since = since.unwrap_or(OffsetDateTime::UNIX_EPOCH);
+
+    // A full sync only needs what exists now.
+    let tombstones = match full_sync {
+        true => vec![],
+        false => {
+            sqlx::query!(
+                "SELECT entity, entity_key FROM tombstone
+				WHERE user_id = $1 AND deleted_at >= $2",
+                user_id,
+                since
+            )
+            .fetch_all(pool)
+            .await?
+        }
+    };
+    let deleted = |entity: &str| -> HashSet<String> {
+        tombstones
+            .iter()
+            .filter(|t| t.entity == entity)
+            .map(|t| t.entity_key.clone())
+            .collect()
+    };
+    let deleted_ids = |entity: &str| -> HashSet<i32> {
+        deleted(entity)
+            .iter()
+            .filter_map(|k| k.parse().ok())
+            .collect()
+    };
+
+    let recipe_ids: HashSet<i32> = sqlx::query_scalar!(
+        "SELECT id FROM recipe WHERE user_id = $1 AND updated_at >= $2",
+        user_id,
+        since
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+    let recipes = recipe::query_multiple(pool, user_id)
+        .await?
+        .into_iter()
+        .filter(|r| recipe_ids.contains(&r.id))
+        .collect();
+
+    // Clearing a user's own price changes the ingredient back to its retail price.
+    let mut ingredient_ids: HashSet<i32> = sqlx::query_scalar!(
+        "SELECT i.id FROM ingredient AS i
+			LEFT JOIN ingredient_price AS p
+			ON p.ingredient_id = i.id AND p.user_id = $1
+		WHERE (i.user_id IS NULL OR i.user_id = $1)
+			AND (i.updated_at >= $2 OR p.updated_at >= $2)",
+        user_id,
+        since
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+    ingredient_ids.extend(deleted_ids("ingredient_price"));
+    let ingredients: Vec<Ingredient> = ingredient::query_multiple(pool, user_id)
+        .await?
+        .into_iter()
+        .filter(|i| ingredient_ids.contains(&i.id))
+        .collect();
+
+    let mut dates: HashSet<String> = sqlx::query_scalar!(
+        "SELECT DISTINCT d.date::text as \"date!\" FROM day AS d
+			INNER JOIN recipe AS r
+			ON r.id = d.recipe_id
+		WHERE r.user_id = $1 AND d.updated_at >= $2",
+        user_id,
+        since
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+    dates.extend(deleted("day"));
+    let days: Vec<Day> = match (dates.iter().min(), dates.iter().max()) {
+        (Some(from), Some(to)) => day::query_range(pool, user_id, from, to)
+            .await?
+            .into_iter()
+            .filter(|d| dates.contains(&d.date))
+            .collect(),
+        _ => vec![],
+    };
+    // A day with nothing left planned on it has been deleted.
+    let mut deleted_days: Vec<String> = dates
+        .into_iter()
+        .filter(|date| !days.iter().any(|d| &d.date == date))
+        .collect();
+    deleted_days.sort();
+
+    let schedule_ids: HashSet<i32> = sqlx::query_scalar!(
+        "SELECT id FROM ingredientschedule WHERE user_id = $1 AND updated_at >= $2",
+        user_id,
+        since
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+    let schedule = ingredient::query_scheduled(pool, user_id)
+        .await?
+        .into_iter()
+        .filter(|s| schedule_ids.contains(&s.id))
+        .collect();
+
+    Ok(SyncChanges {
+        token: token(now),
+        recipes: Changes {
+            updated: recipes,
+            deleted: sorted(deleted_ids("recipe")),
+        },
+        ingredients: Changes {
+            updated: ingredients,
+            deleted: sorted(deleted_ids("ingredient")),
+        },
+        days: Changes {
+            updated: days,
+            deleted: deleted_days,
+        },
+        schedule: Changes {
+            updated: schedule,
+            deleted: sorted(deleted_ids("schedule")),
+        },
+    })
+}
+
+fn sorted(ids: HashSet<i32>) -> Vec<i32> {
+    let mut ids: Vec<i32> = ids.into_iter().collect();
+    ids.sort();
+    ids
+}
@@ -2,22 +2,34 @@ use std::error::Error;
 
 use time::{
     error::Parse, format_description::FormatItem, macros::format_description, Date, Duration,
+    OffsetDateTime,
 };
 const DATE_FORMAT: &[FormatItem<'_>] = format_description!("[year]-[month]-[day]");
 
 /// Parses a string date of the form 'YYYY-MM-DD'.
-pub fn parse_date(date: &String) -> Result<time::Date, Parse> {
+pub fn parse_date(date: &str) -> Result<time::Date, Parse> {
     Date::parse(date, &DATE_FORMAT)
 }
 
 /// Parses a date from a string, subtracts `days` days from it, and re-formats it into a string.
-pub fn sub_days(date: &String, days: i32) -> Result<String, Box<dyn Error>> {
+pub fn sub_days(date: &str, days: i32) -> Result<String, Box<dyn Error>> {
     let date_obj = parse_date(date)? - Duration::days(days as i64);
     Ok(date_obj.format(&DATE_FORMAT)?)
 }
 
+/// Parses a date from a string, adds `days` days to it, and re-formats it into a string.
+pub fn add_days(date: &str, days: i32) -> Result<String, Box<dyn Error>> {
+    let date_obj = parse_date(date)? + Duration::days(days as i64);
+    Ok(date_obj.format(&DATE_FORMAT)?)
+}
+
+/// Returns the current UTC date as a string.
+pub fn today() -> Result<String, Box<dyn Error>> {
+    Ok(OffsetDateTime::now_utc().date().format(&DATE_FORMAT)?)
+}
+
 /// Parses dates from the two inputs, and returns the difference in days between `date_a` and `date_b`.
-pub fn day_diff(date_a: &String, date_b: &String) -> Result<i32, Box<dyn Error>> {
+pub fn day_diff(date_a: &str, date_b: &str) -> Result<i32, Box<dyn Error>> {
     let date_a_obj = parse_date(date_a)?;
     let date_b_obj = parse_date(date_b)?;
 
@@ -53,6 +65,14 @@ mod tests {
         assert_eq!(expected, date);
     }
 
+    #[test]
+    fn test_add_days() {
+        let date_string = String::from("2023-11-25");
+        let date = add_days(&date_string, 6).unwrap();
+        let expected = String::from("2023-12-01");
+        assert_eq!(expected, date);
+    }
+
     #[test]
     fn test_day_diff() {
         let date_a_string = String::from("2023-11-25");
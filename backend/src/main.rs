@@ -1,8 +1,8 @@
 use axum::{routing::get, Router};
-use std::{env, error::Error, process::exit};
+use std::{env, error::Error, process::exit, time::Duration};
 use tracing::{event, Level};
 
-use crate::api::db::Db;
+use crate::api::{alert, db::Db, notify, storage};
 
 mod api;
 mod router;
@@ -67,8 +67,28 @@ async fn start_server() -> Result<(), Box<dyn Error>> {
         }
     }
 
+    // Choose where uploaded images are kept.
+    let storage = storage::from_env()?;
+
+    // Send digests of expiring ingredients, if a notifier is configured.
+    if let Some(notifier) = notify::from_env()? {
+        let hours: u64 = env::var("DIGEST_INTERVAL_HOURS")
+            .unwrap_or("24".into())
+            .parse()?;
+        let within: i32 = env::var("DIGEST_WITHIN_DAYS")
+            .unwrap_or("3".into())
+            .parse()?;
+        tokio::spawn(alert::schedule_digests(
+            db.pool.clone(),
+            notifier,
+            Duration::from_secs(hours * 60 * 60),
+            within,
+        ));
+        event!(Level::INFO, "sending expiry digests every {} hours", hours);
+    }
+
     // Create routes. All requests are routed through the authorise() middleware
-    let router = router::make_router(db, idp_url, idp_enabled).await?;
+    let router = router::make_router(db, storage, idp_url, idp_enabled).await?;
 
     // Start the backend
     let server = axum::Server::bind(&format!("0.0.0.0:{PORT}").parse().unwrap())
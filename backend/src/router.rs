@@ -1,10 +1,12 @@
-use std::{collections::HashMap, error::Error, str::FromStr};
+use std::{collections::HashMap, error::Error, str::FromStr, sync::Arc};
 
 use axum::{
-    extract::{Path, Query, State, TypedHeader},
+    body::{self, Body, Bytes, HttpBody},
+    extract::{DefaultBodyLimit, Multipart, OriginalUri, Path, Query, State, TypedHeader},
     headers::authorization,
-    http::{Request, StatusCode},
-    middleware, response,
+    http::{header, HeaderName, HeaderValue, Method, Request, StatusCode, Uri},
+    middleware,
+    response::{self, IntoResponse},
     routing::{delete, get, post, put},
     Extension, Json, Router,
 };
@@ -14,16 +16,40 @@ use jsonwebtoken::{
     Algorithm, DecodingKey, Validation,
 };
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
 use sqlx::query_as;
 use tracing::{event, Level};
+use utoipa::{
+    openapi::security::{Http, HttpAuthScheme, SecurityScheme},
+    IntoParams, Modify, OpenApi, ToSchema,
+};
 
 use crate::api::{
-    day::{self, Day, DayInput, ShoppingList},
+    alert::{self, ExpiringIngredient},
+    calendar::{self, CalendarToken},
+    day::{
+        self, BatchResult, CookableRecipe, Day, DayInput, MealRecordInput, PlanOperation,
+        RecipeWaste, ShoppingList,
+    },
     db::Db,
+    diet::{self, DietProfile},
+    export::{self, ListFormat},
+    idempotency::{self, Claim, KeyedRequest, StoredResponse},
+    images::{self, InvalidImage, PreparedImage, RecipeImage, MAX_IMAGE_SIZE},
     ingredient::{
-        self, Ingredient, IngredientInput, ScheduledIngredient, ScheduledIngredientInput,
+        self, Ingredient, IngredientFamily, IngredientFamilyInput, IngredientInput, PriceInput,
+        ScheduledIngredient, ScheduledIngredientInput,
     },
-    recipe::{self, Recipe, RecipeInput},
+    page::{self, Page, PageParams},
+    plan::{
+        self, ApplyInput, CopyInput, PlanDraft, PlanInput, PlanTemplate, TemplateInput,
+        TemplateRename,
+    },
+    purchase::{self, Purchase, ShoppingTrip},
+    recipe::{self, Recipe, RecipeFilter, RecipeInput},
+    storage::ImageStorage,
+    sync::{self, SyncChanges},
+    utils::{parse_date, today},
 };
 
 type ServerError = (StatusCode, String);
@@ -40,7 +66,7 @@ struct AuthState {
 
 /// Verify a JWT against the current IDP's keystore. Returns the request sub if validation suceeds.
 async fn verify_token(jwks: JwkSet, token: &str) -> Result<String, Box<dyn Error>> {
-    let header = decode_header(&token)?;
+    let header = decode_header(token)?;
 
     let kid = header.kid.ok_or("KID missing")?;
 
@@ -75,6 +101,213 @@ async fn verify_token(jwks: JwkSet, token: &str) -> Result<String, Box<dyn Error
     Err("Verification failed.".into())
 }
 
+/// The headers a 304 Not Modified must repeat from the response it stands in for.
+const NOT_MODIFIED_HEADERS: [HeaderName; 5] = [
+    header::CACHE_CONTROL,
+    header::CONTENT_LOCATION,
+    header::DATE,
+    header::EXPIRES,
+    header::VARY,
+];
+
+/// Tags successful GET responses with a hash of their body. If the client already holds a
+/// response with the same tag, it is told so with 304 Not Modified instead of sent it again.
+/// Handlers that already know what version they are sending, such as images named by their
+/// key, set their own tag, and other responses that aren't JSON are passed through untagged
+/// rather than read into memory.
+async fn etag<B>(request: Request<B>, next: middleware::Next<B>) -> response::Response {
+    if request.method() != Method::GET {
+        return next.run(request).await;
+    }
+    let if_none_match = request.headers().get(header::IF_NONE_MATCH).cloned();
+    let response = next.run(request).await;
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let is_json = parts
+        .headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|t| t.to_str().ok())
+        .is_some_and(|t| t.starts_with("application/json"));
+    let (tag_value, body) = match parts.headers.get(header::ETAG).cloned() {
+        Some(tag_value) => (tag_value, body),
+        None if !is_json => return response::Response::from_parts(parts, body),
+        None => {
+            let bytes = match collect_body(body, usize::MAX).await {
+                Ok(bytes) => bytes,
+                Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+            };
+            let tag = format!("\"{}\"", &hex::encode(Sha256::digest(&bytes))[..32]);
+            let tag_value = HeaderValue::from_str(&tag).expect("tags are plain hex");
+            (tag_value, body::boxed(body::Full::from(bytes)))
+        }
+    };
+
+    // Weak comparison is used, as only the body matters.
+    let tag = tag_value.to_str().unwrap_or_default();
+    let matches = if_none_match.is_some_and(|header| {
+        header.to_str().unwrap_or_default().split(',').any(|t| {
+            let t = t.trim();
+            t == "*" || t.trim_start_matches("W/") == tag.trim_start_matches("W/")
+        })
+    });
+    if matches {
+        let mut response = StatusCode::NOT_MODIFIED.into_response();
+        for name in NOT_MODIFIED_HEADERS {
+            if let Some(value) = parts.headers.get(&name) {
+                response.headers_mut().insert(name, value.clone());
+            }
+        }
+        response.headers_mut().insert(header::ETAG, tag_value);
+        return response;
+    }
+
+    parts.headers.insert(header::ETAG, tag_value);
+    response::Response::from_parts(parts, body)
+}
+
+/// Reads a whole body into memory, failing if it is longer than `limit` bytes.
+async fn collect_body<B>(mut body: B, limit: usize) -> Result<Vec<u8>, String>
+where
+    B: HttpBody<Data = Bytes> + Unpin,
+    B::Error: std::fmt::Display,
+{
+    let mut bytes = vec![];
+    while let Some(chunk) = body.data().await {
+        bytes.extend_from_slice(&chunk.map_err(|e| e.to_string())?);
+        if bytes.len() > limit {
+            return Err(format!("The body is longer than {} bytes.", limit));
+        }
+    }
+    Ok(bytes)
+}
+
+/// The header clients send a unique key for each write in, which is reused when it is retried.
+const IDEMPOTENCY_KEY: &str = "idempotency-key";
+
+/// The largest request body that can be sent with an idempotency key, as it has to be held in
+/// memory to be hashed. This leaves room for image uploads.
+const MAX_KEYED_BODY_SIZE: usize = MAX_IMAGE_SIZE + 64 * 1024;
+
+/// Makes writes safe to retry. The response to a POST, PUT or DELETE sent with an
+/// `Idempotency-Key` header is stored, and a retry with the same key gets that response back
+/// without the write being made again. Server errors aren't stored, so failed writes can be
+/// retried, and keys held by requests that were dropped before finishing are released once their
+/// lease runs out.
+async fn idempotent(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    request: Request<Body>,
+    next: middleware::Next<Body>,
+) -> response::Response {
+    let writes = matches!(
+        *request.method(),
+        Method::POST | Method::PUT | Method::DELETE
+    );
+    let key = request
+        .headers()
+        .get(IDEMPOTENCY_KEY)
+        .and_then(|k| k.to_str().ok())
+        .map(String::from);
+    let (true, Some(key)) = (writes, key) else {
+        return next.run(request).await;
+    };
+    if key.is_empty() || key.len() > 255 {
+        return (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Idempotency keys must be between 1 and 255 characters long.",
+        )
+            .into_response();
+    }
+
+    let (parts, body) = request.into_parts();
+    let bytes = match collect_body(body, MAX_KEYED_BODY_SIZE).await {
+        Ok(bytes) => bytes,
+        Err(e) => return (StatusCode::PAYLOAD_TOO_LARGE, e).into_response(),
+    };
+    let request_hash = hex::encode(Sha256::digest(&bytes));
+    let path = parts.uri.path_and_query().map_or("/", |p| p.as_str());
+    let keyed_request = KeyedRequest {
+        key: &key,
+        method: parts.method.as_str(),
+        path,
+        request_hash: &request_hash,
+    };
+
+    let result = idempotency::claim(&db.pool, user_id, &keyed_request).await;
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+    match result {
+        Ok(Claim::New) => {}
+        Ok(Claim::InProgress) => {
+            return (
+                StatusCode::CONFLICT,
+                "A request with this idempotency key is still being handled.",
+            )
+                .into_response()
+        }
+        Ok(Claim::Mismatch) => {
+            return (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "This idempotency key has already been used for a different request.",
+            )
+                .into_response()
+        }
+        Ok(Claim::Replay(stored)) => return replay(stored),
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+
+    let response = next
+        .run(Request::from_parts(parts, Body::from(bytes)))
+        .await;
+    if response.status().is_server_error() {
+        if let Err(e) = idempotency::release(&db.pool, user_id, &key).await {
+            event!(Level::ERROR, "{:?}", e);
+        }
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let stored = match collect_body(body, usize::MAX).await {
+        Ok(body) => StoredResponse {
+            status: parts.status.as_u16(),
+            content_type: parts
+                .headers
+                .get(header::CONTENT_TYPE)
+                .and_then(|c| c.to_str().ok())
+                .map(String::from),
+            body,
+        },
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+    };
+    if let Err(e) = idempotency::complete(&db.pool, user_id, &key, &stored).await {
+        event!(Level::ERROR, "{:?}", e);
+    }
+    response::Response::from_parts(parts, body::boxed(body::Full::from(stored.body)))
+}
+
+/// Sends a stored response again, marked as a replay.
+fn replay(stored: StoredResponse) -> response::Response {
+    let mut response = (
+        StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK),
+        stored.body,
+    )
+        .into_response();
+    let headers = response.headers_mut();
+    headers.remove(header::CONTENT_TYPE);
+    if let Some(content_type) = stored
+        .content_type
+        .and_then(|c| HeaderValue::from_str(&c).ok())
+    {
+        headers.insert(header::CONTENT_TYPE, content_type);
+    }
+    headers.insert("idempotent-replayed", HeaderValue::from_static("true"));
+    response
+}
+
 /// Authorises requests to the server by checking the bearer token. If IDP support is enabled,
 /// `user_id` is mapped from the `sub` field - otherwise, whatever bearer token was passed is used.
 async fn authorise<B>(
@@ -127,8 +360,95 @@ async fn authorise<B>(
     }
 }
 
+/// The OpenAPI description of the API, generated from the handlers and the types they take and
+/// return. A copy is kept in `openapi.json`, which the tests check is up to date.
+#[derive(OpenApi)]
+#[openapi(
+    info(description = "The API of the lembas meal planning system."),
+    paths(
+        root,
+        get_recipes,
+        get_recipe,
+        create_recipe,
+        update_recipe,
+        delete_recipe,
+        upload_recipe_image,
+        delete_recipe_image,
+        get_image,
+        get_cookable_recipes,
+        get_days,
+        build_list,
+        complete_shopping_trip,
+        get_purchases,
+        get_sync,
+        get_expiring,
+        get_waste,
+        create_day,
+        apply_day_batch,
+        generate_plan,
+        accept_plan,
+        get_templates,
+        create_template,
+        rename_template,
+        delete_template,
+        apply_template,
+        copy_days,
+        get_calendar,
+        get_calendar_feed,
+        create_calendar_token,
+        delete_calendar_token,
+        delete_day_recipe,
+        record_meal,
+        get_ingredients,
+        get_user_ingredients,
+        create_ingredient,
+        update_ingredient,
+        update_ingredient_price,
+        get_aisles,
+        update_aisles,
+        get_diet_profile,
+        update_diet_profile,
+        delete_ingredient,
+        get_families,
+        create_family,
+        delete_family,
+        get_schedule,
+        create_scheduled_ingredient,
+        delete_scheduled_ingredient,
+        search_ingredients,
+    ),
+    modifiers(&BearerAuth),
+    security(("bearer" = [])),
+)]
+pub struct ApiDoc;
+
+/// Adds the bearer token that most routes are authorised by.
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+        );
+    }
+}
+
+/// The multipart form a recipe image is uploaded in. Only used to describe the upload.
+#[allow(dead_code)]
+#[derive(ToSchema)]
+struct RecipeImageForm {
+    /// A JPEG, PNG or WebP image.
+    #[schema(value_type = String, format = Binary)]
+    image: Vec<u8>,
+    /// The index of the step the image is of, if it isn't of the whole recipe.
+    step: Option<i32>,
+}
+
 pub async fn make_router(
     db: Db,
+    storage: Arc<dyn ImageStorage>,
     idp_url: String,
     idp_enabled: bool,
 ) -> Result<Router, Box<dyn Error>> {
@@ -160,17 +480,56 @@ pub async fn make_router(
     let authorised = Router::new()
         .route("/recipes", get(get_recipes))
         .route("/recipes", post(create_recipe))
+        .route("/recipes/cookable", get(get_cookable_recipes))
         .route("/recipe/:recipe_id", get(get_recipe))
         .route("/recipe/:recipe_id", put(update_recipe))
         .route("/recipe/:recipe_id", delete(delete_recipe))
+        .route(
+            "/recipe/:recipe_id/images",
+            // Leave room for the rest of the multipart body around the image itself.
+            post(upload_recipe_image).layer(DefaultBodyLimit::max(MAX_IMAGE_SIZE + 64 * 1024)),
+        )
+        .route(
+            "/recipe/:recipe_id/image/:image_id",
+            delete(delete_recipe_image),
+        )
         .route("/days", get(get_days))
         .route("/days", post(create_day))
         .route("/days/:date/recipes/:recipe_id", delete(delete_day_recipe))
+        .route("/days/:date/recipes/:recipe_id", put(record_meal))
+        .route("/days/copy", post(copy_days))
+        .route("/days/batch", post(apply_day_batch))
+        .route("/days/calendar.ics", get(get_calendar))
+        .route("/calendar/token", post(create_calendar_token))
+        .route("/calendar/token", delete(delete_calendar_token))
+        .route("/plan/generate", post(generate_plan))
+        .route("/plan/accept", post(accept_plan))
+        .route("/templates", get(get_templates))
+        .route("/templates", post(create_template))
+        .route("/template/:template_id", put(rename_template))
+        .route("/template/:template_id", delete(delete_template))
+        .route("/template/:template_id/apply", post(apply_template))
         .route("/shoppinglist", get(build_list))
+        .route("/shoppinglist/waste", get(get_waste))
+        .route("/shoppinglist/complete", post(complete_shopping_trip))
+        .route("/purchases", get(get_purchases))
+        .route("/alerts/expiring", get(get_expiring))
         .route("/ingredients", get(get_ingredients))
         .route("/ingredients/user", get(get_user_ingredients))
         .route("/ingredients", post(create_ingredient))
+        .route("/ingredient/:ingredient_id", put(update_ingredient))
         .route("/ingredient/:ingredient_id", delete(delete_ingredient))
+        .route(
+            "/ingredient/:ingredient_id/price",
+            put(update_ingredient_price),
+        )
+        .route("/aisles", get(get_aisles))
+        .route("/aisles", put(update_aisles))
+        .route("/diet", get(get_diet_profile))
+        .route("/diet", put(update_diet_profile))
+        .route("/families", get(get_families))
+        .route("/families", post(create_family))
+        .route("/family/:family_id", delete(delete_family))
         .route("/schedule", get(get_schedule))
         .route("/schedule", post(create_scheduled_ingredient))
         .route(
@@ -178,37 +537,93 @@ pub async fn make_router(
             delete(delete_scheduled_ingredient),
         )
         .route("/search/ingredients", get(search_ingredients))
+        .route("/sync", get(get_sync))
+        .layer(middleware::from_fn_with_state(db.clone(), idempotent))
         .layer(middleware::from_fn_with_state(
             auth_state.clone(),
             authorise,
         ))
+        .layer(Extension(storage.clone()))
+        .with_state(db.clone());
+    // Calendar apps can't send a bearer token, so feeds are authorised by a secret in the URL
+    let calendar_feed = Router::new()
+        .route("/calendar/:token/meals.ics", get(get_calendar_feed))
         .with_state(db);
+    // Images are shown by plain links, so are served without authorisation under unguessable names
+    let images = Router::new()
+        .route("/images/:name", get(get_image))
+        .layer(Extension(storage));
     let health_check = Router::new().route("/", get(root));
-    Ok(Router::new().merge(authorised).merge(health_check))
+    let openapi = Router::new().route("/openapi.json", get(get_openapi));
+    Ok(Router::new()
+        .merge(authorised)
+        .merge(calendar_feed)
+        .merge(images)
+        .merge(health_check)
+        .merge(openapi)
+        .layer(middleware::from_fn(etag)))
 }
 
 /// A simple 200 for the root of the API
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "health",
+    responses(
+        (status = 200, description = "The server is running", body = String),
+    ),
+    security(()),
+)]
 async fn root() -> String {
     "Server started OK.".into()
 }
 
-/// Retrieve all the recipes for a given user
+/// The OpenAPI description of the API.
+async fn get_openapi() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+/// Retrieve all the recipes for a given user, optionally filtered by total time.
+#[utoipa::path(
+    get,
+    path = "/recipes",
+    tag = "recipes",
+    params(RecipeFilter, PageParams),
+    responses(
+        (status = 200, description = "A page of recipes. If there are more, a `Link` header points to the next page", body = Vec<Recipe>, headers(("link" = String, description = "The next page, if there is one"))),
+        (status = 422, description = "The request is invalid"),
+    ),
+)]
 async fn get_recipes(
     State(db): State<Db>,
     Extension(user_id): Extension<i32>,
-) -> Result<Json<Vec<Recipe>>, ServerError> {
-    let result = recipe::query_multiple(&db.pool, user_id).await;
+    OriginalUri(uri): OriginalUri,
+    Query(filter): Query<RecipeFilter>,
+    Query(params): Query<PageParams>,
+) -> Result<response::Response, ServerError> {
+    let result = recipe::query_page(&db.pool, user_id, &filter, &params).await;
 
     if result.is_err() {
         event!(Level::ERROR, "{:?}", result);
     }
 
-    result
-        .map(Json)
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+    match result {
+        Ok(Ok(page)) => Ok(page_response(&uri, page)),
+        Ok(Err(e)) => Err((StatusCode::UNPROCESSABLE_ENTITY, e.to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
 }
 
 /// Retrieve a single recipe
+#[utoipa::path(
+    get,
+    path = "/recipe/{recipe_id}",
+    tag = "recipes",
+    params(("recipe_id" = i32, Path, description = "The recipe's ID")),
+    responses(
+        (status = 200, description = "The recipe", body = Recipe),
+    ),
+)]
 async fn get_recipe(
     State(db): State<Db>,
     Path(recipe_id): Path<i32>,
@@ -226,11 +641,26 @@ async fn get_recipe(
 }
 
 /// Create a new recipe. Returns the recipe_id of the new recipe.
+#[utoipa::path(
+    post,
+    path = "/recipes",
+    tag = "recipes",
+    request_body = RecipeInput,
+    responses(
+        (status = 201, description = "The recipe was created"),
+        (status = 422, description = "The request is invalid"),
+    ),
+)]
 async fn create_recipe(
     State(db): State<Db>,
     Extension(user_id): Extension<i32>,
     Json(recipe): Json<RecipeInput>,
 ) -> Result<StatusCode, ServerError> {
+    if let Err(e) = recipe.validate() {
+        return Err((StatusCode::UNPROCESSABLE_ENTITY, e.to_string()));
+    }
+    check_sub_recipes(&db, user_id, None, &recipe).await?;
+
     let result = recipe::create(&db.pool, user_id, recipe).await;
 
     if result.is_err() {
@@ -243,27 +673,82 @@ async fn create_recipe(
 }
 
 /// Update a recipe.
+#[utoipa::path(
+    put,
+    path = "/recipe/{recipe_id}",
+    tag = "recipes",
+    params(("recipe_id" = i32, Path, description = "The recipe's ID")),
+    request_body = RecipeInput,
+    responses(
+        (status = 204, description = "The recipe was updated"),
+        (status = 409, description = "The recipe has been updated since the given version"),
+        (status = 422, description = "The request is invalid"),
+    ),
+)]
 async fn update_recipe(
     State(db): State<Db>,
     Path(recipe_id): Path<i32>,
     Extension(user_id): Extension<i32>,
     Json(recipe): Json<RecipeInput>,
 ) -> Result<StatusCode, ServerError> {
+    if let Err(e) = recipe.validate() {
+        return Err((StatusCode::UNPROCESSABLE_ENTITY, e.to_string()));
+    }
+    check_sub_recipes(&db, user_id, Some(recipe_id), &recipe).await?;
+
     let result = recipe::update(&db.pool, user_id, recipe_id, recipe).await;
     if result.is_err() {
         event!(Level::ERROR, "{:?}", result);
     }
-    result
-        .map(|_| StatusCode::NO_CONTENT)
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+    match result {
+        Ok(Ok(())) => Ok(StatusCode::NO_CONTENT),
+        Ok(Err(e)) => Err((StatusCode::CONFLICT, e.to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+/// Rejects sub-recipes the user doesn't own, or that would make a recipe include itself.
+async fn check_sub_recipes(
+    db: &Db,
+    user_id: i32,
+    recipe_id: Option<i32>,
+    recipe: &RecipeInput,
+) -> Result<(), ServerError> {
+    let result = recipe::check_sub_recipes(&db.pool, user_id, recipe_id, recipe).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    match result {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(e)) => Err((StatusCode::UNPROCESSABLE_ENTITY, e.to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
 }
 
-/// Delete a recipe.
+/// Delete a recipe, along with any stored images.
+#[utoipa::path(
+    delete,
+    path = "/recipe/{recipe_id}",
+    tag = "recipes",
+    params(("recipe_id" = i32, Path, description = "The recipe's ID")),
+    responses(
+        (status = 204, description = "The recipe was deleted"),
+    ),
+)]
 async fn delete_recipe(
     State(db): State<Db>,
     Path(recipe_id): Path<i32>,
     Extension(user_id): Extension<i32>,
+    Extension(storage): Extension<Arc<dyn ImageStorage>>,
 ) -> Result<StatusCode, ServerError> {
+    let result = images::delete_files(&db.pool, storage.as_ref(), user_id, recipe_id).await;
+    if let Err(e) = result {
+        event!(Level::ERROR, "{:?}", e);
+        return Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()));
+    }
+
     let result = recipe::delete(&db.pool, user_id, recipe_id).await;
 
     if result.is_err() {
@@ -275,18 +760,333 @@ async fn delete_recipe(
         .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
 }
 
-#[derive(Deserialize, Debug)]
+/// Upload a photo of a recipe, or of one of its steps. Expects a multipart form with an `image`
+/// file and an optional `step` index.
+#[utoipa::path(
+    post,
+    path = "/recipe/{recipe_id}/images",
+    tag = "recipes",
+    params(("recipe_id" = i32, Path, description = "The recipe's ID")),
+    request_body(content = RecipeImageForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "The image was stored", body = RecipeImage),
+        (status = 404, description = "Not found"),
+        (status = 413, description = "The image is too large, or too wide or high"),
+        (status = 415, description = "The image isn't a JPEG, PNG or WebP"),
+        (status = 422, description = "The request is invalid"),
+    ),
+)]
+async fn upload_recipe_image(
+    State(db): State<Db>,
+    Path(recipe_id): Path<i32>,
+    Extension(user_id): Extension<i32>,
+    Extension(storage): Extension<Arc<dyn ImageStorage>>,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<RecipeImage>), ServerError> {
+    let mut image = None;
+    let mut step = None;
+    loop {
+        let field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            Err(e) => return Err((e.status(), e.body_text())),
+        };
+        match field.name() {
+            Some("image") => {
+                let content_type = field.content_type().unwrap_or_default().to_string();
+                let data = field
+                    .bytes()
+                    .await
+                    .map_err(|e| (e.status(), e.body_text()))?;
+                let prepared = PreparedImage::new(&content_type, data.to_vec()).await;
+                let prepared = prepared.map_err(|e| {
+                    let status = match e {
+                        InvalidImage::UnsupportedType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                        InvalidImage::TooLarge(_) | InvalidImage::TooManyPixels => {
+                            StatusCode::PAYLOAD_TOO_LARGE
+                        }
+                        InvalidImage::Unreadable => StatusCode::UNPROCESSABLE_ENTITY,
+                    };
+                    (status, e.to_string())
+                })?;
+                image = Some(prepared);
+            }
+            Some("step") => {
+                let text = field
+                    .text()
+                    .await
+                    .map_err(|e| (e.status(), e.body_text()))?;
+                let index = text.trim().parse::<i32>().map_err(|_| {
+                    (
+                        StatusCode::UNPROCESSABLE_ENTITY,
+                        "The step must be a number.".to_string(),
+                    )
+                })?;
+                step = Some(index);
+            }
+            _ => {}
+        }
+    }
+    let Some(image) = image else {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "An image must be uploaded.".into(),
+        ));
+    };
+
+    let step_count = match images::step_count(&db.pool, user_id, recipe_id).await {
+        Ok(Some(count)) => count,
+        Ok(None) => return Err((StatusCode::NOT_FOUND, "Recipe not found.".into())),
+        Err(e) => {
+            event!(Level::ERROR, "{:?}", e);
+            return Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()));
+        }
+    };
+    if step.is_some_and(|s| s < 0 || s >= step_count) {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "The recipe has no such step.".into(),
+        ));
+    }
+
+    let result = images::upload(&db.pool, storage.as_ref(), recipe_id, step, image).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|image| (StatusCode::CREATED, Json(image)))
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Remove a photo from a recipe.
+#[utoipa::path(
+    delete,
+    path = "/recipe/{recipe_id}/image/{image_id}",
+    tag = "recipes",
+    params(
+        ("recipe_id" = i32, Path, description = "The recipe's ID"),
+        ("image_id" = i32, Path, description = "The image's ID"),
+    ),
+    responses(
+        (status = 204, description = "The image was deleted"),
+        (status = 404, description = "Not found"),
+    ),
+)]
+async fn delete_recipe_image(
+    State(db): State<Db>,
+    Path((recipe_id, image_id)): Path<(i32, i32)>,
+    Extension(user_id): Extension<i32>,
+    Extension(storage): Extension<Arc<dyn ImageStorage>>,
+) -> Result<StatusCode, ServerError> {
+    let result = images::delete(&db.pool, storage.as_ref(), user_id, recipe_id, image_id).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    match result {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err((StatusCode::NOT_FOUND, "Image not found.".into())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+/// Fetch a stored image or thumbnail by the name in its URL.
+#[utoipa::path(
+    get,
+    path = "/images/{name}",
+    tag = "images",
+    params(("name" = String, Path, description = "The name of the image or thumbnail")),
+    responses(
+        (status = 200, description = "The image", content_type = "image/*"),
+        (status = 404, description = "Not found"),
+    ),
+    security(()),
+)]
+async fn get_image(
+    Path(name): Path<String>,
+    Extension(storage): Extension<Arc<dyn ImageStorage>>,
+) -> Result<impl response::IntoResponse, ServerError> {
+    let result = images::fetch(storage.as_ref(), &name).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    match result {
+        // Names are never reused, so images can be cached indefinitely, and the name is enough
+        // to tell whether the client already has the image.
+        Ok(Some((content_type, data))) => Ok((
+            [
+                (header::CONTENT_TYPE, content_type),
+                (header::CACHE_CONTROL, "public, max-age=31536000, immutable"),
+            ],
+            [(header::ETAG, format!("\"{}\"", name))],
+            data,
+        )),
+        Ok(None) => Err((StatusCode::NOT_FOUND, "Image not found.".into())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct CookableParams {
+    date: String,
+}
+
+/// Rank the user's recipes by how much of each is covered by surplus on a date.
+#[utoipa::path(
+    get,
+    path = "/recipes/cookable",
+    tag = "recipes",
+    params(CookableParams),
+    responses(
+        (status = 200, description = "Recipes ranked by how much of each is covered by surplus", body = Vec<CookableRecipe>),
+    ),
+)]
+async fn get_cookable_recipes(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Query(params): Query<CookableParams>,
+) -> Result<Json<Vec<CookableRecipe>>, ServerError> {
+    let result = day::cookable_recipes(&db.pool, user_id, params.date).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
 struct DayRange {
     from: String,
     to: String,
 }
 /// Fetch all the day/recipe items for the current user.
+#[utoipa::path(
+    get,
+    path = "/days",
+    tag = "days",
+    params(DayRange, PageParams),
+    responses(
+        (status = 200, description = "A page of days. If there are more, a `Link` header points to the next page", body = Vec<Day>, headers(("link" = String, description = "The next page, if there is one"))),
+        (status = 422, description = "The request is invalid"),
+    ),
+)]
 async fn get_days(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    OriginalUri(uri): OriginalUri,
+    Query(range): Query<DayRange>,
+    Query(params): Query<PageParams>,
+) -> Result<response::Response, ServerError> {
+    let result = day::query_page(&db.pool, user_id, &range.from, &range.to, &params).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    match result {
+        Ok(Ok(page)) => Ok(page_response(&uri, page)),
+        Ok(Err(e)) => Err((StatusCode::UNPROCESSABLE_ENTITY, e.to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct ListParams {
+    from: String,
+    to: String,
+    #[serde(default)]
+    format: ListFormat,
+}
+
+/// Build a shopping list for a range of days, as JSON or in an exportable format.
+#[utoipa::path(
+    get,
+    path = "/shoppinglist",
+    tag = "shopping",
+    params(ListParams),
+    responses(
+        (status = 200, description = "The shopping list, in the format asked for", content((ShoppingList = "application/json"), (String = "text/plain"), (String = "text/csv"), (String = "text/markdown"), (String = "text/html"))),
+    ),
+)]
+async fn build_list(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Query(params): Query<ListParams>,
+) -> Result<response::Response, ServerError> {
+    let result = day::build_list_for_range(&db.pool, user_id, params.from, params.to).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|list| match params.format {
+            ListFormat::Json => Json(list).into_response(),
+            format => (
+                [(header::CONTENT_TYPE, format.content_type())],
+                export::render(&list, format),
+            )
+                .into_response(),
+        })
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Record what was actually bought from a shopping list
+#[utoipa::path(
+    post,
+    path = "/shoppinglist/complete",
+    tag = "shopping",
+    request_body = ShoppingTrip,
+    responses(
+        (status = 201, description = "The purchases were recorded"),
+        (status = 422, description = "The request is invalid"),
+    ),
+)]
+async fn complete_shopping_trip(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Json(trip): Json<ShoppingTrip>,
+) -> Result<StatusCode, ServerError> {
+    let result = purchase::complete_trip(&db.pool, user_id, trip).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    match result {
+        Ok(Ok(())) => Ok(StatusCode::CREATED),
+        Ok(Err(e)) => Err((StatusCode::UNPROCESSABLE_ENTITY, e.to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+/// Fetch the purchases recorded between a range of dates
+#[utoipa::path(
+    get,
+    path = "/purchases",
+    tag = "shopping",
+    params(DayRange),
+    responses(
+        (status = 200, description = "The purchases made in the range", body = Vec<Purchase>),
+    ),
+)]
+async fn get_purchases(
     State(db): State<Db>,
     Extension(user_id): Extension<i32>,
     Query(params): Query<DayRange>,
-) -> Result<Json<Vec<Day>>, ServerError> {
-    let result = day::query_range(&db.pool, user_id, &params.from, &params.to).await;
+) -> Result<Json<Vec<Purchase>>, ServerError> {
+    let result = purchase::query_range(&db.pool, user_id, &params.from, &params.to).await;
 
     if result.is_err() {
         event!(Level::ERROR, "{:?}", result);
@@ -297,13 +1097,117 @@ async fn get_days(
         .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
 }
 
-/// Fetch all the day/recipe items for the current user.
-async fn build_list(
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct SyncParams {
+    /// The token returned by the last sync. Without one, everything is returned.
+    since: Option<String>,
+}
+
+/// Fetch everything that has changed since the last sync
+#[utoipa::path(
+    get,
+    path = "/sync",
+    tag = "sync",
+    params(SyncParams),
+    responses(
+        (status = 200, description = "Everything that has changed", body = SyncChanges),
+        (status = 422, description = "The request is invalid"),
+    ),
+)]
+async fn get_sync(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Query(params): Query<SyncParams>,
+) -> Result<Json<SyncChanges>, ServerError> {
+    let since = match params.since.as_deref().map(sync::parse_token) {
+        Some(Ok(since)) => Some(since),
+        Some(Err(e)) => return Err((StatusCode::UNPROCESSABLE_ENTITY, e.to_string())),
+        None => None,
+    };
+    let result = sync::changes(&db.pool, user_id, since)
+        .await
+        .map_err(|e| e.to_string());
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct ExpiringParams {
+    /// The number of days ahead to look for ingredients going off.
+    within: Option<i32>,
+    /// The date to look ahead from, defaulting to today.
+    date: Option<String>,
+}
+
+/// List the surplus ingredients about to go off, with recipes that would use them up
+#[utoipa::path(
+    get,
+    path = "/alerts/expiring",
+    tag = "shopping",
+    params(ExpiringParams),
+    responses(
+        (status = 200, description = "The ingredients going off", body = Vec<ExpiringIngredient>),
+        (status = 422, description = "The request is invalid"),
+    ),
+)]
+async fn get_expiring(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Query(params): Query<ExpiringParams>,
+) -> Result<Json<Vec<ExpiringIngredient>>, ServerError> {
+    let within = params.within.unwrap_or(3);
+    if within < 0 {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "The number of days can't be negative.".into(),
+        ));
+    }
+    let date = match params.date {
+        Some(date) => date,
+        None => today().map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?,
+    };
+    if parse_date(&date).is_err() {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Dates must be in the format YYYY-MM-DD.".into(),
+        ));
+    }
+
+    let result = alert::expiring(&db.pool, user_id, &date, within).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Rank the recipes planned in a range by the waste they are projected to cause.
+#[utoipa::path(
+    get,
+    path = "/shoppinglist/waste",
+    tag = "shopping",
+    params(DayRange),
+    responses(
+        (status = 200, description = "Planned recipes, most wasteful first", body = Vec<RecipeWaste>),
+    ),
+)]
+async fn get_waste(
     State(db): State<Db>,
     Extension(user_id): Extension<i32>,
     Query(params): Query<DayRange>,
-) -> Result<Json<ShoppingList>, ServerError> {
-    let result = day::build_list_for_range(&db.pool, user_id, params.from, params.to).await;
+) -> Result<Json<Vec<RecipeWaste>>, ServerError> {
+    let result = day::waste_for_range(&db.pool, user_id, params.from, params.to).await;
 
     if result.is_err() {
         event!(Level::ERROR, "{:?}", result);
@@ -315,11 +1219,31 @@ async fn build_list(
 }
 
 /// Create a new day/recipe entry in the database
+#[utoipa::path(
+    post,
+    path = "/days",
+    tag = "days",
+    request_body = DayInput,
+    responses(
+        (status = 201, description = "The recipe was planned"),
+        (status = 422, description = "The request is invalid"),
+    ),
+)]
 async fn create_day(
     State(db): State<Db>,
     Extension(user_id): Extension<i32>,
     Json(day): Json<DayInput>,
 ) -> Result<StatusCode, ServerError> {
+    let checked = day::check_leftovers(&db.pool, user_id, &day).await;
+    match checked {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => return Err((StatusCode::UNPROCESSABLE_ENTITY, e.to_string())),
+        Err(e) => {
+            event!(Level::ERROR, "{:?}", e);
+            return Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()));
+        }
+    }
+
     let result = day::create(&db.pool, user_id, day).await;
     if result.is_err() {
         event!(Level::ERROR, "{:?}", result);
@@ -330,7 +1254,370 @@ async fn create_day(
         .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
 }
 
+/// Apply a list of changes to the meal plan. If any of them fails, none are made.
+#[utoipa::path(
+    post,
+    path = "/days/batch",
+    tag = "days",
+    request_body = Vec<PlanOperation>,
+    responses(
+        (status = 200, description = "Every change was made", body = BatchResult),
+        (status = 422, description = "A change failed, so none were made", body = BatchResult),
+    ),
+)]
+async fn apply_day_batch(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Json(operations): Json<Vec<PlanOperation>>,
+) -> Result<(StatusCode, Json<BatchResult>), ServerError> {
+    let result = day::apply_batch(&db.pool, user_id, operations).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    match result {
+        Ok(Ok(batch)) if batch.applied => Ok((StatusCode::OK, Json(batch))),
+        Ok(Ok(batch)) => Ok((StatusCode::UNPROCESSABLE_ENTITY, Json(batch))),
+        Ok(Err(e)) => Err((StatusCode::UNPROCESSABLE_ENTITY, e.to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+/// Generate a draft meal plan for a range of days. Nothing is saved.
+#[utoipa::path(
+    post,
+    path = "/plan/generate",
+    tag = "plans",
+    request_body = PlanInput,
+    responses(
+        (status = 200, description = "A draft plan", body = PlanDraft),
+    ),
+)]
+async fn generate_plan(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Json(input): Json<PlanInput>,
+) -> Result<Json<PlanDraft>, ServerError> {
+    let result = plan::generate(&db.pool, user_id, input).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Save the recipes from an accepted meal plan to the user's days
+#[utoipa::path(
+    post,
+    path = "/plan/accept",
+    tag = "plans",
+    request_body = Vec<DayInput>,
+    responses(
+        (status = 201, description = "The plan was saved"),
+    ),
+)]
+async fn accept_plan(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Json(days): Json<Vec<DayInput>>,
+) -> Result<StatusCode, ServerError> {
+    let result = plan::accept(&db.pool, user_id, days).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|_| StatusCode::CREATED)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Fetch all of the user's plan templates
+#[utoipa::path(
+    get,
+    path = "/templates",
+    tag = "plans",
+    responses(
+        (status = 200, description = "The user's templates", body = Vec<PlanTemplate>),
+    ),
+)]
+async fn get_templates(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+) -> Result<Json<Vec<PlanTemplate>>, ServerError> {
+    let result = plan::query_templates(&db.pool, user_id).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Save a range of days as a plan template
+#[utoipa::path(
+    post,
+    path = "/templates",
+    tag = "plans",
+    request_body = TemplateInput,
+    responses(
+        (status = 201, description = "The template was saved"),
+    ),
+)]
+async fn create_template(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Json(template): Json<TemplateInput>,
+) -> Result<StatusCode, ServerError> {
+    let result = plan::create_template(&db.pool, user_id, template).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|_| StatusCode::CREATED)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Rename a plan template
+#[utoipa::path(
+    put,
+    path = "/template/{template_id}",
+    tag = "plans",
+    params(("template_id" = i32, Path, description = "The template's ID")),
+    request_body = TemplateRename,
+    responses(
+        (status = 204, description = "The template was renamed"),
+    ),
+)]
+async fn rename_template(
+    State(db): State<Db>,
+    Path(template_id): Path<i32>,
+    Extension(user_id): Extension<i32>,
+    Json(rename): Json<TemplateRename>,
+) -> Result<StatusCode, ServerError> {
+    let result = plan::rename_template(&db.pool, user_id, template_id, rename).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Delete a plan template. Days planned from it are kept.
+#[utoipa::path(
+    delete,
+    path = "/template/{template_id}",
+    tag = "plans",
+    params(("template_id" = i32, Path, description = "The template's ID")),
+    responses(
+        (status = 204, description = "The template was deleted"),
+    ),
+)]
+async fn delete_template(
+    State(db): State<Db>,
+    Path(template_id): Path<i32>,
+    Extension(user_id): Extension<i32>,
+) -> Result<StatusCode, ServerError> {
+    let result = plan::delete_template(&db.pool, user_id, template_id).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Plan the recipes in a template from a new start date
+#[utoipa::path(
+    post,
+    path = "/template/{template_id}/apply",
+    tag = "plans",
+    params(("template_id" = i32, Path, description = "The template's ID")),
+    request_body = ApplyInput,
+    responses(
+        (status = 201, description = "The template's recipes were planned"),
+    ),
+)]
+async fn apply_template(
+    State(db): State<Db>,
+    Path(template_id): Path<i32>,
+    Extension(user_id): Extension<i32>,
+    Json(apply): Json<ApplyInput>,
+) -> Result<StatusCode, ServerError> {
+    let result = plan::apply_template(&db.pool, user_id, template_id, apply).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|_| StatusCode::CREATED)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Copy the recipes planned in a range of days to a new start date
+#[utoipa::path(
+    post,
+    path = "/days/copy",
+    tag = "days",
+    request_body = CopyInput,
+    responses(
+        (status = 201, description = "The days were copied"),
+    ),
+)]
+async fn copy_days(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Json(copy): Json<CopyInput>,
+) -> Result<StatusCode, ServerError> {
+    let result = plan::copy_days(&db.pool, user_id, copy).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|_| StatusCode::CREATED)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Export the meals planned in a range of days as an iCalendar file
+#[utoipa::path(
+    get,
+    path = "/days/calendar.ics",
+    tag = "calendar",
+    params(DayRange),
+    responses(
+        (status = 200, description = "An iCalendar file", body = String, content_type = "text/calendar"),
+    ),
+)]
+async fn get_calendar(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Query(params): Query<DayRange>,
+) -> Result<impl response::IntoResponse, ServerError> {
+    let result = calendar::export_range(&db.pool, user_id, params.from, params.to).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|ical| {
+            (
+                [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+                ical,
+            )
+        })
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Fetch a subscribed calendar feed. Authorised by the token in the path, rather than a bearer token.
+#[utoipa::path(
+    get,
+    path = "/calendar/{token}/meals.ics",
+    tag = "calendar",
+    params(("token" = String, Path, description = "The user's calendar token")),
+    responses(
+        (status = 200, description = "An iCalendar file", body = String, content_type = "text/calendar"),
+        (status = 404, description = "Not found"),
+    ),
+    security(()),
+)]
+async fn get_calendar_feed(
+    State(db): State<Db>,
+    Path(token): Path<String>,
+) -> Result<impl response::IntoResponse, ServerError> {
+    let result = calendar::export_feed(&db.pool, token).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    match result {
+        Ok(Some(ical)) => Ok((
+            [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+            ical,
+        )),
+        Ok(None) => Err((StatusCode::NOT_FOUND, "Calendar not found.".into())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+/// Create a calendar subscription token, replacing any existing one
+#[utoipa::path(
+    post,
+    path = "/calendar/token",
+    tag = "calendar",
+    responses(
+        (status = 201, description = "A new token", body = CalendarToken),
+    ),
+)]
+async fn create_calendar_token(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+) -> Result<(StatusCode, Json<CalendarToken>), ServerError> {
+    let result = calendar::create_token(&db.pool, user_id).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|token| (StatusCode::CREATED, Json(token)))
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Revoke the user's calendar subscription token
+#[utoipa::path(
+    delete,
+    path = "/calendar/token",
+    tag = "calendar",
+    responses(
+        (status = 204, description = "The token was revoked"),
+    ),
+)]
+async fn delete_calendar_token(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+) -> Result<StatusCode, ServerError> {
+    let result = calendar::delete_token(&db.pool, user_id).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
 /// Delete a day from the database
+#[utoipa::path(
+    delete,
+    path = "/days/{date}/recipes/{recipe_id}",
+    tag = "days",
+    params(
+        ("date" = String, Path, description = "The planned date (YYYY-MM-DD)"),
+        ("recipe_id" = i32, Path, description = "The recipe's ID"),
+    ),
+    responses(
+        (status = 204, description = "The recipe was removed from the day"),
+    ),
+)]
 async fn delete_day_recipe(
     State(db): State<Db>,
     Extension(user_id): Extension<i32>,
@@ -347,23 +1634,94 @@ async fn delete_day_recipe(
         .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
 }
 
+/// Record whether a planned recipe was cooked, and how much of each ingredient it used
+#[utoipa::path(
+    put,
+    path = "/days/{date}/recipes/{recipe_id}",
+    tag = "days",
+    params(
+        ("date" = String, Path, description = "The planned date (YYYY-MM-DD)"),
+        ("recipe_id" = i32, Path, description = "The recipe's ID"),
+    ),
+    request_body = MealRecordInput,
+    responses(
+        (status = 204, description = "The meal was recorded"),
+        (status = 404, description = "Not found"),
+        (status = 422, description = "The request is invalid"),
+    ),
+)]
+async fn record_meal(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Path((date, recipe_id)): Path<(String, i32)>,
+    Json(record): Json<MealRecordInput>,
+) -> Result<StatusCode, ServerError> {
+    let result = day::record_meal(&db.pool, user_id, date, recipe_id, record).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    match result {
+        Ok(Ok(true)) => Ok(StatusCode::NO_CONTENT),
+        Ok(Ok(false)) => Err((StatusCode::NOT_FOUND, "Planned meal not found.".into())),
+        Ok(Err(e)) => Err((StatusCode::UNPROCESSABLE_ENTITY, e.to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
 /// Fetch all ingredients
+#[utoipa::path(
+    get,
+    path = "/ingredients",
+    tag = "ingredients",
+    params(PageParams),
+    responses(
+        (status = 200, description = "A page of ingredients. If there are more, a `Link` header points to the next page", body = Vec<Ingredient>, headers(("link" = String, description = "The next page, if there is one"))),
+        (status = 422, description = "The request is invalid"),
+    ),
+)]
 async fn get_ingredients(
     State(db): State<Db>,
     Extension(user_id): Extension<i32>,
-) -> Result<Json<Vec<Ingredient>>, ServerError> {
-    let result = ingredient::query_multiple(&db.pool, user_id).await;
+    OriginalUri(uri): OriginalUri,
+    Query(params): Query<PageParams>,
+) -> Result<response::Response, ServerError> {
+    let result = ingredient::query_page(&db.pool, user_id, &params).await;
 
     if result.is_err() {
         event!(Level::ERROR, "{:?}", result);
     }
 
-    result
-        .map(Json)
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+    match result {
+        Ok(Ok(page)) => Ok(page_response(&uri, page)),
+        Ok(Err(e)) => Err((StatusCode::UNPROCESSABLE_ENTITY, e.to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+/// Responds with a page of a list. If there are more items, a `Link` header points to the next
+/// page.
+fn page_response(uri: &Uri, page: Page) -> response::Response {
+    let mut response = Json(page.items).into_response();
+    if let Some(cursor) = page.next_cursor {
+        let link = page::next_link(uri.path(), uri.query(), &cursor);
+        if let Ok(link) = HeaderValue::from_str(&link) {
+            response.headers_mut().insert(header::LINK, link);
+        }
+    }
+    response
 }
 
 /// Fetch a user's ingredients
+#[utoipa::path(
+    get,
+    path = "/ingredients/user",
+    tag = "ingredients",
+    responses(
+        (status = 200, description = "The user's own ingredients", body = Vec<Ingredient>),
+    ),
+)]
 async fn get_user_ingredients(
     State(db): State<Db>,
     Extension(user_id): Extension<i32>,
@@ -380,6 +1738,16 @@ async fn get_user_ingredients(
 }
 
 /// Create an ingredient against a user
+#[utoipa::path(
+    post,
+    path = "/ingredients",
+    tag = "ingredients",
+    request_body = IngredientInput,
+    responses(
+        (status = 201, description = "The ingredient was created"),
+        (status = 400, description = "The ingredient family doesn't exist"),
+    ),
+)]
 async fn create_ingredient(
     State(db): State<Db>,
     Extension(user_id): Extension<i32>,
@@ -391,12 +1759,188 @@ async fn create_ingredient(
         event!(Level::ERROR, "{:?}", result);
     }
 
+    match result {
+        Ok(true) => Ok(StatusCode::CREATED),
+        Ok(false) => Err((
+            StatusCode::BAD_REQUEST,
+            "Ingredient family not found.".into(),
+        )),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+/// Update one of the user's ingredients
+#[utoipa::path(
+    put,
+    path = "/ingredient/{ingredient_id}",
+    tag = "ingredients",
+    params(("ingredient_id" = i32, Path, description = "The ingredient's ID")),
+    request_body = IngredientInput,
+    responses(
+        (status = 204, description = "The ingredient was updated"),
+        (status = 400, description = "The ingredient family doesn't exist"),
+    ),
+)]
+async fn update_ingredient(
+    State(db): State<Db>,
+    Path(ingredient_id): Path<i32>,
+    Extension(user_id): Extension<i32>,
+    Json(ingredient): Json<IngredientInput>,
+) -> Result<StatusCode, ServerError> {
+    let result = ingredient::update(&db.pool, user_id, ingredient_id, ingredient).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    match result {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err((
+            StatusCode::BAD_REQUEST,
+            "Ingredient family not found.".into(),
+        )),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+/// Set the price the user pays for an ingredient
+#[utoipa::path(
+    put,
+    path = "/ingredient/{ingredient_id}/price",
+    tag = "ingredients",
+    params(("ingredient_id" = i32, Path, description = "The ingredient's ID")),
+    request_body = PriceInput,
+    responses(
+        (status = 204, description = "The price was set"),
+    ),
+)]
+async fn update_ingredient_price(
+    State(db): State<Db>,
+    Path(ingredient_id): Path<i32>,
+    Extension(user_id): Extension<i32>,
+    Json(price): Json<PriceInput>,
+) -> Result<StatusCode, ServerError> {
+    let result = ingredient::update_price(&db.pool, user_id, ingredient_id, price).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
     result
-        .map(|_| StatusCode::CREATED)
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Fetch the order of the aisles in the user's store
+#[utoipa::path(
+    get,
+    path = "/aisles",
+    tag = "shopping",
+    responses(
+        (status = 200, description = "The aisles, in order", body = Vec<String>),
+    ),
+)]
+async fn get_aisles(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+) -> Result<Json<Vec<String>>, ServerError> {
+    let result = ingredient::query_aisles(&db.pool, user_id).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Reorder the aisles in the user's store
+#[utoipa::path(
+    put,
+    path = "/aisles",
+    tag = "shopping",
+    request_body = Vec<String>,
+    responses(
+        (status = 204, description = "The aisles were reordered"),
+    ),
+)]
+async fn update_aisles(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Json(aisles): Json<Vec<String>>,
+) -> Result<StatusCode, ServerError> {
+    let result = ingredient::update_aisles(&db.pool, user_id, aisles).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Fetch the user's diet profile
+#[utoipa::path(
+    get,
+    path = "/diet",
+    tag = "diet",
+    responses(
+        (status = 200, description = "The user's diet profile", body = DietProfile),
+    ),
+)]
+async fn get_diet_profile(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+) -> Result<Json<DietProfile>, ServerError> {
+    let result = diet::query_profile(&db.pool, user_id).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Replace the user's diet profile
+#[utoipa::path(
+    put,
+    path = "/diet",
+    tag = "diet",
+    request_body = DietProfile,
+    responses(
+        (status = 204, description = "The diet profile was replaced"),
+    ),
+)]
+async fn update_diet_profile(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Json(profile): Json<DietProfile>,
+) -> Result<StatusCode, ServerError> {
+    let result = diet::update_profile(&db.pool, user_id, profile).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|_| StatusCode::NO_CONTENT)
         .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
 }
 
 /// Delete an ingredient.
+#[utoipa::path(
+    delete,
+    path = "/ingredient/{ingredient_id}",
+    tag = "ingredients",
+    params(("ingredient_id" = i32, Path, description = "The ingredient's ID")),
+    responses(
+        (status = 204, description = "The ingredient was deleted"),
+    ),
+)]
 async fn delete_ingredient(
     State(db): State<Db>,
     Path(ingredient_id): Path<i32>,
@@ -413,7 +1957,91 @@ async fn delete_ingredient(
         .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
 }
 
+/// Fetch all ingredient families, along with their pack sizes
+#[utoipa::path(
+    get,
+    path = "/families",
+    tag = "ingredients",
+    responses(
+        (status = 200, description = "The ingredient families", body = Vec<IngredientFamily>),
+    ),
+)]
+async fn get_families(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+) -> Result<Json<Vec<IngredientFamily>>, ServerError> {
+    let result = ingredient::query_families(&db.pool, user_id).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Group a user's ingredients into a family
+#[utoipa::path(
+    post,
+    path = "/families",
+    tag = "ingredients",
+    request_body = IngredientFamilyInput,
+    responses(
+        (status = 201, description = "The family was created"),
+    ),
+)]
+async fn create_family(
+    State(db): State<Db>,
+    Extension(user_id): Extension<i32>,
+    Json(family): Json<IngredientFamilyInput>,
+) -> Result<StatusCode, ServerError> {
+    let result = ingredient::create_family(&db.pool, user_id, family).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|_| StatusCode::CREATED)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+/// Delete a family. Its ingredients are kept.
+#[utoipa::path(
+    delete,
+    path = "/family/{family_id}",
+    tag = "ingredients",
+    params(("family_id" = i32, Path, description = "The family's ID")),
+    responses(
+        (status = 204, description = "The family was deleted"),
+    ),
+)]
+async fn delete_family(
+    State(db): State<Db>,
+    Path(family_id): Path<i32>,
+    Extension(user_id): Extension<i32>,
+) -> Result<StatusCode, ServerError> {
+    let result = ingredient::delete_family(&db.pool, user_id, family_id).await;
+
+    if result.is_err() {
+        event!(Level::ERROR, "{:?}", result);
+    }
+
+    result
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
 /// Fetch the user's ingredient schedule
+#[utoipa::path(
+    get,
+    path = "/schedule",
+    tag = "schedule",
+    responses(
+        (status = 200, description = "The user's scheduled ingredients", body = Vec<ScheduledIngredient>),
+    ),
+)]
 async fn get_schedule(
     State(db): State<Db>,
     Extension(user_id): Extension<i32>,
@@ -430,6 +2058,15 @@ async fn get_schedule(
 }
 
 /// Create a new recipe. Returns the recipe_id of the new recipe.
+#[utoipa::path(
+    post,
+    path = "/schedule",
+    tag = "schedule",
+    request_body = ScheduledIngredientInput,
+    responses(
+        (status = 201, description = "The ingredient was scheduled"),
+    ),
+)]
 async fn create_scheduled_ingredient(
     State(db): State<Db>,
     Extension(user_id): Extension<i32>,
@@ -447,6 +2084,15 @@ async fn create_scheduled_ingredient(
 }
 
 /// Create a new recipe. Returns the recipe_id of the new recipe.
+#[utoipa::path(
+    delete,
+    path = "/schedule/{schedule_id}",
+    tag = "schedule",
+    params(("schedule_id" = i32, Path, description = "The scheduled ingredient's ID")),
+    responses(
+        (status = 204, description = "The ingredient was unscheduled"),
+    ),
+)]
 async fn delete_scheduled_ingredient(
     State(db): State<Db>,
     Path(schedule_id): Path<i32>,
@@ -463,11 +2109,21 @@ async fn delete_scheduled_ingredient(
         .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 struct SearchParams {
     query: String,
 }
 /// Perform a search over the ingredients table
+#[utoipa::path(
+    get,
+    path = "/search/ingredients",
+    tag = "ingredients",
+    params(SearchParams),
+    responses(
+        (status = 200, description = "Matching ingredients", body = Vec<Ingredient>),
+    ),
+)]
 async fn search_ingredients(
     State(db): State<Db>,
     Query(params): Query<SearchParams>,
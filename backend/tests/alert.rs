@@ -0,0 +1,73 @@
+mod common;
+
+use lembas::api::{
+    alert,
+    day::{self, DayInput},
+    notify::FileNotifier,
+};
+use sqlx::{Pool, Postgres};
+
+/// Plans a recipe that uses 5 carrots on January 10th 2020, leaving 5 of a pack of 10 to go off on
+/// the 20th.
+async fn setup_meal_plan(pool: &Pool<Postgres>) {
+    let day_input = DayInput {
+        recipe_id: 1,
+        date: "2020-01-10".into(),
+        portions: None,
+        leftovers_from: None,
+    };
+    day::create(pool, 1, day_input).await.unwrap();
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn list_expiring_ingredients(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    setup_meal_plan(&pool).await;
+
+    let expiring = alert::expiring(&pool, 1, "2020-01-18", 3).await.unwrap();
+    assert_eq!(expiring.len(), 1);
+    let carrots = &expiring[0];
+    assert_eq!(carrots.ingredient.id, 2000);
+    assert_eq!(carrots.quantity, 5);
+    assert_eq!(carrots.expires_on, "2020-01-20");
+
+    // Both recipes would use all five carrots, so are ranked by ID
+    let suggested: Vec<i32> = carrots
+        .suggested_recipes
+        .iter()
+        .map(|r| r.recipe_id)
+        .collect();
+    assert_eq!(suggested, vec![1, 2]);
+
+    let expiring = alert::expiring(&pool, 1, "2020-01-18", 1).await.unwrap();
+    assert!(
+        expiring.is_empty(),
+        "the carrots last for longer than a day"
+    );
+    let expiring = alert::expiring(&pool, 1, "2020-01-20", 3).await.unwrap();
+    assert!(expiring.is_empty(), "the carrots have already gone off");
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn send_expiry_digests(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    setup_meal_plan(&pool).await;
+    let outbox = std::env::temp_dir().join(format!("lembas-test-outbox-{}", std::process::id()));
+    let notifier = FileNotifier::new(&outbox);
+
+    let sent = alert::send_digests(&pool, &notifier, "2020-01-18", 3)
+        .await
+        .unwrap();
+    assert_eq!(sent, 1);
+
+    let message = std::fs::read_dir(&outbox)?.next().unwrap()?;
+    let message = std::fs::read_to_string(message.path())?;
+    assert!(message.contains("Subject: Carrot is about to go off"));
+    assert!(message.contains("- Use it in Five Carrots"));
+    std::fs::remove_dir_all(&outbox)?;
+
+    let sent = alert::send_digests(&pool, &notifier, "2020-01-01", 3)
+        .await
+        .unwrap();
+    assert_eq!(sent, 0, "nothing is held before the meal is cooked");
+    Ok(())
+}
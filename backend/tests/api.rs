@@ -1,3 +1,5 @@
+mod common;
+
 use std::error::Error;
 
 use axum::body::HttpBody;
@@ -7,6 +9,9 @@ use hyper::body::Buf;
 use lembas::{api::db::Db, router};
 use sqlx::{Pool, Postgres};
 use tower::util::ServiceExt;
+use utoipa::OpenApi;
+
+use crate::common::test_storage;
 
 type Result<T> = std::result::Result<T, Box<dyn Error>>;
 
@@ -54,6 +59,241 @@ fn get_shopping_list(pool: Pool<Postgres>) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day"))]
+fn get_shopping_list_waste(pool: Pool<Postgres>) -> Result<()> {
+    test_route_response(
+        "GET",
+        "shoppinglist/waste",
+        &pool,
+        Some("from=2023-11-01&to=2023-11-10"),
+    )
+    .await?;
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day"))]
+fn get_cookable_recipes(pool: Pool<Postgres>) -> Result<()> {
+    test_route_response("GET", "recipes/cookable", &pool, Some("date=2023-11-17")).await?;
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day"))]
+fn get_expiring_alerts(pool: Pool<Postgres>) -> Result<()> {
+    test_route_response(
+        "GET",
+        "alerts/expiring",
+        &pool,
+        Some("within=3&date=2023-11-17"),
+    )
+    .await?;
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "template"))]
+fn get_templates(pool: Pool<Postgres>) -> Result<()> {
+    test_route_response("GET", "templates", &pool, None).await?;
+    Ok(())
+}
+
+// Paging through lists
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "sub_recipe"))]
+fn page_through_recipes(pool: Pool<Postgres>) -> Result<()> {
+    let mut names: Vec<Vec<String>> = vec![];
+    let mut next = Some(String::from("/recipes?limit=2&sort=-name&fields=name"));
+    while let Some(path) = next {
+        let (status, link, json) = get_json(&path, &pool).await?;
+        assert_eq!(status, StatusCode::OK);
+        let page: Vec<serde_json::Value> = serde_json::from_value(json)?;
+        names.push(
+            page.iter()
+                .map(|r| r["name"].as_str().unwrap().to_string())
+                .collect(),
+        );
+        next = link.map(|link| link[1..link.find('>').unwrap()].to_string());
+    }
+
+    assert_eq!(
+        names,
+        vec![
+            vec!["Six Carrots", "Pizza Dough"],
+            vec!["Pizza", "Five Carrots"],
+            vec!["Calzone"],
+        ]
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "sub_recipe", "day"))]
+fn pages_add_up_to_the_whole_list(pool: Pool<Postgres>) -> Result<()> {
+    let lists = [
+        ("/recipes?sort=name", 2),
+        ("/recipes?sort=-total_time", 2),
+        ("/recipes?sort=cost", 2),
+        ("/recipes?sort=created_at", 1),
+        ("/recipes?sort=-created_at", 1),
+        ("/ingredients?sort=id", 500),
+        ("/ingredients?sort=name", 500),
+        ("/ingredients?sort=-category", 500),
+        ("/ingredients?sort=price", 500),
+        ("/ingredients?sort=-price_per_unit", 500),
+        ("/days?from=2023-11-01&to=2023-11-30&sort=-date", 1),
+    ];
+    for (list, limit) in lists {
+        let (_, _, whole) = get_json(list, &pool).await?;
+
+        let mut pages = vec![];
+        let mut next = Some(format!("{}&limit={}", list, limit));
+        while let Some(path) = next {
+            let (status, link, json) = get_json(&path, &pool).await?;
+            assert_eq!(status, StatusCode::OK, "{}", path);
+            pages.extend(json.as_array().unwrap().iter().cloned());
+            next = link.map(|link| link[1..link.find('>').unwrap()].to_string());
+        }
+        assert_eq!(serde_json::Value::Array(pages), whole, "{}", list);
+    }
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day"))]
+fn select_list_fields(pool: Pool<Postgres>) -> Result<()> {
+    let (_, link, json) = get_json("/ingredients?limit=3&fields=id,name", &pool).await?;
+    let ingredients = json.as_array().unwrap();
+    assert_eq!(ingredients.len(), 3);
+    assert!(ingredients
+        .iter()
+        .all(|i| i.as_object().unwrap().keys().eq(["id", "name"])));
+    assert!(link.is_some_and(|l| l.ends_with("rel=\"next\"")));
+
+    let (_, link, json) = get_json(
+        "/days?from=2023-11-01&to=2023-11-30&sort=-date&fields=date",
+        &pool,
+    )
+    .await?;
+    assert_eq!(
+        json,
+        serde_json::json!([{ "date": "2023-11-16" }, { "date": "2023-11-09" }])
+    );
+    assert_eq!(link, None);
+
+    let (_, _, json) = get_json("/recipes?sort=created_at&fields=id,created_at", &pool).await?;
+    assert_eq!(
+        json,
+        serde_json::json!([
+            { "id": 2, "created_at": "2023-11-01T18:00:00" },
+            { "id": 1, "created_at": "2023-11-02T09:30:00" }
+        ])
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn reject_invalid_pages(pool: Pool<Postgres>) -> Result<()> {
+    let (_, link, _) = get_json("/recipes?limit=1&sort=name", &pool).await?;
+    let link = link.unwrap();
+    let cursor = &link[link.find("cursor=").unwrap()..link.find('>').unwrap()];
+
+    let paths = [
+        String::from("/recipes?limit=0"),
+        String::from("/recipes?limit=100000"),
+        String::from("/recipes?sort=steps"),
+        String::from("/recipes?cursor=abc"),
+        format!("/recipes?limit=1&sort=-name&{}", cursor),
+    ];
+    for path in paths {
+        let (status, _, _) = get_json(&path, &pool).await?;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", path);
+    }
+    Ok(())
+}
+
+// Conditional requests
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn unchanged_responses_are_not_resent(pool: Pool<Postgres>) -> Result<()> {
+    let (status, tag) = get_conditional("/recipes", None, &pool).await?;
+    assert_eq!(status, StatusCode::OK);
+    let tag = tag.unwrap();
+
+    let (status, same_tag) = get_conditional("/recipes", Some(&tag), &pool).await?;
+    assert_eq!(status, StatusCode::NOT_MODIFIED);
+    assert_eq!(same_tag, Some(tag.clone()));
+
+    lembas::api::recipe::delete(&pool, 1, 2).await?;
+    let (status, new_tag) = get_conditional("/recipes", Some(&tag), &pool).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_ne!(new_tag, Some(tag));
+    Ok(())
+}
+
+// API description
+
+#[sqlx::test]
+fn serve_openapi_spec(pool: Pool<Postgres>) -> Result<()> {
+    let (status, _, spec) = get_json("/openapi.json", &pool).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(spec, serde_json::to_value(router::ApiDoc::openapi())?);
+    assert!(spec["paths"]["/recipe/{recipe_id}"]["put"].is_object());
+    Ok(())
+}
+
+/// Makes an authorised GET request with an optional `If-None-Match` header. Returns the status and
+/// the `ETag` header.
+async fn get_conditional(
+    path: &str,
+    if_none_match: Option<&str>,
+    pool: &Pool<Postgres>,
+) -> Result<(StatusCode, Option<String>)> {
+    let db = Db { pool: pool.clone() };
+    let router = router::make_router(db, test_storage(), String::from("localhost"), false).await?;
+
+    let mut request = Request::builder()
+        .header("Authorization", "Bearer abc")
+        .uri(path);
+    if let Some(tag) = if_none_match {
+        request = request.header(http::header::IF_NONE_MATCH, tag);
+    }
+    let response = router.oneshot(request.body(Body::empty())?).await?;
+
+    let tag = response
+        .headers()
+        .get(http::header::ETAG)
+        .map(|t| t.to_str().unwrap().to_string());
+    Ok((response.status(), tag))
+}
+
+/// Makes an authorised GET request. Returns the status, the `Link` header and the body as JSON,
+/// or null if it isn't JSON.
+async fn get_json(
+    path: &str,
+    pool: &Pool<Postgres>,
+) -> Result<(StatusCode, Option<String>, serde_json::Value)> {
+    let db = Db { pool: pool.clone() };
+    let router = router::make_router(db, test_storage(), String::from("localhost"), false).await?;
+
+    let uri = http::uri::Builder::new().path_and_query(path).build()?;
+    let mut response = router
+        .oneshot(
+            Request::builder()
+                .header("Authorization", "Bearer abc")
+                .uri(uri)
+                .body(Body::empty())?,
+        )
+        .await?;
+
+    let status = response.status();
+    let link = response
+        .headers()
+        .get(http::header::LINK)
+        .map(|l| l.to_str().unwrap().to_string());
+    let mut body = vec![];
+    while let Some(chunk) = response.body_mut().data().await {
+        body.extend_from_slice(&chunk?);
+    }
+    let json = serde_json::from_slice(&body).unwrap_or_default();
+    Ok((status, link, json))
+}
+
 /// Hits an API route with the specified method and verified the response
 async fn test_route_response(
     method: &str,
@@ -62,7 +302,7 @@ async fn test_route_response(
     params: Option<&str>,
 ) -> Result<()> {
     let db = Db { pool: pool.clone() };
-    let router = router::make_router(db, String::from("localhost"), false).await?;
+    let router = router::make_router(db, test_storage(), String::from("localhost"), false).await?;
 
     // Construct URI.
     let path = match params {
@@ -97,7 +337,7 @@ async fn test_route_response(
     );
 
     let json: serde_json::Value =
-        serde_json::from_str(&String::from_utf8_lossy(data.chunk()).to_string())?;
+        serde_json::from_str(String::from_utf8_lossy(data.chunk()).as_ref())?;
 
     let path = resource.to_lowercase().replace("/", "-");
 
@@ -113,11 +353,8 @@ async fn test_route_response(
     assert_eq!(
         expected,
         json,
-        "{}",
-        format!(
-            "response should match the expected output in {}.json",
-            resource.to_lowercase()
-        )
+        "response should match the expected output in {}.json",
+        resource.to_lowercase()
     );
     Ok(())
 }
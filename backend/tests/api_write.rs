@@ -1,3 +1,5 @@
+mod common;
+
 use std::error::Error;
 
 use axum::body::HttpBody;
@@ -5,12 +7,18 @@ use axum::http::{self, StatusCode};
 use axum::{body::Body, http::Request};
 use hyper::body::Buf;
 use lembas::{
-    api::{day, db::Db, ingredient, recipe},
+    api::{
+        day::{self, MealStatus},
+        db::Db,
+        diet, ingredient, plan, purchase, recipe,
+    },
     router,
 };
 use sqlx::{Pool, Postgres};
 use tower::util::ServiceExt;
 
+use crate::common::test_storage;
+
 type Result<T> = std::result::Result<T, Box<dyn Error>>;
 
 #[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
@@ -69,16 +77,10 @@ fn delete_day_recipe(pool: Pool<Postgres>) -> Result<()> {
     let response = write_resource("DELETE", "days/2023-11-09/recipes/1", &pool, None, None).await?;
     assert_eq!(response, StatusCode::NO_CONTENT);
 
-    let day = &day::query_range(
-        &pool,
-        1,
-        &"2023-11-02".to_string(),
-        &"2023-11-09".to_string(),
-    )
-    .await?;
+    let day = &day::query_range(&pool, 1, "2023-11-02", "2023-11-09").await?;
 
     let day_recipes = day
-        .get(0)
+        .first()
         .unwrap()
         .recipes
         .iter()
@@ -92,6 +94,50 @@ fn delete_day_recipe(pool: Pool<Postgres>) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day"))]
+fn record_meal(pool: Pool<Postgres>) -> Result<()> {
+    let input = include_str!("api/inputs/meal-record.json").to_string();
+    let path = "days/2023-11-09/recipes/1";
+    let response = write_resource("PUT", path, &pool, None, Some(input.clone().into())).await?;
+    assert_eq!(response, StatusCode::NO_CONTENT);
+
+    let days = day::query_range(&pool, 1, "2023-11-09", "2023-11-09").await?;
+    let record = &days[0].records[0];
+    assert_eq!(record.status, MealStatus::Cooked);
+    assert_eq!(record.used[0].quantity, 4);
+
+    let path = "days/2023-11-10/recipes/1";
+    let response = write_resource("PUT", path, &pool, None, Some(input.into())).await?;
+    assert_eq!(
+        response,
+        StatusCode::NOT_FOUND,
+        "the recipe isn't planned on the 10th"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn complete_shopping_trip(pool: Pool<Postgres>) -> Result<()> {
+    let input = include_str!("api/inputs/shopping-trip.json").to_string();
+    let response = write_resource(
+        "POST",
+        "shoppinglist/complete",
+        &pool,
+        None,
+        Some(input.into()),
+    )
+    .await?;
+    assert_eq!(response, StatusCode::CREATED);
+
+    let purchases = purchase::query_range(&pool, 1, "2020-01-09", "2020-01-09").await?;
+    assert_eq!(
+        purchases.len(),
+        1,
+        "items with nothing bought should be left out"
+    );
+    Ok(())
+}
+
 #[sqlx::test(fixtures("useraccount", "ingredient", "ingredientschedule"))]
 fn delete_scheduled_ingredient(pool: Pool<Postgres>) -> Result<()> {
     let response = write_resource("DELETE", "schedule/1", &pool, None, None).await?;
@@ -128,6 +174,24 @@ fn create_recipe(pool: Pool<Postgres>) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn create_invalid_recipe(pool: Pool<Postgres>) -> Result<()> {
+    // The step uses an ingredient the recipe doesn't.
+    let input = r#"{
+		"name": "Invalid",
+		"portions": 1,
+		"ingredients": [{"id": 2001, "quantity": 500}],
+		"steps": [{"text": "Add the salt", "ingredients": [{"ingredient_id": 2003, "quantity": 5}]}]
+	}"#
+    .to_string();
+    let response = write_resource("POST", "recipes", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let recipes = recipe::query_multiple(&pool, 1).await?;
+    assert!(recipes.is_empty(), "an invalid recipe should not be saved");
+    Ok(())
+}
+
 #[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
 fn create_ingredient(pool: Pool<Postgres>) -> Result<()> {
     let input = include_str!("api/inputs/ingredient.json").to_string();
@@ -146,22 +210,43 @@ fn create_ingredient(pool: Pool<Postgres>) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn create_ingredient_in_another_users_family(pool: Pool<Postgres>) -> Result<()> {
+    sqlx::query("INSERT INTO useraccount (id, idp_id) VALUES (2, 'def')")
+        .execute(&pool)
+        .await?;
+    sqlx::query(
+        "INSERT INTO ingredient_family (id, user_id, name, unit) VALUES (1001, 2, 'Oats', 'g')",
+    )
+    .execute(&pool)
+    .await?;
+
+    let input = r#"{"name": "New Ingredient", "life": 14, "minimum_quantity": 1, "purchase_quantity": 10, "unit": "g", "family_id": 1001}"#;
+    let response = write_resource("POST", "ingredients", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::BAD_REQUEST);
+
+    let response =
+        write_resource("PUT", "ingredient/2004", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::BAD_REQUEST);
+
+    let ingredients = ingredient::query_multiple(&pool, 1).await?;
+    assert!(
+        ingredients.iter().all(|i| i.family_id != Some(1001)),
+        "ingredients should not be put in another user's family"
+    );
+    Ok(())
+}
+
 #[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
 fn create_day(pool: Pool<Postgres>) -> Result<()> {
     let input = include_str!("api/inputs/day.json").to_string();
     let response = write_resource("POST", "days", &pool, None, Some(input.into())).await?;
     assert_eq!(response, StatusCode::CREATED);
 
-    let day = &day::query_range(
-        &pool,
-        1,
-        &"2023-06-15".to_string(),
-        &"2023-06-20".to_string(),
-    )
-    .await?;
+    let day = &day::query_range(&pool, 1, "2023-06-15", "2023-06-20").await?;
     println!("{:?}", day);
     let day_recipes = day
-        .get(0)
+        .first()
         .unwrap()
         .recipes
         .iter()
@@ -175,6 +260,78 @@ fn create_day(pool: Pool<Postgres>) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn create_day_twice(pool: Pool<Postgres>) -> Result<()> {
+    // A retried request without an idempotency key.
+    for _ in 0..2 {
+        let input = include_str!("api/inputs/day.json").to_string();
+        let response = write_resource("POST", "days", &pool, None, Some(input.into())).await?;
+        assert_eq!(response, StatusCode::CREATED);
+    }
+
+    let days = day::query_range(&pool, 1, "2023-06-18", "2023-06-18").await?;
+    assert_eq!(
+        days[0].recipes.len(),
+        1,
+        "the recipe should only be planned once"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn create_day_concurrently(pool: Pool<Postgres>) -> Result<()> {
+    let input = include_str!("api/inputs/day.json");
+    let (first, second) = tokio::join!(
+        write_resource("POST", "days", &pool, None, Some(input.into())),
+        write_resource("POST", "days", &pool, None, Some(input.into())),
+    );
+    assert_eq!(first?, StatusCode::CREATED);
+    assert_eq!(second?, StatusCode::CREATED);
+
+    let days = day::query_range(&pool, 1, "2023-06-18", "2023-06-18").await?;
+    assert_eq!(
+        days[0].recipes.len(),
+        1,
+        "the recipe should only be planned once"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day"))]
+fn create_day_from_missing_leftovers(pool: Pool<Postgres>) -> Result<()> {
+    // The recipe makes a single portion, which is eaten on the day it's cooked.
+    let input = include_str!("api/inputs/leftovers.json").to_string();
+    let response = write_resource("POST", "days", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let days = day::query_range(&pool, 1, "2023-11-10", "2023-11-10").await?;
+    assert!(days.is_empty(), "nothing should have been planned");
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn accept_plan(pool: Pool<Postgres>) -> Result<()> {
+    let input = include_str!("api/inputs/plan.json").to_string();
+    let response = write_resource("POST", "plan/accept", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::CREATED);
+
+    let days = day::query_range(&pool, 1, "2023-06-18", "2023-06-19").await?;
+    let planned = days
+        .iter()
+        .map(|d| (d.date.clone(), d.recipes.iter().map(|r| r.id).collect()))
+        .collect::<Vec<(String, Vec<i32>)>>();
+
+    assert_eq!(
+        planned,
+        vec![
+            ("2023-06-18".to_string(), vec![1]),
+            ("2023-06-19".to_string(), vec![2])
+        ],
+        "every recipe in the plan should be added to its day"
+    );
+    Ok(())
+}
+
 #[sqlx::test(fixtures("useraccount", "ingredient"))]
 fn create_schedule(pool: Pool<Postgres>) -> Result<()> {
     let input = include_str!("api/inputs/scheduledingredient.json").to_string();
@@ -194,6 +351,285 @@ fn create_schedule(pool: Pool<Postgres>) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn create_family(pool: Pool<Postgres>) -> Result<()> {
+    let input = include_str!("api/inputs/family.json").to_string();
+    let response = write_resource("POST", "families", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::CREATED);
+
+    let families = ingredient::query_families(&pool, 1).await?;
+    let members = families
+        .iter()
+        .filter(|f| f.name == "Apples")
+        .flat_map(|f| f.members.iter().map(|i| i.id))
+        .collect::<Vec<i32>>();
+
+    assert_eq!(
+        members,
+        vec![2004],
+        "the family should have been created with the user's ingredient"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "family"))]
+fn delete_system_family(pool: Pool<Postgres>) -> Result<()> {
+    let response = write_resource("DELETE", "family/1000", &pool, None, None).await?;
+    assert_eq!(response, StatusCode::NO_CONTENT);
+
+    let families = ingredient::query_families(&pool, 1)
+        .await?
+        .iter()
+        .map(|f| f.id)
+        .collect::<Vec<i32>>();
+    assert!(
+        families.contains(&1000),
+        "users should not be able to delete system families"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day"))]
+fn create_template(pool: Pool<Postgres>) -> Result<()> {
+    let input = include_str!("api/inputs/template.json").to_string();
+    let response = write_resource("POST", "templates", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::CREATED);
+
+    let templates = plan::query_templates(&pool, 1).await?;
+    let template = templates.first().unwrap();
+    let days = template
+        .days
+        .iter()
+        .map(|d| (d.day_offset, d.recipe_id))
+        .collect::<Vec<(i32, i32)>>();
+
+    assert_eq!(template.name, "Last Week");
+    assert_eq!(
+        days,
+        vec![(0, 1), (0, 2)],
+        "only recipes within the range should be saved"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "template"))]
+fn rename_template(pool: Pool<Postgres>) -> Result<()> {
+    let input = r#"{"name": "Carrot Fortnight"}"#.to_string();
+    let response = write_resource("PUT", "template/1000", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::NO_CONTENT);
+
+    let templates = plan::query_templates(&pool, 1).await?;
+    assert_eq!(templates.first().unwrap().name, "Carrot Fortnight");
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "template"))]
+fn delete_template(pool: Pool<Postgres>) -> Result<()> {
+    let response = write_resource("DELETE", "template/1000", &pool, None, None).await?;
+    assert_eq!(response, StatusCode::NO_CONTENT);
+
+    let templates = plan::query_templates(&pool, 1).await?;
+    assert!(
+        templates.is_empty(),
+        "the template should have been deleted"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "template"))]
+fn apply_template(pool: Pool<Postgres>) -> Result<()> {
+    let input = include_str!("api/inputs/apply-template.json").to_string();
+    let response = write_resource(
+        "POST",
+        "template/1000/apply",
+        &pool,
+        None,
+        Some(input.into()),
+    )
+    .await?;
+    assert_eq!(response, StatusCode::CREATED);
+
+    let days = day::query_range(&pool, 1, "2024-01-01", "2024-01-07").await?;
+    let planned = days
+        .iter()
+        .map(|d| (d.date.clone(), d.recipes.iter().map(|r| r.id).collect()))
+        .collect::<Vec<(String, Vec<i32>)>>();
+
+    assert_eq!(
+        planned,
+        vec![
+            ("2024-01-01".to_string(), vec![1]),
+            ("2024-01-03".to_string(), vec![2])
+        ],
+        "recipes should be planned relative to the start date"
+    );
+
+    // Applying it again doesn't plan the recipes twice.
+    let input = include_str!("api/inputs/apply-template.json").to_string();
+    write_resource(
+        "POST",
+        "template/1000/apply",
+        &pool,
+        None,
+        Some(input.into()),
+    )
+    .await?;
+    let days = day::query_range(&pool, 1, "2024-01-01", "2024-01-07").await?;
+    assert!(days.iter().all(|d| d.recipes.len() == 1));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day"))]
+fn copy_days(pool: Pool<Postgres>) -> Result<()> {
+    let input = include_str!("api/inputs/copy-days.json").to_string();
+    let response = write_resource("POST", "days/copy", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::CREATED);
+
+    let days = day::query_range(&pool, 1, "2023-11-16", "2023-11-22").await?;
+    let mut recipes = days
+        .first()
+        .unwrap()
+        .recipes
+        .iter()
+        .map(|r| r.id)
+        .collect::<Vec<i32>>();
+    recipes.sort();
+
+    assert_eq!(days.len(), 1);
+    assert_eq!(
+        recipes,
+        vec![1, 2],
+        "last week's recipes should be added alongside those already planned"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day"))]
+fn apply_day_batch(pool: Pool<Postgres>) -> Result<()> {
+    let input = include_str!("api/inputs/day-batch.json").to_string();
+    let response = write_resource("POST", "days/batch", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::OK);
+
+    let days = day::query_range(&pool, 1, "2023-11-18", "2023-11-20").await?;
+    let dates: Vec<&str> = days.iter().map(|d| d.date.as_str()).collect();
+    assert_eq!(dates, vec!["2023-11-18", "2023-11-20"]);
+
+    // Repeating the batch fails, as the recipes have already been moved and deleted.
+    let input = include_str!("api/inputs/day-batch.json").to_string();
+    let response = write_resource("POST", "days/batch", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::UNPROCESSABLE_ENTITY);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn update_user_ingredient(pool: Pool<Postgres>) -> Result<()> {
+    let input = include_str!("api/inputs/put-ingredient.json").to_string();
+    let response =
+        write_resource("PUT", "ingredient/2004", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::NO_CONTENT);
+
+    let ingredients = ingredient::query_user(&pool, 1).await?;
+    let apples = ingredients.iter().find(|i| i.id == 2004).unwrap();
+    assert_eq!(apples.category, Some("Fresh Fruit".into()));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn update_system_ingredient(pool: Pool<Postgres>) -> Result<()> {
+    let input = include_str!("api/inputs/put-ingredient.json").to_string();
+    let response =
+        write_resource("PUT", "ingredient/2000", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::NO_CONTENT);
+
+    let carrot = ingredient::search(&pool, 1, "Carrot".into())
+        .await?
+        .into_iter()
+        .find(|i| i.id == 2000)
+        .unwrap();
+    assert_eq!(
+        carrot.name, "Carrot",
+        "users should not be able to edit system ingredients"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn update_ingredient_price(pool: Pool<Postgres>) -> Result<()> {
+    let input = r#"{"price": 80}"#.to_string();
+    let response = write_resource(
+        "PUT",
+        "ingredient/2000/price",
+        &pool,
+        None,
+        Some(input.into()),
+    )
+    .await?;
+    assert_eq!(response, StatusCode::NO_CONTENT);
+
+    let recipe = recipe::query_single(&pool, 1, 1).await?;
+    assert_eq!(recipe.ingredients[0].ingredient.price, Some(80));
+    assert_eq!(recipe.cost, Some(40));
+
+    let carrot = ingredient::search(&pool, 2, "Carrot".into())
+        .await?
+        .into_iter()
+        .find(|i| i.id == 2000)
+        .unwrap();
+    assert_eq!(
+        carrot.price,
+        Some(50),
+        "a user's price should not affect other users"
+    );
+
+    // Clearing the price goes back to the retail price
+    let input = r#"{"price": null}"#.to_string();
+    let response = write_resource(
+        "PUT",
+        "ingredient/2000/price",
+        &pool,
+        None,
+        Some(input.into()),
+    )
+    .await?;
+    assert_eq!(response, StatusCode::NO_CONTENT);
+
+    let recipe = recipe::query_single(&pool, 1, 1).await?;
+    assert_eq!(recipe.ingredients[0].ingredient.price, Some(50));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn update_aisles(pool: Pool<Postgres>) -> Result<()> {
+    let input = r#"["Salt", "Flour"]"#.to_string();
+    let response = write_resource("PUT", "aisles", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::NO_CONTENT);
+
+    let aisles = ingredient::query_aisles(&pool, 1).await?;
+    assert_eq!(aisles[..3], ["Salt", "Flour", "Butter"]);
+    assert_eq!(
+        aisles.iter().filter(|a| *a == "Salt").count(),
+        1,
+        "ordered aisles should not be repeated"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount"))]
+fn update_diet_profile(pool: Pool<Postgres>) -> Result<()> {
+    let input = r#"{"vegetarian": true, "allergens": ["nuts", "dairy"]}"#.to_string();
+    let response = write_resource("PUT", "diet", &pool, None, Some(input.into())).await?;
+    assert_eq!(response, StatusCode::NO_CONTENT);
+
+    let profile = diet::query_profile(&pool, 1).await?;
+    assert!(profile.vegetarian);
+    assert!(!profile.vegan);
+    assert_eq!(
+        profile.allergens,
+        vec![diet::Allergen::Dairy, diet::Allergen::Nuts]
+    );
+    Ok(())
+}
+
 #[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
 fn update_recipe(pool: Pool<Postgres>) -> Result<()> {
     let input = include_str!("api/inputs/put-recipe.json").to_string();
@@ -206,7 +642,7 @@ fn update_recipe(pool: Pool<Postgres>) -> Result<()> {
         .filter(|r| r.id == 2)
         .map(|r| r.name.clone())
         .collect::<Vec<String>>();
-    let name = recipe.get(0).unwrap();
+    let name = recipe.first().unwrap();
 
     assert!(
         name == &"Renamed".to_string(),
@@ -215,6 +651,86 @@ fn update_recipe(pool: Pool<Postgres>) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn reject_stale_recipe_updates(pool: Pool<Postgres>) -> Result<()> {
+    let mut input: serde_json::Value =
+        serde_json::from_str(include_str!("api/inputs/put-recipe.json"))?;
+    input["version"] = 1.into();
+
+    for expected in [StatusCode::NO_CONTENT, StatusCode::CONFLICT] {
+        let body = Body::from(input.to_string());
+        let response = write_resource("PUT", "recipe/2", &pool, None, Some(body)).await?;
+        assert_eq!(response, expected);
+    }
+    assert_eq!(recipe::query_single(&pool, 1, 2).await?.version, 2);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn retried_writes_are_not_repeated(pool: Pool<Postgres>) -> Result<()> {
+    let input = include_str!("api/inputs/day.json");
+    let (status, _) = write_with_key("days", "plan-1", input, &pool).await?;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, replayed) = write_with_key("days", "plan-1", input, &pool).await?;
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(replayed, "the response should have been replayed");
+
+    let days = day::query_range(&pool, 1, "2023-06-18", "2023-06-18").await?;
+    assert_eq!(days[0].recipes.len(), 1, "the meal should be planned once");
+
+    // A key can't be reused for a different request.
+    let other = input.replace("2023-06-18", "2023-06-19");
+    let (status, _) = write_with_key("days", "plan-1", &other, &pool).await?;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn abandoned_keys_can_be_retried(pool: Pool<Postgres>) -> Result<()> {
+    let input = include_str!("api/inputs/day.json");
+    write_with_key("days", "plan-1", input, &pool).await?;
+    // As if the request had been dropped part way through, leaving its key held.
+    sqlx::query("UPDATE idempotency_key SET status = NULL, body = NULL")
+        .execute(&pool)
+        .await?;
+    let (status, _) = write_with_key("days", "plan-1", input, &pool).await?;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    sqlx::query("UPDATE idempotency_key SET created_at = now() - interval '2 minutes'")
+        .execute(&pool)
+        .await?;
+    let (status, replayed) = write_with_key("days", "plan-1", input, &pool).await?;
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(!replayed, "the request should have been handled");
+    Ok(())
+}
+
+/// POSTs a JSON body with an idempotency key. Returns the status, and whether the response was
+/// replayed from an earlier request.
+async fn write_with_key(
+    resource: &str,
+    key: &str,
+    body: &str,
+    pool: &Pool<Postgres>,
+) -> Result<(StatusCode, bool)> {
+    let db = Db { pool: pool.clone() };
+    let router = router::make_router(db, test_storage(), String::from("localhost"), false).await?;
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .header("Authorization", "Bearer abc")
+                .header("Content-Type", "application/json")
+                .header("Idempotency-Key", key)
+                .uri(format!("/{}", resource))
+                .body(Body::from(body.to_string()))?,
+        )
+        .await?;
+    let replayed = response.headers().contains_key("idempotent-replayed");
+    Ok((response.status(), replayed))
+}
+
 /// Hits an API route with the specified method and verified the response
 async fn write_resource(
     method: &str,
@@ -224,7 +740,7 @@ async fn write_resource(
     body: Option<Body>,
 ) -> Result<StatusCode> {
     let db = Db { pool: pool.clone() };
-    let router = router::make_router(db, String::from("localhost"), false).await?;
+    let router = router::make_router(db, test_storage(), String::from("localhost"), false).await?;
 
     // Construct URI.
     let path = match params {
@@ -0,0 +1,118 @@
+mod common;
+
+use std::error::Error;
+
+use axum::{
+    body::{Body, HttpBody},
+    http::{self, Request, StatusCode},
+};
+use hyper::body::Buf;
+use lembas::{
+    api::{
+        calendar,
+        day::{self, DayInput},
+        db::Db,
+        utils::today,
+    },
+    router,
+};
+use sqlx::{Pool, Postgres};
+use tower::util::ServiceExt;
+
+use crate::common::test_storage;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day"))]
+fn export_calendar_range(pool: Pool<Postgres>) -> Result<()> {
+    let ical = calendar::export_range(&pool, 1, "2023-11-01".into(), "2023-11-30".into()).await?;
+
+    assert!(ical.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ical.ends_with("END:VCALENDAR\r\n"));
+    assert_eq!(
+        ical.matches("BEGIN:VEVENT").count(),
+        3,
+        "each planned recipe should be an event"
+    );
+    assert!(ical.contains("UID:20231109-1@lembas\r\n"));
+    assert!(
+        ical.contains(&format!("DTSTAMP:{}T", today()?.replace('-', ""))),
+        "events should be stamped with when the file was generated"
+    );
+    assert!(ical.contains("DTSTART;VALUE=DATE:20231116\r\nDTEND;VALUE=DATE:20231117\r\n"));
+    assert!(ical.contains("SUMMARY:Six Carrots\r\n"));
+    assert!(ical.contains("DESCRIPTION:Ingredients:\\n- 6 Carrot\r\n"));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "sub_recipe"))]
+fn describe_sub_recipe_ingredients(pool: Pool<Postgres>) -> Result<()> {
+    let ical = calendar::export_range(&pool, 1, "2024-01-10".into(), "2024-01-10".into()).await?;
+
+    // The calzone's own salt, with the pizza and dough it's made from
+    assert!(ical.contains("SUMMARY:Calzone\r\n"));
+    assert!(ical.contains("DESCRIPTION:Ingredients:\\n- 6g Salt\\n- 250g Flour\\n- 150g Water\r\n"));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn subscribe_to_calendar(pool: Pool<Postgres>) -> Result<()> {
+    // Feeds only cover the weeks around today.
+    let day_input = DayInput {
+        recipe_id: 1,
+        date: today()?,
+        portions: None,
+        leftovers_from: None,
+    };
+    day::create(&pool, 1, day_input).await?;
+
+    let (status, body) = request("POST", "/calendar/token", &pool, true).await?;
+    assert_eq!(status, StatusCode::CREATED);
+    let token: serde_json::Value = serde_json::from_str(&body)?;
+    let path = token["path"].as_str().unwrap().to_string();
+
+    let (status, body) = request("GET", &path, &pool, false).await?;
+    assert_eq!(
+        status,
+        StatusCode::OK,
+        "the feed should be available without a bearer token"
+    );
+    assert!(body.contains("SUMMARY:Five Carrots\r\n"));
+
+    let (status, _) = request("DELETE", "/calendar/token", &pool, true).await?;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = request("GET", &path, &pool, false).await?;
+    assert_eq!(
+        status,
+        StatusCode::NOT_FOUND,
+        "the feed should not be available once the token is revoked"
+    );
+    Ok(())
+}
+
+/// Makes a request to the router, optionally with a bearer token. Returns the status and body.
+async fn request(
+    method: &str,
+    path: &str,
+    pool: &Pool<Postgres>,
+    authorised: bool,
+) -> Result<(StatusCode, String)> {
+    let db = Db { pool: pool.clone() };
+    let router = router::make_router(db, test_storage(), String::from("localhost"), false).await?;
+
+    let uri = http::uri::Builder::new().path_and_query(path).build()?;
+    let mut request = Request::builder().method(method).uri(uri);
+    if authorised {
+        request = request.header("Authorization", "Bearer abc");
+    }
+
+    let mut response = router.oneshot(request.body(Body::empty())?).await?;
+    let status = response.status();
+    let body = match response.body_mut().data().await {
+        Some(data) => String::from_utf8_lossy(data?.chunk()).to_string(),
+        None => String::new(),
+    };
+
+    Ok((status, body))
+}
@@ -1,25 +1,49 @@
-use std::collections::HashMap;
+#![allow(dead_code)]
 
-use lembas::api::ingredient::Ingredient;
+use std::{collections::HashMap, env, sync::Arc};
+
+use lembas::api::{
+    diet::Allergen,
+    ingredient::Ingredient,
+    nutrition::Nutrition,
+    recipe::Step,
+    storage::{ImageStorage, LocalStorage},
+};
 
 /// Resolves a map of IDs to ingredients based on the test data loaded from fixtures/ingredient
 pub fn test_ingredients() -> HashMap<String, Ingredient> {
     HashMap::from([
         (
             "Carrot".into(),
-            Ingredient::new(2000, "Carrot".into(), None, None, 1, 10, 10),
+            Ingredient::new(2000, "Carrot".into(), None, None, 1, 10, 10)
+                .with_category(Some("Fresh Vegetables".into()))
+                .with_price(Some(50)),
         ),
         (
             "Flour".into(),
-            Ingredient::new(2001, "Flour".into(), None, Some("g".into()), 1000, 1000, 50),
+            Ingredient::new(2001, "Flour".into(), None, Some("g".into()), 1000, 1000, 50)
+                .with_category(Some("Flour".into()))
+                .with_price(Some(90))
+                .with_nutrition(Some(Nutrition {
+                    energy: 341.0,
+                    protein: 10.0,
+                    fat: 1.3,
+                    carbohydrate: 70.0,
+                    fibre: 3.1,
+                    salt: 0.01,
+                }))
+                .with_diet(vec![Allergen::Gluten], true, true),
         ),
         (
             "Water".into(),
-            Ingredient::new(2002, "Water".into(), None, Some("g".into()), 0, 0, 100),
+            Ingredient::new(2002, "Water".into(), None, Some("g".into()), 0, 0, 100)
+                .with_nutrition(Some(Nutrition::default())),
         ),
         (
             "Salt".into(),
-            Ingredient::new(2003, "Salt".into(), None, Some("g".into()), 0, 250, 7),
+            Ingredient::new(2003, "Salt".into(), None, Some("g".into()), 0, 250, 7)
+                .with_category(Some("Salt".into()))
+                .with_price(Some(65)),
         ),
         (
             "Apple".into(),
@@ -27,3 +51,21 @@ pub fn test_ingredients() -> HashMap<String, Ingredient> {
         ),
     ])
 }
+
+/// Builds a step with only an instruction.
+pub fn step(text: &str) -> Step {
+    Step {
+        section: None,
+        text: text.into(),
+        duration: None,
+        timer: false,
+        ingredients: vec![],
+    }
+}
+
+/// Image storage in a temporary directory. Stored names are random, so tests can share it.
+pub fn test_storage() -> Arc<dyn ImageStorage> {
+    Arc::new(LocalStorage::new(
+        env::temp_dir().join("lembas-test-images"),
+    ))
+}
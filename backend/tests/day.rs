@@ -1,6 +1,12 @@
 mod common;
 
-use lembas::api::day::{self, DayInput};
+use lembas::api::{
+    day::{self, DayInput, MealRecordInput, MealStatus, OperationResult, UsedQuantity},
+    diet::{self, Allergen, DietProfile},
+    ingredient,
+    plan::{self, CopyInput},
+    recipe,
+};
 use sqlx::{Pool, Postgres};
 
 /// Sets up a recipe that uses 5 carrots (half of the minimum purchase)
@@ -10,8 +16,10 @@ async fn setup_meal_plan(pool: &Pool<Postgres>) -> sqlx::Result<()> {
     let day_input = DayInput {
         recipe_id: 1,
         date: "2020-01-10".into(),
+        portions: None,
+        leftovers_from: None,
     };
-    day::create(&pool, 1, day_input).await.unwrap();
+    day::create(pool, 1, day_input).await.unwrap();
 
     Ok(())
 }
@@ -22,7 +30,7 @@ fn generate_list_without_surplus(pool: Pool<Postgres>) -> sqlx::Result<()> {
     let list = day::build_list_for_range(&pool, 1, "2020-01-04".into(), "2020-01-11".into())
         .await
         .unwrap();
-    let ingredient = list.ingredients.get(0).unwrap();
+    let ingredient = list.ingredients.first().unwrap();
 
     assert_eq!(ingredient.used_quantity, 5);
     assert_eq!(
@@ -40,6 +48,8 @@ fn generate_list_with_exact_previous_surplus(pool: Pool<Postgres>) -> sqlx::Resu
     let later_day_input = DayInput {
         recipe_id: 1,
         date: "2020-01-15".into(),
+        portions: None,
+        leftovers_from: None,
     };
     day::create(&pool, 1, later_day_input).await.unwrap();
 
@@ -51,7 +61,7 @@ fn generate_list_with_exact_previous_surplus(pool: Pool<Postgres>) -> sqlx::Resu
     let list = day::build_list_for_range(&pool, 1, "2020-01-11".into(), "2020-01-16".into())
         .await
         .unwrap();
-    let ingredient = list.ingredients.get(0).unwrap();
+    let ingredient = list.ingredients.first().unwrap();
 
     assert_eq!(ingredient.used_quantity, 5);
     assert_eq!(
@@ -69,6 +79,8 @@ fn generate_list_with_insufficient_previous_surplus(pool: Pool<Postgres>) -> sql
     let later_day_input = DayInput {
         recipe_id: 2,
         date: "2020-01-15".into(),
+        portions: None,
+        leftovers_from: None,
     };
     day::create(&pool, 1, later_day_input).await.unwrap();
 
@@ -80,7 +92,7 @@ fn generate_list_with_insufficient_previous_surplus(pool: Pool<Postgres>) -> sql
     let list = day::build_list_for_range(&pool, 1, "2020-01-11".into(), "2020-01-16".into())
         .await
         .unwrap();
-    let ingredient = list.ingredients.get(0).unwrap();
+    let ingredient = list.ingredients.first().unwrap();
 
     assert_eq!(ingredient.used_quantity, 6);
     assert_eq!(
@@ -98,6 +110,8 @@ fn generate_list_with_expired_surplus(pool: Pool<Postgres>) -> sqlx::Result<()>
     let later_day_input = DayInput {
         recipe_id: 1,
         date: "2020-01-30".into(),
+        portions: None,
+        leftovers_from: None,
     };
     day::create(&pool, 1, later_day_input).await.unwrap();
 
@@ -108,7 +122,7 @@ fn generate_list_with_expired_surplus(pool: Pool<Postgres>) -> sqlx::Result<()>
     let list = day::build_list_for_range(&pool, 1, "2020-01-29".into(), "2020-02-01".into())
         .await
         .unwrap();
-    let ingredient = list.ingredients.get(0).unwrap();
+    let ingredient = list.ingredients.first().unwrap();
 
     assert_eq!(ingredient.used_quantity, 5);
     assert_eq!(
@@ -117,3 +131,514 @@ fn generate_list_with_expired_surplus(pool: Pool<Postgres>) -> sqlx::Result<()>
     );
     Ok(())
 }
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "family"))]
+fn generate_list_with_family_packs(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let day_input = DayInput {
+        recipe_id: 3,
+        date: "2020-01-10".into(),
+        portions: None,
+        leftovers_from: None,
+    };
+    day::create(&pool, 1, day_input).await.unwrap();
+
+    /* The list should:
+      - Treat the recipe's milk as the family, rather than the 2270ml pack it references
+      - Cover the 3000ml needed with three 1130ml packs, wasting the least
+    */
+    let list = day::build_list_for_range(&pool, 1, "2020-01-04".into(), "2020-01-11".into())
+        .await
+        .unwrap();
+    let ingredient = list.ingredients.first().unwrap();
+
+    assert_eq!(ingredient.used_quantity, 3000);
+    assert_eq!(
+        ingredient.purchase_quantity, 3390,
+        "should have chosen the pack combination with the least waste"
+    );
+    let packs = ingredient
+        .packs
+        .iter()
+        .map(|p| (p.ingredient.id, p.count))
+        .collect::<Vec<(i32, i32)>>();
+    assert_eq!(
+        packs,
+        vec![(2005, 3)],
+        "should have bought three small packs"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "family"))]
+fn reference_family_through_any_member(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    // The same rice pudding, written with the small pack of milk rather than the large one
+    let input = recipe::RecipeInput {
+        name: "Rice Pudding".into(),
+        portions: 4,
+        steps: vec![],
+        ingredients: vec![recipe::RecipeIngredientInput {
+            id: 2005,
+            quantity: 3000,
+        }],
+        sub_recipes: vec![],
+        metadata: Default::default(),
+        version: None,
+    };
+    let small_id = recipe::create(&pool, 1, input).await?;
+
+    let mut lists = vec![];
+    for (recipe_id, date) in [(3, "2020-01-10"), (small_id, "2020-02-10")] {
+        let day_input = DayInput {
+            recipe_id,
+            date: date.into(),
+            portions: None,
+            leftovers_from: None,
+        };
+        day::create(&pool, 1, day_input).await.unwrap();
+        let list = day::build_list_for_range(&pool, 1, date.into(), date.into())
+            .await
+            .unwrap();
+        let summary: Vec<(i32, i32, i32)> = list
+            .ingredients
+            .iter()
+            .map(|i| (i.ingredient.id, i.used_quantity, i.purchase_quantity))
+            .collect();
+        lists.push(summary);
+    }
+    assert_eq!(
+        lists[0], lists[1],
+        "either pack size should stand for the family on the list"
+    );
+    assert_eq!(lists[0], vec![(2005, 3000, 3390)]);
+
+    // Both recipes on the same day share one entry for the family
+    let day_input = DayInput {
+        recipe_id: small_id,
+        date: "2020-01-10".into(),
+        portions: None,
+        leftovers_from: None,
+    };
+    day::create(&pool, 1, day_input).await.unwrap();
+    let list = day::build_list_for_range(&pool, 1, "2020-01-10".into(), "2020-01-10".into())
+        .await
+        .unwrap();
+    assert_eq!(list.ingredients.len(), 1);
+    assert_eq!(list.ingredients[0].used_quantity, 6000);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn generate_list_with_projected_waste(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    setup_meal_plan(&pool).await?;
+
+    /* The list should:
+      - Buy 10 carrots for a recipe that uses 5
+      - Find no later meals to use the other 5, so project them as waste
+    */
+    let list = day::build_list_for_range(&pool, 1, "2020-01-04".into(), "2020-01-11".into())
+        .await
+        .unwrap();
+    let ingredient = list.ingredients.first().unwrap();
+
+    assert_eq!(
+        ingredient.projected_waste, 5,
+        "should have projected the unused carrots as waste"
+    );
+
+    let waste = day::waste_for_range(&pool, 1, "2020-01-04".into(), "2020-01-11".into())
+        .await
+        .unwrap();
+    let recipe = waste.first().unwrap();
+
+    assert_eq!(recipe.recipe_id, 1);
+    assert_eq!(recipe.dates, vec![String::from("2020-01-10")]);
+    assert_eq!(
+        recipe.packs_wasted, 0.5,
+        "the recipe should be responsible for half a pack of carrots"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn generate_list_with_waste_used_later(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    setup_meal_plan(&pool).await?;
+
+    // Make the recipe again 5 days later, after the list's range.
+    let later_day_input = DayInput {
+        recipe_id: 1,
+        date: "2020-01-15".into(),
+        portions: None,
+        leftovers_from: None,
+    };
+    day::create(&pool, 1, later_day_input).await.unwrap();
+
+    /* The list should:
+      - Buy 10 carrots for a recipe that uses 5
+      - Find the later meal uses the other 5 before they expire
+    */
+    let list = day::build_list_for_range(&pool, 1, "2020-01-04".into(), "2020-01-11".into())
+        .await
+        .unwrap();
+    let ingredient = list.ingredients.first().unwrap();
+
+    assert_eq!(
+        ingredient.projected_waste, 0,
+        "leftovers used by later meals should not be projected as waste"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn rank_cookable_recipes(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    // Leaves 5 carrots on the 10th.
+    setup_meal_plan(&pool).await?;
+
+    let cookable = day::cookable_recipes(&pool, 1, "2020-01-11".into())
+        .await
+        .unwrap();
+    let ranked: Vec<i32> = cookable.iter().map(|c| c.recipe.id).collect();
+    assert_eq!(ranked, vec![1, 2], "recipes should be ranked by coverage");
+
+    assert_eq!(cookable[0].coverage, 1.0);
+    assert!(cookable[0].missing.is_empty());
+
+    let missing = cookable[1].missing.first().unwrap();
+    assert_eq!(missing.quantity, 1);
+    assert_eq!(
+        missing.purchase_quantity, 10,
+        "should suggest buying a pack to cover the missing carrot"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn generate_list_sorted_by_aisle(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    // Uses flour, water and salt.
+    let input = serde_json::from_str(include_str!("api/inputs/post-recipe.json")).unwrap();
+    let recipe_id = recipe::create(&pool, 1, input).await?;
+    let day_input = DayInput {
+        recipe_id,
+        date: "2020-01-10".into(),
+        portions: None,
+        leftovers_from: None,
+    };
+    day::create(&pool, 1, day_input).await.unwrap();
+
+    let list = day::build_list_for_range(&pool, 1, "2020-01-10".into(), "2020-01-10".into())
+        .await
+        .unwrap();
+    let ids: Vec<i32> = list.ingredients.iter().map(|i| i.ingredient.id).collect();
+    assert_eq!(
+        ids,
+        vec![2001, 2003, 2002],
+        "aisles should default to the order they were scraped in, with uncategorised ingredients last"
+    );
+
+    ingredient::update_aisles(&pool, 1, vec!["Salt".into()]).await?;
+    let list = day::build_list_for_range(&pool, 1, "2020-01-10".into(), "2020-01-10".into())
+        .await
+        .unwrap();
+    let ids: Vec<i32> = list.ingredients.iter().map(|i| i.ingredient.id).collect();
+    assert_eq!(
+        ids,
+        vec![2003, 2001, 2002],
+        "aisles the user has ordered should come first"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn summarise_day_nutrition(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    // Carrots are counted rather than weighed, so add nothing to the summary.
+    setup_meal_plan(&pool).await?;
+    let input = serde_json::from_str(include_str!("api/inputs/post-recipe.json")).unwrap();
+    let recipe_id = recipe::create(&pool, 1, input).await?;
+    let day_input = DayInput {
+        recipe_id,
+        date: "2020-01-10".into(),
+        portions: None,
+        leftovers_from: None,
+    };
+    day::create(&pool, 1, day_input).await.unwrap();
+
+    let days = day::query_range(&pool, 1, "2020-01-10", "2020-01-10")
+        .await
+        .unwrap();
+    assert_eq!(days[0].recipes.len(), 2);
+    assert_eq!(
+        days[0].nutrition.energy, 852.5,
+        "a day should total one portion of each recipe"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn warn_about_diet(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    setup_meal_plan(&pool).await?;
+    let input = serde_json::from_str(include_str!("api/inputs/post-recipe.json")).unwrap();
+    let recipe_id = recipe::create(&pool, 1, input).await?;
+    let day_input = DayInput {
+        recipe_id,
+        date: "2020-01-10".into(),
+        portions: None,
+        leftovers_from: None,
+    };
+    day::create(&pool, 1, day_input).await.unwrap();
+
+    let profile = DietProfile {
+        vegetarian: true,
+        vegan: false,
+        allergens: vec![Allergen::Gluten],
+    };
+    diet::update_profile(&pool, 1, profile).await?;
+
+    let days = day::query_range(&pool, 1, "2020-01-10", "2020-01-10")
+        .await
+        .unwrap();
+    let warned: Vec<i32> = days[0].warnings.iter().map(|w| w.recipe_id).collect();
+    assert_eq!(
+        warned,
+        vec![recipe_id],
+        "only the recipe containing gluten should be warned about"
+    );
+    assert_eq!(days[0].warnings[0].allergens, vec![Allergen::Gluten]);
+    assert!(!days[0].warnings[0].not_vegetarian);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn plan_leftovers(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    // Uses flour, water and salt. Cook four portions, eat two, and save two for the next day.
+    let mut input: recipe::RecipeInput =
+        serde_json::from_str(include_str!("api/inputs/post-recipe.json")).unwrap();
+    input.portions = 4;
+    let recipe_id = recipe::create(&pool, 1, input).await?;
+    let cooked = DayInput {
+        recipe_id,
+        date: "2020-01-10".into(),
+        portions: Some(2),
+        leftovers_from: None,
+    };
+    day::create(&pool, 1, cooked).await.unwrap();
+    let leftovers = DayInput {
+        recipe_id,
+        date: "2020-01-11".into(),
+        portions: Some(2),
+        leftovers_from: Some("2020-01-10".into()),
+    };
+    assert_eq!(day::check_leftovers(&pool, 1, &leftovers).await?, Ok(()));
+    day::create(&pool, 1, leftovers).await.unwrap();
+
+    let days = day::query_range(&pool, 1, "2020-01-10", "2020-01-11")
+        .await
+        .unwrap();
+    assert!(days[1].recipes.is_empty());
+    let eaten = &days[1].leftovers[0];
+    assert_eq!(eaten.cooked_on, "2020-01-10");
+    assert_eq!(eaten.remaining_portions, 0);
+    assert_eq!(
+        days[1].nutrition, days[0].nutrition,
+        "leftovers should count towards the day's nutrition"
+    );
+
+    let list = day::build_list_for_range(&pool, 1, "2020-01-10".into(), "2020-01-11".into())
+        .await
+        .unwrap();
+    let flour = list.ingredients.first().unwrap();
+    assert_eq!(
+        flour.used_quantity, 500,
+        "the recipe should only be cooked once"
+    );
+
+    let more_leftovers = DayInput {
+        recipe_id,
+        date: "2020-01-12".into(),
+        portions: None,
+        leftovers_from: Some("2020-01-10".into()),
+    };
+    assert!(day::check_leftovers(&pool, 1, &more_leftovers)
+        .await?
+        .is_err());
+    let too_early = DayInput {
+        recipe_id,
+        date: "2020-01-09".into(),
+        portions: None,
+        leftovers_from: Some("2020-01-10".into()),
+    };
+    assert!(day::check_leftovers(&pool, 1, &too_early).await?.is_err());
+
+    // Copied leftovers are eaten from the copy of the meal they were cooked at
+    let copy = CopyInput {
+        from: "2020-01-10".into(),
+        to: "2020-01-11".into(),
+        start_date: "2020-01-17".into(),
+    };
+    plan::copy_days(&pool, 1, copy).await.unwrap();
+    let days = day::query_range(&pool, 1, "2020-01-17", "2020-01-18")
+        .await
+        .unwrap();
+    assert_eq!(days[0].recipes.len(), 1);
+    assert_eq!(days[1].leftovers[0].cooked_on, "2020-01-17");
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn skipped_meals_leave_surplus(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    setup_meal_plan(&pool).await?;
+    let later_day_input = DayInput {
+        recipe_id: 2,
+        date: "2020-01-15".into(),
+        portions: None,
+        leftovers_from: None,
+    };
+    day::create(&pool, 1, later_day_input).await.unwrap();
+
+    // The ten carrots bought for the 10th were never used, so cover the six needed.
+    let record = MealRecordInput {
+        status: MealStatus::Skipped,
+        used: vec![],
+    };
+    let recorded = day::record_meal(&pool, 1, "2020-01-10".into(), 1, record)
+        .await
+        .unwrap();
+    assert_eq!(recorded, Ok(true));
+
+    let list = day::build_list_for_range(&pool, 1, "2020-01-11".into(), "2020-01-16".into())
+        .await
+        .unwrap();
+    let ingredient = list.ingredients.first().unwrap();
+    assert_eq!(ingredient.existing_surplus, 10);
+    assert_eq!(ingredient.purchase_quantity, 0);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn cooked_meals_use_recorded_amounts(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    setup_meal_plan(&pool).await?;
+    let later_day_input = DayInput {
+        recipe_id: 2,
+        date: "2020-01-15".into(),
+        portions: None,
+        leftovers_from: None,
+    };
+    day::create(&pool, 1, later_day_input).await.unwrap();
+
+    // Only two of the five carrots were used, leaving eight.
+    let record = MealRecordInput {
+        status: MealStatus::Cooked,
+        used: vec![UsedQuantity {
+            id: 2000,
+            quantity: 2,
+        }],
+    };
+    day::record_meal(&pool, 1, "2020-01-10".into(), 1, record)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let days = day::query_range(&pool, 1, "2020-01-10", "2020-01-10")
+        .await
+        .unwrap();
+    assert_eq!(days[0].records[0].status, MealStatus::Cooked);
+
+    let list = day::build_list_for_range(&pool, 1, "2020-01-11".into(), "2020-01-16".into())
+        .await
+        .unwrap();
+    let ingredient = list.ingredients.first().unwrap();
+    assert_eq!(ingredient.existing_surplus, 8);
+    assert_eq!(ingredient.purchase_quantity, 0);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn reject_invalid_meal_records(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    setup_meal_plan(&pool).await?;
+    let used = |id| vec![UsedQuantity { id, quantity: 1 }];
+
+    let record = MealRecordInput {
+        status: MealStatus::Cooked,
+        used: used(2001),
+    };
+    let result = day::record_meal(&pool, 1, "2020-01-10".into(), 1, record)
+        .await
+        .unwrap();
+    assert!(result.is_err(), "the recipe doesn't use flour");
+
+    let record = MealRecordInput {
+        status: MealStatus::Skipped,
+        used: used(2000),
+    };
+    let result = day::record_meal(&pool, 1, "2020-01-10".into(), 1, record)
+        .await
+        .unwrap();
+    assert!(result.is_err(), "skipped meals use nothing");
+
+    let record = MealRecordInput {
+        status: MealStatus::Cooked,
+        used: vec![],
+    };
+    let result = day::record_meal(&pool, 1, "2020-01-11".into(), 1, record)
+        .await
+        .unwrap();
+    assert_eq!(result, Ok(false), "nothing is planned on the 11th");
+    Ok(())
+}
+
+/// Lists the recipes planned on each day in November 2023.
+async fn planned_in_november(pool: &Pool<Postgres>) -> Vec<(String, Vec<i32>)> {
+    day::query_range(pool, 1, "2023-11-01", "2023-11-30")
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|d| (d.date, d.recipes.iter().map(|r| r.id).collect()))
+        .collect()
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day"))]
+fn apply_plan_batch(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let operations = serde_json::from_value(serde_json::json!([
+        { "op": "add", "recipe_id": 2, "date": "2023-11-20" },
+        { "op": "move", "recipe_id": 1, "from": "2023-11-16", "to": "2023-11-18" },
+        { "op": "delete", "recipe_id": 2, "date": "2023-11-09" },
+    ]))
+    .unwrap();
+
+    let batch = day::apply_batch(&pool, 1, operations).await?.unwrap();
+    assert!(batch.applied);
+    assert_eq!(batch.results, vec![OperationResult::Ok; 3]);
+    assert_eq!(
+        planned_in_november(&pool).await,
+        vec![
+            ("2023-11-09".into(), vec![1]),
+            ("2023-11-18".into(), vec![1]),
+            ("2023-11-20".into(), vec![2]),
+        ]
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day"))]
+fn failed_batches_change_nothing(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let before = planned_in_november(&pool).await;
+    let operations = serde_json::from_value(serde_json::json!([
+        { "op": "add", "recipe_id": 2, "date": "2023-11-20" },
+        { "op": "delete", "recipe_id": 2, "date": "2023-11-30" },
+        { "op": "delete", "recipe_id": 1, "date": "2023-11-09" },
+    ]))
+    .unwrap();
+
+    let batch = day::apply_batch(&pool, 1, operations).await?.unwrap();
+    assert!(!batch.applied);
+    assert_eq!(
+        batch.results,
+        vec![
+            OperationResult::Ok,
+            OperationResult::Failed {
+                error: "The recipe isn't planned on 2023-11-30.".into()
+            },
+            OperationResult::Skipped,
+        ]
+    );
+    assert_eq!(planned_in_november(&pool).await, before);
+    Ok(())
+}
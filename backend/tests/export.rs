@@ -0,0 +1,61 @@
+mod common;
+
+use lembas::api::{
+    day::{self, ShoppingList},
+    export::{self, ListFormat},
+};
+use sqlx::{Pool, Postgres};
+
+/// Builds a list where carrots are covered by surplus from the 9th, and also scheduled.
+async fn build_list(pool: &Pool<Postgres>) -> ShoppingList {
+    day::build_list_for_range(pool, 1, "2023-11-12".into(), "2023-11-16".into())
+        .await
+        .unwrap()
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day", "ingredientschedule"))]
+fn export_list_as_text(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let list = build_list(&pool).await;
+    let text = export::render(&list, ListFormat::Text);
+
+    assert_eq!(
+        text,
+        "Ingredients\n\
+        -- Fresh Vegetables --\n\
+        - Carrot: nothing to buy (9 already in surplus)\n\
+        \n\
+        Scheduled\n\
+        -- Fresh Vegetables --\n\
+        - Carrot: 1 × 10\n"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day", "ingredientschedule"))]
+fn export_list_as_csv(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let list = build_list(&pool).await;
+    let csv = export::render(&list, ListFormat::Csv);
+
+    assert_eq!(
+        csv,
+        "section,aisle,ingredient,unit,purchase_quantity,packs,used_quantity,existing_surplus\n\
+        Ingredients,Fresh Vegetables,Carrot,,0,,5,9\n\
+        Scheduled,Fresh Vegetables,Carrot,,10,1 × 10,10,0\n"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day", "ingredientschedule"))]
+fn export_list_as_markdown_and_html(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let list = build_list(&pool).await;
+
+    let markdown = export::render(&list, ListFormat::Md);
+    assert!(markdown.starts_with("# Shopping List\n"));
+    assert!(markdown.contains("## Scheduled\n\n### Fresh Vegetables\n\n- [ ] **Carrot**: 1 × 10\n"));
+
+    let html = export::render(&list, ListFormat::Html);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<h2>Ingredients</h2>\n<h3>Fresh Vegetables</h3>\n<ul>"));
+    assert!(html.contains("<span class=\"note\">(9 already in surplus)</span>"));
+    Ok(())
+}
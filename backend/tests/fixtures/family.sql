@@ -0,0 +1,19 @@
+-- Inserts milk in two pack sizes, grouped into a family, and a recipe that uses 3000ml.
+INSERT INTO "ingredient_family" 
+("id", 	"user_id", 	"name",	"unit") VALUES
+(1000,	NULL,		'Milk',	'ml');
+
+INSERT INTO "ingredient" 
+("id", 	"user_id", 	"name", 	"unit",	"minimum_quantity", "purchase_quantity","life",	"family_id") VALUES
+(2005,		NULL,		'Milk', 	'ml',	1,					1130,				7,		1000),
+(2006,		NULL,		'Milk', 	'ml',	1,					2270,				7,		1000);
+
+INSERT INTO "recipe" 
+("id", 	"user_id", 	"name",			"portions",	"steps",	"created_at") VALUES
+(3, 	1,			'Rice Pudding',	4,			'[]',		'2024-05-12 14:30:00');
+
+INSERT INTO "recipe_ingredient" 
+("recipe_id", 	"ingredient_id", 	"quantity") VALUES
+(3, 			2006,					3000);
+
+ALTER SEQUENCE recipe_id_seq RESTART WITH 4;
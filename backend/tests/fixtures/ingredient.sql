@@ -1,8 +1,15 @@
 INSERT INTO "ingredient" 
-("id", 	"user_id", 	"name", 	"unit",	"minimum_quantity", "purchase_quantity","life") VALUES
-(2000,		NULL,		'Carrot', 	NULL,	1,					10,					10),
-(2001,		NULL,		'Flour',	'g',	1,					1000,				50),
-(2002,		NULL,		'Water',	'g',	0,					0,					100),
-(2003,		NULL,		'Salt', 	'g',	0,					250,				7),
+("id", 	"user_id", 	"name", 	"unit",	"minimum_quantity", "purchase_quantity","life",	"category",			"price") VALUES
+(2000,		NULL,		'Carrot', 	NULL,	1,					10,					10,		'Fresh Vegetables',	50),
+(2001,		NULL,		'Flour',	'g',	1,					1000,				50,		'Flour',			90),
+(2002,		NULL,		'Water',	'g',	0,					0,					100,	NULL,				NULL),
+(2003,		NULL,		'Salt', 	'g',	0,					250,				7,		'Salt',				65),
 
-(2004,		1,			'Apples', 	NULL,	0,					250,				7);
\ No newline at end of file
+(2004,		1,			'Apples', 	NULL,	0,					250,				7,		NULL,				NULL);
+
+-- Nutrition per 100g for the ingredients measured by weight.
+UPDATE "ingredient" SET "energy" = 341, "protein" = 10, "fat" = 1.3, "carbohydrate" = 70, "fibre" = 3.1, "salt" = 0.01 WHERE "id" = 2001;
+UPDATE "ingredient" SET "energy" = 0, "protein" = 0, "fat" = 0, "carbohydrate" = 0, "fibre" = 0, "salt" = 0 WHERE "id" = 2002;
+
+-- Flour contains gluten.
+UPDATE "ingredient" SET "allergens" = '{gluten}' WHERE "id" = 2001;
@@ -1,8 +1,8 @@
--- Inserts a recipe that uses 5 carrots, half the minimum purchase quantity.
+-- Inserts a recipe that uses 5 carrots, half the minimum purchase quantity, and takes 10 minutes.
 INSERT INTO "recipe" 
-("id", 	"user_id", 	"name",			"portions",	"steps") VALUES
-(1, 	1,			'Five Carrots',	1,			'[]'),
-(2, 	1,			'Six Carrots',	1,			'[]');
+("id", 	"user_id", 	"name",			"portions",	"steps",	"total_time",	"created_at") VALUES
+(1, 	1,			'Five Carrots',	1,			'[]',		10,				'2023-11-02 09:30:00'),
+(2, 	1,			'Six Carrots',	1,			'[]',		NULL,			'2023-11-01 18:00:00');
 
 -- Quantities for the above recipes.
 INSERT INTO "recipe_ingredient" 
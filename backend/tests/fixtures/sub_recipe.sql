@@ -0,0 +1,20 @@
+-- Inserts a pizza dough, a pizza that uses half of it, and a calzone made from a whole pizza.
+INSERT INTO "recipe" 
+("id", 	"user_id", 	"name",			"portions",	"steps",	"created_at") VALUES
+(20, 	1,			'Pizza Dough',	4,			'[]',		'2024-01-01 12:00:00'),
+(21, 	1,			'Pizza',		2,			'[]',		'2024-01-01 12:00:00'),
+(22, 	1,			'Calzone',		1,			'[]',		'2024-01-02 12:00:00');
+
+INSERT INTO "recipe_ingredient" 
+("recipe_id", 	"ingredient_id", 	"sub_recipe_id",	"quantity") VALUES
+(20, 			2001,				NULL,				500),
+(20, 			2002,				NULL,				300),
+(21, 			2003,				NULL,				5),
+(21, 			NULL,				20,					2),
+(22, 			2003,				NULL,				1),
+(22, 			NULL,				21,					2);
+
+INSERT INTO "day" ("recipe_id", "date") VALUES 
+(22,	'2024-01-10');
+
+ALTER SEQUENCE recipe_id_seq RESTART WITH 23;
@@ -0,0 +1,11 @@
+-- Inserts a template that plans the two carrot recipes two days apart.
+INSERT INTO "plan_template" 
+("id", 	"user_id", 	"name") VALUES
+(1000, 	1,			'Carrot Week');
+
+INSERT INTO "plan_template_day" 
+("template_id", 	"day_offset", 	"recipe_id") VALUES
+(1000, 				0,				1),
+(1000, 				2,				2);
+
+ALTER SEQUENCE plan_template_id_seq RESTART WITH 1001;
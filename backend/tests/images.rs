@@ -0,0 +1,336 @@
+mod common;
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    io::Cursor,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use axum::{
+    body::{Body, Bytes, HttpBody},
+    extract::{Path, State},
+    http::{self, header, HeaderMap, Request, StatusCode},
+    routing::get,
+    Router,
+};
+use image::{ImageOutputFormat, RgbImage};
+use lembas::{
+    api::{
+        db::Db,
+        recipe,
+        storage::{ImageStorage, S3Storage, StorageError},
+    },
+    router,
+};
+use sha2::{Digest, Sha256};
+use sqlx::{Pool, Postgres};
+use tower::util::ServiceExt;
+
+use crate::common::test_storage;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+const BOUNDARY: &str = "lembas-test-boundary";
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn upload_and_fetch_recipe_image(pool: Pool<Postgres>) -> Result<()> {
+    let body = form(&[("image", Some("image/png"), &png(640, 480))]);
+    let (status, _, body) = request("POST", "/recipe/1/images", &pool, Some(body)).await?;
+    assert_eq!(status, StatusCode::CREATED);
+    let uploaded: serde_json::Value = serde_json::from_slice(&body)?;
+
+    let recipe = recipe::query_single(&pool, 1, 1).await?;
+    assert_eq!(recipe.images.len(), 1);
+    let image = &recipe.images[0];
+    assert_eq!(uploaded["url"], image.url.as_str());
+    assert_eq!(image.step, None);
+
+    let (status, headers, original) = request("GET", &image.url, &pool, None).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers[header::CONTENT_TYPE], "image/png");
+    assert_eq!(image::load_from_memory(&original)?.width(), 640);
+
+    // Thumbnails keep the aspect ratio of the original.
+    let (status, headers, thumbnail) = request("GET", &image.thumbnail_url, &pool, None).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers[header::CONTENT_TYPE], "image/jpeg");
+    let thumbnail = image::load_from_memory(&thumbnail)?;
+    assert_eq!((thumbnail.width(), thumbnail.height()), (320, 240));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn cached_images_are_not_resent(pool: Pool<Postgres>) -> Result<()> {
+    let body = form(&[("image", Some("image/png"), &png(10, 10))]);
+    let (status, _, _) = request("POST", "/recipe/1/images", &pool, Some(body)).await?;
+    assert_eq!(status, StatusCode::CREATED);
+    let url = recipe::query_single(&pool, 1, 1).await?.images[0]
+        .url
+        .clone();
+
+    // Images are tagged by their name, as they never change
+    let (_, headers, _) = request("GET", &url, &pool, None).await?;
+    let tag = headers[header::ETAG].clone();
+    assert_eq!(tag, format!("\"{}\"", url.trim_start_matches("/images/")));
+
+    let db = Db { pool: pool.clone() };
+    let router = router::make_router(db, test_storage(), String::from("localhost"), false).await?;
+    let request = Request::builder()
+        .uri(url.as_str())
+        .header(header::IF_NONE_MATCH, tag.clone())
+        .body(Body::empty())?;
+    let response = router.oneshot(request).await?;
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()[header::ETAG], tag);
+    assert_eq!(
+        response.headers()[header::CACHE_CONTROL],
+        "public, max-age=31536000, immutable",
+        "a 304 should keep the caching headers of the response it stands in for"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn reject_invalid_recipe_images(pool: Pool<Postgres>) -> Result<()> {
+    let cases = [
+        (
+            "/recipe/1/images",
+            form(&[("image", Some("text/plain"), b"hello")]),
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        ),
+        (
+            "/recipe/1/images",
+            form(&[("image", Some("image/jpeg"), &png(10, 10))]),
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ),
+        (
+            "/recipe/1/images",
+            form(&[("image", Some("image/png"), &vec![0; 6 * 1024 * 1024])]),
+            StatusCode::PAYLOAD_TOO_LARGE,
+        ),
+        (
+            "/recipe/1/images",
+            form(&[("image", Some("image/png"), &png(8193, 1))]),
+            StatusCode::PAYLOAD_TOO_LARGE,
+        ),
+        (
+            // The fixture recipes have no steps.
+            "/recipe/1/images",
+            form(&[
+                ("image", Some("image/png"), &png(10, 10)),
+                ("step", None, b"0"),
+            ]),
+            StatusCode::UNPROCESSABLE_ENTITY,
+        ),
+        (
+            "/recipe/999/images",
+            form(&[("image", Some("image/png"), &png(10, 10))]),
+            StatusCode::NOT_FOUND,
+        ),
+    ];
+
+    for (path, body, expected) in cases {
+        let (status, _, _) = request("POST", path, &pool, Some(body)).await?;
+        assert_eq!(status, expected);
+    }
+    assert!(recipe::query_single(&pool, 1, 1).await?.images.is_empty());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn fetch_malformed_image_names(pool: Pool<Postgres>) -> Result<()> {
+    for path in [
+        "/images/..%2Fsecret.png",
+        "/images/.hidden.png",
+        "/images/a%20b.png",
+    ] {
+        let (status, _, _) = request("GET", path, &pool, None).await?;
+        assert_eq!(status, StatusCode::NOT_FOUND, "{path} should not be found");
+    }
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn delete_recipe_image(pool: Pool<Postgres>) -> Result<()> {
+    let body = form(&[("image", Some("image/png"), &png(10, 10))]);
+    request("POST", "/recipe/1/images", &pool, Some(body)).await?;
+    let image = recipe::query_single(&pool, 1, 1).await?.images[0].clone();
+
+    let path = format!("/recipe/1/image/{}", image.id);
+    let (status, _, _) = request("DELETE", &path, &pool, None).await?;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _, _) = request("GET", &image.url, &pool, None).await?;
+    assert_eq!(
+        status,
+        StatusCode::NOT_FOUND,
+        "the file should have been removed"
+    );
+    assert!(recipe::query_single(&pool, 1, 1).await?.images.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn s3_storage_round_trip() -> std::result::Result<(), StorageError> {
+    let address = start_s3_stand_in().await;
+    let storage = S3Storage::new(
+        format!("http://{}", address),
+        "lembas".into(),
+        "eu-west-2".into(),
+        "test-key".into(),
+        "test-secret".into(),
+    );
+
+    storage
+        .put("photo.jpg", "image/jpeg", vec![1, 2, 3])
+        .await?;
+    assert_eq!(storage.get("photo.jpg").await?, Some(vec![1, 2, 3]));
+
+    storage.delete("photo.jpg").await?;
+    assert_eq!(storage.get("photo.jpg").await?, None);
+    assert!(storage
+        .put("../photo.jpg", "image/jpeg", vec![])
+        .await
+        .is_err());
+    Ok(())
+}
+
+type Objects = Arc<Mutex<HashMap<String, Vec<u8>>>>;
+
+/// Serves a minimal in-memory S3 API on a random port. Requests must be signed, and the body
+/// must match its declared hash.
+async fn start_s3_stand_in() -> SocketAddr {
+    async fn put_object(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> StatusCode {
+        let hash = hex::encode(Sha256::digest(&body));
+        if !signed(&headers) || headers["x-amz-content-sha256"] != hash.as_str() {
+            return StatusCode::FORBIDDEN;
+        }
+        let mut objects = objects.lock().unwrap();
+        objects.insert(format!("{}/{}", bucket, key), body.to_vec());
+        StatusCode::OK
+    }
+    async fn get_object(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+        headers: HeaderMap,
+    ) -> std::result::Result<Vec<u8>, StatusCode> {
+        if !signed(&headers) {
+            return Err(StatusCode::FORBIDDEN);
+        }
+        let objects = objects.lock().unwrap();
+        objects
+            .get(&format!("{}/{}", bucket, key))
+            .cloned()
+            .ok_or(StatusCode::NOT_FOUND)
+    }
+    async fn delete_object(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+        headers: HeaderMap,
+    ) -> StatusCode {
+        if !signed(&headers) {
+            return StatusCode::FORBIDDEN;
+        }
+        objects
+            .lock()
+            .unwrap()
+            .remove(&format!("{}/{}", bucket, key));
+        StatusCode::NO_CONTENT
+    }
+    fn signed(headers: &HeaderMap) -> bool {
+        headers.get(header::AUTHORIZATION).is_some_and(|a| {
+            a.to_str()
+                .unwrap_or_default()
+                .starts_with("AWS4-HMAC-SHA256 Credential=test-key/")
+        })
+    }
+
+    let objects = Objects::default();
+    let app = Router::new()
+        .route(
+            "/:bucket/:key",
+            get(get_object).put(put_object).delete(delete_object),
+        )
+        .with_state(objects);
+    let server = axum::Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(app.into_make_service());
+    let address = server.local_addr();
+    tokio::spawn(server);
+    address
+}
+
+/// Encodes a solid PNG of the given size.
+fn png(width: u32, height: u32) -> Vec<u8> {
+    let mut data = Cursor::new(vec![]);
+    RgbImage::new(width, height)
+        .write_to(&mut data, ImageOutputFormat::Png)
+        .unwrap();
+    data.into_inner()
+}
+
+/// Builds a multipart form body from a list of field names, content types and values.
+fn form(fields: &[(&str, Option<&str>, &[u8])]) -> Vec<u8> {
+    let mut body = vec![];
+    for (name, content_type, value) in fields {
+        body.extend(format!("--{}\r\n", BOUNDARY).bytes());
+        match content_type {
+            Some(content_type) => body.extend(
+                format!(
+                    "Content-Disposition: form-data; name=\"{}\"; filename=\"upload\"\r\n\
+					Content-Type: {}\r\n\r\n",
+                    name, content_type
+                )
+                .bytes(),
+            ),
+            None => body.extend(
+                format!("Content-Disposition: form-data; name=\"{}\"\r\n\r\n", name).bytes(),
+            ),
+        }
+        body.extend_from_slice(value);
+        body.extend(b"\r\n");
+    }
+    body.extend(format!("--{}--\r\n", BOUNDARY).bytes());
+    body
+}
+
+/// Makes an authorised request to the router, with an optional multipart form body.
+/// Returns the status, headers and body.
+async fn request(
+    method: &str,
+    path: &str,
+    pool: &Pool<Postgres>,
+    form: Option<Vec<u8>>,
+) -> Result<(StatusCode, HeaderMap, Vec<u8>)> {
+    let db = Db { pool: pool.clone() };
+    let router = router::make_router(db, test_storage(), String::from("localhost"), false).await?;
+
+    let uri = http::uri::Builder::new().path_and_query(path).build()?;
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Authorization", "Bearer abc");
+    let request = match form {
+        Some(form) => request
+            .header(
+                header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={}", BOUNDARY),
+            )
+            .body(Body::from(form))?,
+        None => request.body(Body::empty())?,
+    };
+
+    let mut response = router.oneshot(request).await?;
+    let status = response.status();
+    let headers = response.headers().clone();
+    let mut body = vec![];
+    while let Some(chunk) = response.body_mut().data().await {
+        body.extend_from_slice(&chunk?);
+    }
+    Ok((status, headers, body))
+}
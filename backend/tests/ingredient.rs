@@ -16,8 +16,8 @@ fn search_ingredients(pool: Pool<Postgres>) -> sqlx::Result<()> {
 #[sqlx::test(fixtures("useraccount", "ingredient"))]
 fn query_all_ingredients(pool: Pool<Postgres>) -> sqlx::Result<()> {
     let results = ingredient::query_multiple(&pool, 1).await?;
-    let carrot = test_ingredients().get("Carrot".into()).unwrap().clone();
-    let water = test_ingredients().get("Water".into()).unwrap().clone();
+    let carrot = test_ingredients().get("Carrot").unwrap().clone();
+    let water = test_ingredients().get("Water").unwrap().clone();
 
     assert!(results.contains(&carrot), "results should contain carrot");
     assert!(results.contains(&water), "results should contain water");
@@ -27,7 +27,7 @@ fn query_all_ingredients(pool: Pool<Postgres>) -> sqlx::Result<()> {
 #[sqlx::test(fixtures("useraccount", "ingredient", "ingredientschedule"))]
 fn query_schedule(pool: Pool<Postgres>) -> sqlx::Result<()> {
     let results = ingredient::query_scheduled(&pool, 1).await?;
-    let carrot = test_ingredients().get("Carrot".into()).unwrap().clone();
+    let carrot = test_ingredients().get("Carrot").unwrap().clone();
     let scheduled_carrot = ingredient::ScheduledIngredient {
         id: 1,
         ingredient: carrot,
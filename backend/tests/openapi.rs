@@ -0,0 +1,22 @@
+use std::{env, fs, path::Path};
+
+use lembas::router::ApiDoc;
+use utoipa::OpenApi;
+
+/// The committed copy of the spec must match the one generated from the handlers, so that
+/// clients built from it don't drift from the API. Run with `UPDATE_OPENAPI=1` to regenerate it.
+#[test]
+fn openapi_spec_is_up_to_date() {
+    let spec = ApiDoc::openapi().to_pretty_json().unwrap() + "\n";
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("openapi.json");
+
+    if env::var("UPDATE_OPENAPI").is_ok() {
+        fs::write(&path, &spec).unwrap();
+        return;
+    }
+    let committed = fs::read_to_string(&path).unwrap_or_default();
+    assert!(
+        committed == spec,
+        "openapi.json is out of date. Regenerate it with `UPDATE_OPENAPI=1 cargo test --test openapi`."
+    );
+}
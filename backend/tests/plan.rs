@@ -0,0 +1,198 @@
+mod common;
+
+use lembas::api::{
+    day::{self, DayInput},
+    diet::{self, Allergen, DietProfile},
+    plan::{self, PlanInput},
+    recipe,
+};
+use sqlx::{Pool, Postgres};
+
+/// Builds an input for planning one meal a day between two dates.
+fn plan_input(from: &str, to: &str) -> PlanInput {
+    PlanInput {
+        from: from.into(),
+        to: to.into(),
+        meals_per_day: 1,
+        no_repeat_days: 0,
+        required_recipes: vec![],
+        excluded_recipes: vec![],
+        required_ingredients: vec![],
+        excluded_ingredients: vec![],
+    }
+}
+
+/// Lists the recipe IDs planned on each day of a draft.
+fn planned_ids(draft: &plan::PlanDraft) -> Vec<Vec<i32>> {
+    draft
+        .days
+        .iter()
+        .map(|d| d.recipes.iter().map(|r| r.id).collect())
+        .collect()
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn generate_plan_with_least_waste(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let draft = plan::generate(&pool, 1, plan_input("2020-01-10", "2020-01-10"))
+        .await
+        .unwrap();
+
+    // Six carrots leaves fewer of the pack of ten behind than five.
+    assert_eq!(planned_ids(&draft), vec![vec![2]]);
+
+    let ingredient = draft.shopping_list.ingredients.first().unwrap();
+    assert_eq!(ingredient.purchase_quantity, 10);
+    assert_eq!(ingredient.projected_waste, 4);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn generate_plan_without_repeats(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let mut input = plan_input("2020-01-10", "2020-01-11");
+    input.no_repeat_days = 2;
+    let draft = plan::generate(&pool, 1, input).await.unwrap();
+
+    assert_eq!(planned_ids(&draft), vec![vec![2], vec![1]]);
+
+    // The second recipe should use the carrots left over from the first.
+    let ingredient = draft.shopping_list.ingredients.first().unwrap();
+    assert_eq!(ingredient.used_quantity, 11);
+    assert_eq!(ingredient.purchase_quantity, 20);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn generate_plan_with_exclusions(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let mut input = plan_input("2020-01-10", "2020-01-11");
+    input.excluded_recipes = vec![2];
+    let draft = plan::generate(&pool, 1, input).await.unwrap();
+    assert_eq!(planned_ids(&draft), vec![vec![1], vec![1]]);
+
+    let mut input = plan_input("2020-01-10", "2020-01-11");
+    input.excluded_ingredients = vec![2000];
+    let draft = plan::generate(&pool, 1, input).await.unwrap();
+    assert!(
+        draft.days.is_empty(),
+        "no recipes should be planned when all use an excluded ingredient"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "family"))]
+fn generate_plan_with_requirements(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let mut input = plan_input("2020-01-10", "2020-01-11");
+    input.required_recipes = vec![3];
+    let draft = plan::generate(&pool, 1, input).await.unwrap();
+    assert_eq!(planned_ids(&draft), vec![vec![2], vec![3]]);
+
+    let mut input = plan_input("2020-01-10", "2020-01-10");
+    input.required_ingredients = vec![2006];
+    let draft = plan::generate(&pool, 1, input).await.unwrap();
+    assert_eq!(planned_ids(&draft), vec![vec![3]]);
+    assert_eq!(draft.unmet, plan::UnmetRequirements::default());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn report_unmet_recipes(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    // An excluded recipe and one that doesn't exist can't be planned
+    let mut input = plan_input("2020-01-10", "2020-01-11");
+    input.required_recipes = vec![1, 2, 99];
+    input.excluded_recipes = vec![2];
+    let draft = plan::generate(&pool, 1, input).await.unwrap();
+    assert_eq!(planned_ids(&draft), vec![vec![1], vec![1]]);
+    assert_eq!(draft.unmet.recipes, vec![2, 99]);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn report_unmet_ingredients(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    // No recipe uses apples, so the carrot requirement is still met
+    let mut input = plan_input("2020-01-10", "2020-01-10");
+    input.required_ingredients = vec![2004, 2000];
+    let draft = plan::generate(&pool, 1, input).await.unwrap();
+    assert_eq!(planned_ids(&draft), vec![vec![2]]);
+    assert_eq!(draft.unmet.ingredients, vec![2004]);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "family"))]
+fn generate_plan_by_ingredient_family(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    // The rice pudding uses the large milk, which is in the same family as the small one.
+    let mut input = plan_input("2020-01-10", "2020-01-10");
+    input.required_ingredients = vec![2005];
+    let draft = plan::generate(&pool, 1, input).await.unwrap();
+    assert_eq!(planned_ids(&draft), vec![vec![3]]);
+
+    let mut input = plan_input("2020-01-10", "2020-01-11");
+    input.excluded_ingredients = vec![2005];
+    let draft = plan::generate(&pool, 1, input).await.unwrap();
+    assert!(!planned_ids(&draft).concat().contains(&3));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day"))]
+fn generate_plan_around_planned_meals(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let mut input = plan_input("2023-11-09", "2023-11-10");
+    input.meals_per_day = 2;
+    let draft = plan::generate(&pool, 1, input).await.unwrap();
+
+    // The 9th already has two meals, so only the 10th is planned.
+    assert_eq!(draft.days.len(), 1);
+    assert_eq!(draft.days[0].date, "2023-11-10");
+    assert_eq!(draft.days[0].recipes.len(), 2);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn generate_plan_around_leftovers(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let cooked = DayInput {
+        recipe_id: 1,
+        date: "2020-01-10".into(),
+        portions: Some(1),
+        leftovers_from: None,
+    };
+    day::create(&pool, 1, cooked).await.unwrap();
+    let leftovers = DayInput {
+        recipe_id: 1,
+        date: "2020-01-11".into(),
+        portions: Some(1),
+        leftovers_from: Some("2020-01-10".into()),
+    };
+    day::create(&pool, 1, leftovers).await.unwrap();
+
+    // Both days already have a meal, one of them of leftovers.
+    let draft = plan::generate(&pool, 1, plan_input("2020-01-10", "2020-01-11"))
+        .await
+        .unwrap();
+    assert!(draft.days.is_empty());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn generate_plan_for_diet(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let input = serde_json::from_str(include_str!("api/inputs/post-recipe.json")).unwrap();
+    let recipe_id = recipe::create(&pool, 1, input).await?;
+    let mut input = plan_input("2020-01-10", "2020-01-10");
+    input.excluded_recipes = vec![1, 2];
+
+    // Without a profile, the only recipe left is planned.
+    let draft = plan::generate(&pool, 1, input).await.unwrap();
+    assert_eq!(planned_ids(&draft), vec![vec![recipe_id]]);
+
+    let profile = DietProfile {
+        vegetarian: false,
+        vegan: false,
+        allergens: vec![Allergen::Gluten],
+    };
+    diet::update_profile(&pool, 1, profile).await?;
+
+    let mut input = plan_input("2020-01-10", "2020-01-10");
+    input.excluded_recipes = vec![1, 2];
+    let draft = plan::generate(&pool, 1, input).await.unwrap();
+    assert!(
+        draft.days.is_empty(),
+        "recipes containing an allergen the user avoids should not be planned"
+    );
+    Ok(())
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use lembas::api::{
+    day::{self, DayInput},
+    purchase::{self, ShoppingTrip},
+};
+use sqlx::{Pool, Postgres};
+
+/// Plans five carrots on January 10th 2020 and six on the 15th.
+async fn setup_meal_plan(pool: &Pool<Postgres>) {
+    for (recipe_id, date) in [(1, "2020-01-10"), (2, "2020-01-15")] {
+        let day_input = DayInput {
+            recipe_id,
+            date: date.into(),
+            portions: None,
+            leftovers_from: None,
+        };
+        day::create(pool, 1, day_input).await.unwrap();
+    }
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn surplus_from_recorded_purchases(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    setup_meal_plan(&pool).await;
+
+    // Twenty carrots were bought rather than the suggested ten. Nothing is bought of the salt.
+    let trip: ShoppingTrip =
+        serde_json::from_str(include_str!("api/inputs/shopping-trip.json")).unwrap();
+    assert_eq!(purchase::complete_trip(&pool, 1, trip).await?, Ok(()));
+
+    let purchases = purchase::query_range(&pool, 1, "2020-01-01", "2020-01-31")
+        .await
+        .unwrap();
+    assert_eq!(purchases.len(), 1);
+    assert_eq!(purchases[0].quantity, 20);
+    assert_eq!(purchases[0].date, "2020-01-09");
+
+    let list = day::build_list_for_range(&pool, 1, "2020-01-11".into(), "2020-01-16".into())
+        .await
+        .unwrap();
+    let ingredient = list.ingredients.first().unwrap();
+    assert_eq!(
+        ingredient.existing_surplus, 15,
+        "the surplus should come from what was bought, not the packs needed"
+    );
+    assert_eq!(ingredient.purchase_quantity, 0);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn expired_purchases_are_not_surplus(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    setup_meal_plan(&pool).await;
+
+    // Carrots last ten days, so these have gone off by the 15th. Those used on the 10th must
+    // have come from them, so nothing was left over from earlier either.
+    let mut trip: ShoppingTrip =
+        serde_json::from_str(include_str!("api/inputs/shopping-trip.json")).unwrap();
+    trip.date = "2020-01-05".into();
+    purchase::complete_trip(&pool, 1, trip).await?.unwrap();
+
+    let list = day::build_list_for_range(&pool, 1, "2020-01-15".into(), "2020-01-16".into())
+        .await
+        .unwrap();
+    let ingredient = list.ingredients.first().unwrap();
+    assert_eq!(ingredient.existing_surplus, 0);
+    assert_eq!(ingredient.purchase_quantity, 10);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn reject_invalid_trips(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let trip = |id: i32, quantity: i32, date: &str| {
+        serde_json::from_value::<ShoppingTrip>(serde_json::json!({
+            "date": date,
+            "ingredients": [{ "ingredient": { "id": id }, "purchase_quantity": quantity }]
+        }))
+        .unwrap()
+    };
+
+    let invalid = [
+        trip(2000, -1, "2020-01-09"),
+        trip(2000, 10, "09/01/2020"),
+        trip(i32::MAX, 10, "2020-01-09"),
+    ];
+    for trip in invalid {
+        assert!(purchase::complete_trip(&pool, 1, trip).await?.is_err());
+    }
+
+    // Another user's ingredient
+    assert!(
+        purchase::complete_trip(&pool, 2, trip(2004, 10, "2020-01-09"))
+            .await?
+            .is_err()
+    );
+
+    let purchases = purchase::query_range(&pool, 1, "2020-01-01", "2020-01-31")
+        .await
+        .unwrap();
+    assert!(purchases.is_empty());
+    Ok(())
+}
@@ -1,18 +1,18 @@
 mod common;
 
-use lembas::api::{ingredient, recipe};
+use lembas::api::{day, diet::Allergen, ingredient, recipe};
 use sqlx::{Pool, Postgres};
 
-use crate::common::test_ingredients;
+use crate::common::{step, test_ingredients};
 
 #[sqlx::test(fixtures("useraccount", "ingredient"))]
 fn create_and_fetch_recipe(pool: Pool<Postgres>) -> sqlx::Result<()> {
-    let flour = test_ingredients().get("Flour".into()).unwrap().clone();
+    let flour = test_ingredients().get("Flour").unwrap().clone();
 
     // Expecting 1000g of Flour
     let expected_ingredient_quantities =
         vec![ingredient::IngredientQuantity::new(flour.clone(), 1000)];
-    let expected_steps = sqlx::types::JsonValue::Array(vec!["step one".into(), "step two".into()]);
+    let expected_steps = vec![step("step one"), step("step two")];
 
     // Perform recipe insertion
     let ingredient_inputs = vec![recipe::RecipeIngredientInput {
@@ -22,8 +22,11 @@ fn create_and_fetch_recipe(pool: Pool<Postgres>) -> sqlx::Result<()> {
     let recipe_input = recipe::RecipeInput {
         name: "Test Recipe".into(),
         portions: 4,
-        steps: sqlx::types::JsonValue::Array(vec!["step one".into(), "step two".into()]),
+        steps: vec![step("step one"), step("step two")],
         ingredients: ingredient_inputs,
+        sub_recipes: vec![],
+        metadata: Default::default(),
+        version: None,
     };
 
     let id = recipe::create(&pool, 1, recipe_input).await?;
@@ -63,7 +66,7 @@ fn delete_recipe(pool: Pool<Postgres>) -> sqlx::Result<()> {
 #[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
 fn query_single_recipe(pool: Pool<Postgres>) -> sqlx::Result<()> {
     let recipe = recipe::query_single(&pool, 1, 1).await?;
-    let carrot = test_ingredients().get("Carrot".into()).unwrap().clone();
+    let carrot = test_ingredients().get("Carrot").unwrap().clone();
 
     let expected_ingredients = vec![ingredient::IngredientQuantity::new(carrot, 5)];
     assert_eq!(
@@ -72,3 +75,313 @@ fn query_single_recipe(pool: Pool<Postgres>) -> sqlx::Result<()> {
     );
     Ok(())
 }
+
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn cost_recipe(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let recipe_input = recipe::RecipeInput {
+        name: "Bread".into(),
+        portions: 4,
+        steps: vec![],
+        ingredients: vec![
+            recipe::RecipeIngredientInput {
+                id: 2001,
+                quantity: 500,
+            },
+            recipe::RecipeIngredientInput {
+                id: 2002,
+                quantity: 300,
+            },
+        ],
+        sub_recipes: vec![],
+        metadata: Default::default(),
+        version: None,
+    };
+    let id = recipe::create(&pool, 1, recipe_input).await?;
+
+    // Half a 90p bag of flour, and water which can't be bought
+    let bread = recipe::query_single(&pool, 1, id).await?;
+    assert_eq!(bread.cost, Some(45));
+    assert_eq!(bread.cost_per_portion, Some(11));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn cost_recipe_unpriced(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let recipe_input = recipe::RecipeInput {
+        name: "Apple Bread".into(),
+        portions: 1,
+        steps: vec![],
+        ingredients: vec![
+            recipe::RecipeIngredientInput {
+                id: 2001,
+                quantity: 500,
+            },
+            recipe::RecipeIngredientInput {
+                id: 2004,
+                quantity: 250,
+            },
+        ],
+        sub_recipes: vec![],
+        metadata: Default::default(),
+        version: None,
+    };
+    let id = recipe::create(&pool, 1, recipe_input).await?;
+
+    let recipe = recipe::query_single(&pool, 1, id).await?;
+    assert_eq!(
+        recipe.cost, None,
+        "a recipe with an unpriced ingredient should not be costed"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn recipe_nutrition(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    // Uses 500g flour, 375g water and 10g salt, which has no nutrition data.
+    let input = serde_json::from_str(include_str!("api/inputs/post-recipe.json")).unwrap();
+    let id = recipe::create(&pool, 1, input).await?;
+
+    let recipe = recipe::query_single(&pool, 1, id).await?;
+    assert_eq!(recipe.nutrition.total.energy, 1705.0);
+    assert_eq!(recipe.nutrition.total.carbohydrate, 350.0);
+    assert_eq!(recipe.nutrition.per_portion.protein, 25.0);
+    assert_eq!(
+        recipe.nutrition.unknown_ingredients,
+        vec![2003],
+        "ingredients without nutrition data should be reported"
+    );
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn classify_recipe(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    // Uses flour, which contains gluten.
+    let input = serde_json::from_str(include_str!("api/inputs/post-recipe.json")).unwrap();
+    let id = recipe::create(&pool, 1, input).await?;
+
+    let recipe = recipe::query_single(&pool, 1, id).await?;
+    assert_eq!(recipe.diet.allergens, vec![Allergen::Gluten]);
+    assert!(recipe.diet.vegetarian);
+    assert!(recipe.diet.vegan);
+    Ok(())
+}
+
+#[test]
+fn validate_recipe_steps() {
+    let valid: recipe::RecipeInput =
+        serde_json::from_str(include_str!("api/inputs/post-recipe.json")).unwrap();
+    assert_eq!(valid.validate(), Ok(()));
+
+    let mut no_duration =
+        serde_json::from_str::<recipe::RecipeInput>(include_str!("api/inputs/post-recipe.json"))
+            .unwrap();
+    no_duration.steps[1].duration = None;
+    assert!(
+        no_duration.validate().is_err(),
+        "a timer should need a duration"
+    );
+
+    let mut overused =
+        serde_json::from_str::<recipe::RecipeInput>(include_str!("api/inputs/post-recipe.json"))
+            .unwrap();
+    overused.steps[0].ingredients[0].quantity = 501;
+    assert!(
+        overused.validate().is_err(),
+        "steps should not use more of an ingredient than the recipe"
+    );
+
+    let mut blank =
+        serde_json::from_str::<recipe::RecipeInput>(include_str!("api/inputs/post-recipe.json"))
+            .unwrap();
+    blank.steps[2].text = "  ".into();
+    assert!(blank.validate().is_err(), "steps should have text");
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn create_and_fetch_structured_steps(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let input: recipe::RecipeInput =
+        serde_json::from_str(include_str!("api/inputs/post-recipe.json")).unwrap();
+    let expected = input.steps.clone();
+    let id = recipe::create(&pool, 1, input).await?;
+
+    let recipe = recipe::query_single(&pool, 1, id).await?;
+    assert_eq!(recipe.steps, expected);
+    assert_eq!(recipe.steps[0].section, Some("Dough".into()));
+    assert_eq!(recipe.steps[1].duration, Some(3600));
+    Ok(())
+}
+
+#[test]
+fn validate_recipe_metadata() {
+    let parse = || {
+        serde_json::from_str::<recipe::RecipeInput>(include_str!("api/inputs/post-recipe.json"))
+            .unwrap()
+    };
+
+    let mut negative = parse();
+    negative.metadata.prep_time = Some(-5);
+    assert!(negative.validate().is_err(), "times should not be negative");
+
+    let mut too_short = parse();
+    too_short.metadata.total_time = Some(30);
+    assert!(
+        too_short.validate().is_err(),
+        "the total time should cover the prep and cook times"
+    );
+
+    let mut bad_url = parse();
+    bad_url.metadata.source_url = Some("javascript:alert(1)".into());
+    assert!(
+        bad_url.validate().is_err(),
+        "sources should be web addresses"
+    );
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient"))]
+fn create_and_fetch_recipe_metadata(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let mut input: recipe::RecipeInput =
+        serde_json::from_str(include_str!("api/inputs/post-recipe.json")).unwrap();
+    let expected = input.metadata.clone();
+    let id = recipe::create(&pool, 1, input).await?;
+
+    let recipe = recipe::query_single(&pool, 1, id).await?;
+    assert_eq!(recipe.metadata, expected);
+    assert_eq!(recipe.metadata.difficulty, Some(recipe::Difficulty::Medium));
+
+    // Without a total time, the prep and cook times are added together.
+    input = serde_json::from_str(include_str!("api/inputs/post-recipe.json")).unwrap();
+    input.metadata.total_time = None;
+    let id = recipe::create(&pool, 1, input).await?;
+
+    let recipe = recipe::query_single(&pool, 1, id).await?;
+    assert_eq!(recipe.metadata.total_time, Some(60));
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn filter_recipes_by_time(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let filter = recipe::RecipeFilter { max_time: Some(15) };
+    let ids: Vec<i32> = recipe::query_filtered(&pool, 1, &filter)
+        .await?
+        .iter()
+        .map(|r| r.id)
+        .collect();
+
+    // Only the ten minute recipe is quick enough. Recipes without a time are left out.
+    assert_eq!(ids, vec![1]);
+
+    let filter = recipe::RecipeFilter { max_time: Some(5) };
+    assert!(recipe::query_filtered(&pool, 1, &filter).await?.is_empty());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "sub_recipe"))]
+fn expand_sub_recipes(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let pizza = recipe::query_single(&pool, 1, 21).await?;
+    assert_eq!(pizza.ingredients.len(), 1, "only salt is used directly");
+    assert_eq!(pizza.sub_recipes[0].name, "Pizza Dough");
+
+    // Half of the dough, and the pizza's own salt
+    let quantities: Vec<(i32, i32)> = pizza
+        .all_ingredients()
+        .iter()
+        .map(|i| (i.ingredient.id, i.quantity))
+        .collect();
+    assert_eq!(quantities, vec![(2003, 5), (2001, 250), (2002, 150)]);
+    assert_eq!(
+        pizza.diet.allergens,
+        vec![Allergen::Gluten],
+        "the dough's flour should count towards the pizza's diet"
+    );
+
+    // The calzone uses a whole pizza, which in turn uses half the dough
+    let calzone = recipe::query_single(&pool, 1, 22).await?;
+    assert_eq!(calzone.sub_recipes[0].recipe.sub_recipes[0].recipe_id, 20);
+    let flour = calzone
+        .all_ingredients()
+        .into_iter()
+        .find(|i| i.ingredient.id == 2001)
+        .unwrap();
+    assert_eq!(flour.quantity, 250);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "sub_recipe"))]
+fn list_sub_recipe_ingredients(pool: Pool<Postgres>) -> Result<(), Box<dyn std::error::Error>> {
+    let list =
+        day::build_list_for_range(&pool, 1, "2024-01-10".into(), "2024-01-10".into()).await?;
+
+    let used: Vec<(i32, i32)> = list
+        .ingredients
+        .iter()
+        .map(|i| (i.ingredient.id, i.used_quantity))
+        .collect();
+    assert_eq!(used, vec![(2001, 250), (2003, 6), (2002, 150)]);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "sub_recipe"))]
+fn create_recipe_from_sub_recipes(pool: Pool<Postgres>) -> Result<(), Box<dyn std::error::Error>> {
+    let input = recipe::RecipeInput {
+        name: "Two Pizzas".into(),
+        portions: 4,
+        steps: vec![],
+        ingredients: vec![],
+        sub_recipes: vec![recipe::SubRecipeInput {
+            recipe_id: 21,
+            portions: 4,
+        }],
+        metadata: Default::default(),
+        version: None,
+    };
+    assert_eq!(input.validate(), Ok(()));
+    let id = recipe::create(&pool, 1, input).await?;
+
+    // A recipe with no ingredients of its own is still listed and planned
+    let recipes = recipe::query_multiple(&pool, 1).await?;
+    assert!(recipes.iter().any(|r| r.id == id));
+    let pizzas = recipe::query_single(&pool, 1, id).await?;
+    assert!(pizzas.ingredients.is_empty());
+    assert_eq!(pizzas.all_ingredients().len(), 3);
+
+    sqlx::query("INSERT INTO day (recipe_id, date) VALUES ($1, '2024-01-11')")
+        .bind(id)
+        .execute(&pool)
+        .await?;
+    let days = day::query_range(&pool, 1, "2024-01-11", "2024-01-11").await?;
+    assert_eq!(days[0].meals()[0].id, id);
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "sub_recipe"))]
+fn reject_sub_recipe_cycles(pool: Pool<Postgres>) -> sqlx::Result<()> {
+    let input = |sub_recipe_id| recipe::RecipeInput {
+        name: "Pizza Dough".into(),
+        portions: 4,
+        steps: vec![],
+        ingredients: vec![recipe::RecipeIngredientInput {
+            id: 2001,
+            quantity: 500,
+        }],
+        sub_recipes: vec![recipe::SubRecipeInput {
+            recipe_id: sub_recipe_id,
+            portions: 1,
+        }],
+        metadata: Default::default(),
+        version: None,
+    };
+
+    // The dough can't use the calzone, which uses the pizza, which uses the dough
+    let result = recipe::check_sub_recipes(&pool, 1, Some(20), &input(22)).await?;
+    assert!(result.is_err(), "an indirect cycle should be rejected");
+    let result = recipe::check_sub_recipes(&pool, 1, Some(20), &input(20)).await?;
+    assert!(result.is_err(), "a recipe should not include itself");
+
+    // Other users' recipes can't be used
+    let result = recipe::check_sub_recipes(&pool, 2, None, &input(21)).await?;
+    assert!(result.is_err());
+
+    let result = recipe::check_sub_recipes(&pool, 1, Some(22), &input(20)).await?;
+    assert_eq!(result, Ok(()));
+    Ok(())
+}
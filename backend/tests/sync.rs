@@ -0,0 +1,112 @@
+mod common;
+
+use std::error::Error;
+
+use lembas::api::{
+    day,
+    ingredient::{self, PriceInput},
+    recipe, sync,
+};
+use sqlx::{Pool, Postgres};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day", "ingredientschedule"))]
+fn full_sync(pool: Pool<Postgres>) -> Result<()> {
+    let changes = sync::changes(&pool, 1, None).await?;
+
+    let recipe_ids: Vec<i32> = changes.recipes.updated.iter().map(|r| r.id).collect();
+    assert_eq!(recipe_ids, vec![1, 2]);
+    let dates: Vec<&str> = changes
+        .days
+        .updated
+        .iter()
+        .map(|d| d.date.as_str())
+        .collect();
+    assert_eq!(dates, vec!["2023-11-09", "2023-11-16"]);
+    assert_eq!(changes.schedule.updated.len(), 1);
+    // Every global ingredient is included, along with the user's own.
+    assert!(changes.ingredients.updated.len() > 1000);
+    assert!(changes.recipes.deleted.is_empty() && changes.days.deleted.is_empty());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe", "day", "ingredientschedule"))]
+fn sync_changes_since_token(pool: Pool<Postgres>) -> Result<()> {
+    let token = sync::changes(&pool, 1, None).await?.token;
+
+    recipe::delete(&pool, 1, 2).await?;
+    day::delete_day_recipe(&pool, 1, "2023-11-16".into(), 1).await?;
+    ingredient::update_price(&pool, 1, 2003, PriceInput { price: Some(80) }).await?;
+    ingredient::delete_scheduled(&pool, 1, 1).await?;
+
+    let since = sync::parse_token(&token)?;
+    let changes = sync::changes(&pool, 1, Some(since)).await?;
+
+    assert!(changes.recipes.updated.is_empty());
+    assert_eq!(changes.recipes.deleted, vec![2]);
+
+    // Deleting the recipe changed the 9th, and nothing is left on the 16th.
+    assert_eq!(changes.days.updated.len(), 1);
+    let day = &changes.days.updated[0];
+    assert_eq!(day.date, "2023-11-09");
+    assert_eq!(
+        day.recipes.iter().map(|r| r.id).collect::<Vec<i32>>(),
+        vec![1]
+    );
+    assert_eq!(changes.days.deleted, vec!["2023-11-16"]);
+
+    let ingredients: Vec<(i32, Option<i32>)> = changes
+        .ingredients
+        .updated
+        .iter()
+        .map(|i| (i.id, i.price))
+        .collect();
+    assert_eq!(ingredients, vec![(2003, Some(80))]);
+
+    assert!(changes.schedule.updated.is_empty());
+    assert_eq!(changes.schedule.deleted, vec![1]);
+
+    // Nothing has changed since the last sync.
+    let since = sync::parse_token(&changes.token)?;
+    let changes = sync::changes(&pool, 1, Some(since)).await?;
+    assert!(changes.recipes.deleted.is_empty());
+    assert!(changes.days.updated.is_empty() && changes.days.deleted.is_empty());
+    assert!(changes.ingredients.updated.is_empty());
+    Ok(())
+}
+
+#[sqlx::test(fixtures("useraccount", "ingredient", "recipe"))]
+fn sync_changes_committed_after_token(pool: Pool<Postgres>) -> Result<()> {
+    // A write that starts before a sync, but commits after it.
+    let mut tx = pool.begin().await?;
+    sqlx::query("UPDATE recipe SET name = 'Renamed' WHERE id = 1")
+        .execute(&mut *tx)
+        .await?;
+
+    let changes = sync::changes(&pool, 1, None).await?;
+    let recipe = changes.recipes.updated.iter().find(|r| r.id == 1).unwrap();
+    assert_eq!(recipe.name, "Five Carrots");
+    tx.commit().await?;
+
+    let since = sync::parse_token(&changes.token)?;
+    let changes = sync::changes(&pool, 1, Some(since)).await?;
+    let names: Vec<&str> = changes
+        .recipes
+        .updated
+        .iter()
+        .map(|r| r.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        vec!["Renamed"],
+        "the next sync should include the write"
+    );
+    Ok(())
+}
+
+#[test]
+fn reject_invalid_tokens() {
+    assert!(sync::parse_token("1700000000000000").is_ok());
+    assert!(sync::parse_token("yesterday").is_err());
+}
@@ -0,0 +1,108 @@
+use std::{env, fs, path::Path};
+
+use lembas::api::{
+    alert, calendar, day, diet, images, ingredient, nutrition, plan, purchase, recipe, sync,
+};
+use ts_rs::TS;
+
+/// Where the generated definitions are kept in the frontend's API package.
+const TYPES_PATH: &str = "../frontend/packages/lembas-api/src/generated.ts";
+
+const HEADER: &str =
+    "// Generated from the backend's request and response types. Don't edit this file by hand:
+// regenerate it with `UPDATE_TYPESCRIPT=1 cargo test --test typescript` in the backend.
+";
+
+/// Exports the declaration of each type, with its docs, in the order given.
+macro_rules! declarations {
+    ($($ty:ty),* $(,)?) => {
+        vec![$(format!("{}export {}", <$ty>::docs().unwrap_or_default(), <$ty>::decl())),*]
+    };
+}
+
+fn typescript() -> String {
+    let declarations = declarations![
+        recipe::Recipe,
+        recipe::SubRecipe,
+        recipe::Difficulty,
+        recipe::RecipeMetadata,
+        recipe::Step,
+        recipe::StepIngredient,
+        recipe::RecipeInput,
+        recipe::RecipeIngredientInput,
+        recipe::SubRecipeInput,
+        images::RecipeImage,
+        ingredient::Ingredient,
+        ingredient::IngredientInput,
+        ingredient::IngredientQuantity,
+        ingredient::PriceInput,
+        ingredient::IngredientFamily,
+        ingredient::IngredientFamilyInput,
+        ingredient::PackQuantity,
+        ingredient::ScheduledIngredient,
+        ingredient::ScheduledIngredientInput,
+        nutrition::Nutrition,
+        nutrition::RecipeNutrition,
+        diet::Allergen,
+        diet::RecipeDiet,
+        diet::DietProfile,
+        diet::DietWarning,
+        day::Day,
+        day::DayInput,
+        day::MealStatus,
+        day::UsedQuantity,
+        day::MealRecord,
+        day::MealRecordInput,
+        day::PlannedLeftovers,
+        day::PlanOperation,
+        day::OperationResult,
+        day::BatchResult,
+        day::ShoppingList,
+        day::IngredientPurchaseQuantity,
+        day::CookableRecipe,
+        day::MissingIngredient,
+        day::RecipeWaste,
+        purchase::Purchase,
+        purchase::ShoppingTrip,
+        purchase::PurchasedIngredient,
+        purchase::IngredientId,
+        plan::PlanInput,
+        plan::PlanDraft,
+        plan::UnmetRequirements,
+        plan::PlanTemplate,
+        plan::TemplateDay,
+        plan::TemplateInput,
+        plan::TemplateRename,
+        plan::ApplyInput,
+        plan::CopyInput,
+        alert::ExpiringIngredient,
+        alert::RecipeSuggestion,
+        calendar::CalendarToken,
+        sync::SyncChanges,
+        sync::Changes<ts_rs::Dummy, ts_rs::Dummy>,
+    ];
+    let types = format!("{}\n{}\n", HEADER, declarations.join("\n\n"));
+    // ts-rs leaves spaces at the ends of some lines.
+    types
+        .lines()
+        .map(|l| l.trim_end().to_string() + "\n")
+        .collect()
+}
+
+/// The frontend's copy of the API types must match the backend's, so that the two don't drift
+/// apart. Run with `UPDATE_TYPESCRIPT=1` to regenerate it.
+#[test]
+fn typescript_types_are_up_to_date() {
+    let types = typescript();
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(TYPES_PATH);
+
+    if env::var("UPDATE_TYPESCRIPT").is_ok() {
+        fs::write(&path, &types).unwrap();
+        return;
+    }
+    let committed = fs::read_to_string(&path).unwrap_or_default();
+    assert!(
+        committed == types,
+        "generated.ts is out of date. Regenerate it with `UPDATE_TYPESCRIPT=1 cargo test --test typescript`."
+    );
+}
@@ -1,1158 +1,1270 @@
-DELETE FROM ingredient WHERE user_id IS NULL;
-INSERT INTO ingredient (id, name, unit, minimum_quantity, purchase_quantity, life) VALUES
-(1,'Butter Salted','g',1,250,60),
-(2,'Butter Unsalted','g',1,250,60),
-(3,'Butter Salted','g',1,500,60),
-(4,'Butter Slightly Salted','g',1,250,60),
-(5,'Butter Unsalted','g',1,250,60),
-(6,'Farmhouse Butter','g',1,250,60),
-(7,'Farmhouse Butter','g',1,250,60),
-(8,'Semi Skimmed Milk','ml',1,2270,7),
-(9,'Semi Skimmed Milk','ml',1,1130,7),
-(10,'Whole Milk','ml',1,2270,7),
-(11,'Skimmed Milk','ml',1,2270,7),
-(12,'Semi Skimmed Milk','ml',1,3400,7),
-(13,'Skimmed Milk','ml',1,1130,7),
-(14,'Whole Milk','ml',1,1130,7),
-(15,'Filtered Semi Skimmed Milk','ml',1,2000,7),
-(16,'Semi Skimmed Milk','ml',1,2270,7),
-(17,'Semi Skimmed Milk Pint',NULL,1,1,7),
-(18,'Whole Milk','ml',1,2270,7),
-(19,'Lactose Free Semi Skimmed Dairy Drink','ml',1,1000,7),
-(20,'Whole Milk','ml',1,3400,7),
-(21,'Semi Skimmed Milk','ml',1,1130,7),
-(22,'Whole Milk','ml',1,568,7),
-(23,'Filtered Whole Milk','ml',1,2000,7),
-(24,'Skimmed Milk','ml',1,568,7),
-(25,'Whole Milk','ml',1,1130,7),
-(26,'Lactose Free Skimmed Dairy Drink','ml',1,1000,7),
-(27,'Lactose Free Whole Dairy Drink',NULL,1,1,7),
-(28,'Skimmed Milk','ml',1,1136,7),
-(29,'Semi Skimmed Milk','ml',1,568,7),
-(30,'Jersey Milk','ml',1,1000,7),
-(31,'5% Fat Beef Mince','g',1,500,7),
-(32,'Skin on Salmon Fillets',NULL,1,2,7),
-(33,'Fresh Chicken Breast Fillets Skinless & Boneless','g',1,300,7),
-(34,'Fresh Chicken Breast Fillets Skinless & Boneless','g',1,640,7),
-(35,'Fresh Chicken Breast Fillets Skinless & Boneless','g',1,1000,7),
-(36,'Fresh Chicken Thigh Fillets Skinless & Boneless','g',1,640,7),
-(37,'Breaded Cooked Ham Slices',NULL,1,7,7),
-(38,'Pork Sausages',NULL,1,6,7),
-(39,'Smoked Back Bacon Rashers',NULL,1,10,7),
-(40,'Honey Roast Cooked Ham Slices',NULL,1,7,7),
-(41,'5% Fat Beef Mince','g',1,750,7),
-(42,'Unsmoked Back Bacon Rashers',NULL,1,10,7),
-(43,'Fresh Chicken Breast Fillets','g',1,320,7),
-(44,'Fresh Chicken Skin on Thighs','g',1,1000,7),
-(45,'20% Fat Beef Mince','g',1,500,7),
-(46,'Fresh Large Whole Chicken','g',1,1900,7),
-(47,'Smoked Streaky Bacon',NULL,1,14,7),
-(48,'Smoked Mackerel Fillets','g',1,140,7),
-(49,'Sea Bass Fillets','g',1,180,7),
-(50,'Thick Cut Unsmoked Back Bacon Rashers',NULL,1,6,7),
-(51,'Cooked Roast Chicken Breast Fillets',NULL,1,2,7),
-(52,'Breaded Yorkshire Cured Cooked Ham Slices',NULL,1,4,7),
-(53,'Skin on Salmon Fillets',NULL,1,4,7),
-(54,'Fresh Chicken Breast Fillets','g',1,640,7),
-(55,'Pork Sausage',NULL,1,8,7),
-(56,'Fresh Extra Large Whole Chicken',NULL,1,1,7),
-(57,'5% Fat Beef Mince','g',1,250,7),
-(58,'Spanish Chorizo Ring','g',1,225,7),
-(59,'Fresh Diced Chicken Breast','g',1,410,7),
-(60,'Breaded Cooked Ham Slices',NULL,1,14,7),
-(61,'Ham Slices','g',1,120,7),
-(62,'Pork Chipolata sausages',NULL,1,12,7),
-(63,'Thick Cut Smoked Back Bacon Rashers',NULL,1,6,7),
-(64,'Large King Prawns','g',1,150,7),
-(65,'Quarter Pounder Beef Burgers',NULL,1,4,7),
-(66,'Pork Mince 5% Fat','g',1,500,7),
-(67,'Pork Chipolata sausages',NULL,1,12,7),
-(68,'Cooked Ham Slices',NULL,1,10,7),
-(69,'Free Range Chicken Breast Fillets',NULL,1,2,7),
-(70,'Breaded Fresh Chicken Breast Fillets',NULL,1,2,7),
-(71,'Pork Belly Slices','g',1,500,7),
-(72,'Fresh Medium Whole Chicken','g',1,1600,7),
-(73,'Breaded Chunky Cod Loins',NULL,1,2,7),
-(74,'Pork Sausage',NULL,1,12,7),
-(75,'Unsmoked Streaky Bacon',NULL,1,14,7),
-(76,'Extra Lean Diced Beef','g',1,500,7),
-(77,'Beef Mince 5% Fat','g',1,500,7),
-(78,'Roast Cooked Chicken Breast Slices',NULL,1,7,7),
-(79,'Fresh Small Whole Chicken','g',1,1350,7),
-(80,'Beef Mince','g',1,1000,7),
-(81,'Day Matured Sirloin Steak',NULL,1,21,7),
-(82,'Fresh Chicken Thigh Fillets Skinless & Boneless','g',1,320,7),
-(83,'2% Fat Fresh Turkey Mince','g',1,500,7),
-(84,'Unsmoked Bacon Medallions',NULL,1,8,7),
-(85,'Fresh Chicken Wings','g',1,1000,7),
-(86,'Brussels Pâté','g',1,170,7),
-(87,'Pork Sausages',NULL,1,6,7),
-(88,'Cooked Roast Beef Slices',NULL,1,3,7),
-(89,'Pork Sausage',NULL,1,8,7),
-(90,'Cod Fillets Boneless & Skinless','g',1,250,7),
-(91,'Extra Lean Diced Beef','g',1,250,7),
-(92,'Free Range Whole Chicken',NULL,1,1,7),
-(93,'12% Fat Beef Mince','g',1,500,7),
-(94,'Wiltshire Cured Ham slices',NULL,1,4,7),
-(95,'Fresh Chicken Breast Fillet garlic Kyiv',NULL,1,2,7),
-(96,'Brussels Spreadable Pâté','g',1,175,7),
-(97,'Smoked Boneless Mackerel',NULL,1,1,7),
-(98,'Day Matured Rump Steak',NULL,1,21,7),
-(99,'Beef Mince 12% Fat','g',1,500,7),
-(100,'Parma Ham Slices',NULL,1,6,7),
-(101,'Pork and Red Onion Sausages',NULL,1,6,7),
-(102,'Fresh Chicken Skin on Drumsticks','g',1,1000,7),
-(103,'Smoked Streaky Bacon Rashers','g',1,300,7),
-(104,'Smoked Back Bacon Rashers 2x250g',NULL,1,16,7),
-(105,'Just Cook Hunters Chicken Breasts','g',1,430,7),
-(106,'Day Matured Sizzler Steaks',NULL,1,21,7),
-(107,'Wiltshire Cured Unsmoked Back Bacon',NULL,1,8,7),
-(108,'Roast Boneless Cooked Chicken Breast',NULL,1,4,7),
-(109,'Yorkshire Puddings',NULL,1,6,7),
-(110,'Pork & Apple Sausages',NULL,1,6,7),
-(111,'Fresh Skin on Chicken Legs','g',1,1000,7),
-(112,'Oak Smoked Dry Cure Bacon x',NULL,1,6,7),
-(113,'Day Matured Thin Cut Sirloin Steak',NULL,1,21,7),
-(114,'Lamb Leg Steaks','g',1,300,7),
-(115,'10% Fat Lamb Mince','g',1,500,7),
-(116,'20% Fat Lamb Mince','g',1,500,7),
-(117,'Pork Sausage',NULL,1,12,7),
-(118,'Pork Loin Steaks',NULL,1,2,7),
-(119,'Pork Shoulder Joint',NULL,1,1,7),
-(120,'Extra Lean Casserole Steak','g',1,500,7),
-(121,'Lamb Rump Steaks','g',1,300,7),
-(122,'Day Matured Extra Lean Beef Roasting Joint',NULL,1,21,7),
-(123,'Pork Tenderloin Fillet',NULL,1,1,7),
-(124,'Lamb Liver',NULL,1,1,7),
-(125,'Unsmoked Back Bacon Rashers',NULL,1,16,7),
-(126,'Butcher''s Choice Pork Sausagemeat','g',1,400,7),
-(127,'Just Cook Butter Basted Chicken','g',1,500,7),
-(128,'Liver Sausage Slices','g',1,125,7),
-(129,'Pork Loin Steaks',NULL,1,8,7),
-(130,'Pork Loin Medallions',NULL,1,4,7),
-(131,'Wafer Thin Cooked Roast Beef Slices',NULL,1,5,7),
-(132,'Beef Meatballs','g',1,350,7),
-(133,'Unsmoked Streaky Bacon Rashers','g',1,300,7),
-(134,'7% Fat Fresh Turkey Mince','g',1,500,7),
-(135,'Pork Loin Steaks',NULL,1,4,7),
-(136,'Unsmoked Dry Cure Bacon x',NULL,1,6,7),
-(137,'Pork Boneless Crackling Loin Joint',NULL,1,1,7),
-(138,'Southern fried Fresh Chicken breast fillet',NULL,1,2,7),
-(139,'Loch Trout Fillets',NULL,1,2,7),
-(140,'Fishcakes Melting Middle Cod & Parsley Sauce Fishcakes',NULL,1,2,7),
-(141,'Days Matured Beef Sirloin Steak',NULL,1,30,7),
-(142,'Beef Steak Burgers',NULL,1,4,7),
-(143,'Beef steak Burgers',NULL,1,2,7),
-(144,'Beef Mince 12% Fat','g',1,500,7),
-(145,'Diced Beef','g',1,500,7),
-(146,'Lamb Chops','g',1,340,7),
-(147,'Extra Lean Diced Lamb','g',1,300,7),
-(148,'Ardennes Pâté','g',1,170,7),
-(149,'Pork Lunch Tongue Slices',NULL,1,6,7),
-(150,'Butcher''s Choice Pork & Bacon Pigs In Blankets',NULL,1,12,7),
-(151,'Whole Leg Of Lamb',NULL,1,1,7),
-(152,'Days Matured Beef Fillet Steak',NULL,1,30,7),
-(153,'Day Matured Ribeye Steak',NULL,1,21,7),
-(154,'Corn Fed Whole Chicken',NULL,1,1,7),
-(155,'Lincolnshire Pork Sausage',NULL,1,8,7),
-(156,'Lincolnshire Pork Sausages',NULL,1,6,7),
-(157,'Beef Steak & Caramelised Onion Burgers',NULL,1,2,7),
-(158,'Thick Cut Pork Chops',NULL,1,2,7),
-(159,'Days Matured Beef Ribeye Steak',NULL,1,30,7),
-(160,'Free Range Whole Fresh Chicken',NULL,1,1,7),
-(161,'Diced Beef','g',1,500,7),
-(162,'Fresh Pork Crackling Leg Joint 1kg-1.8kg',NULL,1,1,7),
-(163,'Pork Chops',NULL,1,4,7),
-(164,'Peppered Cooked Roast Beef Slices',NULL,1,3,7),
-(165,'Breaded Firecracker Fresh Chicken Breast',NULL,1,2,7),
-(166,'Chicken Liver Pâté','g',1,170,7),
-(167,'Days Matured Beef Rump Steak',NULL,1,30,7),
-(168,'Outdoor Bred Pork Cutlets',NULL,1,2,7),
-(169,'Day Matured Lean Beef Roasting Joint',NULL,1,21,7),
-(170,'Duck & Orange Pâté','g',1,170,7),
-(171,'Thin Cut Lamb Chops','g',1,425,7),
-(172,'Js Duck Legs','g',1,480,7),
-(173,'Breaded Wiltshire Cured Ham slices',NULL,1,4,7),
-(174,'Days Matured Beef Roasting Joint',NULL,1,30,7),
-(175,'Days Matured Beef Thin Cut Sirloin Steak',NULL,1,30,7),
-(176,'Pork Shoulder Steaks',NULL,1,6,7),
-(177,'Half Leg Of Lamb',NULL,1,1,7),
-(178,'Half Lamb Shoulder',NULL,1,1,7),
-(179,'New Zealand Lamb Neck Fillet',NULL,1,1,7),
-(180,'Day Matured Fillet Steak',NULL,1,21,7),
-(181,'Battered Fresh Chicken Nuggets','g',1,540,7),
-(182,'Slow Cooked Japanese Style Outdoor Bred Pork Belly Marinated','g',1,600,7),
-(183,'Mature Cheddar Cheese','g',1,400,7),
-(184,'Cypriot Halloumi Cheese','g',1,225,7),
-(185,'Greek Feta Cheese','g',1,200,7),
-(186,'Extra Mature Cheddar Cheese','g',1,400,7),
-(187,'Medium Cheddar Cheese','g',1,400,7),
-(188,'Parmigiano Reggiano Cheese','g',1,200,7),
-(189,'Grated Mozzarella Cheese','g',1,250,7),
-(190,'Mozzarella Cheese','g',1,150,7),
-(191,'Mature Grated Cheddar Cheese','g',1,250,7),
-(192,'Mild Cheddar Cheese','g',1,400,7),
-(193,'Mature Cheddar Cheese Slices','g',1,240,7),
-(194,'Grated Parmesan Cheese','g',1,120,7),
-(195,'White Soft Cheese','g',1,300,7),
-(196,'Cottage Cheese Fat Free','g',1,300,7),
-(197,'West Country Farmhouse Mature Cheddar Cheese','g',1,400,7),
-(198,'Fresh Grated Parmigiano Cheese','g',1,60,7),
-(199,'French Mild Brie Cheese','g',1,200,7),
-(200,'Red Leicester Cheese','g',1,250,7),
-(201,'Mature Grated Cheddar Cheese','g',1,500,7),
-(202,'Gouda Cheese Slices','g',1,250,7),
-(203,'Cottage Cheese','g',1,300,7),
-(204,'Barber''s Mature Cruncher Cheese','g',1,350,7),
-(205,'Spanish Manchego Cheese','g',1,170,7),
-(206,'Emmental Cheese Slices','g',1,250,7),
-(207,'Blue Stilton Cheese','g',1,220,7),
-(208,'Grana Padano Cheese','g',1,200,7),
-(209,'Grated Mozzarella Cheese','g',1,500,7),
-(210,'Mascarpone Cheese','g',1,250,7),
-(211,'Lighter Mature Cheese','g',1,400,7),
-(212,'Lighter Mature Grated Cheese','g',1,250,7),
-(213,'Light Soft Cheese','g',1,250,7),
-(214,'Double Gloucester Cheese','g',1,250,7),
-(215,'Cheesy Slices',NULL,1,10,7),
-(216,'Extra Mature Grated Cheddar Cheese','g',1,250,7),
-(217,'Ricotta Cheese','g',1,250,7),
-(218,'Goats'' Cheese','g',1,120,7),
-(219,'Mature Cheddar Cheese','g',1,270,7),
-(220,'Lighter Mozzarella Cheese','g',1,125,7),
-(221,'Brie Cheese','g',1,230,7),
-(222,'Swiss Mature Reserve Gruyere Cheese','g',1,200,7),
-(223,'Emmental Cheese','g',1,250,7),
-(224,'Medium Grated Cheddar Cheese','g',1,250,7),
-(225,'Fat Free Pineapple Cottage Cheese','g',1,300,7),
-(226,'Abergavenny Goats Cheese','g',1,125,7),
-(227,'Fat Free Onion & Chive Cottage Cheese','g',1,300,7),
-(228,'Edam Cheese','g',1,310,7),
-(229,'Edam Cheese Slices','g',1,250,7),
-(230,'Red Leicester Cheese Slices','g',1,240,7),
-(231,'Cottage Cheese','g',1,600,7),
-(232,'Mild Cheddar Cheese Slices','g',1,240,7),
-(233,'Medium Grated Cheddar Cheese','g',1,500,7),
-(234,'Stilton Blue Cheese','g',1,210,7),
-(235,'Mature Cheddar','g',1,220,7),
-(236,'Mild Cheddar Cheese','g',1,270,7),
-(237,'Shaved Parmesan Cheese','g',1,80,7),
-(238,'German Smoked Cheese','g',1,150,7),
-(239,'Brie Cheese','g',1,160,7),
-(240,'Gouda Cheese','g',1,265,7),
-(241,'Goats Cheese','g',1,100,7),
-(242,'Greek Feta Cubes In Brine','g',1,390,7),
-(243,'Lighter Cypriot Cheese','g',1,225,7),
-(244,'Cambozola Cheese','g',1,175,7),
-(245,'Mozzarella Cheese Pearls','g',1,125,7),
-(246,'Edam Cheese Slices','g',1,400,7),
-(247,'Dried Grated Hard Cheese','g',1,80,7),
-(248,'Greek Feta','g',1,200,7),
-(249,'Breaded Halloumi Fries',NULL,1,12,7),
-(250,'Extra Mature Cheddar','g',1,270,7),
-(251,'Pecorino Romano Cheese','g',1,170,7),
-(252,'Wensleydale Cheese','g',1,250,7),
-(253,'Parmigiano Reggiano Cheese','g',1,200,7),
-(254,'Medium Cheddar Cheese Slices','g',1,240,7),
-(255,'Mild Cheddar','g',1,220,7),
-(256,'Grated Red Leicester Cheese','g',1,250,7),
-(257,'Grated Mild Cheddar','g',1,250,7),
-(258,'Light Garlic & Herb Soft Cheese','g',1,250,7),
-(259,'Mature Grated White Cheese','g',1,250,7),
-(260,'Mozzarella Di Bufala Campana DOP','g',1,290,7),
-(261,'Roule Cheese','g',1,125,7),
-(262,'Wensleydale & Apricot Cheese','g',1,200,7),
-(263,'Reduced Fat Cheesy Slices','g',1,200,7),
-(264,'Lighter Mature Cheese Slices','g',1,240,7),
-(265,'Barber''s Red Cruncher Cheese','g',1,350,7),
-(266,'Mature Cheddar Sticks 5x20g',NULL,1,1,7),
-(267,'Austrian Smoked Cheese','g',1,125,7),
-(268,'Parmigiano Reggiano Cheese','g',1,200,7),
-(269,'Extra Mature Cheddar Cheese Slices','g',1,240,7),
-(270,'Double Gloucester Cheese & Chive','g',1,200,7),
-(271,'Red Leicester Cheese','g',1,270,7),
-(272,'Grana Padano Riserva Cheese','g',1,150,7),
-(273,'Barrel Aged Feta','g',1,200,7),
-(274,'Medium Cheddar','g',1,220,7),
-(275,'TTD Shropshire Red','g',1,200,7),
-(276,'Vintage Cheddar','g',1,270,7),
-(277,'Ossau Iraty Cheese','g',1,180,7),
-(278,'Smoky Cheddar Cheese','g',1,200,7),
-(279,'Cypriot Halloumi','g',1,250,7),
-(280,'Wensleydale Cheese','g',1,240,7),
-(281,'Mozzarella Pearls','g',1,125,7),
-(282,'Gorgonzola Dolce Cheese','g',1,200,7),
-(283,'White Cheshire Cheese','g',1,250,7),
-(284,'Mature Cheddar Sticks 10x20g',NULL,1,1,7),
-(285,'Greek Style Salad Cheese Be Good to Yourself','g',1,200,7),
-(286,'Beacon Fell Creamy Lancashire Cheese','g',1,240,7),
-(287,'Cheddar & Onion','g',1,200,7),
-(288,'Coconut Based alternative To Soft Cheese','g',1,170,7),
-(289,'West Country Farmhouse Mature Cheddar','g',1,220,7),
-(290,'Cheese Selection','g',1,320,7),
-(291,'Chaource Cheese','g',1,250,7),
-(292,'Grated Cheddar-Style Coconut Based','g',1,200,7),
-(293,'Favourite Cheese Selection','g',1,456,7),
-(294,'Lighter Mature Cheese','g',1,220,7),
-(295,'Mozzarella Slices','g',1,250,7),
-(296,'French Goats Cheese Mild','g',1,150,7),
-(297,'French Camembert Cheese','g',1,250,7),
-(298,'Extra Mature Cheddar','g',1,220,7),
-(299,'Lighter Mature Cheese Sticks 5x20g',NULL,1,1,7),
-(300,'Vintage Gouda Cheese','g',1,180,7),
-(301,'Soft Cheese','g',1,250,7),
-(302,'Chipotle Chilli Cheddar Cheese','g',1,200,7),
-(303,'French Camembert Cheese Whole','g',1,250,7),
-(304,'Welsh Slate Cavern Aged Cheddar','g',1,200,7),
-(305,'Epoisses Cheese','g',1,125,7),
-(306,'Cheddar-Style Coconut Based','g',1,200,7),
-(307,'Cheese Selection Classic Assortment','g',1,285,7),
-(308,'Continental Grated Cheese','g',1,250,7),
-(309,'Vintage Reserve Cheddar','g',1,350,7),
-(310,'Breaded Camembert','g',1,200,7),
-(311,'Truffle Gouda','g',1,180,7),
-(312,'Lancashire Cheese','g',1,250,7),
-(313,'Smoked Shropshire Red',NULL,1,1,7),
-(314,'Sliced Cheddar Style','g',1,180,7),
-(315,'Blue D''affinois','g',1,180,7),
-(316,'Fromager Excellnce Triple Cream','g',1,185,7),
-(317,'Greek Style','g',1,200,7),
-(318,'Free Range Eggs Large',NULL,1,12,14),
-(319,'Free Range Eggs Large',NULL,1,6,14),
-(320,'Free Range Eggs Medium',NULL,1,12,14),
-(321,'Free Range Eggs Medium',NULL,1,6,14),
-(322,'Woodland Free Range Golden Yolked Mixed Weight Eggs',NULL,1,6,14),
-(323,'Free Range Eggs Medium',NULL,1,15,14),
-(324,'Woodland Free Range Medium Eggs',NULL,1,6,14),
-(325,'Woodland Free Range Large Eggs',NULL,1,6,14),
-(326,'Woodland Free Range Mixed Weight Eggs',NULL,1,12,14),
-(327,'Woodland Trust Free Range Blue Mixed Weight Eggs',NULL,1,6,14),
-(328,'Unsweetened Soya Drink','ml',1,1000,7),
-(329,'Oat Drink','ml',1,1000,7),
-(330,'Unsweetened Almond Drink','ml',1,1000,7),
-(331,'Sweetened Soya Drink','ml',1,1000,7),
-(332,'Unsweetened Almond Drink','ml',1,1000,7),
-(333,'Unsweetened Soya Drink','ml',1,1000,7),
-(334,'Sweetened Coconut Drink','ml',1,1000,7),
-(335,'Sweetened Almond Drink','ml',1,1000,7),
-(336,'Sweetened Soya Drink','ml',1,1000,7),
-(337,'Bananas',NULL,1,1,7),
-(338,'Bananas',NULL,1,5,7),
-(339,'Red Grapes','g',1,500,7),
-(340,'Easy Peelers','g',1,600,7),
-(341,'Blueberries','g',1,150,7),
-(342,'Raspberries','g',1,150,7),
-(343,'Bananas',NULL,1,8,7),
-(344,'Royal Gala Apples',NULL,1,6,7),
-(345,'White Grapes','g',1,500,7),
-(346,'Lemons',NULL,1,1,7),
-(347,'Lemons',NULL,1,5,7),
-(348,'Blueberries','g',1,500,7),
-(349,'Conference Pears',NULL,1,4,7),
-(350,'Blueberries','g',1,300,7),
-(351,'Small Bananas',NULL,1,8,7),
-(352,'Strawberries','g',1,400,7),
-(353,'Pink Lady Apples',NULL,1,4,7),
-(354,'Pink Lady Apples',NULL,1,6,7),
-(355,'Easy Peelers','g',1,600,7),
-(356,'Limes',NULL,1,1,7),
-(357,'Braeburn Apples',NULL,1,6,7),
-(358,'Raspberries','g',1,250,7),
-(359,'Oranges',NULL,1,4,7),
-(360,'Bananas',NULL,1,6,7),
-(361,'Red & White Grapes','g',1,500,7),
-(362,'Granny Smith Apples',NULL,1,6,7),
-(363,'Honeydew Melon',NULL,1,1,7),
-(364,'Jazz Apples',NULL,1,6,7),
-(365,'Tangerines','g',1,600,7),
-(366,'Oranges Large',NULL,1,4,7),
-(367,'Royal Gala Apples',NULL,1,6,7),
-(368,'Conference Pear Single',NULL,1,1,7),
-(369,'Plum Punnet','g',1,400,7),
-(370,'Red Grapefruit',NULL,1,1,7),
-(371,'Limes',NULL,1,5,7),
-(372,'Best Of Apples',NULL,1,6,7),
-(373,'Black Sable Grapes','g',1,400,7),
-(374,'Bramley Cooking Apples',NULL,1,1,7),
-(375,'Oranges',NULL,1,5,7),
-(376,'Mango Single',NULL,1,1,7),
-(377,'Black Grapes','g',1,500,7),
-(378,'Pomegranate','g',1,80,7),
-(379,'Cantaloupe Melon',NULL,1,1,7),
-(380,'Blueberries','g',1,150,7),
-(381,'Large Pineapple',NULL,1,1,7),
-(382,'Mixed Grapes','g',1,900,7),
-(383,'Mango','g',1,250,7),
-(384,'Braeburn Apple Single',NULL,1,1,7),
-(385,'Apples','g',1,1000,7),
-(386,'Kanzi Apples',NULL,1,6,7),
-(387,'Lemons',NULL,1,3,7),
-(388,'Crownless Pineapple',NULL,1,1,7),
-(389,'Nectarine Punnet',NULL,1,1,7),
-(390,'Oranges',NULL,1,1,7),
-(391,'Watermelon',NULL,1,1,7),
-(392,'Cherries','g',1,250,7),
-(393,'Pink Lady Apple Single',NULL,1,1,7),
-(394,'Pomegranate','g',1,250,7),
-(395,'Blackberries','g',1,225,7),
-(396,'Mango','g',1,120,7),
-(397,'Baby Watermelon',NULL,1,1,7),
-(398,'Galia Melon',NULL,1,1,7),
-(399,'Medjool Dates','g',1,200,7),
-(400,'Pineapple','g',1,160,7),
-(401,'Golden Delicious Apples',NULL,1,6,7),
-(402,'Grapes','g',1,400,7),
-(403,'Passion Fruit',NULL,1,1,7),
-(404,'Pomegranate Single',NULL,1,1,7),
-(405,'Easy Peeler',NULL,1,1,7),
-(406,'Easy Peelers min','g',1,500,7),
-(407,'Large Kiwi Fruit Single',NULL,1,1,7),
-(408,'Braeburn Apples',NULL,1,6,7),
-(409,'Pink Lady Apples',NULL,1,6,7),
-(410,'Royal Gala Apple Single',NULL,1,1,7),
-(411,'Ripe Mangoes',NULL,1,2,7),
-(412,'Medjool Dates','g',1,500,7),
-(413,'Figs','g',1,140,7),
-(414,'Medjool Dates','g',1,200,7),
-(415,'Watermelon','g',1,160,7),
-(416,'Strawberries',NULL,1,1,7),
-(417,'Red Grapes','g',1,300,7),
-(418,'Granny Smith Apples Single',NULL,1,1,7),
-(419,'Rhubarb','g',1,400,7),
-(420,'White Grapefruit',NULL,1,1,7),
-(421,'Blood Oranges',NULL,1,5,7),
-(422,'Royal Gala Apples',NULL,1,4,7),
-(423,'Lychees','g',1,200,7),
-(424,'Raspberries','g',1,150,7),
-(425,'White Grapes','g',1,300,7),
-(426,'Jazz Apples',NULL,1,4,7),
-(427,'Mango & Watermelon Fingers','g',1,270,7),
-(428,'Melon & Grape','g',1,160,7),
-(429,'Pineapple','g',1,500,7),
-(430,'Deglet Nour Dates','g',1,200,7),
-(431,'Berry Fruit Salad','g',1,240,7),
-(432,'Melon & Pineapple Fingers','g',1,260,7),
-(433,'Blueberries',NULL,1,1,7),
-(434,'Physalis','g',1,100,7),
-(435,'Coconut','g',1,80,7),
-(436,'Cherries','g',1,500,7),
-(437,'Rocha Pears',NULL,1,6,7),
-(438,'White Grapes','g',1,400,7),
-(439,'Best Of Apples',NULL,1,4,7),
-(440,'Pink Lady Apple','g',1,350,7),
-(441,'Vitoria Grape','g',1,400,7),
-(442,'Apple Pineapple & Grape','g',1,160,7),
-(443,'Watermelon','g',1,300,7),
-(444,'Braeburn Apples',NULL,1,1,7),
-(445,'Grapes&Berries','g',1,115,7),
-(446,'Pineapple','g',1,300,7),
-(447,'Monkey Nuts in Shell','g',1,500,7),
-(448,'Exotic Fruit Salad','g',1,240,7),
-(449,'Coconut Single',NULL,1,1,7),
-(450,'Melon Kiwi & Strawberry','g',1,220,7),
-(451,'Apple Mango Strawberry & Raspberry','g',1,220,7),
-(452,'Zamli Dates','g',1,200,7),
-(453,'Melon & Grape','g',1,300,7),
-(454,'Apple & Grape','g',1,130,7),
-(455,'Oranges Family Size',NULL,1,10,7),
-(456,'Melon Stone Fruit& Raspberry','g',1,220,7),
-(457,'Apple Banana Strawberry Grape','g',1,220,7),
-(458,'Giant Mango',NULL,1,1,7),
-(459,'Red Kiwi',NULL,1,1,7),
-(460,'Whole Cucumber',NULL,1,1,7),
-(461,'Broccoli',NULL,1,1,7),
-(462,'Cherry Vine Tomatoes','g',1,250,7),
-(463,'Carrots','g',1,1000,7),
-(464,'Sweet Peppers',NULL,1,3,7),
-(465,'Red Pepper',NULL,1,1,7),
-(466,'Tenderstem Broccoli','g',1,200,7),
-(467,'Closed Cup White Mushrooms','g',1,300,7),
-(468,'Spring Onions','g',1,100,7),
-(469,'Maris Piper Potatoes','g',1,2000,7),
-(470,'Carrots',NULL,1,1,7),
-(471,'Onions',NULL,1,1,7),
-(472,'Baby Potatoes','g',1,1000,7),
-(473,'Closed Cup Chestnut Mushrooms','g',1,300,7),
-(474,'Cauliflower',NULL,1,1,7),
-(475,'Brown Onions',NULL,1,3,7),
-(476,'Baking Potatoes',NULL,1,4,7),
-(477,'Baby Leaf Spinach','g',1,100,7),
-(478,'Red Onions',NULL,1,1,7),
-(479,'Whole Cucumber',NULL,1,1,7),
-(480,'White Potatoes','g',1,2000,7),
-(481,'Celery',NULL,1,1,7),
-(482,'Sweet Potatoes',NULL,1,1,7),
-(483,'Classic Round Tomatoes',NULL,1,6,7),
-(484,'Carrots','g',1,700,7),
-(485,'Courgettes',NULL,1,1,7),
-(486,'Fine Green Beans','g',1,200,7),
-(487,'Garlic',NULL,1,1,7),
-(488,'Baby Plum Tomatoes','g',1,325,7),
-(489,'Courgettes','g',1,500,7),
-(490,'Aubergine',NULL,1,1,7),
-(491,'Onions','g',1,1000,7),
-(492,'Half Cucumber Portion',NULL,1,1,7),
-(493,'Baking Potatoes',NULL,1,1,7),
-(494,'Red Onions',NULL,1,3,7),
-(495,'Fresh Packed Coriander','g',1,30,7),
-(496,'Young Spinach','g',1,260,7),
-(497,'Majestic Vine Ripened Tomatoes','g',1,300,7),
-(498,'Yellow Pepper',NULL,1,1,7),
-(499,'Leeks','g',1,500,7),
-(500,'Baking Potatoes','g',1,2000,7),
-(501,'Vivaldi Baking Potatoes',NULL,1,4,7),
-(502,'Sweetheart Cabbage',NULL,1,1,7),
-(503,'Large Vine Tomatoes','g',1,500,7),
-(504,'Large Garlic',NULL,1,1,7),
-(505,'Sweet Gem Lettuce',NULL,1,2,7),
-(506,'Broccoli','g',1,300,7),
-(507,'Extra Fine Beans','g',1,200,7),
-(508,'Kale','g',1,200,7),
-(509,'Parsnips',NULL,1,1,7),
-(510,'Swede',NULL,1,1,7),
-(511,'Fresh Packed Flat Leaf Parsley','g',1,30,7),
-(512,'Carrots','g',1,500,7),
-(513,'Fresh Packed Basil','g',1,30,7),
-(514,'Baby Button Mushrooms','g',1,200,7),
-(515,'Baby Plum Tomatoes','g',1,250,7),
-(516,'Sweet Potato','g',1,1250,7),
-(517,'Parsnips','g',1,500,7),
-(518,'Brussels Sprouts','g',1,500,7),
-(519,'King Edward Potatoes','g',1,2000,7),
-(520,'Leeks',NULL,1,1,7),
-(521,'Green Pepper',NULL,1,1,7),
-(522,'Sugar Snap Peas','g',1,200,7),
-(523,'Garlic',NULL,1,4,7),
-(524,'Baby Leaf Spinach','g',1,200,7),
-(525,'Root Ginger',NULL,1,1,7),
-(526,'Large Whole Cucumber',NULL,1,1,7),
-(527,'Butternut Squash',NULL,1,1,7),
-(528,'Cherry Tomatoes','g',1,330,7),
-(529,'Asparagus Spears','g',1,250,7),
-(530,'Free Range Fresh Egg Noodles','g',1,410,7),
-(531,'Large Flat White Mushrooms','g',1,250,7),
-(532,'Red Onions','g',1,1000,7),
-(533,'Red Chillies','g',1,65,7),
-(534,'Beetroot Vacuum Pack','g',1,250,7),
-(535,'Closed Cup White Mushrooms','g',1,500,7),
-(536,'Savoy Cabbage',NULL,1,1,7),
-(537,'Potatoes','g',1,750,7),
-(538,'White Vivaldi Potatoes','g',1,2000,7),
-(539,'Charlotte Potatoes','g',1,1000,7),
-(540,'Cherry Tomatoes','g',1,500,7),
-(541,'Traditionally Sliced Runner Beans','g',1,160,7),
-(542,'Echalion Shallots','g',1,400,7),
-(543,'Babycorn','g',1,175,7),
-(544,'Fresh Packed Mint','g',1,30,7),
-(545,'Best of Tomatoes','g',1,250,7),
-(546,'Celery',NULL,1,1,7),
-(547,'Fresh Packed Coriander','g',1,100,7),
-(548,'Baby Corn & Mange Tout','g',1,200,7),
-(549,'Red Sweet Pointed Pepper',NULL,1,1,7),
-(550,'Beetroot In Vinegar','g',1,300,7),
-(551,'Carrot Batons','g',1,400,7),
-(552,'Cavolo Nero Black Kale','g',1,200,7),
-(553,'Sweetcorn Cobettes',NULL,1,4,7),
-(554,'White Cabbage Each',NULL,1,1,7),
-(555,'Red Potatoes','g',1,2000,7),
-(556,'Plum Vine Tomatoes','g',1,250,7),
-(557,'Asparagus Tips','g',1,100,7),
-(558,'Cherry Vine Tomatoes','g',1,400,7),
-(559,'Greens','g',1,500,7),
-(560,'Chantenay Carrots','g',1,400,7),
-(561,'Pak Choi','g',1,250,7),
-(562,'Beansprouts','g',1,300,7),
-(563,'Sweetcorn',NULL,1,2,7),
-(564,'Onions','g',1,750,7),
-(565,'Ginger','g',1,100,7),
-(566,'Trimmed Mange Tout','g',1,200,7),
-(567,'Thyme','g',1,20,7),
-(568,'Fresh Salad Cress','g',1,20,7),
-(569,'Chestnut Super Mushrooms','g',1,300,7),
-(570,'Salad Onions Large','g',1,130,7),
-(571,'Shiitake Mushrooms',NULL,1,1,7),
-(572,'Young Spinach','g',1,200,7),
-(573,'Brussels Sprouts','g',1,200,7),
-(574,'Tomatoes',NULL,1,1,7),
-(575,'Sweet & Crunchy Stir Fry','g',1,600,7),
-(576,'Mixed Peppers',NULL,1,3,7),
-(577,'Red Cabbage Each',NULL,1,1,7),
-(578,'Spring Onion','g',1,100,7),
-(579,'White Potatoes','g',1,1500,7),
-(580,'Baby Vegetable Stir Fry','g',1,220,7),
-(581,'Stringless Beans','g',1,225,7),
-(582,'Dill','g',1,20,7),
-(583,'Chestnut Baby Button Mushrooms','g',1,200,7),
-(584,'Whole Green Beans','g',1,900,7),
-(585,'Broccoli Florets','g',1,160,7),
-(586,'Oriental Vegetable Stir Fry','g',1,300,7),
-(587,'Leeks','g',1,400,7),
-(588,'Chives','g',1,20,7),
-(589,'Mixed Chillies','g',1,80,7),
-(590,'Butternut Squash Slices','g',1,400,7),
-(591,'Closed Cup White Mushrooms',NULL,1,1,7),
-(592,'Sweet Spear Carrots','g',1,450,7),
-(593,'Baby Sweet Peppers','g',1,300,7),
-(594,'Cauliflower Extra Large',NULL,1,1,7),
-(595,'Beetroot','g',1,500,7),
-(596,'Orange Pepper',NULL,1,1,7),
-(597,'Singapore Style Noodles','g',1,300,7),
-(598,'Potato Carrot & Swede Mash','g',1,400,7),
-(599,'Mushroom Stir Fry','g',1,320,7),
-(600,'Crispy Potato Slices','g',1,350,7),
-(601,'Dwarf Beans','g',1,300,7),
-(602,'Broccoli & Cauliflower Florets','g',1,240,7),
-(603,'White Closed Cup Mushrooms','g',1,300,7),
-(604,'Fennel',NULL,1,1,7),
-(605,'Sweet Pointed Peppers',NULL,1,2,7),
-(606,'Sweet Potato','g',1,750,7),
-(607,'Mixed Pepper Stir Fry','g',1,300,7),
-(608,'Vivaldi Salad Potatoes','g',1,1000,7),
-(609,'Garlic',NULL,1,3,7),
-(610,'Fresh Packed Rosemary','g',1,20,7),
-(611,'Sliced White Mushrooms','g',1,250,7),
-(612,'Chinese Leaf Lettuce',NULL,1,1,7),
-(613,'Carrot Cauliflower & Broccoli','g',1,240,7),
-(614,'Chestnut Mushrooms','g',1,300,7),
-(615,'Courgettes','g',1,300,7),
-(616,'Fresh Living Basil Pot',NULL,1,1,7),
-(617,'Tenderstem Broccoli','g',1,200,7),
-(618,'Diced Onions','g',1,200,7),
-(619,'Beetroot Vacuum Pack','g',1,250,7),
-(620,'Bird Eye Chillies','g',1,20,7),
-(621,'Runner Beans','g',1,225,7),
-(622,'Carrots','g',1,400,7),
-(623,'Plum Tomatoes','g',1,500,7),
-(624,'Fine Beans & Tenderstem Broccoli','g',1,200,7),
-(625,'Casserole Vegetables','g',1,700,7),
-(626,'Cabbage',NULL,1,1,7),
-(627,'Fresh Packed Flat Leaf Parsley','g',1,100,7),
-(628,'Mixed Vegetable Stir Fry','g',1,325,7),
-(629,'Shallots','g',1,400,7),
-(630,'Red Onions','g',1,500,7),
-(631,'Rice Noodles','g',1,300,7),
-(632,'Purple Sprouting Broccoli Spears','g',1,200,7),
-(633,'Beans Tenderstem & Baby Corn','g',1,240,7),
-(634,'Green Chillies','g',1,65,7),
-(635,'Stir Fry Vegetables','g',1,500,7),
-(636,'Edamame Stir Fry','g',1,300,7),
-(637,'Cauliflower Cheese','g',1,400,7),
-(638,'Baby Corn','g',1,125,7),
-(639,'Rainbow Chantenay Carrots',NULL,1,1,7),
-(640,'Potato Croquettes','g',1,220,7),
-(641,'Portabella Mushrooms','g',1,150,7),
-(642,'Cauliflower',NULL,1,1,7),
-(643,'Bellaverde Broccoli','g',1,200,7),
-(644,'Spinach','g',1,100,7),
-(645,'Brussels Sprouts',NULL,1,1,7),
-(646,'Diced Butternut & Sweet Potato','g',1,300,7),
-(647,'Speciality Mushroom Mix',NULL,1,1,7),
-(648,'Plum & Hoisin Stir Fry Sauce','g',1,175,7),
-(649,'Finger Chillies','g',1,80,7),
-(650,'Fresh Living Coriander Pot',NULL,1,1,7),
-(651,'Tenderstem Broccoli','g',1,330,7),
-(652,'Fresh Curly Parsley','g',1,30,7),
-(653,'Celeriac',NULL,1,1,7),
-(654,'Shelled Peas','g',1,160,7),
-(655,'Chunky Mediterranean Style Vegetables','g',1,600,7),
-(656,'Carrot & Swede Mash','g',1,400,7),
-(657,'Edamame Beans','g',1,175,7),
-(658,'Heritage Baby Parsnips','g',1,230,7),
-(659,'Cabbage & Leek','g',1,200,7),
-(660,'Tenderstem Stir Fry','g',1,220,7),
-(661,'Cauliflower Cheese','g',1,400,7),
-(662,'Fresh Packed Lemongrass Sticks',NULL,1,2,7),
-(663,'Fresh Packed Sage','g',1,20,7),
-(664,'Tomatoes','g',1,225,7),
-(665,'Butternut Squash',NULL,1,1,7),
-(666,'Baby Courgettes','g',1,200,7),
-(667,'Red Chicory','g',1,140,7),
-(668,'Okra','g',1,175,7),
-(669,'Sliced Mushrooms','g',1,500,7),
-(670,'Sliced Red Onions','g',1,150,7),
-(671,'Baby Salad Potatoes','g',1,750,7),
-(672,'Asparagus Sugar Snaps & Tenderstem Broccoli','g',1,200,7),
-(673,'Vibrant & Sweet Diced Swede & Carrots','g',1,600,7),
-(674,'Potato Dauphinoise','g',1,400,7),
-(675,'Butternut Squash & Spinach Croquettes','g',1,225,7),
-(676,'Aubergine',NULL,1,1,7),
-(677,'Fresh Packed Tarragon','g',1,20,7),
-(678,'White Chicory','g',1,250,7),
-(679,'White Turnips',NULL,1,1,7),
-(680,'Chinese Sauce','g',1,175,7),
-(681,'Cauliflower Rice','g',1,300,7),
-(682,'Dwarf Beans','g',1,210,7),
-(683,'Choi Sum','g',1,200,7),
-(684,'Brussels Sprouts','g',1,400,7),
-(685,'Cheese & Garlic Mushrooms','g',1,200,7),
-(686,'Thai Basil','g',1,20,7),
-(687,'Black Bean Sauce','g',1,175,7),
-(688,'Katsu Curry Stir Fry Sauce','g',1,175,7),
-(689,'Hot & Spicy Stir Fry','g',1,300,7),
-(690,'Soy Ginger & Garlic Stir Fry Sauce','g',1,175,7),
-(691,'Fresh Living Mint Pot',NULL,1,1,7),
-(692,'Fresh Living Curly Parsley Pot',NULL,1,1,7),
-(693,'Baking Sweet Potatoes',NULL,1,2,7),
-(694,'Broccoli Baby Corn Carrots & Sugar Snap Peas','g',1,240,7),
-(695,'Baby Cauliflowers',NULL,1,2,7),
-(696,'Chunky Roast Potatoes','g',1,450,7),
-(697,'Anya Potatoes','g',1,750,7),
-(698,'Sweet Onions',NULL,1,3,7),
-(699,'Fresh Living Flat Leaf Parsley Pot',NULL,1,1,7),
-(700,'Scotch Bonnet Chillies','g',1,60,7),
-(701,'Baby Leeks','g',1,175,7),
-(702,'Fresh Living Thyme Pot',NULL,1,1,7),
-(703,'Sweet Potato Mash','g',1,400,7),
-(704,'Jacket Potatoes','g',1,345,7),
-(705,'Baby Cabbage',NULL,1,2,7),
-(706,'Peas & Sweetcorn Layered Vegetable','g',1,350,7),
-(707,'Rainbow Chard','g',1,200,7),
-(708,'Baby Brussels Sprouts',NULL,1,1,7),
-(709,'Mixed Sweet Pointed Peppers',NULL,1,2,7),
-(710,'Fresh Packed Bay Leaves',NULL,1,1,7),
-(711,'Carrot Cauliflower & Broccoli','g',1,480,7),
-(712,'Roast Potatoes','g',1,400,7),
-(713,'Sprout Tree',NULL,1,1,7),
-(714,'Fresh Living Chives Pot',NULL,1,1,7),
-(715,'Cheese Stuffed Peppers','g',1,320,7),
-(716,'Roasting Herb Mix','g',1,30,7),
-(717,'Rumbledethumps','g',1,400,7),
-(718,'Broccoli Cheese','g',1,400,7),
-(719,'Baby Aubergines','g',1,200,7),
-(720,'Samphire','g',1,90,7),
-(721,'Fresh Living Rosemary',NULL,1,1,7),
-(722,'Vegetable Soup Mix','g',1,600,7),
-(723,'Extra Large Brown Onions',NULL,1,2,7),
-(724,'Curry Leaves','g',1,5,7),
-(725,'Fresh Packed Lime Leaves',NULL,1,4,7),
-(726,'Minted Tenderstem Broccoli & Asparagus','g',1,260,7),
-(727,'Creamed Green Cabbage','g',1,250,7),
-(728,'Fresh Turmeric','g',1,100,7),
-(729,'Baby Romanesco Cauliflower',NULL,1,2,7),
-(730,'White Flesh Sweet Potato','g',1,750,7),
-(731,'Freeze Dried Raspberries','g',1,6,7),
-(732,'Carrots & Peas','g',1,480,7),
-(733,'Fresh Galangal','g',1,100,7),
-(734,'Thai Style Green Curry Herb Kit','g',1,80,7),
-(735,'Teriyaki Stir Fry Sauce','g',1,175,7),
-(736,'Sweet Chilli Stir Fry Sauce','g',1,175,7),
-(737,'Garlic Paste','g',1,85,7),
-(738,'Ginger Paste','g',1,80,7),
-(739,'Fresh Oregano','g',1,20,7),
-(740,'Chilli Paste','g',1,80,7),
-(741,'Middle Eastern Inspired Herb Mix','g',1,40,7),
-(742,'Soft Multiseed Farmhouse Thick Sliced Wholemeal Bread','g',1,800,7),
-(743,'Medium Sliced Wholemeal Bread','g',1,800,7),
-(744,'Toastie Thick Sliced White Bread','g',1,800,7),
-(745,'Soft Medium Sliced White Bread','g',1,800,7),
-(746,'Garlic Baguette',NULL,1,2,7),
-(747,'Sourdough Medium Sliced White Bread','g',1,400,7),
-(748,'Multiseed Loaf','g',1,800,7),
-(749,'Crusty White Baguette','g',1,400,7),
-(750,'Thick Sliced Wholemeal Bread','g',1,800,7),
-(751,'Sourdough Pave','g',1,400,7),
-(752,'Seeded Sourdough Loaf Bread','g',1,400,7),
-(753,'Wholemeal Bread','g',1,400,7),
-(754,'All Butter Croissants',NULL,1,4,7),
-(755,'Wholemeal Sourdough Half Bloomer Bread','g',1,400,7),
-(756,'Garlic Baguette','g',1,195,7),
-(757,'Giraffe Bread','g',1,800,7),
-(758,'Kalamata Olive Bread','g',1,400,7),
-(759,'Wheat & Rye Sourdough Half Bloomer Bread','g',1,400,7),
-(760,'White Baton',NULL,1,1,7),
-(761,'Garlic Baguette',NULL,1,2,7),
-(762,'Soft Multiseed Wholemeal','g',1,430,7),
-(763,'Giraffe Baguette','g',1,400,7),
-(764,'Ancient Grain Pave','g',1,400,7),
-(765,'Super Seeds White Farmhouse','g',1,800,7),
-(766,'San Francisco Style Sourdough','g',1,400,7),
-(767,'Thick Lightly Seeded','g',1,800,7),
-(768,'Farmhouse Loaf White Bread','g',1,800,7),
-(769,'Multiseed Loaf','g',1,400,7),
-(770,'Sandwich Loaf White Bread','g',1,800,7),
-(771,'Walnut Cob','g',1,400,7),
-(772,'Giraffe Bread','g',1,400,7),
-(773,'Farmhouse White Bread','g',1,400,7),
-(774,'Sunflower & Pumpkin','g',1,800,7),
-(775,'White Sourdough Bloomer Thick Sliced','g',1,800,7),
-(776,'Medium Both in One','g',1,800,7),
-(777,'Wholemeal Bread Medium','g',1,400,7),
-(778,'Cheese Baton',NULL,1,1,7),
-(779,'Stonebaked Ciabatta','g',1,400,7),
-(780,'Hand Crafted Bread','g',1,400,7),
-(781,'Rosemary & Sea Salt Focaccia','g',1,375,7),
-(782,'White Bloomer','g',1,800,7),
-(783,'Multiseed Medium Sliced Half Bloomer Bread','g',1,400,7),
-(784,'Sliced Brioche Loaf','g',1,500,7),
-(785,'Toastie Sliced White Bread','g',1,400,7),
-(786,'White Sliced Bread','g',1,535,7),
-(787,'Sliced Seeded Bread','g',1,535,7),
-(788,'Thick Soft White Farmhouse','g',1,800,7),
-(789,'Baguettine',NULL,1,1,7),
-(790,'Seeded Baguette','g',1,400,7),
-(791,'Beer Sourdough Loaves','g',1,400,7),
-(792,'White Bread','g',1,400,7),
-(793,'Brown Sliced Bread','g',1,535,7),
-(794,'Tiger Loaf','g',1,400,7),
-(795,'Multigrain & Rye Bloomer','g',1,400,7),
-(796,'White Bloomer','g',1,400,7),
-(797,'Well Fired Loaf','g',1,400,7),
-(798,'Wholemeal Rustic Roll',NULL,1,1,7),
-(799,'Seeded Sourdough Cob','g',1,400,7),
-(800,'Artisan West Country Cheddar Stick',NULL,1,1,7),
-(801,'Stonebaked White Baguette',NULL,1,1,7),
-(802,'Giraffe Baton',NULL,1,1,7),
-(803,'Sourdough Bloomer','g',1,400,7),
-(804,'Fruit Loaf','g',1,385,7),
-(805,'Cheese Bloomer','g',1,400,7),
-(806,'Brown Sourdough','g',1,400,7),
-(807,'Stonebaked White Baton',NULL,1,1,7),
-(808,'Oregano','g',1,12,365),
-(809,'Garlic Granules','g',1,56,365),
-(810,'Smoked Paprika','g',1,44,365),
-(811,'Thyme','g',1,12,365),
-(812,'Mixed Herbs','g',1,14,365),
-(813,'Paprika','g',1,44,365),
-(814,'Ground Cumin','g',1,43,365),
-(815,'Ground Cinnamon','g',1,38,365),
-(816,'Herb Seasoning','g',1,14,365),
-(817,'Parsley','g',1,6,365),
-(818,'Bay Leaves','g',1,3,365),
-(819,'Ground Coriander','g',1,36,365),
-(820,'Cajun Seasoning','g',1,50,365),
-(821,'Medium Curry Powder','g',1,80,365),
-(822,'Rosemary','g',1,22,365),
-(823,'Turmeric','g',1,48,365),
-(824,'Basil','g',1,15,365),
-(825,'Whole Black Peppercorns','g',1,100,365),
-(826,'Ground Ginger','g',1,38,365),
-(827,'Cayenne Pepper','g',1,42,365),
-(828,'Garam Masala','g',1,38,365),
-(829,'Cinnamon Sticks','g',1,13,365),
-(830,'Mild Chilli Powder','g',1,44,365),
-(831,'Mild Curry Powder','g',1,79,365),
-(832,'Coarse Ground Black Pepper','g',1,45,365),
-(833,'Sesame Seeds','g',1,50,365),
-(834,'Black Peppercorns','g',1,275,365),
-(835,'Fajita Seasoning','g',1,48,365),
-(836,'Chinese Spice',NULL,1,5,365),
-(837,'Black Peppercorns','g',1,45,365),
-(838,'Hot Chilli Powder','g',1,44,365),
-(839,'Cumin Seed','g',1,38,365),
-(840,'Chives','g',1,5,365),
-(841,'Ground Black Pepper','g',1,100,365),
-(842,'Ground Black Pepper','g',1,48,365),
-(843,'Chipotle Chilli Flakes','g',1,45,365),
-(844,'Ground White Pepper','g',1,44,365),
-(845,'Ground Nutmeg','g',1,50,365),
-(846,'Tamarind Paste','g',1,90,365),
-(847,'Sage','g',1,13,365),
-(848,'Fennel Seed','g',1,34,365),
-(849,'Tarragon','g',1,12,365),
-(850,'Ground Mixed Spice','g',1,34,365),
-(851,'Herbes De Provence','g',1,14,365),
-(852,'Ras El Hanout','g',1,45,365),
-(853,'Garlic & Herb Seasoning','g',1,40,365),
-(854,'Cardamom Pods','g',1,28,365),
-(855,'Hot Paprika','g',1,44,365),
-(856,'Miso Paste','g',1,100,365),
-(857,'Coriander Seed','g',1,25,365),
-(858,'Chicken Seasoning','g',1,55,365),
-(859,'Ground White Pepper','g',1,100,365),
-(860,'Coriander Leaf','g',1,9,365),
-(861,'Ground Fenugreek','g',1,50,365),
-(862,'Garlic & Ginger Paste','g',1,90,365),
-(863,'Makrut Lime Leaves','g',1,2,365),
-(864,'Ground Allspice','g',1,40,365),
-(865,'Mild & Smoky BBQ','g',1,44,365),
-(866,'Sumac','g',1,45,365),
-(867,'Mustard Seed','g',1,60,365),
-(868,'Nigella Seeds','g',1,48,365),
-(869,'Star Anise','g',1,15,365),
-(870,'Whole Cloves','g',1,30,365),
-(871,'Dill','g',1,13,365),
-(872,'Mint','g',1,12,365),
-(873,'Harissa Spices','g',1,45,365),
-(874,'Marjoram','g',1,10,365),
-(875,'Whole Nutmeg','g',1,36,365),
-(876,'Bouquet Garni','g',1,5,365),
-(877,'Celery Salt','g',1,78,365),
-(878,'Caraway Seed','g',1,43,365),
-(879,'Onion Salt','g',1,86,365),
-(880,'Ancho Chilli Flakes','g',1,27,365),
-(881,'Saffron','g',1,0.4,365),
-(882,'Tikka Curry Powder','g',1,90,365),
-(883,'Steak Seasoning','g',1,54,365),
-(884,'Mixed Peppercorns','g',1,43,365),
-(885,'Jerk Seasoning','g',1,50,365),
-(886,'Ginger Paste','g',1,90,365),
-(887,'Red Chilli Paste','g',1,90,365),
-(888,'Season All','g',1,67,365),
-(889,'Hot Curry Powder','g',1,83,365),
-(890,'Peri-Peri Seasoning','g',1,49,365),
-(891,'Ground Cloves','g',1,37,365),
-(892,'Chip Seasoning','g',1,56,365),
-(893,'Asafoetida','g',1,60,365),
-(894,'Middle Eastern Style Spice Mix','g',1,40,365),
-(895,'Ground Mace','g',1,42,365),
-(896,'Caribbean Style Seasoning','g',1,112,365),
-(897,'Garlic Paste','g',1,90,365),
-(898,'Juniper Berries','g',1,28,365),
-(899,'Hot & Smokey Chipotle','g',1,45,365),
-(900,'Red Kidney Beans in Water','g',1,400,365),
-(901,'Chickpeas in Water','g',1,400,365),
-(902,'Cannellini Beans In Water','g',1,400,365),
-(903,'Butter Beans In Water','g',1,400,365),
-(904,'Black Beans in Water','g',1,400,365),
-(905,'Green Lentils In Water','g',1,400,365),
-(906,'Red Kidney Beans in Chilli Sauce','g',1,400,365),
-(907,'Black Beans Carton','g',1,380,365),
-(908,'Mixed Beans in Mild Chilli Sauce','g',1,395,365),
-(909,'Red Kidney Beans in Water','g',1,215,365),
-(910,'Chickpeas','g',1,380,365),
-(911,'Borlotti Beans In Water','g',1,400,365),
-(912,'Quinoa','g',1,300,365),
-(913,'Black Eye Beans Water','g',1,400,365),
-(914,'Red Kidney Beans Carton','g',1,380,365),
-(915,'Mixed Bean Salad','g',1,400,365),
-(916,'Butter Beans Carton','g',1,380,365),
-(917,'Green Lentils','g',1,380,365),
-(918,'Haricot Beans In Water','g',1,400,365),
-(919,'Cracked Bulgur Wheat','g',1,500,365),
-(920,'Dried Green Lentils','g',1,500,365),
-(921,'Chickpeas','g',1,215,365),
-(922,'Cannellini Beans Carton','g',1,380,365),
-(923,'Butter Beans In Water','g',1,215,365),
-(924,'Red Lentils','g',1,1000,365),
-(925,'Dried Red Lentils','g',1,500,365),
-(926,'White Red & Black Quinoa','g',1,300,365),
-(927,'Red Lentils','g',1,500,365),
-(928,'Polenta','g',1,400,365),
-(929,'Dried Yellow Split Peas','g',1,500,365),
-(930,'Lentilles Vertes Bag','g',1,500,365),
-(931,'Dried Pinto Beans Bag','g',1,500,365),
-(932,'Buckwheat','g',1,500,365),
-(933,'Chickpeas','g',1,500,365),
-(934,'Cannellini Beans Bag','g',1,500,365),
-(935,'Green Lentils','g',1,250,365),
-(936,'Haricot Beans','g',1,500,365),
-(937,'Split Green Peas','g',1,500,365),
-(938,'Plain Flour','g',1,1500,365),
-(939,'Self Raising Flour','g',1,1500,365),
-(940,'Strong White Bread Flour','g',1,1500,365),
-(941,'Plain Flour','g',1,500,365),
-(942,'Cornflour','g',1,500,365),
-(943,'Fast Action Dried Yeast Sachets',NULL,1,8,365),
-(944,'Self Raising Flour','g',1,500,365),
-(945,'Wholegrain Seeded Flour','g',1,1000,365),
-(946,'Strong Stoneground 100% Wholemeal Flour','g',1,1500,365),
-(947,'Golden Multiseed Bread Mix','g',1,500,365),
-(948,'Grade Pasta Flour',NULL,1,0,365),
-(949,'Strong Brown Flour','g',1,1500,365),
-(950,'Very Strong Canadian Bread Flour','g',1,1000,365),
-(951,'Cream of Tartar','g',1,160,365),
-(952,'Arrowroot','g',1,80,365),
-(953,'White Sourdough Bread','g',1,500,365),
-(954,'Sauce Flour','g',1,500,365),
-(955,'Onion Bhaji Batter Mix','g',1,130,365),
-(956,'Extra Fine Sponge Flour','g',1,1000,365),
-(957,'Coarse Sea Salt','g',1,350,365),
-(958,'Cooking Salt','g',1,1500,365),
-(959,'Pink Peppercorns','g',1,24,365),
-(960,'Vegetable Oil','ml',1,1000,365),
-(961,'Olive Oil Extra Virgin','ml',1,1000,365),
-(962,'Sunflower Oil','ml',1,1000,365),
-(963,'Olive Oil','ml',1,1000,365),
-(964,'Olive Oil','ml',1,500,365),
-(965,'Sesame Oil Toasted','ml',1,250,365),
-(966,'Olive Oil Extra Virgin','ml',1,500,365),
-(967,'Sunflower & Extra Virgin Olive Oil','ml',1,500,365),
-(968,'Olive Oil Extra Virgin','ml',1,250,365),
-(969,'Olive Oil Extra Virgin','ml',1,500,365),
-(970,'Vegetable Oil','ml',1,3000,365),
-(971,'Olive Oil Extra Virgin','ml',1,2000,365),
-(972,'Olive Oil','ml',1,250,365),
-(973,'Garlic Flavoured Olive Oil','ml',1,250,365),
-(974,'Virgin Coconut Oil','ml',1,300,365),
-(975,'Goose Fat','g',1,320,365),
-(976,'Groundnut Oil','ml',1,500,365),
-(977,'Mirin','ml',1,150,365),
-(978,'Olive Oil Cooking Spray','ml',1,190,365),
-(979,'Chilli Flavoured Olive Oil','ml',1,250,365),
-(980,'Olive Oil Light In Colour','ml',1,1000,365),
-(981,'Unfiltered Extra Virgin Olive Oil','ml',1,500,365),
-(982,'Sunflower Oil','ml',1,1000,365),
-(983,'Vegetable Oil Spray','ml',1,200,365),
-(984,'Stir Fry Oil','ml',1,250,365),
-(985,'Olive Oil','ml',1,2000,365),
-(986,'Sunflower Oil','ml',1,3000,365),
-(987,'Cold Pressed Rapeseed Oil','ml',1,500,365),
-(988,'Olive Oil Light Colour','ml',1,500,365),
-(989,'Greek Kalamata Extra Virgin Olive Oil','ml',1,500,365),
-(990,'Duck Fat','g',1,320,365),
-(991,'Walnut Oil','ml',1,250,365),
-(992,'Beef Dripping','g',1,320,365),
-(993,'Sunflower Oil Cooking Spray','ml',1,190,365),
-(994,'Vegetable Oil Cooking Spray','ml',1,190,365),
-(995,'Greek Olive Oil Extra Virgin','ml',1,500,365),
-(996,'Olive Oil Extra Virgin','ml',1,500,365),
-(997,'Grapeseed Oil','ml',1,500,365),
-(998,'Corn Oil','ml',1,1000,365),
-(999,'Toscano Extra Virgin Olive Oil','ml',1,500,365),
-(1000,'Basmati Rice','g',1,1000,365),
-(1001,'Arborio Risotto Rice','g',1,500,365),
-(1002,'Easy Cook Long Grain White Rice','g',1,1000,365),
-(1003,'Basmati Rice','g',1,500,365),
-(1004,'Boil In The Bag White Rice 4x125g',NULL,1,1,365),
-(1005,'Spanish Paella Rice','g',1,500,365),
-(1006,'Brown Basmati Rice','g',1,1000,365),
-(1007,'Basmati Rice','g',1,2000,365),
-(1008,'Brown Rice','g',1,500,365),
-(1009,'Easy Cook Long Grain White Rice','g',1,500,365),
-(1010,'Easy Cook Long Grain White Rice','g',1,2000,365),
-(1011,'Boil In The Bag Basmati Rice 4x125g',NULL,1,1,365),
-(1012,'Savoury Rice Golden Vegetable','g',1,105,365),
-(1013,'Thai Fragrant Rice','g',1,500,365),
-(1014,'Basmati & Wild Rice','g',1,500,365),
-(1015,'Brown Rice','g',1,500,365),
-(1016,'Long Grain Rice','g',1,1000,365),
-(1017,'Basmati Rice','g',1,4000,365),
-(1018,'Boil In The Bag Brown Rice 4x125g',NULL,1,1,365),
-(1019,'Basmati Rice','g',1,500,365),
-(1020,'Thai Sticky Rice','g',1,500,365),
-(1021,'Boil In Bag Thai Fragrant Rice 4x125g',NULL,1,1,365),
-(1022,'Arborio Risotto Rice','g',1,500,365),
-(1023,'Pink Lady Apple & Honey Herb Stuffing','g',1,110,365),
-(1024,'Thai Black Rice','g',1,500,365),
-(1025,'Carnaroli Rice','g',1,500,365),
-(1026,'Long Grain Rice','g',1,2000,365),
-(1027,'Fusilli','g',1,1000,365),
-(1028,'Rigatoni','g',1,500,365),
-(1029,'Spaghetti','g',1,500,365),
-(1030,'Penne','g',1,1000,365),
-(1031,'Wholewheat Fusilli','g',1,500,365),
-(1032,'Spaghetti Wholewheat','g',1,500,365),
-(1033,'Penne Rigate','g',1,500,365),
-(1034,'Linguine','g',1,500,365),
-(1035,'Fusilli','g',1,500,365),
-(1036,'Spirali','g',1,500,365),
-(1037,'Macaroni','g',1,1000,365),
-(1038,'Lasagne Sheets','g',1,500,365),
-(1039,'Orzo','g',1,500,365),
-(1040,'Wholewheat Penne','g',1,500,365),
-(1041,'Spaghetti','g',1,1000,365),
-(1042,'Tagliatelle','g',1,500,365),
-(1043,'Fusilli','g',1,500,365),
-(1044,'Penne','g',1,500,365),
-(1045,'Fusilli Tricolori','g',1,500,365),
-(1046,'Wholewheat Fusilli','g',1,1000,365),
-(1047,'Farfalle','g',1,500,365),
-(1048,'Spaghetti','g',1,500,365),
-(1049,'Wholewheat Tagliatelle','g',1,500,365),
-(1050,'Wholewheat Lasagne Sheets','g',1,500,365),
-(1051,'Wholewheat Fusilli','g',1,500,365),
-(1052,'Casarecce','g',1,500,365),
-(1053,'Spelt Fusilli','g',1,500,365),
-(1054,'Stars Pasta','g',1,500,365),
-(1055,'Spaghetti','g',1,500,365),
-(1056,'Bucatini Spaghetti','g',1,500,365),
-(1057,'Green Pea Fusilli','g',1,250,365),
-(1058,'Lasagne Sheets','g',1,250,365),
-(1059,'Wholegrain Spelt Spaghetti','g',1,500,365),
-(1060,'Penne','g',1,500,365),
-(1061,'Buckwheat Penne','g',1,250,365),
-(1062,'Cannelloni','g',1,250,365),
-(1063,'Spinach & Ricotta Girasoli','g',1,250,365),
-(1064,'Conchiglioni','g',1,500,365),
-(1065,'Mafalda Corta','g',1,500,365),
-(1066,'Casarecce','g',1,500,365),
-(1067,'Linguine','g',1,400,365),
-(1068,'Wholewheat Spaghetti','g',1,500,365),
-(1069,'Linguine','g',1,500,365),
-(1070,'Medium Noodles','g',1,250,365),
-(1071,'Udon Noodles Quick To Cook',NULL,1,2,365),
-(1072,'Rice Noodles','g',1,250,365),
-(1073,'Wholewheat Medium Noodles','g',1,250,365),
-(1074,'Fine Egg Noodles','g',1,250,365),
-(1075,'Medium Noodles Quick To Cook',NULL,1,2,365),
-(1076,'Pad Thai Ribbon Noodles Quick To Cook','g',1,300,365),
-(1077,'Stock Cubes Vegetable 10x10g',NULL,1,1,365),
-(1078,'Stock Cubes Chicken 10x10g',NULL,1,1,365),
-(1079,'Stock Cubes Beef 10x10g',NULL,1,1,365),
-(1080,'Chicken Stock','ml',1,500,365),
-(1081,'Beef Stock','ml',1,500,365),
-(1082,'Parsley Sauce','g',1,200,365),
-(1083,'Vegetable Stock','ml',1,500,365),
-(1084,'Prawn Cocktail Sauce','g',1,200,365),
-(1085,'Double Cream','ml',1,300,365),
-(1086,'White Granulated Sugar','g',1,1000,365),
-(1087,'Double Cream','ml',1,600,365),
-(1088,'Single Cream','ml',1,300,365),
-(1089,'White Caster Sugar','g',1,1000,365),
-(1090,'Raisins','g',1,500,365),
-(1091,'Milk Chocolate Chips','g',1,100,365),
-(1092,'Strawberry Jam','g',1,454,365),
-(1093,'White Granulated Sugar','g',1,1000,365),
-(1094,'Mixed Nuts','g',1,300,365),
-(1095,'Dark Chocolate Chips','g',1,100,365),
-(1096,'Whole Almonds','g',1,300,365),
-(1097,'Oats','g',1,450,365),
-(1098,'Shelled Walnuts','g',1,300,365),
-(1099,'Mixed Nut Pack','g',1,200,365),
-(1100,'Dried Apricots','g',1,500,365),
-(1101,'Icing Sugar','g',1,1000,365),
-(1102,'Light Soft Brown Sugar','g',1,500,365),
-(1103,'Baking Powder','g',1,160,365),
-(1104,'Buttermilk','ml',1,300,365),
-(1105,'Demerara Cane Sugar','g',1,500,365),
-(1106,'White Caster Sugar','g',1,1000,365),
-(1107,'Light Brown Soft Sugar','g',1,1000,365),
-(1108,'Madagascan Vanilla Extract','ml',1,38,365),
-(1109,'Bicarbonate Of Soda','g',1,180,365),
-(1110,'Demerara Cane Sugar','g',1,1000,365),
-(1111,'Golden Caster Sugar','g',1,1000,365),
-(1112,'White Granulated Sugar','g',1,5000,365),
-(1113,'Meringue Nests','g',1,108,365),
-(1114,'Dark Soft Brown Sugar','g',1,500,365),
-(1115,'Mixed Dried Fruit','g',1,500,365),
-(1116,'Cornflakes','g',1,300,365),
-(1117,'Compote Morello Cherry','g',1,400,365),
-(1118,'Plain Cupcake Cases',NULL,1,75,365),
-(1119,'Belgian Dark 85% Cocoa Cooking Chocolate','g',1,100,365),
-(1120,'Raisins','g',1,500,365),
-(1121,'Plain Cake Cases',NULL,1,100,365),
-(1122,'Rice Pops','g',1,300,365),
-(1123,'Moroccan Almond Extract','ml',1,38,365),
-(1124,'Golden Marzipan','g',1,454,365),
-(1125,'Valencian Orange Oil Extract','ml',1,38,365),
-(1126,'Cut Mixed Peel','g',1,200,365),
-(1127,'American Peppermint Extract','ml',1,38,365),
-(1128,'Belgian Dark Chips','g',1,150,365),
-(1129,'Belgian Milk Chips','g',1,150,365),
-(1130,'Raspberry Coulis','g',1,250,365),
-(1131,'Sicilian Lemon Oil Extract','ml',1,38,365),
-(1132,'White Marzipan','g',1,454,365),
-(1133,'Salted Caramel Sauce','g',1,260,365),
-(1134,'Savoiardi Sponge Fingers','g',1,200,365),
-(1135,'Mango & Passionfruit Coulis','g',1,250,365),
-(1136,'Meringues',NULL,1,12,365),
-(1137,'Madagascan Vanilla Pods',NULL,1,2,365),
-(1138,'Morello Cherries','g',1,125,365),
-(1139,'Silver Crispy Pearls','g',1,55,365),
-(1140,'Writing Icing Colours 4x19g',NULL,1,1,365),
-(1141,'Tomato Puree Double Concentrate','g',1,200,365),
-(1142,'Passata','g',1,500,365),
-(1143,'Chopped Tomatoes Can','g',1,400,365),
-(1144,'Chopped Tomatoes','g',1,227,365),
-(1145,'Plum Tomatoes','g',1,400,365),
-(1146,'Chopped Tomatoes in Tomato Juice','g',1,400,365),
-(1147,'Passata','g',1,690,365),
-(1148,'Tomato Purée Double Concentrate','g',1,142,365),
-(1149,'Marinated Sundried Tomatoes','g',1,140,365),
-(1150,'Peeled Plum Tomatoes','g',1,227,365),
-(1151,'Tomato Puree Double Concentrated','g',1,312,365),
-(1152,'Tomato Paste','g',1,150,365),
-(1153,'Tomato Puree','g',1,312,365),
-(1154,'Rustica Passata','g',1,430,365),
-(1155,'Plum Tomatoes in Tomato Juice','g',1,400,365);
-ALTER SEQUENCE ingredient_id_seq RESTART WITH 1156;
\ No newline at end of file
+INSERT INTO ingredient (id, name, unit, minimum_quantity, purchase_quantity, life, category, price, energy, protein, fat, carbohydrate, fibre, salt) VALUES
+(1,'Butter Salted','g',1,250,60,'Butter',169,NULL,NULL,NULL,NULL,NULL,NULL),
+(2,'Butter Unsalted','g',1,250,60,'Butter',169,NULL,NULL,NULL,NULL,NULL,NULL),
+(3,'Butter Salted','g',1,500,60,'Butter',335,NULL,NULL,NULL,NULL,NULL,NULL),
+(4,'Butter Slightly Salted','g',1,250,60,'Butter',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(5,'Butter Unsalted','g',1,250,60,'Butter',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(6,'Farmhouse Butter','g',1,250,60,'Butter',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(7,'Farmhouse Butter','g',1,250,60,'Butter',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(8,'Semi Skimmed Milk','ml',1,2270,7,'Milk',145,NULL,NULL,NULL,NULL,NULL,NULL),
+(9,'Semi Skimmed Milk','ml',1,1130,7,'Milk',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(10,'Whole Milk','ml',1,2270,7,'Milk',145,NULL,NULL,NULL,NULL,NULL,NULL),
+(11,'Skimmed Milk','ml',1,2270,7,'Milk',145,NULL,NULL,NULL,NULL,NULL,NULL),
+(12,'Semi Skimmed Milk','ml',1,3400,7,'Milk',215,NULL,NULL,NULL,NULL,NULL,NULL),
+(13,'Skimmed Milk','ml',1,1130,7,'Milk',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(14,'Whole Milk','ml',1,1130,7,'Milk',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(15,'Filtered Semi Skimmed Milk','ml',1,2000,7,'Milk',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(16,'Semi Skimmed Milk','ml',1,2270,7,'Milk',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(17,'Semi Skimmed Milk Pint',NULL,1,1,7,'Milk',90,NULL,NULL,NULL,NULL,NULL,NULL),
+(18,'Whole Milk','ml',1,2270,7,'Milk',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(19,'Lactose Free Semi Skimmed Dairy Drink','ml',1,1000,7,'Milk',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(20,'Whole Milk','ml',1,3400,7,'Milk',215,NULL,NULL,NULL,NULL,NULL,NULL),
+(21,'Semi Skimmed Milk','ml',1,1130,7,'Milk',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(22,'Whole Milk','ml',1,568,7,'Milk',90,NULL,NULL,NULL,NULL,NULL,NULL),
+(23,'Filtered Whole Milk','ml',1,2000,7,'Milk',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(24,'Skimmed Milk','ml',1,568,7,'Milk',90,NULL,NULL,NULL,NULL,NULL,NULL),
+(25,'Whole Milk','ml',1,1130,7,'Milk',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(26,'Lactose Free Skimmed Dairy Drink','ml',1,1000,7,'Milk',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(27,'Lactose Free Whole Dairy Drink',NULL,1,1,7,'Milk',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(28,'Skimmed Milk','ml',1,1136,7,'Milk',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(29,'Semi Skimmed Milk','ml',1,568,7,'Milk',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(30,'Jersey Milk','ml',1,1000,7,'Milk',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(31,'5% Fat Beef Mince','g',1,500,7,'Meat and Fish Essentials',349,NULL,NULL,NULL,NULL,NULL,NULL),
+(32,'Skin on Salmon Fillets',NULL,1,2,7,'Meat and Fish Essentials',450,NULL,NULL,NULL,NULL,NULL,NULL),
+(33,'Fresh Chicken Breast Fillets Skinless & Boneless','g',1,300,7,'Meat and Fish Essentials',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(34,'Fresh Chicken Breast Fillets Skinless & Boneless','g',1,640,7,'Meat and Fish Essentials',442,NULL,NULL,NULL,NULL,NULL,NULL),
+(35,'Fresh Chicken Breast Fillets Skinless & Boneless','g',1,1000,7,'Meat and Fish Essentials',649,NULL,NULL,NULL,NULL,NULL,NULL),
+(36,'Fresh Chicken Thigh Fillets Skinless & Boneless','g',1,640,7,'Meat and Fish Essentials',515,NULL,NULL,NULL,NULL,NULL,NULL),
+(37,'Breaded Cooked Ham Slices',NULL,1,7,7,'Meat and Fish Essentials',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(38,'Pork Sausages',NULL,1,6,7,'Meat and Fish Essentials',325,NULL,NULL,NULL,NULL,NULL,NULL),
+(39,'Smoked Back Bacon Rashers',NULL,1,10,7,'Meat and Fish Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(40,'Honey Roast Cooked Ham Slices',NULL,1,7,7,'Meat and Fish Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(41,'5% Fat Beef Mince','g',1,750,7,'Meat and Fish Essentials',499,NULL,NULL,NULL,NULL,NULL,NULL),
+(42,'Unsmoked Back Bacon Rashers',NULL,1,10,7,'Meat and Fish Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(43,'Fresh Chicken Breast Fillets','g',1,320,7,'Meat and Fish Essentials',239,NULL,NULL,NULL,NULL,NULL,NULL),
+(44,'Fresh Chicken Skin on Thighs','g',1,1000,7,'Meat and Fish Essentials',285,NULL,NULL,NULL,NULL,NULL,NULL),
+(45,'20% Fat Beef Mince','g',1,500,7,'Meat and Fish Essentials',249,NULL,NULL,NULL,NULL,NULL,NULL),
+(46,'Fresh Large Whole Chicken','g',1,1900,7,'Meat and Fish Essentials',550,NULL,NULL,NULL,NULL,NULL,NULL),
+(47,'Smoked Streaky Bacon',NULL,1,14,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(48,'Smoked Mackerel Fillets','g',1,140,7,'Meat and Fish Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(49,'Sea Bass Fillets','g',1,180,7,'Meat and Fish Essentials',425,NULL,NULL,NULL,NULL,NULL,NULL),
+(50,'Thick Cut Unsmoked Back Bacon Rashers',NULL,1,6,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(51,'Cooked Roast Chicken Breast Fillets',NULL,1,2,7,'Meat and Fish Essentials',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(52,'Breaded Yorkshire Cured Cooked Ham Slices',NULL,1,4,7,'Meat and Fish Essentials',325,NULL,NULL,NULL,NULL,NULL,NULL),
+(53,'Skin on Salmon Fillets',NULL,1,4,7,'Meat and Fish Essentials',875,NULL,NULL,NULL,NULL,NULL,NULL),
+(54,'Fresh Chicken Breast Fillets','g',1,640,7,'Meat and Fish Essentials',426,NULL,NULL,NULL,NULL,NULL,NULL),
+(55,'Pork Sausage',NULL,1,8,7,'Meat and Fish Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(56,'Fresh Extra Large Whole Chicken',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(57,'5% Fat Beef Mince','g',1,250,7,'Meat and Fish Essentials',219,NULL,NULL,NULL,NULL,NULL,NULL),
+(58,'Spanish Chorizo Ring','g',1,225,7,'Meat and Fish Essentials',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(59,'Fresh Diced Chicken Breast','g',1,410,7,'Meat and Fish Essentials',385,NULL,NULL,NULL,NULL,NULL,NULL),
+(60,'Breaded Cooked Ham Slices',NULL,1,14,7,'Meat and Fish Essentials',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(61,'Ham Slices','g',1,120,7,'Meat and Fish Essentials',325,NULL,NULL,NULL,NULL,NULL,NULL),
+(62,'Pork Chipolata sausages',NULL,1,12,7,'Meat and Fish Essentials',216,NULL,NULL,NULL,NULL,NULL,NULL),
+(63,'Thick Cut Smoked Back Bacon Rashers',NULL,1,6,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(64,'Large King Prawns','g',1,150,7,'Meat and Fish Essentials',350,NULL,NULL,NULL,NULL,NULL,NULL),
+(65,'Quarter Pounder Beef Burgers',NULL,1,4,7,'Meat and Fish Essentials',259,NULL,NULL,NULL,NULL,NULL,NULL),
+(66,'Pork Mince 5% Fat','g',1,500,7,'Meat and Fish Essentials',259,NULL,NULL,NULL,NULL,NULL,NULL),
+(67,'Pork Chipolata sausages',NULL,1,12,7,'Meat and Fish Essentials',325,NULL,NULL,NULL,NULL,NULL,NULL),
+(68,'Cooked Ham Slices',NULL,1,10,7,'Meat and Fish Essentials',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(69,'Free Range Chicken Breast Fillets',NULL,1,2,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(70,'Breaded Fresh Chicken Breast Fillets',NULL,1,2,7,'Meat and Fish Essentials',360,NULL,NULL,NULL,NULL,NULL,NULL),
+(71,'Pork Belly Slices','g',1,500,7,'Meat and Fish Essentials',283,NULL,NULL,NULL,NULL,NULL,NULL),
+(72,'Fresh Medium Whole Chicken','g',1,1600,7,'Meat and Fish Essentials',400,NULL,NULL,NULL,NULL,NULL,NULL),
+(73,'Breaded Chunky Cod Loins',NULL,1,2,7,'Meat and Fish Essentials',450,NULL,NULL,NULL,NULL,NULL,NULL),
+(74,'Pork Sausage',NULL,1,12,7,'Meat and Fish Essentials',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(75,'Unsmoked Streaky Bacon',NULL,1,14,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(76,'Extra Lean Diced Beef','g',1,500,7,'Meat and Fish Essentials',524,NULL,NULL,NULL,NULL,NULL,NULL),
+(77,'Beef Mince 5% Fat','g',1,500,7,'Meat and Fish Essentials',550,NULL,NULL,NULL,NULL,NULL,NULL),
+(78,'Roast Cooked Chicken Breast Slices',NULL,1,7,7,'Meat and Fish Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(79,'Fresh Small Whole Chicken','g',1,1350,7,'Meat and Fish Essentials',395,NULL,NULL,NULL,NULL,NULL,NULL),
+(80,'Beef Mince','g',1,1000,7,'Meat and Fish Essentials',660,NULL,NULL,NULL,NULL,NULL,NULL),
+(81,'Day Matured Sirloin Steak',NULL,1,21,7,'Meat and Fish Essentials',450,NULL,NULL,NULL,NULL,NULL,NULL),
+(82,'Fresh Chicken Thigh Fillets Skinless & Boneless','g',1,320,7,'Meat and Fish Essentials',290,NULL,NULL,NULL,NULL,NULL,NULL),
+(83,'2% Fat Fresh Turkey Mince','g',1,500,7,'Meat and Fish Essentials',379,NULL,NULL,NULL,NULL,NULL,NULL),
+(84,'Unsmoked Bacon Medallions',NULL,1,8,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(85,'Fresh Chicken Wings','g',1,1000,7,'Meat and Fish Essentials',199,NULL,NULL,NULL,NULL,NULL,NULL),
+(86,'Brussels Pâté','g',1,170,7,'Meat and Fish Essentials',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(87,'Pork Sausages',NULL,1,6,7,'Meat and Fish Essentials',216,NULL,NULL,NULL,NULL,NULL,NULL),
+(88,'Cooked Roast Beef Slices',NULL,1,3,7,'Meat and Fish Essentials',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(89,'Pork Sausage',NULL,1,8,7,'Meat and Fish Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(90,'Cod Fillets Boneless & Skinless','g',1,250,7,'Meat and Fish Essentials',475,NULL,NULL,NULL,NULL,NULL,NULL),
+(91,'Extra Lean Diced Beef','g',1,250,7,'Meat and Fish Essentials',270,NULL,NULL,NULL,NULL,NULL,NULL),
+(92,'Free Range Whole Chicken',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(93,'12% Fat Beef Mince','g',1,500,7,'Meat and Fish Essentials',330,NULL,NULL,NULL,NULL,NULL,NULL),
+(94,'Wiltshire Cured Ham slices',NULL,1,4,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(95,'Fresh Chicken Breast Fillet garlic Kyiv',NULL,1,2,7,'Meat and Fish Essentials',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(96,'Brussels Spreadable Pâté','g',1,175,7,'Meat and Fish Essentials',69,NULL,NULL,NULL,NULL,NULL,NULL),
+(97,'Smoked Boneless Mackerel',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(98,'Day Matured Rump Steak',NULL,1,21,7,'Meat and Fish Essentials',338,NULL,NULL,NULL,NULL,NULL,NULL),
+(99,'Beef Mince 12% Fat','g',1,500,7,'Meat and Fish Essentials',475,NULL,NULL,NULL,NULL,NULL,NULL),
+(100,'Parma Ham Slices',NULL,1,6,7,'Meat and Fish Essentials',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(101,'Pork and Red Onion Sausages',NULL,1,6,7,'Meat and Fish Essentials',325,NULL,NULL,NULL,NULL,NULL,NULL),
+(102,'Fresh Chicken Skin on Drumsticks','g',1,1000,7,'Meat and Fish Essentials',199,NULL,NULL,NULL,NULL,NULL,NULL),
+(103,'Smoked Streaky Bacon Rashers','g',1,300,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(104,'Smoked Back Bacon Rashers 2x250g',NULL,1,16,7,'Meat and Fish Essentials',350,NULL,NULL,NULL,NULL,NULL,NULL),
+(105,'Just Cook Hunters Chicken Breasts','g',1,430,7,'Meat and Fish Essentials',450,NULL,NULL,NULL,NULL,NULL,NULL),
+(106,'Day Matured Sizzler Steaks',NULL,1,21,7,'Meat and Fish Essentials',400,NULL,NULL,NULL,NULL,NULL,NULL),
+(107,'Wiltshire Cured Unsmoked Back Bacon',NULL,1,8,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(108,'Roast Boneless Cooked Chicken Breast',NULL,1,4,7,'Meat and Fish Essentials',675,NULL,NULL,NULL,NULL,NULL,NULL),
+(109,'Yorkshire Puddings',NULL,1,6,7,'Meat and Fish Essentials',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(110,'Pork & Apple Sausages',NULL,1,6,7,'Meat and Fish Essentials',216,NULL,NULL,NULL,NULL,NULL,NULL),
+(111,'Fresh Skin on Chicken Legs','g',1,1000,7,'Meat and Fish Essentials',229,NULL,NULL,NULL,NULL,NULL,NULL),
+(112,'Oak Smoked Dry Cure Bacon x',NULL,1,6,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(113,'Day Matured Thin Cut Sirloin Steak',NULL,1,21,7,'Meat and Fish Essentials',325,NULL,NULL,NULL,NULL,NULL,NULL),
+(114,'Lamb Leg Steaks','g',1,300,7,'Meat and Fish Essentials',475,NULL,NULL,NULL,NULL,NULL,NULL),
+(115,'10% Fat Lamb Mince','g',1,500,7,'Meat and Fish Essentials',650,NULL,NULL,NULL,NULL,NULL,NULL),
+(116,'20% Fat Lamb Mince','g',1,500,7,'Meat and Fish Essentials',570,NULL,NULL,NULL,NULL,NULL,NULL),
+(117,'Pork Sausage',NULL,1,12,7,'Meat and Fish Essentials',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(118,'Pork Loin Steaks',NULL,1,2,7,'Meat and Fish Essentials',325,NULL,NULL,NULL,NULL,NULL,NULL),
+(119,'Pork Shoulder Joint',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(120,'Extra Lean Casserole Steak','g',1,500,7,'Meat and Fish Essentials',500,NULL,NULL,NULL,NULL,NULL,NULL),
+(121,'Lamb Rump Steaks','g',1,300,7,'Meat and Fish Essentials',425,NULL,NULL,NULL,NULL,NULL,NULL),
+(122,'Day Matured Extra Lean Beef Roasting Joint',NULL,1,21,7,'Meat and Fish Essentials',600,NULL,NULL,NULL,NULL,NULL,NULL),
+(123,'Pork Tenderloin Fillet',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(124,'Lamb Liver',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(125,'Unsmoked Back Bacon Rashers',NULL,1,16,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(126,'Butcher''s Choice Pork Sausagemeat','g',1,400,7,'Meat and Fish Essentials',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(127,'Just Cook Butter Basted Chicken','g',1,500,7,'Meat and Fish Essentials',500,NULL,NULL,NULL,NULL,NULL,NULL),
+(128,'Liver Sausage Slices','g',1,125,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(129,'Pork Loin Steaks',NULL,1,8,7,'Meat and Fish Essentials',475,NULL,NULL,NULL,NULL,NULL,NULL),
+(130,'Pork Loin Medallions',NULL,1,4,7,'Meat and Fish Essentials',349,NULL,NULL,NULL,NULL,NULL,NULL),
+(131,'Wafer Thin Cooked Roast Beef Slices',NULL,1,5,7,'Meat and Fish Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(132,'Beef Meatballs','g',1,350,7,'Meat and Fish Essentials',375,NULL,NULL,NULL,NULL,NULL,NULL),
+(133,'Unsmoked Streaky Bacon Rashers','g',1,300,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(134,'7% Fat Fresh Turkey Mince','g',1,500,7,'Meat and Fish Essentials',370,NULL,NULL,NULL,NULL,NULL,NULL),
+(135,'Pork Loin Steaks',NULL,1,4,7,'Meat and Fish Essentials',450,NULL,NULL,NULL,NULL,NULL,NULL),
+(136,'Unsmoked Dry Cure Bacon x',NULL,1,6,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(137,'Pork Boneless Crackling Loin Joint',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(138,'Southern fried Fresh Chicken breast fillet',NULL,1,2,7,'Meat and Fish Essentials',375,NULL,NULL,NULL,NULL,NULL,NULL),
+(139,'Loch Trout Fillets',NULL,1,2,7,'Meat and Fish Essentials',450,NULL,NULL,NULL,NULL,NULL,NULL),
+(140,'Fishcakes Melting Middle Cod & Parsley Sauce Fishcakes',NULL,1,2,7,'Meat and Fish Essentials',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(141,'Days Matured Beef Sirloin Steak',NULL,1,30,7,'Meat and Fish Essentials',545,NULL,NULL,NULL,NULL,NULL,NULL),
+(142,'Beef Steak Burgers',NULL,1,4,7,'Meat and Fish Essentials',600,NULL,NULL,NULL,NULL,NULL,NULL),
+(143,'Beef steak Burgers',NULL,1,2,7,'Meat and Fish Essentials',233,NULL,NULL,NULL,NULL,NULL,NULL),
+(144,'Beef Mince 12% Fat','g',1,500,7,'Meat and Fish Essentials',525,NULL,NULL,NULL,NULL,NULL,NULL),
+(145,'Diced Beef','g',1,500,7,'Meat and Fish Essentials',500,NULL,NULL,NULL,NULL,NULL,NULL),
+(146,'Lamb Chops','g',1,340,7,'Meat and Fish Essentials',525,NULL,NULL,NULL,NULL,NULL,NULL),
+(147,'Extra Lean Diced Lamb','g',1,300,7,'Meat and Fish Essentials',575,NULL,NULL,NULL,NULL,NULL,NULL),
+(148,'Ardennes Pâté','g',1,170,7,'Meat and Fish Essentials',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(149,'Pork Lunch Tongue Slices',NULL,1,6,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(150,'Butcher''s Choice Pork & Bacon Pigs In Blankets',NULL,1,12,7,'Meat and Fish Essentials',325,NULL,NULL,NULL,NULL,NULL,NULL),
+(151,'Whole Leg Of Lamb',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(152,'Days Matured Beef Fillet Steak',NULL,1,30,7,'Meat and Fish Essentials',650,NULL,NULL,NULL,NULL,NULL,NULL),
+(153,'Day Matured Ribeye Steak',NULL,1,21,7,'Meat and Fish Essentials',525,NULL,NULL,NULL,NULL,NULL,NULL),
+(154,'Corn Fed Whole Chicken',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(155,'Lincolnshire Pork Sausage',NULL,1,8,7,'Meat and Fish Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(156,'Lincolnshire Pork Sausages',NULL,1,6,7,'Meat and Fish Essentials',216,NULL,NULL,NULL,NULL,NULL,NULL),
+(157,'Beef Steak & Caramelised Onion Burgers',NULL,1,2,7,'Meat and Fish Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(158,'Thick Cut Pork Chops',NULL,1,2,7,'Meat and Fish Essentials',450,NULL,NULL,NULL,NULL,NULL,NULL),
+(159,'Days Matured Beef Ribeye Steak',NULL,1,30,7,'Meat and Fish Essentials',725,NULL,NULL,NULL,NULL,NULL,NULL),
+(160,'Free Range Whole Fresh Chicken',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(161,'Diced Beef','g',1,500,7,'Meat and Fish Essentials',550,NULL,NULL,NULL,NULL,NULL,NULL),
+(162,'Fresh Pork Crackling Leg Joint 1kg-1.8kg',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(163,'Pork Chops',NULL,1,4,7,'Meat and Fish Essentials',550,NULL,NULL,NULL,NULL,NULL,NULL),
+(164,'Peppered Cooked Roast Beef Slices',NULL,1,3,7,'Meat and Fish Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(165,'Breaded Firecracker Fresh Chicken Breast',NULL,1,2,7,'Meat and Fish Essentials',290,NULL,NULL,NULL,NULL,NULL,NULL),
+(166,'Chicken Liver Pâté','g',1,170,7,'Meat and Fish Essentials',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(167,'Days Matured Beef Rump Steak',NULL,1,30,7,'Meat and Fish Essentials',425,NULL,NULL,NULL,NULL,NULL,NULL),
+(168,'Outdoor Bred Pork Cutlets',NULL,1,2,7,'Meat and Fish Essentials',525,NULL,NULL,NULL,NULL,NULL,NULL),
+(169,'Day Matured Lean Beef Roasting Joint',NULL,1,21,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(170,'Duck & Orange Pâté','g',1,170,7,'Meat and Fish Essentials',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(171,'Thin Cut Lamb Chops','g',1,425,7,'Meat and Fish Essentials',625,NULL,NULL,NULL,NULL,NULL,NULL),
+(172,'Js Duck Legs','g',1,480,7,'Meat and Fish Essentials',480,NULL,NULL,NULL,NULL,NULL,NULL),
+(173,'Breaded Wiltshire Cured Ham slices',NULL,1,4,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(174,'Days Matured Beef Roasting Joint',NULL,1,30,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(175,'Days Matured Beef Thin Cut Sirloin Steak',NULL,1,30,7,'Meat and Fish Essentials',400,NULL,NULL,NULL,NULL,NULL,NULL),
+(176,'Pork Shoulder Steaks',NULL,1,6,7,'Meat and Fish Essentials',650,NULL,NULL,NULL,NULL,NULL,NULL),
+(177,'Half Leg Of Lamb',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(178,'Half Lamb Shoulder',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(179,'New Zealand Lamb Neck Fillet',NULL,1,1,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(180,'Day Matured Fillet Steak',NULL,1,21,7,'Meat and Fish Essentials',625,NULL,NULL,NULL,NULL,NULL,NULL),
+(181,'Battered Fresh Chicken Nuggets','g',1,540,7,'Meat and Fish Essentials',400,NULL,NULL,NULL,NULL,NULL,NULL),
+(182,'Slow Cooked Japanese Style Outdoor Bred Pork Belly Marinated','g',1,600,7,'Meat and Fish Essentials',800,NULL,NULL,NULL,NULL,NULL,NULL),
+(183,'Mature Cheddar Cheese','g',1,400,7,'Cheese',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(184,'Cypriot Halloumi Cheese','g',1,225,7,'Cheese',230,NULL,NULL,NULL,NULL,NULL,NULL),
+(185,'Greek Feta Cheese','g',1,200,7,'Cheese',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(186,'Extra Mature Cheddar Cheese','g',1,400,7,'Cheese',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(187,'Medium Cheddar Cheese','g',1,400,7,'Cheese',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(188,'Parmigiano Reggiano Cheese','g',1,200,7,'Cheese',340,NULL,NULL,NULL,NULL,NULL,NULL),
+(189,'Grated Mozzarella Cheese','g',1,250,7,'Cheese',255,NULL,NULL,NULL,NULL,NULL,NULL),
+(190,'Mozzarella Cheese','g',1,150,7,'Cheese',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(191,'Mature Grated Cheddar Cheese','g',1,250,7,'Cheese',255,NULL,NULL,NULL,NULL,NULL,NULL),
+(192,'Mild Cheddar Cheese','g',1,400,7,'Cheese',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(193,'Mature Cheddar Cheese Slices','g',1,240,7,'Cheese',265,NULL,NULL,NULL,NULL,NULL,NULL),
+(194,'Grated Parmesan Cheese','g',1,120,7,'Cheese',285,NULL,NULL,NULL,NULL,NULL,NULL),
+(195,'White Soft Cheese','g',1,300,7,'Cheese',165,NULL,NULL,NULL,NULL,NULL,NULL),
+(196,'Cottage Cheese Fat Free','g',1,300,7,'Cheese',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(197,'West Country Farmhouse Mature Cheddar Cheese','g',1,400,7,'Cheese',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(198,'Fresh Grated Parmigiano Cheese','g',1,60,7,'Cheese',145,NULL,NULL,NULL,NULL,NULL,NULL),
+(199,'French Mild Brie Cheese','g',1,200,7,'Cheese',169,NULL,NULL,NULL,NULL,NULL,NULL),
+(200,'Red Leicester Cheese','g',1,250,7,'Cheese',280,NULL,NULL,NULL,NULL,NULL,NULL),
+(201,'Mature Grated Cheddar Cheese','g',1,500,7,'Cheese',445,NULL,NULL,NULL,NULL,NULL,NULL),
+(202,'Gouda Cheese Slices','g',1,250,7,'Cheese',177,NULL,NULL,NULL,NULL,NULL,NULL),
+(203,'Cottage Cheese','g',1,300,7,'Cheese',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(204,'Barber''s Mature Cruncher Cheese','g',1,350,7,'Cheese',380,NULL,NULL,NULL,NULL,NULL,NULL),
+(205,'Spanish Manchego Cheese','g',1,170,7,'Cheese',430,NULL,NULL,NULL,NULL,NULL,NULL),
+(206,'Emmental Cheese Slices','g',1,250,7,'Cheese',176,NULL,NULL,NULL,NULL,NULL,NULL),
+(207,'Blue Stilton Cheese','g',1,220,7,'Cheese',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(208,'Grana Padano Cheese','g',1,200,7,'Cheese',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(209,'Grated Mozzarella Cheese','g',1,500,7,'Cheese',445,NULL,NULL,NULL,NULL,NULL,NULL),
+(210,'Mascarpone Cheese','g',1,250,7,'Cheese',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(211,'Lighter Mature Cheese','g',1,400,7,'Cheese',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(212,'Lighter Mature Grated Cheese','g',1,250,7,'Cheese',255,NULL,NULL,NULL,NULL,NULL,NULL),
+(213,'Light Soft Cheese','g',1,250,7,'Cheese',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(214,'Double Gloucester Cheese','g',1,250,7,'Cheese',280,NULL,NULL,NULL,NULL,NULL,NULL),
+(215,'Cheesy Slices',NULL,1,10,7,'Cheese',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(216,'Extra Mature Grated Cheddar Cheese','g',1,250,7,'Cheese',255,NULL,NULL,NULL,NULL,NULL,NULL),
+(217,'Ricotta Cheese','g',1,250,7,'Cheese',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(218,'Goats'' Cheese','g',1,120,7,'Cheese',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(219,'Mature Cheddar Cheese','g',1,270,7,'Cheese',320,NULL,NULL,NULL,NULL,NULL,NULL),
+(220,'Lighter Mozzarella Cheese','g',1,125,7,'Cheese',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(221,'Brie Cheese','g',1,230,7,'Cheese',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(222,'Swiss Mature Reserve Gruyere Cheese','g',1,200,7,'Cheese',390,NULL,NULL,NULL,NULL,NULL,NULL),
+(223,'Emmental Cheese','g',1,250,7,'Cheese',255,NULL,NULL,NULL,NULL,NULL,NULL),
+(224,'Medium Grated Cheddar Cheese','g',1,250,7,'Cheese',255,NULL,NULL,NULL,NULL,NULL,NULL),
+(225,'Fat Free Pineapple Cottage Cheese','g',1,300,7,'Cheese',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(226,'Abergavenny Goats Cheese','g',1,125,7,'Cheese',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(227,'Fat Free Onion & Chive Cottage Cheese','g',1,300,7,'Cheese',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(228,'Edam Cheese','g',1,310,7,'Cheese',280,NULL,NULL,NULL,NULL,NULL,NULL),
+(229,'Edam Cheese Slices','g',1,250,7,'Cheese',177,NULL,NULL,NULL,NULL,NULL,NULL),
+(230,'Red Leicester Cheese Slices','g',1,240,7,'Cheese',265,NULL,NULL,NULL,NULL,NULL,NULL),
+(231,'Cottage Cheese','g',1,600,7,'Cheese',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(232,'Mild Cheddar Cheese Slices','g',1,240,7,'Cheese',265,NULL,NULL,NULL,NULL,NULL,NULL),
+(233,'Medium Grated Cheddar Cheese','g',1,500,7,'Cheese',445,NULL,NULL,NULL,NULL,NULL,NULL),
+(234,'Stilton Blue Cheese','g',1,210,7,'Cheese',315,NULL,NULL,NULL,NULL,NULL,NULL),
+(235,'Mature Cheddar','g',1,220,7,'Cheese',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(236,'Mild Cheddar Cheese','g',1,270,7,'Cheese',320,NULL,NULL,NULL,NULL,NULL,NULL),
+(237,'Shaved Parmesan Cheese','g',1,80,7,'Cheese',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(238,'German Smoked Cheese','g',1,150,7,'Cheese',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(239,'Brie Cheese','g',1,160,7,'Cheese',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(240,'Gouda Cheese','g',1,265,7,'Cheese',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(241,'Goats Cheese','g',1,100,7,'Cheese',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(242,'Greek Feta Cubes In Brine','g',1,390,7,'Cheese',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(243,'Lighter Cypriot Cheese','g',1,225,7,'Cheese',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(244,'Cambozola Cheese','g',1,175,7,'Cheese',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(245,'Mozzarella Cheese Pearls','g',1,125,7,'Cheese',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(246,'Edam Cheese Slices','g',1,400,7,'Cheese',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(247,'Dried Grated Hard Cheese','g',1,80,7,'Cheese',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(248,'Greek Feta','g',1,200,7,'Cheese',290,NULL,NULL,NULL,NULL,NULL,NULL),
+(249,'Breaded Halloumi Fries',NULL,1,12,7,'Cheese',325,NULL,NULL,NULL,NULL,NULL,NULL),
+(250,'Extra Mature Cheddar','g',1,270,7,'Cheese',320,NULL,NULL,NULL,NULL,NULL,NULL),
+(251,'Pecorino Romano Cheese','g',1,170,7,'Cheese',380,NULL,NULL,NULL,NULL,NULL,NULL),
+(252,'Wensleydale Cheese','g',1,250,7,'Cheese',280,NULL,NULL,NULL,NULL,NULL,NULL),
+(253,'Parmigiano Reggiano Cheese','g',1,200,7,'Cheese',410,NULL,NULL,NULL,NULL,NULL,NULL),
+(254,'Medium Cheddar Cheese Slices','g',1,240,7,'Cheese',265,NULL,NULL,NULL,NULL,NULL,NULL),
+(255,'Mild Cheddar','g',1,220,7,'Cheese',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(256,'Grated Red Leicester Cheese','g',1,250,7,'Cheese',255,NULL,NULL,NULL,NULL,NULL,NULL),
+(257,'Grated Mild Cheddar','g',1,250,7,'Cheese',255,NULL,NULL,NULL,NULL,NULL,NULL),
+(258,'Light Garlic & Herb Soft Cheese','g',1,250,7,'Cheese',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(259,'Mature Grated White Cheese','g',1,250,7,'Cheese',199,NULL,NULL,NULL,NULL,NULL,NULL),
+(260,'Mozzarella Di Bufala Campana DOP','g',1,290,7,'Cheese',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(261,'Roule Cheese','g',1,125,7,'Cheese',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(262,'Wensleydale & Apricot Cheese','g',1,200,7,'Cheese',265,NULL,NULL,NULL,NULL,NULL,NULL),
+(263,'Reduced Fat Cheesy Slices','g',1,200,7,'Cheese',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(264,'Lighter Mature Cheese Slices','g',1,240,7,'Cheese',265,NULL,NULL,NULL,NULL,NULL,NULL),
+(265,'Barber''s Red Cruncher Cheese','g',1,350,7,'Cheese',380,NULL,NULL,NULL,NULL,NULL,NULL),
+(266,'Mature Cheddar Sticks 5x20g',NULL,1,1,7,'Cheese',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(267,'Austrian Smoked Cheese','g',1,125,7,'Cheese',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(268,'Parmigiano Reggiano Cheese','g',1,200,7,'Cheese',410,NULL,NULL,NULL,NULL,NULL,NULL),
+(269,'Extra Mature Cheddar Cheese Slices','g',1,240,7,'Cheese',265,NULL,NULL,NULL,NULL,NULL,NULL),
+(270,'Double Gloucester Cheese & Chive','g',1,200,7,'Cheese',265,NULL,NULL,NULL,NULL,NULL,NULL),
+(271,'Red Leicester Cheese','g',1,270,7,'Cheese',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(272,'Grana Padano Riserva Cheese','g',1,150,7,'Cheese',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(273,'Barrel Aged Feta','g',1,200,7,'Cheese',355,NULL,NULL,NULL,NULL,NULL,NULL),
+(274,'Medium Cheddar','g',1,220,7,'Cheese',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(275,'TTD Shropshire Red','g',1,200,7,'Cheese',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(276,'Vintage Cheddar','g',1,270,7,'Cheese',320,NULL,NULL,NULL,NULL,NULL,NULL),
+(277,'Ossau Iraty Cheese','g',1,180,7,'Cheese',410,NULL,NULL,NULL,NULL,NULL,NULL),
+(278,'Smoky Cheddar Cheese','g',1,200,7,'Cheese',265,NULL,NULL,NULL,NULL,NULL,NULL),
+(279,'Cypriot Halloumi','g',1,250,7,'Cheese',400,NULL,NULL,NULL,NULL,NULL,NULL),
+(280,'Wensleydale Cheese','g',1,240,7,'Cheese',375,NULL,NULL,NULL,NULL,NULL,NULL),
+(281,'Mozzarella Pearls','g',1,125,7,'Cheese',215,NULL,NULL,NULL,NULL,NULL,NULL),
+(282,'Gorgonzola Dolce Cheese','g',1,200,7,'Cheese',340,NULL,NULL,NULL,NULL,NULL,NULL),
+(283,'White Cheshire Cheese','g',1,250,7,'Cheese',280,NULL,NULL,NULL,NULL,NULL,NULL),
+(284,'Mature Cheddar Sticks 10x20g',NULL,1,1,7,'Cheese',232,NULL,NULL,NULL,NULL,NULL,NULL),
+(285,'Greek Style Salad Cheese Be Good to Yourself','g',1,200,7,'Cheese',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(286,'Beacon Fell Creamy Lancashire Cheese','g',1,240,7,'Cheese',375,NULL,NULL,NULL,NULL,NULL,NULL),
+(287,'Cheddar & Onion','g',1,200,7,'Cheese',265,NULL,NULL,NULL,NULL,NULL,NULL),
+(288,'Coconut Based alternative To Soft Cheese','g',1,170,7,'Cheese',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(289,'West Country Farmhouse Mature Cheddar','g',1,220,7,'Cheese',255,NULL,NULL,NULL,NULL,NULL,NULL),
+(290,'Cheese Selection','g',1,320,7,'Cheese',365,NULL,NULL,NULL,NULL,NULL,NULL),
+(291,'Chaource Cheese','g',1,250,7,'Cheese',355,NULL,NULL,NULL,NULL,NULL,NULL),
+(292,'Grated Cheddar-Style Coconut Based','g',1,200,7,'Cheese',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(293,'Favourite Cheese Selection','g',1,456,7,'Cheese',660,NULL,NULL,NULL,NULL,NULL,NULL),
+(294,'Lighter Mature Cheese','g',1,220,7,'Cheese',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(295,'Mozzarella Slices','g',1,250,7,'Cheese',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(296,'French Goats Cheese Mild','g',1,150,7,'Cheese',245,NULL,NULL,NULL,NULL,NULL,NULL),
+(297,'French Camembert Cheese','g',1,250,7,'Cheese',320,NULL,NULL,NULL,NULL,NULL,NULL),
+(298,'Extra Mature Cheddar','g',1,220,7,'Cheese',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(299,'Lighter Mature Cheese Sticks 5x20g',NULL,1,1,7,'Cheese',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(300,'Vintage Gouda Cheese','g',1,180,7,'Cheese',395,NULL,NULL,NULL,NULL,NULL,NULL),
+(301,'Soft Cheese','g',1,250,7,'Cheese',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(302,'Chipotle Chilli Cheddar Cheese','g',1,200,7,'Cheese',265,NULL,NULL,NULL,NULL,NULL,NULL),
+(303,'French Camembert Cheese Whole','g',1,250,7,'Cheese',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(304,'Welsh Slate Cavern Aged Cheddar','g',1,200,7,'Cheese',270,NULL,NULL,NULL,NULL,NULL,NULL),
+(305,'Epoisses Cheese','g',1,125,7,'Cheese',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(306,'Cheddar-Style Coconut Based','g',1,200,7,'Cheese',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(307,'Cheese Selection Classic Assortment','g',1,285,7,'Cheese',570,NULL,NULL,NULL,NULL,NULL,NULL),
+(308,'Continental Grated Cheese','g',1,250,7,'Cheese',255,NULL,NULL,NULL,NULL,NULL,NULL),
+(309,'Vintage Reserve Cheddar','g',1,350,7,'Cheese',380,NULL,NULL,NULL,NULL,NULL,NULL),
+(310,'Breaded Camembert','g',1,200,7,'Cheese',400,NULL,NULL,NULL,NULL,NULL,NULL),
+(311,'Truffle Gouda','g',1,180,7,'Cheese',450,NULL,NULL,NULL,NULL,NULL,NULL),
+(312,'Lancashire Cheese','g',1,250,7,'Cheese',280,NULL,NULL,NULL,NULL,NULL,NULL),
+(313,'Smoked Shropshire Red',NULL,1,1,7,'Cheese',325,NULL,NULL,NULL,NULL,NULL,NULL),
+(314,'Sliced Cheddar Style','g',1,180,7,'Cheese',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(315,'Blue D''affinois','g',1,180,7,'Cheese',400,NULL,NULL,NULL,NULL,NULL,NULL),
+(316,'Fromager Excellnce Triple Cream','g',1,185,7,'Cheese',450,NULL,NULL,NULL,NULL,NULL,NULL),
+(317,'Greek Style','g',1,200,7,'Cheese',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(318,'Free Range Eggs Large',NULL,1,12,14,'Eggs',315,NULL,NULL,NULL,NULL,NULL,NULL),
+(319,'Free Range Eggs Large',NULL,1,6,14,'Eggs',185,NULL,NULL,NULL,NULL,NULL,NULL),
+(320,'Free Range Eggs Medium',NULL,1,12,14,'Eggs',265,NULL,NULL,NULL,NULL,NULL,NULL),
+(321,'Free Range Eggs Medium',NULL,1,6,14,'Eggs',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(322,'Woodland Free Range Golden Yolked Mixed Weight Eggs',NULL,1,6,14,'Eggs',265,NULL,NULL,NULL,NULL,NULL,NULL),
+(323,'Free Range Eggs Medium',NULL,1,15,14,'Eggs',320,NULL,NULL,NULL,NULL,NULL,NULL),
+(324,'Woodland Free Range Medium Eggs',NULL,1,6,14,'Eggs',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(325,'Woodland Free Range Large Eggs',NULL,1,6,14,'Eggs',270,NULL,NULL,NULL,NULL,NULL,NULL),
+(326,'Woodland Free Range Mixed Weight Eggs',NULL,1,12,14,'Eggs',450,NULL,NULL,NULL,NULL,NULL,NULL),
+(327,'Woodland Trust Free Range Blue Mixed Weight Eggs',NULL,1,6,14,'Eggs',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(328,'Unsweetened Soya Drink','ml',1,1000,7,'Dairy Free',85,NULL,NULL,NULL,NULL,NULL,NULL),
+(329,'Oat Drink','ml',1,1000,7,'Dairy Free',145,NULL,NULL,NULL,NULL,NULL,NULL),
+(330,'Unsweetened Almond Drink','ml',1,1000,7,'Dairy Free',99,NULL,NULL,NULL,NULL,NULL,NULL),
+(331,'Sweetened Soya Drink','ml',1,1000,7,'Dairy Free',99,NULL,NULL,NULL,NULL,NULL,NULL),
+(332,'Unsweetened Almond Drink','ml',1,1000,7,'Dairy Free',145,NULL,NULL,NULL,NULL,NULL,NULL),
+(333,'Unsweetened Soya Drink','ml',1,1000,7,'Dairy Free',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(334,'Sweetened Coconut Drink','ml',1,1000,7,'Dairy Free',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(335,'Sweetened Almond Drink','ml',1,1000,7,'Dairy Free',99,NULL,NULL,NULL,NULL,NULL,NULL),
+(336,'Sweetened Soya Drink','ml',1,1000,7,'Dairy Free',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(337,'Bananas',NULL,1,1,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(338,'Bananas',NULL,1,5,7,'Fresh Fruit',78,NULL,NULL,NULL,NULL,NULL,NULL),
+(339,'Red Grapes','g',1,500,7,'Fresh Fruit',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(340,'Easy Peelers','g',1,600,7,'Fresh Fruit',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(341,'Blueberries','g',1,150,7,'Fresh Fruit',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(342,'Raspberries','g',1,150,7,'Fresh Fruit',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(343,'Bananas',NULL,1,8,7,'Fresh Fruit',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(344,'Royal Gala Apples',NULL,1,6,7,'Fresh Fruit',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(345,'White Grapes','g',1,500,7,'Fresh Fruit',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(346,'Lemons',NULL,1,1,7,'Fresh Fruit',30,NULL,NULL,NULL,NULL,NULL,NULL),
+(347,'Lemons',NULL,1,5,7,'Fresh Fruit',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(348,'Blueberries','g',1,500,7,'Fresh Fruit',375,NULL,NULL,NULL,NULL,NULL,NULL),
+(349,'Conference Pears',NULL,1,4,7,'Fresh Fruit',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(350,'Blueberries','g',1,300,7,'Fresh Fruit',315,NULL,NULL,NULL,NULL,NULL,NULL),
+(351,'Small Bananas',NULL,1,8,7,'Fresh Fruit',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(352,'Strawberries','g',1,400,7,'Fresh Fruit',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(353,'Pink Lady Apples',NULL,1,4,7,'Fresh Fruit',230,NULL,NULL,NULL,NULL,NULL,NULL),
+(354,'Pink Lady Apples',NULL,1,6,7,'Fresh Fruit',280,NULL,NULL,NULL,NULL,NULL,NULL),
+(355,'Easy Peelers','g',1,600,7,'Fresh Fruit',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(356,'Limes',NULL,1,1,7,'Fresh Fruit',24,NULL,NULL,NULL,NULL,NULL,NULL),
+(357,'Braeburn Apples',NULL,1,6,7,'Fresh Fruit',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(358,'Raspberries','g',1,250,7,'Fresh Fruit',290,NULL,NULL,NULL,NULL,NULL,NULL),
+(359,'Oranges',NULL,1,4,7,'Fresh Fruit',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(360,'Bananas',NULL,1,6,7,'Fresh Fruit',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(361,'Red & White Grapes','g',1,500,7,'Fresh Fruit',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(362,'Granny Smith Apples',NULL,1,6,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(363,'Honeydew Melon',NULL,1,1,7,'Fresh Fruit',189,NULL,NULL,NULL,NULL,NULL,NULL),
+(364,'Jazz Apples',NULL,1,6,7,'Fresh Fruit',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(365,'Tangerines','g',1,600,7,'Fresh Fruit',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(366,'Oranges Large',NULL,1,4,7,'Fresh Fruit',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(367,'Royal Gala Apples',NULL,1,6,7,'Fresh Fruit',230,NULL,NULL,NULL,NULL,NULL,NULL),
+(368,'Conference Pear Single',NULL,1,1,7,'Fresh Fruit',40,NULL,NULL,NULL,NULL,NULL,NULL),
+(369,'Plum Punnet','g',1,400,7,'Fresh Fruit',129,NULL,NULL,NULL,NULL,NULL,NULL),
+(370,'Red Grapefruit',NULL,1,1,7,'Fresh Fruit',55,NULL,NULL,NULL,NULL,NULL,NULL),
+(371,'Limes',NULL,1,5,7,'Fresh Fruit',95,NULL,NULL,NULL,NULL,NULL,NULL),
+(372,'Best Of Apples',NULL,1,6,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(373,'Black Sable Grapes','g',1,400,7,'Fresh Fruit',230,NULL,NULL,NULL,NULL,NULL,NULL),
+(374,'Bramley Cooking Apples',NULL,1,1,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(375,'Oranges',NULL,1,5,7,'Fresh Fruit',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(376,'Mango Single',NULL,1,1,7,'Fresh Fruit',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(377,'Black Grapes','g',1,500,7,'Fresh Fruit',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(378,'Pomegranate','g',1,80,7,'Fresh Fruit',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(379,'Cantaloupe Melon',NULL,1,1,7,'Fresh Fruit',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(380,'Blueberries','g',1,150,7,'Fresh Fruit',260,NULL,NULL,NULL,NULL,NULL,NULL),
+(381,'Large Pineapple',NULL,1,1,7,'Fresh Fruit',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(382,'Mixed Grapes','g',1,900,7,'Fresh Fruit',375,NULL,NULL,NULL,NULL,NULL,NULL),
+(383,'Mango','g',1,250,7,'Fresh Fruit',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(384,'Braeburn Apple Single',NULL,1,1,7,'Fresh Fruit',35,NULL,NULL,NULL,NULL,NULL,NULL),
+(385,'Apples','g',1,1000,7,'Fresh Fruit',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(386,'Kanzi Apples',NULL,1,6,7,'Fresh Fruit',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(387,'Lemons',NULL,1,3,7,'Fresh Fruit',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(388,'Crownless Pineapple',NULL,1,1,7,'Fresh Fruit',80,NULL,NULL,NULL,NULL,NULL,NULL),
+(389,'Nectarine Punnet',NULL,1,1,7,'Fresh Fruit',220,NULL,NULL,NULL,NULL,NULL,NULL),
+(390,'Oranges',NULL,1,1,7,'Fresh Fruit',30,NULL,NULL,NULL,NULL,NULL,NULL),
+(391,'Watermelon',NULL,1,1,7,'Fresh Fruit',279,NULL,NULL,NULL,NULL,NULL,NULL),
+(392,'Cherries','g',1,250,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(393,'Pink Lady Apple Single',NULL,1,1,7,'Fresh Fruit',50,NULL,NULL,NULL,NULL,NULL,NULL),
+(394,'Pomegranate','g',1,250,7,'Fresh Fruit',260,NULL,NULL,NULL,NULL,NULL,NULL),
+(395,'Blackberries','g',1,225,7,'Fresh Fruit',315,NULL,NULL,NULL,NULL,NULL,NULL),
+(396,'Mango','g',1,120,7,'Fresh Fruit',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(397,'Baby Watermelon',NULL,1,1,7,'Fresh Fruit',220,NULL,NULL,NULL,NULL,NULL,NULL),
+(398,'Galia Melon',NULL,1,1,7,'Fresh Fruit',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(399,'Medjool Dates','g',1,200,7,'Fresh Fruit',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(400,'Pineapple','g',1,160,7,'Fresh Fruit',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(401,'Golden Delicious Apples',NULL,1,6,7,'Fresh Fruit',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(402,'Grapes','g',1,400,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(403,'Passion Fruit',NULL,1,1,7,'Fresh Fruit',40,NULL,NULL,NULL,NULL,NULL,NULL),
+(404,'Pomegranate Single',NULL,1,1,7,'Fresh Fruit',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(405,'Easy Peeler',NULL,1,1,7,'Fresh Fruit',30,NULL,NULL,NULL,NULL,NULL,NULL),
+(406,'Easy Peelers min','g',1,500,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(407,'Large Kiwi Fruit Single',NULL,1,1,7,'Fresh Fruit',30,NULL,NULL,NULL,NULL,NULL,NULL),
+(408,'Braeburn Apples',NULL,1,6,7,'Fresh Fruit',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(409,'Pink Lady Apples',NULL,1,6,7,'Fresh Fruit',350,NULL,NULL,NULL,NULL,NULL,NULL),
+(410,'Royal Gala Apple Single',NULL,1,1,7,'Fresh Fruit',35,NULL,NULL,NULL,NULL,NULL,NULL),
+(411,'Ripe Mangoes',NULL,1,2,7,'Fresh Fruit',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(412,'Medjool Dates','g',1,500,7,'Fresh Fruit',350,NULL,NULL,NULL,NULL,NULL,NULL),
+(413,'Figs','g',1,140,7,'Fresh Fruit',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(414,'Medjool Dates','g',1,200,7,'Fresh Fruit',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(415,'Watermelon','g',1,160,7,'Fresh Fruit',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(416,'Strawberries',NULL,1,1,7,'Fresh Fruit',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(417,'Red Grapes','g',1,300,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(418,'Granny Smith Apples Single',NULL,1,1,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(419,'Rhubarb','g',1,400,7,'Fresh Fruit',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(420,'White Grapefruit',NULL,1,1,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(421,'Blood Oranges',NULL,1,5,7,'Fresh Fruit',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(422,'Royal Gala Apples',NULL,1,4,7,'Fresh Fruit',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(423,'Lychees','g',1,200,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(424,'Raspberries','g',1,150,7,'Fresh Fruit',260,NULL,NULL,NULL,NULL,NULL,NULL),
+(425,'White Grapes','g',1,300,7,'Fresh Fruit',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(426,'Jazz Apples',NULL,1,4,7,'Fresh Fruit',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(427,'Mango & Watermelon Fingers','g',1,270,7,'Fresh Fruit',260,NULL,NULL,NULL,NULL,NULL,NULL),
+(428,'Melon & Grape','g',1,160,7,'Fresh Fruit',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(429,'Pineapple','g',1,500,7,'Fresh Fruit',320,NULL,NULL,NULL,NULL,NULL,NULL),
+(430,'Deglet Nour Dates','g',1,200,7,'Fresh Fruit',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(431,'Berry Fruit Salad','g',1,240,7,'Fresh Fruit',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(432,'Melon & Pineapple Fingers','g',1,260,7,'Fresh Fruit',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(433,'Blueberries',NULL,1,1,7,'Fresh Fruit',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(434,'Physalis','g',1,100,7,'Fresh Fruit',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(435,'Coconut','g',1,80,7,'Fresh Fruit',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(436,'Cherries','g',1,500,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(437,'Rocha Pears',NULL,1,6,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(438,'White Grapes','g',1,400,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(439,'Best Of Apples',NULL,1,4,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(440,'Pink Lady Apple','g',1,350,7,'Fresh Fruit',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(441,'Vitoria Grape','g',1,400,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(442,'Apple Pineapple & Grape','g',1,160,7,'Fresh Fruit',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(443,'Watermelon','g',1,300,7,'Fresh Fruit',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(444,'Braeburn Apples',NULL,1,1,7,'Fresh Fruit',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(445,'Grapes&Berries','g',1,115,7,'Fresh Fruit',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(446,'Pineapple','g',1,300,7,'Fresh Fruit',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(447,'Monkey Nuts in Shell','g',1,500,7,'Fresh Fruit',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(448,'Exotic Fruit Salad','g',1,240,7,'Fresh Fruit',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(449,'Coconut Single',NULL,1,1,7,'Fresh Fruit',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(450,'Melon Kiwi & Strawberry','g',1,220,7,'Fresh Fruit',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(451,'Apple Mango Strawberry & Raspberry','g',1,220,7,'Fresh Fruit',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(452,'Zamli Dates','g',1,200,7,'Fresh Fruit',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(453,'Melon & Grape','g',1,300,7,'Fresh Fruit',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(454,'Apple & Grape','g',1,130,7,'Fresh Fruit',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(455,'Oranges Family Size',NULL,1,10,7,'Fresh Fruit',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(456,'Melon Stone Fruit& Raspberry','g',1,220,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(457,'Apple Banana Strawberry Grape','g',1,220,7,'Fresh Fruit',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(458,'Giant Mango',NULL,1,1,7,'Fresh Fruit',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(459,'Red Kiwi',NULL,1,1,7,'Fresh Fruit',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(460,'Whole Cucumber',NULL,1,1,7,'Fresh Vegetables',89,NULL,NULL,NULL,NULL,NULL,NULL),
+(461,'Broccoli',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(462,'Cherry Vine Tomatoes','g',1,250,7,'Fresh Vegetables',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(463,'Carrots','g',1,1000,7,'Fresh Vegetables',65,NULL,NULL,NULL,NULL,NULL,NULL),
+(464,'Sweet Peppers',NULL,1,3,7,'Fresh Vegetables',179,NULL,NULL,NULL,NULL,NULL,NULL),
+(465,'Red Pepper',NULL,1,1,7,'Fresh Vegetables',59,NULL,NULL,NULL,NULL,NULL,NULL),
+(466,'Tenderstem Broccoli','g',1,200,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(467,'Closed Cup White Mushrooms','g',1,300,7,'Fresh Vegetables',89,NULL,NULL,NULL,NULL,NULL,NULL),
+(468,'Spring Onions','g',1,100,7,'Fresh Vegetables',59,NULL,NULL,NULL,NULL,NULL,NULL),
+(469,'Maris Piper Potatoes','g',1,2000,7,'Fresh Vegetables',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(470,'Carrots',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(471,'Onions',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(472,'Baby Potatoes','g',1,1000,7,'Fresh Vegetables',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(473,'Closed Cup Chestnut Mushrooms','g',1,300,7,'Fresh Vegetables',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(474,'Cauliflower',NULL,1,1,7,'Fresh Vegetables',109,NULL,NULL,NULL,NULL,NULL,NULL),
+(475,'Brown Onions',NULL,1,3,7,'Fresh Vegetables',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(476,'Baking Potatoes',NULL,1,4,7,'Fresh Vegetables',69,NULL,NULL,NULL,NULL,NULL,NULL),
+(477,'Baby Leaf Spinach','g',1,100,7,'Fresh Vegetables',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(478,'Red Onions',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(479,'Whole Cucumber',NULL,1,1,7,'Fresh Vegetables',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(480,'White Potatoes','g',1,2000,7,'Fresh Vegetables',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(481,'Celery',NULL,1,1,7,'Fresh Vegetables',69,NULL,NULL,NULL,NULL,NULL,NULL),
+(482,'Sweet Potatoes',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(483,'Classic Round Tomatoes',NULL,1,6,7,'Fresh Vegetables',95,NULL,NULL,NULL,NULL,NULL,NULL),
+(484,'Carrots','g',1,700,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(485,'Courgettes',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(486,'Fine Green Beans','g',1,200,7,'Fresh Vegetables',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(487,'Garlic',NULL,1,1,7,'Fresh Vegetables',24,NULL,NULL,NULL,NULL,NULL,NULL),
+(488,'Baby Plum Tomatoes','g',1,325,7,'Fresh Vegetables',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(489,'Courgettes','g',1,500,7,'Fresh Vegetables',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(490,'Aubergine',NULL,1,1,7,'Fresh Vegetables',95,NULL,NULL,NULL,NULL,NULL,NULL),
+(491,'Onions','g',1,1000,7,'Fresh Vegetables',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(492,'Half Cucumber Portion',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(493,'Baking Potatoes',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(494,'Red Onions',NULL,1,3,7,'Fresh Vegetables',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(495,'Fresh Packed Coriander','g',1,30,7,'Fresh Vegetables',52,NULL,NULL,NULL,NULL,NULL,NULL),
+(496,'Young Spinach','g',1,260,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(497,'Majestic Vine Ripened Tomatoes','g',1,300,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(498,'Yellow Pepper',NULL,1,1,7,'Fresh Vegetables',59,NULL,NULL,NULL,NULL,NULL,NULL),
+(499,'Leeks','g',1,500,7,'Fresh Vegetables',129,NULL,NULL,NULL,NULL,NULL,NULL),
+(500,'Baking Potatoes','g',1,2000,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(501,'Vivaldi Baking Potatoes',NULL,1,4,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(502,'Sweetheart Cabbage',NULL,1,1,7,'Fresh Vegetables',69,NULL,NULL,NULL,NULL,NULL,NULL),
+(503,'Large Vine Tomatoes','g',1,500,7,'Fresh Vegetables',119,NULL,NULL,NULL,NULL,NULL,NULL),
+(504,'Large Garlic',NULL,1,1,7,'Fresh Vegetables',55,NULL,NULL,NULL,NULL,NULL,NULL),
+(505,'Sweet Gem Lettuce',NULL,1,2,7,'Fresh Vegetables',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(506,'Broccoli','g',1,300,7,'Fresh Vegetables',115,NULL,NULL,NULL,NULL,NULL,NULL),
+(507,'Extra Fine Beans','g',1,200,7,'Fresh Vegetables',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(508,'Kale','g',1,200,7,'Fresh Vegetables',85,NULL,NULL,NULL,NULL,NULL,NULL),
+(509,'Parsnips',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(510,'Swede',NULL,1,1,7,'Fresh Vegetables',62,NULL,NULL,NULL,NULL,NULL,NULL),
+(511,'Fresh Packed Flat Leaf Parsley','g',1,30,7,'Fresh Vegetables',52,NULL,NULL,NULL,NULL,NULL,NULL),
+(512,'Carrots','g',1,500,7,'Fresh Vegetables',50,NULL,NULL,NULL,NULL,NULL,NULL),
+(513,'Fresh Packed Basil','g',1,30,7,'Fresh Vegetables',52,NULL,NULL,NULL,NULL,NULL,NULL),
+(514,'Baby Button Mushrooms','g',1,200,7,'Fresh Vegetables',115,NULL,NULL,NULL,NULL,NULL,NULL),
+(515,'Baby Plum Tomatoes','g',1,250,7,'Fresh Vegetables',185,NULL,NULL,NULL,NULL,NULL,NULL),
+(516,'Sweet Potato','g',1,1250,7,'Fresh Vegetables',149,NULL,NULL,NULL,NULL,NULL,NULL),
+(517,'Parsnips','g',1,500,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(518,'Brussels Sprouts','g',1,500,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(519,'King Edward Potatoes','g',1,2000,7,'Fresh Vegetables',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(520,'Leeks',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(521,'Green Pepper',NULL,1,1,7,'Fresh Vegetables',59,NULL,NULL,NULL,NULL,NULL,NULL),
+(522,'Sugar Snap Peas','g',1,200,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(523,'Garlic',NULL,1,4,7,'Fresh Vegetables',95,NULL,NULL,NULL,NULL,NULL,NULL),
+(524,'Baby Leaf Spinach','g',1,200,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(525,'Root Ginger',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(526,'Large Whole Cucumber',NULL,1,1,7,'Fresh Vegetables',99,NULL,NULL,NULL,NULL,NULL,NULL),
+(527,'Butternut Squash',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(528,'Cherry Tomatoes','g',1,330,7,'Fresh Vegetables',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(529,'Asparagus Spears','g',1,250,7,'Fresh Vegetables',199,NULL,NULL,NULL,NULL,NULL,NULL),
+(530,'Free Range Fresh Egg Noodles','g',1,410,7,'Fresh Vegetables',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(531,'Large Flat White Mushrooms','g',1,250,7,'Fresh Vegetables',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(532,'Red Onions','g',1,1000,7,'Fresh Vegetables',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(533,'Red Chillies','g',1,65,7,'Fresh Vegetables',60,NULL,NULL,NULL,NULL,NULL,NULL),
+(534,'Beetroot Vacuum Pack','g',1,250,7,'Fresh Vegetables',50,NULL,NULL,NULL,NULL,NULL,NULL),
+(535,'Closed Cup White Mushrooms','g',1,500,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(536,'Savoy Cabbage',NULL,1,1,7,'Fresh Vegetables',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(537,'Potatoes','g',1,750,7,'Fresh Vegetables',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(538,'White Vivaldi Potatoes','g',1,2000,7,'Fresh Vegetables',230,NULL,NULL,NULL,NULL,NULL,NULL),
+(539,'Charlotte Potatoes','g',1,1000,7,'Fresh Vegetables',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(540,'Cherry Tomatoes','g',1,500,7,'Fresh Vegetables',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(541,'Traditionally Sliced Runner Beans','g',1,160,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(542,'Echalion Shallots','g',1,400,7,'Fresh Vegetables',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(543,'Babycorn','g',1,175,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(544,'Fresh Packed Mint','g',1,30,7,'Fresh Vegetables',52,NULL,NULL,NULL,NULL,NULL,NULL),
+(545,'Best of Tomatoes','g',1,250,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(546,'Celery',NULL,1,1,7,'Fresh Vegetables',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(547,'Fresh Packed Coriander','g',1,100,7,'Fresh Vegetables',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(548,'Baby Corn & Mange Tout','g',1,200,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(549,'Red Sweet Pointed Pepper',NULL,1,1,7,'Fresh Vegetables',65,NULL,NULL,NULL,NULL,NULL,NULL),
+(550,'Beetroot In Vinegar','g',1,300,7,'Fresh Vegetables',85,NULL,NULL,NULL,NULL,NULL,NULL),
+(551,'Carrot Batons','g',1,400,7,'Fresh Vegetables',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(552,'Cavolo Nero Black Kale','g',1,200,7,'Fresh Vegetables',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(553,'Sweetcorn Cobettes',NULL,1,4,7,'Fresh Vegetables',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(554,'White Cabbage Each',NULL,1,1,7,'Fresh Vegetables',80,NULL,NULL,NULL,NULL,NULL,NULL),
+(555,'Red Potatoes','g',1,2000,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(556,'Plum Vine Tomatoes','g',1,250,7,'Fresh Vegetables',220,NULL,NULL,NULL,NULL,NULL,NULL),
+(557,'Asparagus Tips','g',1,100,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(558,'Cherry Vine Tomatoes','g',1,400,7,'Fresh Vegetables',270,NULL,NULL,NULL,NULL,NULL,NULL),
+(559,'Greens','g',1,500,7,'Fresh Vegetables',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(560,'Chantenay Carrots','g',1,400,7,'Fresh Vegetables',80,NULL,NULL,NULL,NULL,NULL,NULL),
+(561,'Pak Choi','g',1,250,7,'Fresh Vegetables',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(562,'Beansprouts','g',1,300,7,'Fresh Vegetables',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(563,'Sweetcorn',NULL,1,2,7,'Fresh Vegetables',139,NULL,NULL,NULL,NULL,NULL,NULL),
+(564,'Onions','g',1,750,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(565,'Ginger','g',1,100,7,'Fresh Vegetables',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(566,'Trimmed Mange Tout','g',1,200,7,'Fresh Vegetables',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(567,'Thyme','g',1,20,7,'Fresh Vegetables',52,NULL,NULL,NULL,NULL,NULL,NULL),
+(568,'Fresh Salad Cress','g',1,20,7,'Fresh Vegetables',40,NULL,NULL,NULL,NULL,NULL,NULL),
+(569,'Chestnut Super Mushrooms','g',1,300,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(570,'Salad Onions Large','g',1,130,7,'Fresh Vegetables',80,NULL,NULL,NULL,NULL,NULL,NULL),
+(571,'Shiitake Mushrooms',NULL,1,1,7,'Fresh Vegetables',165,NULL,NULL,NULL,NULL,NULL,NULL),
+(572,'Young Spinach','g',1,200,7,'Fresh Vegetables',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(573,'Brussels Sprouts','g',1,200,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(574,'Tomatoes',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(575,'Sweet & Crunchy Stir Fry','g',1,600,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(576,'Mixed Peppers',NULL,1,3,7,'Fresh Vegetables',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(577,'Red Cabbage Each',NULL,1,1,7,'Fresh Vegetables',80,NULL,NULL,NULL,NULL,NULL,NULL),
+(578,'Spring Onion','g',1,100,7,'Fresh Vegetables',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(579,'White Potatoes','g',1,1500,7,'Fresh Vegetables',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(580,'Baby Vegetable Stir Fry','g',1,220,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(581,'Stringless Beans','g',1,225,7,'Fresh Vegetables',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(582,'Dill','g',1,20,7,'Fresh Vegetables',60,NULL,NULL,NULL,NULL,NULL,NULL),
+(583,'Chestnut Baby Button Mushrooms','g',1,200,7,'Fresh Vegetables',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(584,'Whole Green Beans','g',1,900,7,'Fresh Vegetables',107,NULL,NULL,NULL,NULL,NULL,NULL),
+(585,'Broccoli Florets','g',1,160,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(586,'Oriental Vegetable Stir Fry','g',1,300,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(587,'Leeks','g',1,400,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(588,'Chives','g',1,20,7,'Fresh Vegetables',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(589,'Mixed Chillies','g',1,80,7,'Fresh Vegetables',74,NULL,NULL,NULL,NULL,NULL,NULL),
+(590,'Butternut Squash Slices','g',1,400,7,'Fresh Vegetables',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(591,'Closed Cup White Mushrooms',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(592,'Sweet Spear Carrots','g',1,450,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(593,'Baby Sweet Peppers','g',1,300,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(594,'Cauliflower Extra Large',NULL,1,1,7,'Fresh Vegetables',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(595,'Beetroot','g',1,500,7,'Fresh Vegetables',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(596,'Orange Pepper',NULL,1,1,7,'Fresh Vegetables',59,NULL,NULL,NULL,NULL,NULL,NULL),
+(597,'Singapore Style Noodles','g',1,300,7,'Fresh Vegetables',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(598,'Potato Carrot & Swede Mash','g',1,400,7,'Fresh Vegetables',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(599,'Mushroom Stir Fry','g',1,320,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(600,'Crispy Potato Slices','g',1,350,7,'Fresh Vegetables',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(601,'Dwarf Beans','g',1,300,7,'Fresh Vegetables',121,NULL,NULL,NULL,NULL,NULL,NULL),
+(602,'Broccoli & Cauliflower Florets','g',1,240,7,'Fresh Vegetables',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(603,'White Closed Cup Mushrooms','g',1,300,7,'Fresh Vegetables',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(604,'Fennel',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(605,'Sweet Pointed Peppers',NULL,1,2,7,'Fresh Vegetables',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(606,'Sweet Potato','g',1,750,7,'Fresh Vegetables',260,NULL,NULL,NULL,NULL,NULL,NULL),
+(607,'Mixed Pepper Stir Fry','g',1,300,7,'Fresh Vegetables',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(608,'Vivaldi Salad Potatoes','g',1,1000,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(609,'Garlic',NULL,1,3,7,'Fresh Vegetables',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(610,'Fresh Packed Rosemary','g',1,20,7,'Fresh Vegetables',52,NULL,NULL,NULL,NULL,NULL,NULL),
+(611,'Sliced White Mushrooms','g',1,250,7,'Fresh Vegetables',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(612,'Chinese Leaf Lettuce',NULL,1,1,7,'Fresh Vegetables',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(613,'Carrot Cauliflower & Broccoli','g',1,240,7,'Fresh Vegetables',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(614,'Chestnut Mushrooms','g',1,300,7,'Fresh Vegetables',255,NULL,NULL,NULL,NULL,NULL,NULL),
+(615,'Courgettes','g',1,300,7,'Fresh Vegetables',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(616,'Fresh Living Basil Pot',NULL,1,1,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(617,'Tenderstem Broccoli','g',1,200,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(618,'Diced Onions','g',1,200,7,'Fresh Vegetables',50,NULL,NULL,NULL,NULL,NULL,NULL),
+(619,'Beetroot Vacuum Pack','g',1,250,7,'Fresh Vegetables',95,NULL,NULL,NULL,NULL,NULL,NULL),
+(620,'Bird Eye Chillies','g',1,20,7,'Fresh Vegetables',65,NULL,NULL,NULL,NULL,NULL,NULL),
+(621,'Runner Beans','g',1,225,7,'Fresh Vegetables',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(622,'Carrots','g',1,400,7,'Fresh Vegetables',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(623,'Plum Tomatoes','g',1,500,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(624,'Fine Beans & Tenderstem Broccoli','g',1,200,7,'Fresh Vegetables',215,NULL,NULL,NULL,NULL,NULL,NULL),
+(625,'Casserole Vegetables','g',1,700,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(626,'Cabbage',NULL,1,1,7,'Fresh Vegetables',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(627,'Fresh Packed Flat Leaf Parsley','g',1,100,7,'Fresh Vegetables',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(628,'Mixed Vegetable Stir Fry','g',1,325,7,'Fresh Vegetables',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(629,'Shallots','g',1,400,7,'Fresh Vegetables',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(630,'Red Onions','g',1,500,7,'Fresh Vegetables',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(631,'Rice Noodles','g',1,300,7,'Fresh Vegetables',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(632,'Purple Sprouting Broccoli Spears','g',1,200,7,'Fresh Vegetables',185,NULL,NULL,NULL,NULL,NULL,NULL),
+(633,'Beans Tenderstem & Baby Corn','g',1,240,7,'Fresh Vegetables',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(634,'Green Chillies','g',1,65,7,'Fresh Vegetables',60,NULL,NULL,NULL,NULL,NULL,NULL),
+(635,'Stir Fry Vegetables','g',1,500,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(636,'Edamame Stir Fry','g',1,300,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(637,'Cauliflower Cheese','g',1,400,7,'Fresh Vegetables',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(638,'Baby Corn','g',1,125,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(639,'Rainbow Chantenay Carrots',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(640,'Potato Croquettes','g',1,220,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(641,'Portabella Mushrooms','g',1,150,7,'Fresh Vegetables',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(642,'Cauliflower',NULL,1,1,7,'Fresh Vegetables',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(643,'Bellaverde Broccoli','g',1,200,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(644,'Spinach','g',1,100,7,'Fresh Vegetables',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(645,'Brussels Sprouts',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(646,'Diced Butternut & Sweet Potato','g',1,300,7,'Fresh Vegetables',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(647,'Speciality Mushroom Mix',NULL,1,1,7,'Fresh Vegetables',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(648,'Plum & Hoisin Stir Fry Sauce','g',1,175,7,'Fresh Vegetables',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(649,'Finger Chillies','g',1,80,7,'Fresh Vegetables',95,NULL,NULL,NULL,NULL,NULL,NULL),
+(650,'Fresh Living Coriander Pot',NULL,1,1,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(651,'Tenderstem Broccoli','g',1,330,7,'Fresh Vegetables',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(652,'Fresh Curly Parsley','g',1,30,7,'Fresh Vegetables',52,NULL,NULL,NULL,NULL,NULL,NULL),
+(653,'Celeriac',NULL,1,1,7,'Fresh Vegetables',131,NULL,NULL,NULL,NULL,NULL,NULL),
+(654,'Shelled Peas','g',1,160,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(655,'Chunky Mediterranean Style Vegetables','g',1,600,7,'Fresh Vegetables',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(656,'Carrot & Swede Mash','g',1,400,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(657,'Edamame Beans','g',1,175,7,'Fresh Vegetables',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(658,'Heritage Baby Parsnips','g',1,230,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(659,'Cabbage & Leek','g',1,200,7,'Fresh Vegetables',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(660,'Tenderstem Stir Fry','g',1,220,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(661,'Cauliflower Cheese','g',1,400,7,'Fresh Vegetables',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(662,'Fresh Packed Lemongrass Sticks',NULL,1,2,7,'Fresh Vegetables',60,NULL,NULL,NULL,NULL,NULL,NULL),
+(663,'Fresh Packed Sage','g',1,20,7,'Fresh Vegetables',60,NULL,NULL,NULL,NULL,NULL,NULL),
+(664,'Tomatoes','g',1,225,7,'Fresh Vegetables',215,NULL,NULL,NULL,NULL,NULL,NULL),
+(665,'Butternut Squash',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(666,'Baby Courgettes','g',1,200,7,'Fresh Vegetables',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(667,'Red Chicory','g',1,140,7,'Fresh Vegetables',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(668,'Okra','g',1,175,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(669,'Sliced Mushrooms','g',1,500,7,'Fresh Vegetables',99,NULL,NULL,NULL,NULL,NULL,NULL),
+(670,'Sliced Red Onions','g',1,150,7,'Fresh Vegetables',65,NULL,NULL,NULL,NULL,NULL,NULL),
+(671,'Baby Salad Potatoes','g',1,750,7,'Fresh Vegetables',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(672,'Asparagus Sugar Snaps & Tenderstem Broccoli','g',1,200,7,'Fresh Vegetables',230,NULL,NULL,NULL,NULL,NULL,NULL),
+(673,'Vibrant & Sweet Diced Swede & Carrots','g',1,600,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(674,'Potato Dauphinoise','g',1,400,7,'Fresh Vegetables',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(675,'Butternut Squash & Spinach Croquettes','g',1,225,7,'Fresh Vegetables',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(676,'Aubergine',NULL,1,1,7,'Fresh Vegetables',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(677,'Fresh Packed Tarragon','g',1,20,7,'Fresh Vegetables',60,NULL,NULL,NULL,NULL,NULL,NULL),
+(678,'White Chicory','g',1,250,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(679,'White Turnips',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(680,'Chinese Sauce','g',1,175,7,'Fresh Vegetables',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(681,'Cauliflower Rice','g',1,300,7,'Fresh Vegetables',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(682,'Dwarf Beans','g',1,210,7,'Fresh Vegetables',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(683,'Choi Sum','g',1,200,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(684,'Brussels Sprouts','g',1,400,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(685,'Cheese & Garlic Mushrooms','g',1,200,7,'Fresh Vegetables',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(686,'Thai Basil','g',1,20,7,'Fresh Vegetables',90,NULL,NULL,NULL,NULL,NULL,NULL),
+(687,'Black Bean Sauce','g',1,175,7,'Fresh Vegetables',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(688,'Katsu Curry Stir Fry Sauce','g',1,175,7,'Fresh Vegetables',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(689,'Hot & Spicy Stir Fry','g',1,300,7,'Fresh Vegetables',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(690,'Soy Ginger & Garlic Stir Fry Sauce','g',1,175,7,'Fresh Vegetables',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(691,'Fresh Living Mint Pot',NULL,1,1,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(692,'Fresh Living Curly Parsley Pot',NULL,1,1,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(693,'Baking Sweet Potatoes',NULL,1,2,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(694,'Broccoli Baby Corn Carrots & Sugar Snap Peas','g',1,240,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(695,'Baby Cauliflowers',NULL,1,2,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(696,'Chunky Roast Potatoes','g',1,450,7,'Fresh Vegetables',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(697,'Anya Potatoes','g',1,750,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(698,'Sweet Onions',NULL,1,3,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(699,'Fresh Living Flat Leaf Parsley Pot',NULL,1,1,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(700,'Scotch Bonnet Chillies','g',1,60,7,'Fresh Vegetables',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(701,'Baby Leeks','g',1,175,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(702,'Fresh Living Thyme Pot',NULL,1,1,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(703,'Sweet Potato Mash','g',1,400,7,'Fresh Vegetables',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(704,'Jacket Potatoes','g',1,345,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(705,'Baby Cabbage',NULL,1,2,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(706,'Peas & Sweetcorn Layered Vegetable','g',1,350,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(707,'Rainbow Chard','g',1,200,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(708,'Baby Brussels Sprouts',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(709,'Mixed Sweet Pointed Peppers',NULL,1,2,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(710,'Fresh Packed Bay Leaves',NULL,1,1,7,'Fresh Vegetables',70,NULL,NULL,NULL,NULL,NULL,NULL),
+(711,'Carrot Cauliflower & Broccoli','g',1,480,7,'Fresh Vegetables',215,NULL,NULL,NULL,NULL,NULL,NULL),
+(712,'Roast Potatoes','g',1,400,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(713,'Sprout Tree',NULL,1,1,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(714,'Fresh Living Chives Pot',NULL,1,1,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(715,'Cheese Stuffed Peppers','g',1,320,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(716,'Roasting Herb Mix','g',1,30,7,'Fresh Vegetables',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(717,'Rumbledethumps','g',1,400,7,'Fresh Vegetables',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(718,'Broccoli Cheese','g',1,400,7,'Fresh Vegetables',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(719,'Baby Aubergines','g',1,200,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(720,'Samphire','g',1,90,7,'Fresh Vegetables',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(721,'Fresh Living Rosemary',NULL,1,1,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(722,'Vegetable Soup Mix','g',1,600,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(723,'Extra Large Brown Onions',NULL,1,2,7,'Fresh Vegetables',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(724,'Curry Leaves','g',1,5,7,'Fresh Vegetables',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(725,'Fresh Packed Lime Leaves',NULL,1,4,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(726,'Minted Tenderstem Broccoli & Asparagus','g',1,260,7,'Fresh Vegetables',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(727,'Creamed Green Cabbage','g',1,250,7,'Fresh Vegetables',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(728,'Fresh Turmeric','g',1,100,7,'Fresh Vegetables',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(729,'Baby Romanesco Cauliflower',NULL,1,2,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(730,'White Flesh Sweet Potato','g',1,750,7,'Fresh Vegetables',220,NULL,NULL,NULL,NULL,NULL,NULL),
+(731,'Freeze Dried Raspberries','g',1,6,7,'Fresh Vegetables',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(732,'Carrots & Peas','g',1,480,7,'Fresh Vegetables',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(733,'Fresh Galangal','g',1,100,7,'Fresh Vegetables',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(734,'Thai Style Green Curry Herb Kit','g',1,80,7,'Fresh Vegetables',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(735,'Teriyaki Stir Fry Sauce','g',1,175,7,'Fresh Herbs',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(736,'Sweet Chilli Stir Fry Sauce','g',1,175,7,'Fresh Herbs',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(737,'Garlic Paste','g',1,85,7,'Fresh Herbs',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(738,'Ginger Paste','g',1,80,7,'Fresh Herbs',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(739,'Fresh Oregano','g',1,20,7,'Fresh Herbs',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(740,'Chilli Paste','g',1,80,7,'Fresh Herbs',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(741,'Middle Eastern Inspired Herb Mix','g',1,40,7,'Fresh Herbs',85,NULL,NULL,NULL,NULL,NULL,NULL),
+(742,'Soft Multiseed Farmhouse Thick Sliced Wholemeal Bread','g',1,800,7,'Bread',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(743,'Medium Sliced Wholemeal Bread','g',1,800,7,'Bread',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(744,'Toastie Thick Sliced White Bread','g',1,800,7,'Bread',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(745,'Soft Medium Sliced White Bread','g',1,800,7,'Bread',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(746,'Garlic Baguette',NULL,1,2,7,'Bread',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(747,'Sourdough Medium Sliced White Bread','g',1,400,7,'Bread',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(748,'Multiseed Loaf','g',1,800,7,'Bread',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(749,'Crusty White Baguette','g',1,400,7,'Bread',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(750,'Thick Sliced Wholemeal Bread','g',1,800,7,'Bread',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(751,'Sourdough Pave','g',1,400,7,'Bread',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(752,'Seeded Sourdough Loaf Bread','g',1,400,7,'Bread',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(753,'Wholemeal Bread','g',1,400,7,'Bread',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(754,'All Butter Croissants',NULL,1,4,7,'Bread',99,NULL,NULL,NULL,NULL,NULL,NULL),
+(755,'Wholemeal Sourdough Half Bloomer Bread','g',1,400,7,'Bread',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(756,'Garlic Baguette','g',1,195,7,'Bread',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(757,'Giraffe Bread','g',1,800,7,'Bread',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(758,'Kalamata Olive Bread','g',1,400,7,'Bread',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(759,'Wheat & Rye Sourdough Half Bloomer Bread','g',1,400,7,'Bread',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(760,'White Baton',NULL,1,1,7,'Bread',80,NULL,NULL,NULL,NULL,NULL,NULL),
+(761,'Garlic Baguette',NULL,1,2,7,'Bread',85,NULL,NULL,NULL,NULL,NULL,NULL),
+(762,'Soft Multiseed Wholemeal','g',1,430,7,'Bread',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(763,'Giraffe Baguette','g',1,400,7,'Bread',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(764,'Ancient Grain Pave','g',1,400,7,'Bread',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(765,'Super Seeds White Farmhouse','g',1,800,7,'Bread',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(766,'San Francisco Style Sourdough','g',1,400,7,'Bread',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(767,'Thick Lightly Seeded','g',1,800,7,'Bread',85,NULL,NULL,NULL,NULL,NULL,NULL),
+(768,'Farmhouse Loaf White Bread','g',1,800,7,'Bread',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(769,'Multiseed Loaf','g',1,400,7,'Bread',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(770,'Sandwich Loaf White Bread','g',1,800,7,'Bread',145,NULL,NULL,NULL,NULL,NULL,NULL),
+(771,'Walnut Cob','g',1,400,7,'Bread',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(772,'Giraffe Bread','g',1,400,7,'Bread',115,NULL,NULL,NULL,NULL,NULL,NULL),
+(773,'Farmhouse White Bread','g',1,400,7,'Bread',115,NULL,NULL,NULL,NULL,NULL,NULL),
+(774,'Sunflower & Pumpkin','g',1,800,7,'Bread',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(775,'White Sourdough Bloomer Thick Sliced','g',1,800,7,'Bread',260,NULL,NULL,NULL,NULL,NULL,NULL),
+(776,'Medium Both in One','g',1,800,7,'Bread',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(777,'Wholemeal Bread Medium','g',1,400,7,'Bread',65,NULL,NULL,NULL,NULL,NULL,NULL),
+(778,'Cheese Baton',NULL,1,1,7,'Bread',80,NULL,NULL,NULL,NULL,NULL,NULL),
+(779,'Stonebaked Ciabatta','g',1,400,7,'Bread',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(780,'Hand Crafted Bread','g',1,400,7,'Bread',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(781,'Rosemary & Sea Salt Focaccia','g',1,375,7,'Bread',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(782,'White Bloomer','g',1,800,7,'Bread',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(783,'Multiseed Medium Sliced Half Bloomer Bread','g',1,400,7,'Bread',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(784,'Sliced Brioche Loaf','g',1,500,7,'Bread',169,NULL,NULL,NULL,NULL,NULL,NULL),
+(785,'Toastie Sliced White Bread','g',1,400,7,'Bread',65,NULL,NULL,NULL,NULL,NULL,NULL),
+(786,'White Sliced Bread','g',1,535,7,'Bread',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(787,'Sliced Seeded Bread','g',1,535,7,'Bread',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(788,'Thick Soft White Farmhouse','g',1,800,7,'Bread',85,NULL,NULL,NULL,NULL,NULL,NULL),
+(789,'Baguettine',NULL,1,1,7,'Bread',50,NULL,NULL,NULL,NULL,NULL,NULL),
+(790,'Seeded Baguette','g',1,400,7,'Bread',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(791,'Beer Sourdough Loaves','g',1,400,7,'Bread',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(792,'White Bread','g',1,400,7,'Bread',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(793,'Brown Sliced Bread','g',1,535,7,'Bread',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(794,'Tiger Loaf','g',1,400,7,'Bread',315,NULL,NULL,NULL,NULL,NULL,NULL),
+(795,'Multigrain & Rye Bloomer','g',1,400,7,'Bread',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(796,'White Bloomer','g',1,400,7,'Bread',115,NULL,NULL,NULL,NULL,NULL,NULL),
+(797,'Well Fired Loaf','g',1,400,7,'Bread',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(798,'Wholemeal Rustic Roll',NULL,1,1,7,'Bread',40,NULL,NULL,NULL,NULL,NULL,NULL),
+(799,'Seeded Sourdough Cob','g',1,400,7,'Bread',315,NULL,NULL,NULL,NULL,NULL,NULL),
+(800,'Artisan West Country Cheddar Stick',NULL,1,1,7,'Bread',65,NULL,NULL,NULL,NULL,NULL,NULL),
+(801,'Stonebaked White Baguette',NULL,1,1,7,'Bread',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(802,'Giraffe Baton',NULL,1,1,7,'Bread',80,NULL,NULL,NULL,NULL,NULL,NULL),
+(803,'Sourdough Bloomer','g',1,400,7,'Bread',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(804,'Fruit Loaf','g',1,385,7,'Bread',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(805,'Cheese Bloomer','g',1,400,7,'Bread',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(806,'Brown Sourdough','g',1,400,7,'Bread',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(807,'Stonebaked White Baton',NULL,1,1,7,'Bread',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(808,'Oregano','g',1,12,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(809,'Garlic Granules','g',1,56,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(810,'Smoked Paprika','g',1,44,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(811,'Thyme','g',1,12,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(812,'Mixed Herbs','g',1,14,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(813,'Paprika','g',1,44,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(814,'Ground Cumin','g',1,43,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(815,'Ground Cinnamon','g',1,38,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(816,'Herb Seasoning','g',1,14,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(817,'Parsley','g',1,6,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(818,'Bay Leaves','g',1,3,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(819,'Ground Coriander','g',1,36,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(820,'Cajun Seasoning','g',1,50,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(821,'Medium Curry Powder','g',1,80,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(822,'Rosemary','g',1,22,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(823,'Turmeric','g',1,48,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(824,'Basil','g',1,15,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(825,'Whole Black Peppercorns','g',1,100,365,'Herbs and Spices',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(826,'Ground Ginger','g',1,38,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(827,'Cayenne Pepper','g',1,42,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(828,'Garam Masala','g',1,38,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(829,'Cinnamon Sticks','g',1,13,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(830,'Mild Chilli Powder','g',1,44,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(831,'Mild Curry Powder','g',1,79,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(832,'Coarse Ground Black Pepper','g',1,45,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(833,'Sesame Seeds','g',1,50,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(834,'Black Peppercorns','g',1,275,365,'Herbs and Spices',470,NULL,NULL,NULL,NULL,NULL,NULL),
+(835,'Fajita Seasoning','g',1,48,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(836,'Chinese Spice',NULL,1,5,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(837,'Black Peppercorns','g',1,45,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(838,'Hot Chilli Powder','g',1,44,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(839,'Cumin Seed','g',1,38,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(840,'Chives','g',1,5,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(841,'Ground Black Pepper','g',1,100,365,'Herbs and Spices',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(842,'Ground Black Pepper','g',1,48,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(843,'Chipotle Chilli Flakes','g',1,45,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(844,'Ground White Pepper','g',1,44,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(845,'Ground Nutmeg','g',1,50,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(846,'Tamarind Paste','g',1,90,365,'Herbs and Spices',165,NULL,NULL,NULL,NULL,NULL,NULL),
+(847,'Sage','g',1,13,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(848,'Fennel Seed','g',1,34,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(849,'Tarragon','g',1,12,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(850,'Ground Mixed Spice','g',1,34,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(851,'Herbes De Provence','g',1,14,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(852,'Ras El Hanout','g',1,45,365,'Herbs and Spices',245,NULL,NULL,NULL,NULL,NULL,NULL),
+(853,'Garlic & Herb Seasoning','g',1,40,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(854,'Cardamom Pods','g',1,28,365,'Herbs and Spices',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(855,'Hot Paprika','g',1,44,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(856,'Miso Paste','g',1,100,365,'Herbs and Spices',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(857,'Coriander Seed','g',1,25,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(858,'Chicken Seasoning','g',1,55,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(859,'Ground White Pepper','g',1,100,365,'Herbs and Spices',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(860,'Coriander Leaf','g',1,9,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(861,'Ground Fenugreek','g',1,50,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(862,'Garlic & Ginger Paste','g',1,90,365,'Herbs and Spices',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(863,'Makrut Lime Leaves','g',1,2,365,'Herbs and Spices',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(864,'Ground Allspice','g',1,40,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(865,'Mild & Smoky BBQ','g',1,44,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(866,'Sumac','g',1,45,365,'Herbs and Spices',245,NULL,NULL,NULL,NULL,NULL,NULL),
+(867,'Mustard Seed','g',1,60,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(868,'Nigella Seeds','g',1,48,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(869,'Star Anise','g',1,15,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(870,'Whole Cloves','g',1,30,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(871,'Dill','g',1,13,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(872,'Mint','g',1,12,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(873,'Harissa Spices','g',1,45,365,'Herbs and Spices',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(874,'Marjoram','g',1,10,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(875,'Whole Nutmeg','g',1,36,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(876,'Bouquet Garni','g',1,5,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(877,'Celery Salt','g',1,78,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(878,'Caraway Seed','g',1,43,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(879,'Onion Salt','g',1,86,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(880,'Ancho Chilli Flakes','g',1,27,365,'Herbs and Spices',140,NULL,NULL,NULL,NULL,NULL,NULL),
+(881,'Saffron','g',1,0.4,365,'Herbs and Spices',315,NULL,NULL,NULL,NULL,NULL,NULL),
+(882,'Tikka Curry Powder','g',1,90,365,'Herbs and Spices',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(883,'Steak Seasoning','g',1,54,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(884,'Mixed Peppercorns','g',1,43,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(885,'Jerk Seasoning','g',1,50,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(886,'Ginger Paste','g',1,90,365,'Herbs and Spices',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(887,'Red Chilli Paste','g',1,90,365,'Herbs and Spices',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(888,'Season All','g',1,67,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(889,'Hot Curry Powder','g',1,83,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(890,'Peri-Peri Seasoning','g',1,49,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(891,'Ground Cloves','g',1,37,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(892,'Chip Seasoning','g',1,56,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(893,'Asafoetida','g',1,60,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(894,'Middle Eastern Style Spice Mix','g',1,40,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(895,'Ground Mace','g',1,42,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(896,'Caribbean Style Seasoning','g',1,112,365,'Herbs and Spices',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(897,'Garlic Paste','g',1,90,365,'Herbs and Spices',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(898,'Juniper Berries','g',1,28,365,'Herbs and Spices',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(899,'Hot & Smokey Chipotle','g',1,45,365,'Herbs and Spices',320,NULL,NULL,NULL,NULL,NULL,NULL),
+(900,'Red Kidney Beans in Water','g',1,400,365,'Pulses and Beans',49,NULL,NULL,NULL,NULL,NULL,NULL),
+(901,'Chickpeas in Water','g',1,400,365,'Pulses and Beans',49,NULL,NULL,NULL,NULL,NULL,NULL),
+(902,'Cannellini Beans In Water','g',1,400,365,'Pulses and Beans',48,NULL,NULL,NULL,NULL,NULL,NULL),
+(903,'Butter Beans In Water','g',1,400,365,'Pulses and Beans',48,NULL,NULL,NULL,NULL,NULL,NULL),
+(904,'Black Beans in Water','g',1,400,365,'Pulses and Beans',49,NULL,NULL,NULL,NULL,NULL,NULL),
+(905,'Green Lentils In Water','g',1,400,365,'Pulses and Beans',55,NULL,NULL,NULL,NULL,NULL,NULL),
+(906,'Red Kidney Beans in Chilli Sauce','g',1,400,365,'Pulses and Beans',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(907,'Black Beans Carton','g',1,380,365,'Pulses and Beans',105,NULL,NULL,NULL,NULL,NULL,NULL),
+(908,'Mixed Beans in Mild Chilli Sauce','g',1,395,365,'Pulses and Beans',95,NULL,NULL,NULL,NULL,NULL,NULL),
+(909,'Red Kidney Beans in Water','g',1,215,365,'Pulses and Beans',50,NULL,NULL,NULL,NULL,NULL,NULL),
+(910,'Chickpeas','g',1,380,365,'Pulses and Beans',105,NULL,NULL,NULL,NULL,NULL,NULL),
+(911,'Borlotti Beans In Water','g',1,400,365,'Pulses and Beans',54,NULL,NULL,NULL,NULL,NULL,NULL),
+(912,'Quinoa','g',1,300,365,'Pulses and Beans',260,NULL,NULL,NULL,NULL,NULL,NULL),
+(913,'Black Eye Beans Water','g',1,400,365,'Pulses and Beans',59,NULL,NULL,NULL,NULL,NULL,NULL),
+(914,'Red Kidney Beans Carton','g',1,380,365,'Pulses and Beans',105,NULL,NULL,NULL,NULL,NULL,NULL),
+(915,'Mixed Bean Salad','g',1,400,365,'Pulses and Beans',95,NULL,NULL,NULL,NULL,NULL,NULL),
+(916,'Butter Beans Carton','g',1,380,365,'Pulses and Beans',105,NULL,NULL,NULL,NULL,NULL,NULL),
+(917,'Green Lentils','g',1,380,365,'Pulses and Beans',105,NULL,NULL,NULL,NULL,NULL,NULL),
+(918,'Haricot Beans In Water','g',1,400,365,'Pulses and Beans',59,NULL,NULL,NULL,NULL,NULL,NULL),
+(919,'Cracked Bulgur Wheat','g',1,500,365,'Pulses and Beans',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(920,'Dried Green Lentils','g',1,500,365,'Pulses and Beans',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(921,'Chickpeas','g',1,215,365,'Pulses and Beans',50,NULL,NULL,NULL,NULL,NULL,NULL),
+(922,'Cannellini Beans Carton','g',1,380,365,'Pulses and Beans',105,NULL,NULL,NULL,NULL,NULL,NULL),
+(923,'Butter Beans In Water','g',1,215,365,'Pulses and Beans',50,NULL,NULL,NULL,NULL,NULL,NULL),
+(924,'Red Lentils','g',1,1000,365,'Pulses and Beans',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(925,'Dried Red Lentils','g',1,500,365,'Pulses and Beans',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(926,'White Red & Black Quinoa','g',1,300,365,'Pulses and Beans',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(927,'Red Lentils','g',1,500,365,'Pulses and Beans',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(928,'Polenta','g',1,400,365,'Pulses and Beans',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(929,'Dried Yellow Split Peas','g',1,500,365,'Pulses and Beans',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(930,'Lentilles Vertes Bag','g',1,500,365,'Pulses and Beans',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(931,'Dried Pinto Beans Bag','g',1,500,365,'Pulses and Beans',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(932,'Buckwheat','g',1,500,365,'Pulses and Beans',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(933,'Chickpeas','g',1,500,365,'Pulses and Beans',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(934,'Cannellini Beans Bag','g',1,500,365,'Pulses and Beans',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(935,'Green Lentils','g',1,250,365,'Pulses and Beans',145,NULL,NULL,NULL,NULL,NULL,NULL),
+(936,'Haricot Beans','g',1,500,365,'Pulses and Beans',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(937,'Split Green Peas','g',1,500,365,'Pulses and Beans',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(938,'Plain Flour','g',1,1500,365,'Flour',79,NULL,NULL,NULL,NULL,NULL,NULL),
+(939,'Self Raising Flour','g',1,1500,365,'Flour',79,NULL,NULL,NULL,NULL,NULL,NULL),
+(940,'Strong White Bread Flour','g',1,1500,365,'Flour',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(941,'Plain Flour','g',1,500,365,'Flour',45,NULL,NULL,NULL,NULL,NULL,NULL),
+(942,'Cornflour','g',1,500,365,'Flour',245,NULL,NULL,NULL,NULL,NULL,NULL),
+(943,'Fast Action Dried Yeast Sachets',NULL,1,8,365,'Flour',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(944,'Self Raising Flour','g',1,500,365,'Flour',45,NULL,NULL,NULL,NULL,NULL,NULL),
+(945,'Wholegrain Seeded Flour','g',1,1000,365,'Flour',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(946,'Strong Stoneground 100% Wholemeal Flour','g',1,1500,365,'Flour',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(947,'Golden Multiseed Bread Mix','g',1,500,365,'Flour',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(948,'Grade Pasta Flour',NULL,1,0,365,'Flour',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(949,'Strong Brown Flour','g',1,1500,365,'Flour',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(950,'Very Strong Canadian Bread Flour','g',1,1000,365,'Flour',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(951,'Cream of Tartar','g',1,160,365,'Flour',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(952,'Arrowroot','g',1,80,365,'Flour',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(953,'White Sourdough Bread','g',1,500,365,'Flour',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(954,'Sauce Flour','g',1,500,365,'Flour',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(955,'Onion Bhaji Batter Mix','g',1,130,365,'Flour',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(956,'Extra Fine Sponge Flour','g',1,1000,365,'Flour',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(957,'Coarse Sea Salt','g',1,350,365,'Salt',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(958,'Cooking Salt','g',1,1500,365,'Salt',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(959,'Pink Peppercorns','g',1,24,365,'Salt',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(960,'Vegetable Oil','ml',1,1000,365,'Oil',185,NULL,NULL,NULL,NULL,NULL,NULL),
+(961,'Olive Oil Extra Virgin','ml',1,1000,365,'Oil',840,NULL,NULL,NULL,NULL,NULL,NULL),
+(962,'Sunflower Oil','ml',1,1000,365,'Oil',215,NULL,NULL,NULL,NULL,NULL,NULL),
+(963,'Olive Oil','ml',1,1000,365,'Oil',780,NULL,NULL,NULL,NULL,NULL,NULL),
+(964,'Olive Oil','ml',1,500,365,'Oil',575,NULL,NULL,NULL,NULL,NULL,NULL),
+(965,'Sesame Oil Toasted','ml',1,250,365,'Oil',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(966,'Olive Oil Extra Virgin','ml',1,500,365,'Oil',650,NULL,NULL,NULL,NULL,NULL,NULL),
+(967,'Sunflower & Extra Virgin Olive Oil','ml',1,500,365,'Oil',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(968,'Olive Oil Extra Virgin','ml',1,250,365,'Oil',360,NULL,NULL,NULL,NULL,NULL,NULL),
+(969,'Olive Oil Extra Virgin','ml',1,500,365,'Oil',700,NULL,NULL,NULL,NULL,NULL,NULL),
+(970,'Vegetable Oil','ml',1,3000,365,'Oil',520,NULL,NULL,NULL,NULL,NULL,NULL),
+(971,'Olive Oil Extra Virgin','ml',1,2000,365,'Oil',1610,NULL,NULL,NULL,NULL,NULL,NULL),
+(972,'Olive Oil','ml',1,250,365,'Oil',330,NULL,NULL,NULL,NULL,NULL,NULL),
+(973,'Garlic Flavoured Olive Oil','ml',1,250,365,'Oil',350,NULL,NULL,NULL,NULL,NULL,NULL),
+(974,'Virgin Coconut Oil','ml',1,300,365,'Oil',330,NULL,NULL,NULL,NULL,NULL,NULL),
+(975,'Goose Fat','g',1,320,365,'Oil',430,NULL,NULL,NULL,NULL,NULL,NULL),
+(976,'Groundnut Oil','ml',1,500,365,'Oil',270,NULL,NULL,NULL,NULL,NULL,NULL),
+(977,'Mirin','ml',1,150,365,'Oil',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(978,'Olive Oil Cooking Spray','ml',1,190,365,'Oil',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(979,'Chilli Flavoured Olive Oil','ml',1,250,365,'Oil',350,NULL,NULL,NULL,NULL,NULL,NULL),
+(980,'Olive Oil Light In Colour','ml',1,1000,365,'Oil',780,NULL,NULL,NULL,NULL,NULL,NULL),
+(981,'Unfiltered Extra Virgin Olive Oil','ml',1,500,365,'Oil',750,NULL,NULL,NULL,NULL,NULL,NULL),
+(982,'Sunflower Oil','ml',1,1000,365,'Oil',330,NULL,NULL,NULL,NULL,NULL,NULL),
+(983,'Vegetable Oil Spray','ml',1,200,365,'Oil',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(984,'Stir Fry Oil','ml',1,250,365,'Oil',220,NULL,NULL,NULL,NULL,NULL,NULL),
+(985,'Olive Oil','ml',1,2000,365,'Oil',1540,NULL,NULL,NULL,NULL,NULL,NULL),
+(986,'Sunflower Oil','ml',1,3000,365,'Oil',645,NULL,NULL,NULL,NULL,NULL,NULL),
+(987,'Cold Pressed Rapeseed Oil','ml',1,500,365,'Oil',350,NULL,NULL,NULL,NULL,NULL,NULL),
+(988,'Olive Oil Light Colour','ml',1,500,365,'Oil',575,NULL,NULL,NULL,NULL,NULL,NULL),
+(989,'Greek Kalamata Extra Virgin Olive Oil','ml',1,500,365,'Oil',840,NULL,NULL,NULL,NULL,NULL,NULL),
+(990,'Duck Fat','g',1,320,365,'Oil',430,NULL,NULL,NULL,NULL,NULL,NULL),
+(991,'Walnut Oil','ml',1,250,365,'Oil',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(992,'Beef Dripping','g',1,320,365,'Oil',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(993,'Sunflower Oil Cooking Spray','ml',1,190,365,'Oil',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(994,'Vegetable Oil Cooking Spray','ml',1,190,365,'Oil',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(995,'Greek Olive Oil Extra Virgin','ml',1,500,365,'Oil',770,NULL,NULL,NULL,NULL,NULL,NULL),
+(996,'Olive Oil Extra Virgin','ml',1,500,365,'Oil',770,NULL,NULL,NULL,NULL,NULL,NULL),
+(997,'Grapeseed Oil','ml',1,500,365,'Oil',410,NULL,NULL,NULL,NULL,NULL,NULL),
+(998,'Corn Oil','ml',1,1000,365,'Oil',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(999,'Toscano Extra Virgin Olive Oil','ml',1,500,365,'Oil',995,NULL,NULL,NULL,NULL,NULL,NULL),
+(1000,'Basmati Rice','g',1,1000,365,'Rice',185,NULL,NULL,NULL,NULL,NULL,NULL),
+(1001,'Arborio Risotto Rice','g',1,500,365,'Rice',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(1002,'Easy Cook Long Grain White Rice','g',1,1000,365,'Rice',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(1003,'Basmati Rice','g',1,500,365,'Rice',92,NULL,NULL,NULL,NULL,NULL,NULL),
+(1004,'Boil In The Bag White Rice 4x125g',NULL,1,1,365,'Rice',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(1005,'Spanish Paella Rice','g',1,500,365,'Rice',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(1006,'Brown Basmati Rice','g',1,1000,365,'Rice',185,NULL,NULL,NULL,NULL,NULL,NULL),
+(1007,'Basmati Rice','g',1,2000,365,'Rice',365,NULL,NULL,NULL,NULL,NULL,NULL),
+(1008,'Brown Rice','g',1,500,365,'Rice',69,NULL,NULL,NULL,NULL,NULL,NULL),
+(1009,'Easy Cook Long Grain White Rice','g',1,500,365,'Rice',63,NULL,NULL,NULL,NULL,NULL,NULL),
+(1010,'Easy Cook Long Grain White Rice','g',1,2000,365,'Rice',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(1011,'Boil In The Bag Basmati Rice 4x125g',NULL,1,1,365,'Rice',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(1012,'Savoury Rice Golden Vegetable','g',1,105,365,'Rice',60,NULL,NULL,NULL,NULL,NULL,NULL),
+(1013,'Thai Fragrant Rice','g',1,500,365,'Rice',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(1014,'Basmati & Wild Rice','g',1,500,365,'Rice',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(1015,'Brown Rice','g',1,500,365,'Rice',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(1016,'Long Grain Rice','g',1,1000,365,'Rice',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(1017,'Basmati Rice','g',1,4000,365,'Rice',735,NULL,NULL,NULL,NULL,NULL,NULL),
+(1018,'Boil In The Bag Brown Rice 4x125g',NULL,1,1,365,'Rice',115,NULL,NULL,NULL,NULL,NULL,NULL),
+(1019,'Basmati Rice','g',1,500,365,'Rice',325,NULL,NULL,NULL,NULL,NULL,NULL),
+(1020,'Thai Sticky Rice','g',1,500,365,'Rice',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(1021,'Boil In Bag Thai Fragrant Rice 4x125g',NULL,1,1,365,'Rice',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(1022,'Arborio Risotto Rice','g',1,500,365,'Rice',350,NULL,NULL,NULL,NULL,NULL,NULL),
+(1023,'Pink Lady Apple & Honey Herb Stuffing','g',1,110,365,'Rice',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(1024,'Thai Black Rice','g',1,500,365,'Rice',390,NULL,NULL,NULL,NULL,NULL,NULL),
+(1025,'Carnaroli Rice','g',1,500,365,'Rice',320,NULL,NULL,NULL,NULL,NULL,NULL),
+(1026,'Long Grain Rice','g',1,2000,365,'Rice',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(1027,'Fusilli','g',1,1000,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1028,'Rigatoni','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1029,'Spaghetti','g',1,500,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1030,'Penne','g',1,1000,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1031,'Wholewheat Fusilli','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1032,'Spaghetti Wholewheat','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1033,'Penne Rigate','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1034,'Linguine','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1035,'Fusilli','g',1,500,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1036,'Spirali','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1037,'Macaroni','g',1,1000,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1038,'Lasagne Sheets','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1039,'Orzo','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1040,'Wholewheat Penne','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1041,'Spaghetti','g',1,1000,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1042,'Tagliatelle','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1043,'Fusilli','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1044,'Penne','g',1,500,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1045,'Fusilli Tricolori','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1046,'Wholewheat Fusilli','g',1,1000,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1047,'Farfalle','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1048,'Spaghetti','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1049,'Wholewheat Tagliatelle','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1050,'Wholewheat Lasagne Sheets','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1051,'Wholewheat Fusilli','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1052,'Casarecce','g',1,500,365,'Pasta',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1053,'Spelt Fusilli','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1054,'Stars Pasta','g',1,500,365,'Pasta',72,NULL,NULL,NULL,NULL,NULL,NULL),
+(1055,'Spaghetti','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1056,'Bucatini Spaghetti','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1057,'Green Pea Fusilli','g',1,250,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1058,'Lasagne Sheets','g',1,250,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1059,'Wholegrain Spelt Spaghetti','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1060,'Penne','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1061,'Buckwheat Penne','g',1,250,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1062,'Cannelloni','g',1,250,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1063,'Spinach & Ricotta Girasoli','g',1,250,365,'Pasta',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(1064,'Conchiglioni','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1065,'Mafalda Corta','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1066,'Casarecce','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1067,'Linguine','g',1,400,365,'Pasta',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1068,'Wholewheat Spaghetti','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1069,'Linguine','g',1,500,365,'Pasta',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1070,'Medium Noodles','g',1,250,365,'Noodles',115,NULL,NULL,NULL,NULL,NULL,NULL),
+(1071,'Udon Noodles Quick To Cook',NULL,1,2,365,'Noodles',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(1072,'Rice Noodles','g',1,250,365,'Noodles',115,NULL,NULL,NULL,NULL,NULL,NULL),
+(1073,'Wholewheat Medium Noodles','g',1,250,365,'Noodles',115,NULL,NULL,NULL,NULL,NULL,NULL),
+(1074,'Fine Egg Noodles','g',1,250,365,'Noodles',115,NULL,NULL,NULL,NULL,NULL,NULL),
+(1075,'Medium Noodles Quick To Cook',NULL,1,2,365,'Noodles',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(1076,'Pad Thai Ribbon Noodles Quick To Cook','g',1,300,365,'Noodles',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(1077,'Stock Cubes Vegetable 10x10g',NULL,1,1,365,'Stock',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(1078,'Stock Cubes Chicken 10x10g',NULL,1,1,365,'Stock',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(1079,'Stock Cubes Beef 10x10g',NULL,1,1,365,'Stock',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(1080,'Chicken Stock','ml',1,500,365,'Stock',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(1081,'Beef Stock','ml',1,500,365,'Stock',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(1082,'Parsley Sauce','g',1,200,365,'Stock',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(1083,'Vegetable Stock','ml',1,500,365,'Stock',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(1084,'Prawn Cocktail Sauce','g',1,200,365,'Stock',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(1085,'Double Cream','ml',1,300,365,'Baking Essentials',99,NULL,NULL,NULL,NULL,NULL,NULL),
+(1086,'White Granulated Sugar','g',1,1000,365,'Baking Essentials',109,NULL,NULL,NULL,NULL,NULL,NULL),
+(1087,'Double Cream','ml',1,600,365,'Baking Essentials',195,NULL,NULL,NULL,NULL,NULL,NULL),
+(1088,'Single Cream','ml',1,300,365,'Baking Essentials',99,NULL,NULL,NULL,NULL,NULL,NULL),
+(1089,'White Caster Sugar','g',1,1000,365,'Baking Essentials',149,NULL,NULL,NULL,NULL,NULL,NULL),
+(1090,'Raisins','g',1,500,365,'Baking Essentials',185,NULL,NULL,NULL,NULL,NULL,NULL),
+(1091,'Milk Chocolate Chips','g',1,100,365,'Baking Essentials',90,NULL,NULL,NULL,NULL,NULL,NULL),
+(1092,'Strawberry Jam','g',1,454,365,'Baking Essentials',89,NULL,NULL,NULL,NULL,NULL,NULL),
+(1093,'White Granulated Sugar','g',1,1000,365,'Baking Essentials',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(1094,'Mixed Nuts','g',1,300,365,'Baking Essentials',410,NULL,NULL,NULL,NULL,NULL,NULL),
+(1095,'Dark Chocolate Chips','g',1,100,365,'Baking Essentials',90,NULL,NULL,NULL,NULL,NULL,NULL),
+(1096,'Whole Almonds','g',1,300,365,'Baking Essentials',375,NULL,NULL,NULL,NULL,NULL,NULL),
+(1097,'Oats','g',1,450,365,'Baking Essentials',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(1098,'Shelled Walnuts','g',1,300,365,'Baking Essentials',430,NULL,NULL,NULL,NULL,NULL,NULL),
+(1099,'Mixed Nut Pack','g',1,200,365,'Baking Essentials',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(1100,'Dried Apricots','g',1,500,365,'Baking Essentials',395,NULL,NULL,NULL,NULL,NULL,NULL),
+(1101,'Icing Sugar','g',1,1000,365,'Baking Essentials',255,NULL,NULL,NULL,NULL,NULL,NULL),
+(1102,'Light Soft Brown Sugar','g',1,500,365,'Baking Essentials',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(1103,'Baking Powder','g',1,160,365,'Baking Essentials',61,NULL,NULL,NULL,NULL,NULL,NULL),
+(1104,'Buttermilk','ml',1,300,365,'Baking Essentials',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(1105,'Demerara Cane Sugar','g',1,500,365,'Baking Essentials',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(1106,'White Caster Sugar','g',1,1000,365,'Baking Essentials',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(1107,'Light Brown Soft Sugar','g',1,1000,365,'Baking Essentials',345,NULL,NULL,NULL,NULL,NULL,NULL),
+(1108,'Madagascan Vanilla Extract','ml',1,38,365,'Baking Essentials',230,NULL,NULL,NULL,NULL,NULL,NULL),
+(1109,'Bicarbonate Of Soda','g',1,180,365,'Baking Essentials',59,NULL,NULL,NULL,NULL,NULL,NULL),
+(1110,'Demerara Cane Sugar','g',1,1000,365,'Baking Essentials',245,NULL,NULL,NULL,NULL,NULL,NULL),
+(1111,'Golden Caster Sugar','g',1,1000,365,'Baking Essentials',260,NULL,NULL,NULL,NULL,NULL,NULL),
+(1112,'White Granulated Sugar','g',1,5000,365,'Baking Essentials',500,NULL,NULL,NULL,NULL,NULL,NULL),
+(1113,'Meringue Nests','g',1,108,365,'Baking Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1114,'Dark Soft Brown Sugar','g',1,500,365,'Baking Essentials',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(1115,'Mixed Dried Fruit','g',1,500,365,'Baking Essentials',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(1116,'Cornflakes','g',1,300,365,'Baking Essentials',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(1117,'Compote Morello Cherry','g',1,400,365,'Baking Essentials',285,NULL,NULL,NULL,NULL,NULL,NULL),
+(1118,'Plain Cupcake Cases',NULL,1,75,365,'Baking Essentials',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(1119,'Belgian Dark 85% Cocoa Cooking Chocolate','g',1,100,365,'Baking Essentials',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(1120,'Raisins','g',1,500,365,'Baking Essentials',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(1121,'Plain Cake Cases',NULL,1,100,365,'Baking Essentials',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(1122,'Rice Pops','g',1,300,365,'Baking Essentials',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(1123,'Moroccan Almond Extract','ml',1,38,365,'Baking Essentials',195,NULL,NULL,NULL,NULL,NULL,NULL),
+(1124,'Golden Marzipan','g',1,454,365,'Baking Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1125,'Valencian Orange Oil Extract','ml',1,38,365,'Baking Essentials',195,NULL,NULL,NULL,NULL,NULL,NULL),
+(1126,'Cut Mixed Peel','g',1,200,365,'Baking Essentials',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(1127,'American Peppermint Extract','ml',1,38,365,'Baking Essentials',195,NULL,NULL,NULL,NULL,NULL,NULL),
+(1128,'Belgian Dark Chips','g',1,150,365,'Baking Essentials',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(1129,'Belgian Milk Chips','g',1,150,365,'Baking Essentials',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(1130,'Raspberry Coulis','g',1,250,365,'Baking Essentials',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(1131,'Sicilian Lemon Oil Extract','ml',1,38,365,'Baking Essentials',195,NULL,NULL,NULL,NULL,NULL,NULL),
+(1132,'White Marzipan','g',1,454,365,'Baking Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1133,'Salted Caramel Sauce','g',1,260,365,'Baking Essentials',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(1134,'Savoiardi Sponge Fingers','g',1,200,365,'Baking Essentials',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(1135,'Mango & Passionfruit Coulis','g',1,250,365,'Baking Essentials',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(1136,'Meringues',NULL,1,12,365,'Baking Essentials',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1137,'Madagascan Vanilla Pods',NULL,1,2,365,'Baking Essentials',600,NULL,NULL,NULL,NULL,NULL,NULL),
+(1138,'Morello Cherries','g',1,125,365,'Baking Essentials',450,NULL,NULL,NULL,NULL,NULL,NULL),
+(1139,'Silver Crispy Pearls','g',1,55,365,'Baking Essentials',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(1140,'Writing Icing Colours 4x19g',NULL,1,1,365,'Baking Essentials',235,NULL,NULL,NULL,NULL,NULL,NULL),
+(1141,'Tomato Puree Double Concentrate','g',1,200,365,'Packaged Tomatoes',59,NULL,NULL,NULL,NULL,NULL,NULL),
+(1142,'Passata','g',1,500,365,'Packaged Tomatoes',55,NULL,NULL,NULL,NULL,NULL,NULL),
+(1143,'Chopped Tomatoes Can','g',1,400,365,'Packaged Tomatoes',49,NULL,NULL,NULL,NULL,NULL,NULL),
+(1144,'Chopped Tomatoes','g',1,227,365,'Packaged Tomatoes',40,NULL,NULL,NULL,NULL,NULL,NULL),
+(1145,'Plum Tomatoes','g',1,400,365,'Packaged Tomatoes',49,NULL,NULL,NULL,NULL,NULL,NULL),
+(1146,'Chopped Tomatoes in Tomato Juice','g',1,400,365,'Packaged Tomatoes',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(1147,'Passata','g',1,690,365,'Packaged Tomatoes',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(1148,'Tomato Purée Double Concentrate','g',1,142,365,'Packaged Tomatoes',55,NULL,NULL,NULL,NULL,NULL,NULL),
+(1149,'Marinated Sundried Tomatoes','g',1,140,365,'Packaged Tomatoes',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(1150,'Peeled Plum Tomatoes','g',1,227,365,'Packaged Tomatoes',40,NULL,NULL,NULL,NULL,NULL,NULL),
+(1151,'Tomato Puree Double Concentrated','g',1,312,365,'Packaged Tomatoes',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(1152,'Tomato Paste','g',1,150,365,'Packaged Tomatoes',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(1153,'Tomato Puree','g',1,312,365,'Packaged Tomatoes',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(1154,'Rustica Passata','g',1,430,365,'Packaged Tomatoes',160,NULL,NULL,NULL,NULL,NULL,NULL),
+(1155,'Plum Tomatoes in Tomato Juice','g',1,400,365,'Packaged Tomatoes',110,NULL,NULL,NULL,NULL,NULL,NULL),
+(1156,'Water','ml',1,1,365,NULL,NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1157,'Unsmoked Thick Cut Back Bacon Rashers',NULL,1,6,7,'Meat and Fish Essentials',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(1158,'Smoked Air Dried Streaky Bacon Rashers',NULL,1,14,7,'Meat and Fish Essentials',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(1159,'Smoked Thick Cut Back Bacon Rashers',NULL,1,6,7,'Meat and Fish Essentials',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(1160,'Smoked Air Dried Dry Cured Back Bacon Rashers',NULL,1,6,7,'Meat and Fish Essentials',350,NULL,NULL,NULL,NULL,NULL,NULL),
+(1161,'Smoked Streaky Bacon Rashers',NULL,1,14,7,'Meat and Fish Essentials',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(1162,'Unsmoked Bacon Medallions Be Good to Yourself',NULL,1,8,7,'Meat and Fish Essentials',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(1163,'Unsmoked Air Dried Dry Cured Back Bacon Rashers',NULL,1,6,7,'Meat and Fish Essentials',350,NULL,NULL,NULL,NULL,NULL,NULL),
+(1164,'Unsmoked Air Dried Streaky Bacon Rashers',NULL,1,14,7,'Meat and Fish Essentials',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(1165,'Unsmoked Back Bacon Rashers 2x250g',NULL,1,16,7,'Meat and Fish Essentials',350,NULL,NULL,NULL,NULL,NULL,NULL),
+(1166,'Unsmoked Streaky Bacon Rashers',NULL,1,14,7,'Meat and Fish Essentials',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(1167,'Unsmoked Air Dried Wiltshire Cured Back Bacon Rashers',NULL,1,8,7,'Meat and Fish Essentials',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(1168,'West Country Lemon Sole','g',1,291,7,'Meat and Fish Essentials',NULL,NULL,NULL,NULL,NULL,NULL,NULL),
+(1169,'Lighter Cypriot Halloumi Cheese','g',1,225,7,'Cheese',230,NULL,NULL,NULL,NULL,NULL,NULL),
+(1170,'Beechwood Smoked Cheese','g',1,150,7,'Cheese',215,NULL,NULL,NULL,NULL,NULL,NULL),
+(1171,'Greek Style Salad Feta Cheese Be Good to Yourself','g',1,200,7,'Cheese',180,NULL,NULL,NULL,NULL,NULL,NULL),
+(1172,'Chilli Halloumi Sticks','g',1,200,7,'Cheese',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1173,'Coconut Based Alternative to Soft Cheese','g',1,170,7,'Cheese',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(1174,'Grated Cheddar Style','g',1,200,7,'Cheese',260,NULL,NULL,NULL,NULL,NULL,NULL),
+(1175,'Triple Cheese Burger Melts','g',1,100,7,'Cheese',116,NULL,NULL,NULL,NULL,NULL,NULL),
+(1176,'Blue Stilton Burger Melts Summer Edition','g',1,100,7,'Cheese',116,NULL,NULL,NULL,NULL,NULL,NULL),
+(1177,'Cheddar & Harissa Burger Melts','g',1,100,7,'Cheese',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(1178,'Cheddar & Truffle Burger Melts','g',1,100,7,'Cheese',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(1179,'Cheddar Style','g',1,200,7,'Cheese',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(1180,'Nacho Cheese Bake Summer Edition','g',1,150,7,'Cheese',400,NULL,NULL,NULL,NULL,NULL,NULL),
+(1181,'Mediterranean Halloumi Sticks','g',1,200,7,'Cheese',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1182,'Brie De Meaux','g',1,175,7,'Cheese',375,NULL,NULL,NULL,NULL,NULL,NULL),
+(1183,'Ash Goats Log','g',1,100,7,'Cheese',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(1184,'Chipotle Chilli Cheddar Slices','g',1,110,7,'Cheese',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(1185,'Free Range Eggs Extra Large',NULL,1,6,14,'Eggs',210,NULL,NULL,NULL,NULL,NULL,NULL),
+(1186,'Satsumas','g',1,600,7,'Fresh Fruit',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(1187,'Sweet Blackberries','g',1,150,7,'Fresh Fruit',220,NULL,NULL,NULL,NULL,NULL,NULL),
+(1188,'Mango','g',1,450,7,'Fresh Fruit',400,NULL,NULL,NULL,NULL,NULL,NULL),
+(1189,'Red Candy Grapes',NULL,1,1,7,'Fresh Fruit',240,NULL,NULL,NULL,NULL,NULL,NULL),
+(1190,'Peach Punnet',NULL,1,1,7,'Fresh Fruit',220,NULL,NULL,NULL,NULL,NULL,NULL),
+(1191,'Melon','g',1,500,7,'Fresh Fruit',320,NULL,NULL,NULL,NULL,NULL,NULL),
+(1192,'Rocha & Packham Pears',NULL,1,5,7,'Fresh Fruit',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(1193,'Sharon Fruit',NULL,1,1,7,'Fresh Fruit',50,NULL,NULL,NULL,NULL,NULL,NULL),
+(1194,'Mango Pineapple & Passion Fruit','g',1,250,7,'Fresh Fruit',260,NULL,NULL,NULL,NULL,NULL,NULL),
+(1195,'Pineapple & Mango','g',1,160,7,'Fresh Fruit',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(1196,'Seeded Grapes','g',1,700,7,'Fresh Fruit',275,NULL,NULL,NULL,NULL,NULL,NULL),
+(1197,'Opal Apples',NULL,1,4,7,'Fresh Fruit',250,NULL,NULL,NULL,NULL,NULL,NULL),
+(1198,'Summer Fruit Bowl','g',1,450,7,'Fresh Fruit',375,NULL,NULL,NULL,NULL,NULL,NULL),
+(1199,'Baby Cucumbers','g',1,200,7,'Fresh Vegetables',95,NULL,NULL,NULL,NULL,NULL,NULL),
+(1200,'Jersey Royal Baby New Potatoes','g',1,450,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1201,'Small Whole Cucumber',NULL,1,1,7,'Fresh Vegetables',60,NULL,NULL,NULL,NULL,NULL,NULL),
+(1202,'Jersey Royal New Potatoes',NULL,1,1,7,'Fresh Vegetables',300,NULL,NULL,NULL,NULL,NULL,NULL),
+(1203,'Butternut Squash Crinkle Chips','g',1,300,7,'Fresh Vegetables',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(1204,'Asparagus Fine','g',1,100,7,'Fresh Vegetables',175,NULL,NULL,NULL,NULL,NULL,NULL),
+(1205,'Spanish Inspired Potatoes Summer Edition','g',1,340,7,'Fresh Vegetables',285,NULL,NULL,NULL,NULL,NULL,NULL),
+(1206,'Rocket Chillies',NULL,1,1,7,'Fresh Vegetables',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(1207,'Golden Cherry Vine Tomatoes','g',1,250,7,'Fresh Vegetables',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1208,'On The Vine Tomatoes','g',1,250,7,'Fresh Vegetables',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(1209,'Garden Mint',NULL,1,1,7,'Fresh Herbs',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(1210,'Lemon Thyme',NULL,1,1,7,'Fresh Herbs',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(1211,'White Sourdough Bloomer','g',1,400,7,'Bread',230,NULL,NULL,NULL,NULL,NULL,NULL),
+(1212,'Seeded Sliced Bread','g',1,535,7,'Bread',190,NULL,NULL,NULL,NULL,NULL,NULL),
+(1213,'Walnut Pave',NULL,1,1,7,'Bread',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(1214,'Mixed Fruit Bloomer','g',1,350,7,'Bread',225,NULL,NULL,NULL,NULL,NULL,NULL),
+(1215,'Cheddar Chive & Black Pepper Flour Bread','g',1,350,7,'Bread',230,NULL,NULL,NULL,NULL,NULL,NULL),
+(1216,'Tahini','g',1,300,365,'Herbs and Spices',260,NULL,NULL,NULL,NULL,NULL,NULL),
+(1217,'Gochujang','g',1,90,365,'Herbs and Spices',155,NULL,NULL,NULL,NULL,NULL,NULL),
+(1218,'Tikka Curry Powder','g',1,80,365,'Herbs and Spices',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(1219,'Fine Sea Salt','g',1,350,365,'Salt',120,NULL,NULL,NULL,NULL,NULL,NULL),
+(1220,'Rock Salt','g',1,350,365,'Salt',125,NULL,NULL,NULL,NULL,NULL,NULL),
+(1221,'Reduced Sodium Salt','g',1,350,365,'Salt',130,NULL,NULL,NULL,NULL,NULL,NULL),
+(1222,'Extra Virgin Olive Oil Spray','ml',1,200,365,'Oil',245,NULL,NULL,NULL,NULL,NULL,NULL),
+(1223,'Brown Rice','g',1,1000,365,'Rice',139,NULL,NULL,NULL,NULL,NULL,NULL),
+(1224,'Wholegrain & Wild Rice','g',1,250,365,'Rice',100,NULL,NULL,NULL,NULL,NULL,NULL),
+(1225,'Fusilli Pasta','g',1,1000,365,'Pasta',129,NULL,NULL,NULL,NULL,NULL,NULL),
+(1226,'Spaghetti Pasta','g',1,500,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1227,'Penne Rigate Pasta','g',1,500,365,'Pasta',69,NULL,NULL,NULL,NULL,NULL,NULL),
+(1228,'Penne Pasta','g',1,1000,365,'Pasta',138,NULL,NULL,NULL,NULL,NULL,NULL),
+(1229,'Linguine Pasta','g',1,500,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1230,'Spaghetti Wholewheat Pasta','g',1,500,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1231,'Fusilli Pasta','g',1,500,365,'Pasta',65,NULL,NULL,NULL,NULL,NULL,NULL),
+(1232,'Wholewheat Fusilli Pasta','g',1,500,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1233,'Orzo Pasta','g',1,500,365,'Pasta',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1234,'Spirali Pasta','g',1,500,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1235,'Wholewheat Penne Pasta','g',1,500,365,'Pasta',69,NULL,NULL,NULL,NULL,NULL,NULL),
+(1236,'Conchiglie Shells Pasta','g',1,500,365,'Pasta',69,NULL,NULL,NULL,NULL,NULL,NULL),
+(1237,'Spaghetti Pasta','g',1,1000,365,'Pasta',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(1238,'Macaroni Pasta','g',1,1000,365,'Pasta',144,NULL,NULL,NULL,NULL,NULL,NULL),
+(1239,'Macaroni Pasta','g',1,500,365,'Pasta',72,NULL,NULL,NULL,NULL,NULL,NULL),
+(1240,'Tagliatelle Pasta','g',1,500,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1241,'Lasagne Sheets Pasta','g',1,500,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1242,'Farfalle Pasta','g',1,500,365,'Pasta',69,NULL,NULL,NULL,NULL,NULL,NULL),
+(1243,'Wholewheat Fusilli Pasta','g',1,1000,365,'Pasta',150,NULL,NULL,NULL,NULL,NULL,NULL),
+(1244,'Red Lentil Penne Pasta','g',1,250,365,'Pasta',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(1245,'Wholewheat Tagliatelle Pasta','g',1,500,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1246,'Pappardelle Pasta','g',1,500,365,'Pasta',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1247,'Casarecce Pasta','g',1,500,365,'Pasta',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1248,'Wholewheat Fusilli Pasta','g',1,500,365,'Pasta',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(1249,'Wholewheat Lasagne Sheets Pasta','g',1,500,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1250,'Spaghetti Pasta','g',1,500,365,'Pasta',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(1251,'Penne Pasta','g',1,500,365,'Pasta',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(1252,'Bucatini Spaghetti Pasta','g',1,500,365,'Pasta',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1253,'Buckwheat Penne Pasta','g',1,250,365,'Pasta',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(1254,'Wholegrain Spelt Spaghetti Pasta','g',1,500,365,'Pasta',165,NULL,NULL,NULL,NULL,NULL,NULL),
+(1255,'Green Pea Fusilli Pasta','g',1,250,365,'Pasta',170,NULL,NULL,NULL,NULL,NULL,NULL),
+(1256,'Spelt Fusilli Pasta','g',1,500,365,'Pasta',165,NULL,NULL,NULL,NULL,NULL,NULL),
+(1257,'Mafalda Corta Pasta','g',1,500,365,'Pasta',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1258,'Cannelloni Pasta','g',1,250,365,'Pasta',37,NULL,NULL,NULL,NULL,NULL,NULL),
+(1259,'Vermicelli Pasta','g',1,500,365,'Pasta',75,NULL,NULL,NULL,NULL,NULL,NULL),
+(1260,'High Fibre White Macaroni Pasta','g',1,500,365,'Pasta',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(1261,'Conchiglioni Pasta','g',1,500,365,'Pasta',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1262,'Mezzi Rigatoni Pasta','g',1,500,365,'Pasta',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1263,'Added Fibre Fusilli Pasta','g',1,500,365,'Pasta',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(1264,'Linguine Pasta','g',1,500,365,'Pasta',200,NULL,NULL,NULL,NULL,NULL,NULL),
+(1265,'Wholewheat Spaghetti Pasta','g',1,500,365,'Pasta',135,NULL,NULL,NULL,NULL,NULL,NULL),
+(1266,'Plant Pioneers Thai Style Spicy Coconut Noodles','g',1,72,365,'Noodles',130,NULL,NULL,NULL,NULL,NULL,NULL)
+ON CONFLICT (id) DO UPDATE SET name = EXCLUDED.name, unit = EXCLUDED.unit, minimum_quantity = EXCLUDED.minimum_quantity, purchase_quantity = EXCLUDED.purchase_quantity, life = EXCLUDED.life, category = EXCLUDED.category, price = EXCLUDED.price, energy = EXCLUDED.energy, protein = EXCLUDED.protein, fat = EXCLUDED.fat, carbohydrate = EXCLUDED.carbohydrate, fibre = EXCLUDED.fibre, salt = EXCLUDED.salt
+WHERE ingredient.user_id IS NULL;
+ALTER SEQUENCE ingredient_id_seq RESTART WITH 1267;
\ No newline at end of file
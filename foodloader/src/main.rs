@@ -1,17 +1,20 @@
-use std::{env, error, iter};
+use std::{env, error};
 
 mod loader;
 mod scraper;
 
 const LOAD_ONLY: &str = "--load-only";
 const SCRAPE_ONLY: &str = "--scrape-only";
+const REPROCESS: &str = "--reprocess";
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn error::Error>> {
     print_bordered("foodloader v0.1");
     let args: Vec<String> = env::args().collect();
 
-    if !args.contains(&String::from(LOAD_ONLY)) {
+    if args.contains(&String::from(REPROCESS)) {
+        scraper::reprocess()?;
+    } else if !args.contains(&String::from(LOAD_ONLY)) {
         scraper::scrape().await?;
     }
     if !args.contains(&String::from(SCRAPE_ONLY)) {
@@ -23,7 +26,7 @@ async fn main() -> Result<(), Box<dyn error::Error>> {
 
 fn print_bordered(string: &str) {
     let len = string.len();
-    let frame = format!("+{}+", iter::repeat("-").take(len + 2).collect::<String>());
+    let frame = format!("+{}+", "-".repeat(len + 2));
     let contents = format!("| {} |", string);
     println!("{}", frame);
     println!("{}", contents);
@@ -3,7 +3,12 @@ use regex::Regex;
 use reqwest::{header, Client};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::{error::Error, fs::File, io::Write, u64::MAX};
+use std::{
+    error::Error,
+    fs::{self, File},
+    io::{ErrorKind, Write},
+    sync::OnceLock,
+};
 
 pub const INGREDIENTS_FILE: &str = "data/ingredients.json";
 
@@ -24,6 +29,27 @@ pub struct Ingredient {
     pub minimum_quantity: f64,
     pub purchase_quantity: f64,
     pub life: i32,
+    /// The category the ingredient was scraped from, e.g. "Fresh Vegetables".
+    #[serde(default)]
+    pub category: Option<String>,
+    /// The retail price of one purchase quantity, in pounds.
+    #[serde(default)]
+    pub price: Option<f64>,
+    /// Nutrition per 100g/ml, where the product lists it.
+    #[serde(default)]
+    pub nutrition: Option<Nutrition>,
+}
+
+/// Nutrition per 100g/ml, as printed on the product's nutrition table.
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Nutrition {
+    /// Energy in kcal
+    pub energy: f64,
+    pub protein: f64,
+    pub fat: f64,
+    pub carbohydrate: f64,
+    pub fibre: f64,
+    pub salt: f64,
 }
 
 // Currently restricted to Sainsbury's own brand items.
@@ -38,53 +64,64 @@ fn build_api_request(category_id: String, page_number: u64) -> String {
     )
 }
 
+// Finds a single product from the last segment of its URL, e.g. "sainsburys-butter-salted-250g".
+fn build_details_request(seo_url: &str) -> String {
+    format!(
+        "https://www.sainsburys.co.uk/groceries-api/gol-services/product/v1\
+        /product?filter[product_seo_url]=gb%2Fgroceries%2F{seo_url}&include[ASSOCIATIONS]=true"
+    )
+}
+
+/// The categories to scrape (label, id, lifetime).
+/// Order is important, as we want to make sure lifetime estimates are as accurate as possible -
+/// if an ingredient appears in 'Milk' and 'Baking', we take the first category which has a shorter life.
+const CATEGORIES: [(&str, &str, i32); 21] = [
+    ("Butter", "428877", 60),
+    ("Milk", "428869", 7),
+    ("Meat and Fish Essentials", "474595", 7),
+    ("Cheese", "428899", 7),
+    ("Eggs", "428936", 14),
+    ("Dairy Free", "428940", 7),
+    ("Fresh Fruit", "12545", 7),
+    ("Fresh Vegetables", "13237", 7),
+    ("Fresh Herbs", "258774", 7),
+    ("Bread", "272787", 7),
+    ("Herbs and Spices", "364401", 365),
+    ("Pulses and Beans", "13263", 365),
+    ("Flour", "514859", 365),
+    ("Salt", "13153", 365),
+    ("Oil", "267545", 365),
+    ("Rice", "243754", 365),
+    ("Pasta", "276023", 365),
+    ("Noodles", "12595", 365),
+    ("Stock", "13158", 365),
+    ("Baking Essentials", "318452", 365),
+    ("Packaged Tomatoes", "13254", 365),
+];
+
+/// Tracks the ingredients found so far, so products seen in more than one category are only kept once.
+#[derive(Default)]
+struct Processed {
+    ingredients: Vec<Ingredient>,
+    seen_uids: Vec<String>,
+}
+
 /// Scrapes Sainsbury's internal product search API for each ingredient in a given dataset.
 pub async fn scrape() -> Result<(), Box<dyn Error>> {
     let client = build_client()?;
 
-    // The category IDs to scrape (label id lifetime).
-    // Order is important, as we want to make sure lifetime estimates are as accurate as possible -
-    // if an ingredient appears in 'Milk' and 'Baking', we take the first category which has a shorter life.
-    let category_ids = vec![
-        ("Butter", "428877", 60),
-        ("Milk", "428869", 7),
-        ("Meat and Fish Essentials", "474595", 7),
-        ("Cheese", "428899", 7),
-        ("Eggs", "428936", 14),
-        ("Dairy Free", "428940", 7),
-        ("Fresh Fruit", "12545", 7),
-        ("Fresh Vegetables", "13237", 7),
-        ("Fresh Herbs", "258774", 7),
-        ("Bread", "272787", 7),
-        ("Herbs and Spices", "364401", 365),
-        ("Pulses and Beans", "13263", 365),
-        ("Flour", "514859", 365),
-        ("Salt", "13153", 365),
-        ("Oil", "267545", 365),
-        ("Rice", "243754", 365),
-        ("Pasta", "276023", 365),
-        ("Noodles", "12595", 365),
-        ("Stock", "13158", 365),
-        ("Baking Essentials", "318452", 365),
-        ("Packaged Tomatoes", "13254", 365),
-    ];
-
-    let mut ingredients: Vec<Ingredient> = vec![];
-    let mut current_id = 1;
+    let mut processed = Processed::default();
 
     // Record responses to disk - might as well, and will have a contingency if the API is shut down.
     let mut responses: Vec<serde_json::Value> = vec![];
 
-    // To avoid duplicate products
-    let mut seen_uids: Vec<String> = vec![];
-
     let mut rng = rand::thread_rng();
 
-    for (label, id, life) in category_ids {
+    for (label, id, life) in CATEGORIES {
         println!("\n-> Scraping \"{}\"", label);
 
         let mut page: u64 = 1;
-        let mut max_page: u64 = MAX;
+        let mut max_page: u64 = u64::MAX;
 
         while page <= max_page {
             println!("-> Page {}", page);
@@ -93,7 +130,7 @@ pub async fn scrape() -> Result<(), Box<dyn Error>> {
             page += 1;
 
             // Get all Sainsbury's own brand foods for each category
-            let response = client
+            let mut response = client
                 .get(url)
                 .send()
                 .await?
@@ -101,20 +138,12 @@ pub async fn scrape() -> Result<(), Box<dyn Error>> {
                 .await?;
 
             // Pagination discovered in first request
-            if let Some(pages) = response
-                .get("controls")
-                .and_then(|a| a.get("page"))
-                .and_then(|a| a.get("last"))
-                .and_then(|a| a.as_number())
-                .and_then(|a| a.as_u64())
-            {
-                max_page = pages;
-            } else {
-                max_page = 1;
-            }
+            max_page = last_page(&response).unwrap_or(1);
+
+            add_details(&client, &mut response).await?;
+
             if let Some(products) = response.get("products").and_then(|a| a.as_array()) {
-                let ingredients_initial_len = ingredients.len();
-                if products.len() == 0 {
+                if products.is_empty() {
                     println!("   ✗ Nothing returned");
                 }
 
@@ -131,38 +160,15 @@ pub async fn scrape() -> Result<(), Box<dyn Error>> {
                 // Save the raw response data.
                 responses.push(response.clone());
 
-                // Process each product.
-                for product in products {
-                    if let Some((ingredient, uid)) = process_product(product.clone()) {
-                        if seen_uids.contains(&uid) {
-                            println!("   - Duplicate skipped: \"{}\"", ingredient.name);
-                            continue;
-                        }
-                        seen_uids.push(uid);
-                        let mut ingredient_mod = ingredient.clone();
-                        ingredient_mod.id = current_id;
-                        ingredient_mod.life = life;
-                        current_id += 1;
-                        ingredients.push(ingredient_mod);
-                    } else {
-                        println!("   - Item skipped: invalid or filtered out.")
-                    }
-                }
-                let added = ingredients.len() - ingredients_initial_len;
+                let added = process_products(products, label, life, &mut processed);
                 println!("   ✓ Category contained {} ingredients", added);
             } else {
                 println!("   ✗ Request failed or returned erroneous data.")
             }
         }
     }
-    println!(
-        "\nWriting {} ingredients to '{}'...",
-        ingredients.len(),
-        INGREDIENTS_FILE
-    );
-    let j = serde_json::to_string(&ingredients)?;
-    let mut ingredients = File::create(INGREDIENTS_FILE)?;
-    ingredients.write_all(j.as_bytes())?;
+
+    write_ingredients(processed.ingredients)?;
 
     println!(
         "Writing {} responses to '{}'...\n",
@@ -176,6 +182,168 @@ pub async fn scrape() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Category listings leave each product's `details_html` empty, so it is filled in from a request
+/// for the product itself. This has the nutrition table, and is recorded along with the rest of the
+/// response so that reprocessing can use it.
+async fn add_details(client: &Client, response: &mut Value) -> Result<(), Box<dyn Error>> {
+    let Some(products) = response.get_mut("products").and_then(|p| p.as_array_mut()) else {
+        return Ok(());
+    };
+
+    for product in products {
+        let Some(seo_url) = product
+            .get("full_url")
+            .and_then(|u| u.as_str())
+            .and_then(|u| u.rsplit('/').next())
+        else {
+            continue;
+        };
+
+        let details = client
+            .get(build_details_request(seo_url))
+            .send()
+            .await?
+            .json::<Value>()
+            .await?;
+
+        if let Some(html) = details
+            .get("products")
+            .and_then(|p| p.get(0))
+            .and_then(|p| p.get("details_html"))
+        {
+            product["details_html"] = html.clone();
+        }
+    }
+
+    Ok(())
+}
+
+/// Rebuilds the ingredient dataset from previously recorded responses, without calling the API.
+/// Responses are recorded in category order, and each category starts again from page 1.
+pub fn reprocess() -> Result<(), Box<dyn Error>> {
+    println!("Reprocessing responses from '{}'...", RESPONSES_FILE);
+
+    let data = fs::read_to_string(RESPONSES_FILE)?;
+    let responses: Vec<Value> = serde_json::from_str(&data)?;
+
+    let mut processed = Processed::default();
+    let mut categories = CATEGORIES.iter();
+    let mut category = None;
+
+    for response in &responses {
+        if active_page(response) == Some(1) {
+            category = categories.next();
+        }
+        let Some((label, _, life)) = category else {
+            return Err("more categories were recorded than are configured".into());
+        };
+
+        if let Some(products) = response.get("products").and_then(|a| a.as_array()) {
+            process_products(products, label, *life, &mut processed);
+        }
+    }
+
+    write_ingredients(processed.ingredients)
+}
+
+/// Processes a page of products from a category, adding new ingredients. Returns the number added.
+fn process_products(
+    products: &[Value],
+    label: &str,
+    life: i32,
+    processed: &mut Processed,
+) -> usize {
+    let initial_len = processed.ingredients.len();
+
+    for product in products {
+        if let Some((mut ingredient, uid)) = process_product(product.clone()) {
+            ingredient.price = process_price(product, &ingredient);
+            ingredient.nutrition = process_nutrition(product);
+            if processed.seen_uids.contains(&uid) {
+                println!("   - Duplicate skipped: \"{}\"", ingredient.name);
+                continue;
+            }
+            processed.seen_uids.push(uid);
+            ingredient.life = life;
+            ingredient.category = Some(label.into());
+            processed.ingredients.push(ingredient);
+        } else {
+            println!("   - Item skipped: invalid or filtered out.")
+        }
+    }
+
+    processed.ingredients.len() - initial_len
+}
+
+/// Reads the current page number from a response.
+fn active_page(response: &Value) -> Option<u64> {
+    response
+        .get("controls")
+        .and_then(|a| a.get("page"))
+        .and_then(|a| a.get("active"))
+        .and_then(|a| a.as_u64())
+}
+
+/// Reads the number of pages in a category from a response.
+fn last_page(response: &Value) -> Option<u64> {
+    response
+        .get("controls")
+        .and_then(|a| a.get("page"))
+        .and_then(|a| a.get("last"))
+        .and_then(|a| a.as_u64())
+}
+
+/// Reads the current dataset, if there is one.
+pub fn read_ingredients() -> Result<Vec<Ingredient>, Box<dyn Error>> {
+    match fs::read_to_string(INGREDIENTS_FILE) {
+        Ok(data) => Ok(serde_json::from_str(&data)?),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Numbers scraped ingredients so the IDs already in the dataset stay stable, as recipes, plans and
+/// purchases refer to them. A scraped product takes the ID of the existing ingredient with the same
+/// name and pack size, and new products are numbered after the highest existing ID. Existing
+/// ingredients that weren't scraped this time are kept as they are.
+fn assign_ids(scraped: Vec<Ingredient>, existing: Vec<Ingredient>) -> Vec<Ingredient> {
+    let mut next_id = existing.iter().map(|i| i.id).max().unwrap_or(0) + 1;
+    let mut unmatched = existing;
+    let mut ingredients = vec![];
+
+    for mut ingredient in scraped {
+        match unmatched.iter().position(|e| {
+            e.name == ingredient.name
+                && e.unit == ingredient.unit
+                && e.purchase_quantity == ingredient.purchase_quantity
+        }) {
+            Some(index) => ingredient.id = unmatched.remove(index).id,
+            None => {
+                ingredient.id = next_id;
+                next_id += 1;
+            }
+        }
+        ingredients.push(ingredient);
+    }
+
+    ingredients.extend(unmatched);
+    ingredients.sort_by_key(|i| i.id);
+    ingredients
+}
+
+fn write_ingredients(scraped: Vec<Ingredient>) -> Result<(), Box<dyn Error>> {
+    let ingredients = assign_ids(scraped, read_ingredients()?);
+    println!(
+        "\nWriting {} ingredients to '{}'...",
+        ingredients.len(),
+        INGREDIENTS_FILE
+    );
+    let j = serde_json::to_string(&ingredients)?;
+    let mut file = File::create(INGREDIENTS_FILE)?;
+    file.write_all(j.as_bytes())?;
+    Ok(())
+}
+
 /// Process a product into an ingredient. Takes a JSON value and set of existing UIDs for debugging purposes.
 /// Returns an ingredient and a set of additional UIDs.
 fn process_product(product: Value) -> Option<(Ingredient, String)> {
@@ -231,16 +399,15 @@ fn process_product(product: Value) -> Option<(Ingredient, String)> {
     let unit_filter = final_name.clone();
     // Check each word of the product name to find a unit string
     let amount_strings = unit_filter
-        .split(" ")
-        .into_iter()
+        .split(' ')
         .filter(|&s| is_amount_string(s.into()))
         .collect::<Vec<_>>();
 
-    if let Some(&amount_string) = amount_strings.get(0) {
+    if let Some(&amount_string) = amount_strings.first() {
         // Remove the amount string from the product name and reformat
         final_name = final_name.replace(amount_string, "");
         final_name = clean_product_string(&final_name);
-        if let Some((amount, unit)) = get_amount_unit(amount_string.into()).ok() {
+        if let Ok((amount, unit)) = get_amount_unit(amount_string.into()) {
             final_name = strip_unit_strings(&final_name);
             return Some((
                 Ingredient {
@@ -248,8 +415,11 @@ fn process_product(product: Value) -> Option<(Ingredient, String)> {
                     name: final_name,
                     unit: unit.clone(),
                     minimum_quantity: 1.0,
-                    purchase_quantity: amount.clone(),
+                    purchase_quantity: amount,
                     life: 7,
+                    category: None,
+                    price: None,
+                    nutrition: None,
                 },
                 uid,
             ));
@@ -266,13 +436,118 @@ fn process_product(product: Value) -> Option<(Ingredient, String)> {
             minimum_quantity: 1.0,
             purchase_quantity: 1.0,
             life: 7,
+            category: None,
+            price: None,
+            nutrition: None,
         },
         uid,
     ))
 }
 
+/// Finds the price of one purchase quantity of an ingredient. Most products are priced per item,
+/// but loose products are priced per kg - these can only be used if the ingredient is sold by weight.
+fn process_price(product: &Value, ingredient: &Ingredient) -> Option<f64> {
+    let retail_price = product.get("retail_price")?;
+    let price = retail_price.get("price").and_then(|p| p.as_f64())?;
+    let measure = retail_price
+        .get("measure")
+        .and_then(|m| m.as_str())
+        .unwrap_or_default();
+
+    match (measure, ingredient.unit.as_deref()) {
+        ("" | "unit", _) => Some(price),
+        ("kg", Some("g")) => Some(price * ingredient.purchase_quantity / 1000.0),
+        _ => None,
+    }
+}
+
+/// The patterns used to read a nutrition table, compiled on first use.
+struct NutritionPatterns {
+    row: Regex,
+    heading: Regex,
+    cell: Regex,
+    number: Regex,
+    kcal: Regex,
+}
+
+static NUTRITION_PATTERNS: OnceLock<NutritionPatterns> = OnceLock::new();
+
+/// Matches words in brackets in a product name, compiled on first use.
+static BRACKETED: OnceLock<Regex> = OnceLock::new();
+
+/// Reads nutrition per 100g/ml from the nutrition table in a product's details, if it has one.
+/// The first value column of the table is per 100g/ml. Energy is the first value given in kcal,
+/// which is usually on its own row under a shared "Energy" heading, and fibre is taken as 0 if it
+/// isn't listed.
+fn process_nutrition(product: &Value) -> Option<Nutrition> {
+    let details = product.get("details_html").and_then(|d| d.as_str())?;
+    let NutritionPatterns {
+        row,
+        heading,
+        cell,
+        number,
+        kcal,
+    } = NUTRITION_PATTERNS.get_or_init(|| NutritionPatterns {
+        row: Regex::new(r"(?s)<tr[^>]*>(.*?)</tr>").unwrap(),
+        heading: Regex::new(r"(?s)<th[^>]*>\s*(.*?)\s*</th>").unwrap(),
+        cell: Regex::new(r"(?s)<td[^>]*>\s*(.*?)\s*</td>").unwrap(),
+        number: Regex::new(r"(\d+(?:\.\d+)?)").unwrap(),
+        kcal: Regex::new(r"(?i)(\d+(?:\.\d+)?)\s*kcal").unwrap(),
+    });
+
+    let mut energy = None;
+    let mut nutrition = Nutrition::default();
+    let mut found = 0;
+    let mut label = String::new();
+
+    for captures in row.captures_iter(details) {
+        let row = &captures[1];
+        // Rows without a heading continue the one above, e.g. kcal after kJ.
+        if let Some(heading) = heading.captures(row) {
+            label = heading[1].to_lowercase();
+        }
+        let Some(value) = cell.captures(row).map(|c| c[1].to_string()) else {
+            continue;
+        };
+
+        if let Some(kcal) = kcal.captures(&value) {
+            energy = energy.or(kcal[1].parse::<f64>().ok());
+            continue;
+        }
+        let Some(amount) = number
+            .captures(&value)
+            .and_then(|n| n[1].parse::<f64>().ok())
+        else {
+            continue;
+        };
+
+        let field = if label.starts_with("protein") {
+            &mut nutrition.protein
+        } else if label.starts_with("fat") {
+            &mut nutrition.fat
+        } else if label.starts_with("carbohydrate") {
+            &mut nutrition.carbohydrate
+        } else if label.starts_with("fibre") {
+            &mut nutrition.fibre
+        } else if label.starts_with("salt") {
+            &mut nutrition.salt
+        } else {
+            continue;
+        };
+        *field = amount;
+        found += 1;
+    }
+
+    // Protein, fat, carbohydrate and salt are always given on a complete table.
+    if found < 4 {
+        return None;
+    }
+    nutrition.energy = energy?;
+    Some(nutrition)
+}
+
 /// Removes all unit strings from a string
-fn strip_unit_strings(string: &String) -> String {
+fn strip_unit_strings(string: &str) -> String {
     string
         .split(' ')
         .filter(|w| !is_amount_string(w.to_string()))
@@ -280,7 +555,7 @@ fn strip_unit_strings(string: &String) -> String {
         .join(" ")
 }
 /// Tidies up product strings
-fn clean_product_string(name: &String) -> String {
+fn clean_product_string(name: &str) -> String {
     // Pointless branding words - filtered out.
     let ignored_words = vec![
         "Sainsbury's",
@@ -340,8 +615,8 @@ fn clean_product_string(name: &String) -> String {
     cleaned = cleaned.replace(" ", " ");
 
     // Filter out words in brackets (these are usually erroneous)
-    let re = Regex::new(r"\((.*?)\)").unwrap();
-    cleaned = re.replace_all(&cleaned, "").to_string();
+    let bracketed = BRACKETED.get_or_init(|| Regex::new(r"\((.*?)\)").unwrap());
+    cleaned = bracketed.replace_all(&cleaned, "").to_string();
 
     // Filter out ignored words
     for word in &ignored_words {
@@ -360,11 +635,7 @@ fn clean_product_string(name: &String) -> String {
 /// Splits a string into unit and amount (e.g. 200g -> (200.0, Some("g"))).
 fn get_amount_unit(string: String) -> Result<(f64, Option<String>), Box<dyn Error>> {
     // Remove all numeric and symbol characters and return true if only a unit remains
-    let maybe_unit: String = string
-        .chars()
-        .into_iter()
-        .filter(|c| c.is_alphabetic())
-        .collect();
+    let maybe_unit: String = string.chars().filter(|c| c.is_alphabetic()).collect();
 
     // Leaves unitless strings unchanged.
     let mut amount: f64 = string.replace(&maybe_unit, "").parse()?;
@@ -425,3 +696,103 @@ fn build_client() -> Result<Client, Box<dyn Error>> {
         .gzip(true)
         .build()?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ingredient(id: i32, name: &str, purchase_quantity: f64) -> Ingredient {
+        Ingredient {
+            id,
+            name: name.into(),
+            unit: Some("g".into()),
+            minimum_quantity: 1.0,
+            purchase_quantity,
+            life: 7,
+            category: None,
+            price: None,
+            nutrition: None,
+        }
+    }
+
+    /// A product from the details request, trimmed to the fields the scraper reads.
+    fn butter() -> Value {
+        serde_json::json!({
+            "product_uid": "1304",
+            "name": "Sainsbury's English Butter, Salted 250g",
+            "retail_price": { "price": 1.69, "measure": "" },
+            "details_html": "<h3 class=\"productDataItemHeader\">Nutrition</h3>\
+                <div class=\"productText\"><div>\
+                <table class=\"nutritionTable\"><thead><tr class=\"tableTitleRow\">\
+                <th scope=\"col\">Typical Values</th><th scope=\"col\">Per 100g</th>\
+                <th scope=\"col\">Per 10g</th><th scope=\"col\">% RI*</th></tr></thead>\
+                <tr class=\"tableRow1\"><th scope=\"row\" class=\"rowHeader\" rowspan=\"2\">Energy</th>\
+                <td class=\"tableRow1\">3059kJ</td><td class=\"tableRow1\">306kJ</td><td class=\"tableRow1\">-</td></tr>\
+                <tr class=\"tableRow0\"><td class=\"tableRow0\">744kcal</td><td class=\"tableRow0\">74kcal</td>\
+                <td class=\"tableRow0\">4%</td></tr>\
+                <tr class=\"tableRow1\"><th scope=\"row\" class=\"rowHeader\">Fat</th><td class=\"tableRow1\">82.2g</td>\
+                <td class=\"tableRow1\">8.2g</td><td class=\"tableRow1\">12%</td></tr>\
+                <tr class=\"tableRow0\"><th scope=\"row\" class=\"rowHeader\">of which saturates</th>\
+                <td class=\"tableRow0\">52.1g</td><td class=\"tableRow0\">5.2g</td><td class=\"tableRow0\">26%</td></tr>\
+                <tr class=\"tableRow1\"><th scope=\"row\" class=\"rowHeader\">Carbohydrate</th>\
+                <td class=\"tableRow1\">0.6g</td><td class=\"tableRow1\">&lt;0.1g</td><td class=\"tableRow1\">&lt;1%</td></tr>\
+                <tr class=\"tableRow0\"><th scope=\"row\" class=\"rowHeader\">of which sugars</th>\
+                <td class=\"tableRow0\">0.6g</td><td class=\"tableRow0\">&lt;0.1g</td><td class=\"tableRow0\">&lt;1%</td></tr>\
+                <tr class=\"tableRow1\"><th scope=\"row\" class=\"rowHeader\">Protein</th>\
+                <td class=\"tableRow1\">0.6g</td><td class=\"tableRow1\">&lt;0.1g</td><td class=\"tableRow1\">&lt;1%</td></tr>\
+                <tr class=\"tableRow0\"><th scope=\"row\" class=\"rowHeader\">Salt</th>\
+                <td class=\"tableRow0\">1.5g</td><td class=\"tableRow0\">0.15g</td><td class=\"tableRow0\">3%</td></tr>\
+                </table></div></div>"
+        })
+    }
+
+    #[test]
+    fn process_nutrition_reads_per_100g_column() {
+        assert_eq!(
+            process_nutrition(&butter()),
+            Some(Nutrition {
+                energy: 744.0,
+                protein: 0.6,
+                fat: 82.2,
+                carbohydrate: 0.6,
+                fibre: 0.0,
+                salt: 1.5,
+            })
+        );
+    }
+
+    #[test]
+    fn process_nutrition_needs_a_table() {
+        let mut product = butter();
+        product["details_html"] = "".into();
+        assert_eq!(process_nutrition(&product), None);
+    }
+
+    #[test]
+    fn assign_ids_keeps_existing_ids() {
+        let existing = vec![
+            ingredient(1, "Butter Salted", 250.0),
+            ingredient(2, "Butter Salted", 500.0),
+            ingredient(3, "Spaghetti", 500.0),
+        ];
+        let scraped = vec![
+            ingredient(0, "Butter Salted", 500.0),
+            ingredient(0, "Spaghetti Pasta", 500.0),
+            ingredient(0, "Butter Salted", 250.0),
+        ];
+
+        let ids: Vec<_> = assign_ids(scraped, existing)
+            .into_iter()
+            .map(|i| (i.id, i.name, i.purchase_quantity))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                (1, "Butter Salted".into(), 250.0),
+                (2, "Butter Salted".into(), 500.0),
+                (3, "Spaghetti".into(), 500.0),
+                (4, "Spaghetti Pasta".into(), 500.0),
+            ]
+        );
+    }
+}
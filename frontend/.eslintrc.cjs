@@ -4,6 +4,8 @@ module.exports = {
 	parser: '@typescript-eslint/parser',
 	plugins: ['@typescript-eslint'],
 	root: true,
+	// Generated from the backend's types.
+	ignorePatterns: ['**/generated.ts'],
 	rules: {
 		quotes: ['error', 'single'],
 		'@typescript-eslint/no-unused-vars': [
@@ -1,4 +1,4 @@
-import { RecipeInput, Ingredient, RecipeIngredientInput, Recipe, DayInput, Day, ShoppingList, ScheduledIngredient, ScheduledIngredientInput, IngredientInput, ShoppingListEditable } from './types';
+import { RecipeInput, Ingredient, IngredientDraft, RecipeIngredientInput, Recipe, RecipeDraft, DayInput, Day, ShoppingList, ScheduledIngredient, ScheduledIngredientInput, IngredientInput, ShoppingListEditable } from './types';
 import { getISODateString, toShoppingListEditable } from './utils';
 import { fetchAuthSession } from 'aws-amplify/auth';
 
@@ -48,7 +48,7 @@ export async function loadDemoData() {
 			'Leave to ferment for 1 hour',
 			'Shape and prove for 1 more hour',
 			'Preheat a dutch oven to 250C. Bake for 20 minutes covered, then uncovered until dark brown.',
-		]
+		].map(text => ({ text, timer: false, ingredients: [] }))
 	};
 	const celeriac_soup: RecipeInput = {
 		name: 'Celeriac Soup',
@@ -95,7 +95,7 @@ export async function loadDemoData() {
 			'Add onions and sautee for a few minutes',
 			'Add celeriac, potato and stock and simmer until soft',
 			'Blend until smooth'
-		]
+		].map(text => ({ text, timer: false, ingredients: [] }))
 	};
 	const lentil_pasta: RecipeInput = {
 		name: 'Lentil Pasta',
@@ -142,7 +142,7 @@ export async function loadDemoData() {
 			'Add lentils and brown',
 			'Add remaining ingredients and simmer until lentils are soft',
 			'Meanwhile, cook pasta until al dente. Combine and serve',
-		]
+		].map(text => ({ text, timer: false, ingredients: [] }))
 	};
 
 	const coffee = {
@@ -190,7 +190,7 @@ export async function getRecipes(): Promise<Recipe[]> {
 	}
 }
 
-export async function createRecipe(recipe: Recipe): Promise<number> {
+export async function createRecipe(recipe: RecipeDraft): Promise<number> {
 	// Map the Recipe object onto a RecipeInput
 	const ingredientInputs: RecipeIngredientInput[] = recipe.ingredients.map(ingredient => ({
 		id: ingredient.ingredient.id,
@@ -215,7 +215,7 @@ export async function createRecipe(recipe: Recipe): Promise<number> {
 }
 
 
-export async function updateRecipe(recipe: Recipe): Promise<number> {
+export async function updateRecipe(recipe: RecipeDraft): Promise<number> {
 	// Map the Recipe object onto a RecipeInput
 	const ingredientInputs: RecipeIngredientInput[] = recipe.ingredients.map(ingredient => ({
 		id: ingredient.ingredient.id,
@@ -353,7 +353,7 @@ export async function getScheduledIngredients(): Promise<ScheduledIngredient[]>
 /**
  * Creates a scheduled ingredient.
  */
-export async function createScheduledIngredient(ingredient: Ingredient, startDate: string, interval: number): Promise<number> {
+export async function createScheduledIngredient(ingredient: IngredientDraft, startDate: string, interval: number): Promise<number> {
 	const input: ScheduledIngredientInput = {
 		ingredient_id: ingredient.id,
 		start_date: startDate,
@@ -372,7 +372,7 @@ export async function createScheduledIngredient(ingredient: Ingredient, startDat
 /**
  * Updates a scheduled ingredient for the current user.
  */
-export async function createUserIngredient(ingredient: Ingredient): Promise<number> {
+export async function createUserIngredient(ingredient: IngredientDraft): Promise<number> {
 
 	const input: IngredientInput = {
 		name: ingredient.name,
@@ -0,0 +1,720 @@
+// Generated from the backend's request and response types. Don't edit this file by hand:
+// regenerate it with `UPDATE_TYPESCRIPT=1 cargo test --test typescript` in the backend.
+
+/**
+ * Represents a recipe with fully populated ingredient data.
+ */
+export type Recipe = {
+/**
+ * The recipe's unique ID.
+ */
+id: number,
+/**
+ * The display name of the recipe.
+ */
+name: string,
+/**
+ * The number of portions this recipe makes.
+ */
+portions: number,
+/**
+ * The recipe's method, in order.
+ */
+steps: Array<Step>,
+/**
+ * A list of the recipe's ingredients.
+ */
+ingredients: Array<IngredientQuantity>,
+/**
+ * The estimated cost of the ingredients used, in pence. Absent if any ingredient is unpriced.
+ */
+cost: number | null,
+/**
+ * The estimated cost of a single portion, in pence.
+ */
+cost_per_portion: number | null,
+/**
+ * The nutrition of the whole recipe and of a single portion.
+ */
+nutrition: RecipeNutrition,
+/**
+ * The allergens in the recipe, and whether it suits vegetarians and vegans.
+ */
+diet: RecipeDiet,
+/**
+ * Photos of the recipe and its steps.
+ */
+images: Array<RecipeImage>,
+/**
+ * Other recipes used in this one, such as a sauce or dough.
+ */
+sub_recipes: Array<SubRecipe>,
+/**
+ * Goes up by one each time the recipe is updated. Updates may give the version they were
+ * made from, so that they don't overwrite a newer one.
+ */
+version: number,
+/**
+ * When the recipe was added, as YYYY-MM-DDTHH:MM:SS.
+ */
+created_at: string, prep_time?: number | null, cook_time?: number | null,
+/**
+ * The time from start to finish, including any resting or proving. Defaults to the sum of
+ * the prep and cook times.
+ */
+total_time?: number | null,
+/**
+ * Where the recipe came from.
+ */
+source_url?: string | null, notes?: string | null,
+/**
+ * What the recipe makes, e.g. "1 large loaf" or "12 cookies".
+ */
+yield_description?: string | null, difficulty?: Difficulty | null, };
+
+/**
+ * Another recipe used as part of a recipe, measured in portions.
+ */
+export type SubRecipe = { recipe_id: number, name: string,
+/**
+ * The number of portions of the sub-recipe used.
+ */
+portions: number, };
+
+/**
+ * How hard a recipe is to make.
+ */
+export type Difficulty = "easy" | "medium" | "hard";
+
+/**
+ * Optional timings and provenance of a recipe. Times are in minutes.
+ */
+export type RecipeMetadata = { prep_time?: number | null, cook_time?: number | null,
+/**
+ * The time from start to finish, including any resting or proving. Defaults to the sum of
+ * the prep and cook times.
+ */
+total_time?: number | null,
+/**
+ * Where the recipe came from.
+ */
+source_url?: string | null, notes?: string | null,
+/**
+ * What the recipe makes, e.g. "1 large loaf" or "12 cookies".
+ */
+yield_description?: string | null, difficulty?: Difficulty | null, };
+
+/**
+ * A single step of a recipe's method.
+ */
+export type Step = {
+/**
+ * A heading that starts a new section of the method, e.g. "For the sauce".
+ */
+section?: string | null,
+/**
+ * The instruction itself.
+ */
+text: string,
+/**
+ * How long the step takes, in seconds.
+ */
+duration?: number | null,
+/**
+ * True if a timer should be offered for the step's duration.
+ */
+timer?: boolean,
+/**
+ * The recipe's ingredients used in this step.
+ */
+ingredients?: Array<StepIngredient>, };
+
+/**
+ * A reference to one of the recipe's ingredients from a step.
+ */
+export type StepIngredient = { ingredient_id: number,
+/**
+ * The amount of the ingredient used in the step.
+ */
+quantity: number, };
+
+/**
+ * An input to insert a recipe into the database.
+ */
+export type RecipeInput = {
+/**
+ * The display name of the recipe.
+ */
+name: string,
+/**
+ * The number of portions the recipe makes.
+ */
+portions: number,
+/**
+ * The recipe's method, in order.
+ */
+steps: Array<Step>,
+/**
+ * Represents the ingredients of the recipe as relations on ID.
+ */
+ingredients: Array<RecipeIngredientInput>,
+/**
+ * Other recipes used in this one.
+ */
+sub_recipes?: Array<SubRecipeInput>,
+/**
+ * When updating, the version of the recipe the changes were made to. If the recipe has been
+ * updated since, the changes are rejected rather than overwriting it.
+ */
+version?: number | null, prep_time?: number | null, cook_time?: number | null,
+/**
+ * The time from start to finish, including any resting or proving. Defaults to the sum of
+ * the prep and cook times.
+ */
+total_time?: number | null,
+/**
+ * Where the recipe came from.
+ */
+source_url?: string | null, notes?: string | null,
+/**
+ * What the recipe makes, e.g. "1 large loaf" or "12 cookies".
+ */
+yield_description?: string | null, difficulty?: Difficulty | null, };
+
+/**
+ * Represents a single recipe/ingredient relation, with quantity.
+ * - `id` - The unique ID of the ingredient
+ * - `quantity` - The amount of the ingredient used in whatever recipe the relation is linked to
+ */
+export type RecipeIngredientInput = { id: number, quantity: number, };
+
+/**
+ * Represents the use of another of the user's recipes, in portions.
+ */
+export type SubRecipeInput = { recipe_id: number, portions: number, };
+
+/**
+ * A photo attached to a recipe, or to one of its steps.
+ */
+export type RecipeImage = { id: number,
+/**
+ * The index of the step the photo shows, if it isn't of the whole recipe.
+ */
+step: number | null, url: string, thumbnail_url: string, };
+
+/**
+ * Represents an ingredient.
+ */
+export type Ingredient = {
+/**
+ * The ingredient's unique ID
+ */
+id: number,
+/**
+ * Present if the ingredient is assigned to a user.
+ */
+user_id: number | null,
+/**
+ * The name of the ingredient
+ */
+name: string,
+/**
+ * The unit the ingredient's quantity is measured in
+ */
+unit: string | null,
+/**
+ * The minimum usable quantity of the ingredient
+ */
+minimum_quantity: number,
+/**
+ * The minimum buyable quantity of the ingredient
+ */
+purchase_quantity: number,
+/**
+ * The estimated shelf life of the ingredient
+ */
+life: number,
+/**
+ * Present if the ingredient is one of several pack sizes of the same product.
+ */
+family_id: number | null,
+/**
+ * The aisle the ingredient is found in, e.g. "Fresh Vegetables"
+ */
+category: string | null,
+/**
+ * The price of one purchase quantity in pence, if known. A user's own price takes the place
+ * of the retail price.
+ */
+price: number | null,
+/**
+ * The price of a single unit (e.g. 1g) in pence, derived from `price`.
+ */
+price_per_unit: number | null,
+/**
+ * Nutrition per 100g/ml, if known.
+ */
+nutrition: Nutrition | null,
+/**
+ * The allergens the ingredient contains.
+ */
+allergens: Array<Allergen>,
+/**
+ * False if the ingredient contains meat or fish.
+ */
+vegetarian: boolean,
+/**
+ * False if the ingredient contains any animal product.
+ */
+vegan: boolean, };
+
+/**
+ * An input to create a new ingredient.
+ */
+export type IngredientInput = { name: string, unit?: string | null, minimum_quantity: number, purchase_quantity: number, life: number,
+/**
+ * An optional family to add the ingredient to as another pack size.
+ */
+family_id?: number | null,
+/**
+ * An optional aisle category, used to group shopping lists.
+ */
+category?: string | null,
+/**
+ * An optional price for one purchase quantity, in pence.
+ */
+price?: number | null,
+/**
+ * Optional nutrition per 100g/ml.
+ */
+nutrition?: Nutrition | null,
+/**
+ * The allergens the ingredient contains.
+ */
+allergens?: Array<Allergen>, vegetarian?: boolean, vegan?: boolean, };
+
+/**
+ * Represents an ingredient in the context of a recipe
+ * - `ingredient` - The ingredient the quantity is relevant to
+ * - `quantity` - The quantity of the ingredient
+ */
+export type IngredientQuantity = { ingredient: Ingredient, quantity: number, };
+
+/**
+ * An input to set a user's own price for an ingredient.
+ */
+export type PriceInput = {
+/**
+ * The price of one purchase quantity in pence, or null to go back to the retail price.
+ */
+price: number | null, };
+
+/**
+ * Represents a product that is sold in several pack sizes, each of which is stored as a
+ * separate ingredient (e.g. 1130ml and 2270ml of whole milk).
+ */
+export type IngredientFamily = {
+/**
+ * The family's unique ID
+ */
+id: number,
+/**
+ * Present if the family is assigned to a user.
+ */
+user_id: number | null,
+/**
+ * The name of the product
+ */
+name: string,
+/**
+ * The unit shared by every pack size in the family
+ */
+unit: string | null,
+/**
+ * The pack sizes available, ordered from smallest to largest.
+ */
+members: Array<Ingredient>, };
+
+/**
+ * An input to group a user's ingredients into a family.
+ */
+export type IngredientFamilyInput = { name: string, unit?: string | null,
+/**
+ * The IDs of the user's ingredients that make up the family.
+ */
+ingredient_ids: Array<number>, };
+
+/**
+ * A number of packs of a single ingredient.
+ */
+export type PackQuantity = { ingredient: Ingredient, count: number, };
+
+/**
+ * Represents a recurring purchase of an ingredient derived from a start date
+ * and interval in days.
+ */
+export type ScheduledIngredient = { id: number, ingredient: Ingredient, start_date: string, interval: number, };
+
+/**
+ * Represents an API input to create a recurring purchase of an ingredient derived from a start date
+ * and interval in days.
+ */
+export type ScheduledIngredientInput = { ingredient_id: number, start_date: string, interval: number, };
+
+/**
+ * Nutrition per 100g/ml of an ingredient, or the total nutrition of a recipe or day.
+ */
+export type Nutrition = {
+/**
+ * Energy in kcal
+ */
+energy: number,
+/**
+ * Protein in grams
+ */
+protein: number,
+/**
+ * Fat in grams
+ */
+fat: number,
+/**
+ * Carbohydrate in grams
+ */
+carbohydrate: number,
+/**
+ * Fibre in grams
+ */
+fibre: number,
+/**
+ * Salt in grams
+ */
+salt: number, };
+
+/**
+ * The nutrition of a whole recipe and of a single portion.
+ */
+export type RecipeNutrition = { total: Nutrition, per_portion: Nutrition,
+/**
+ * The IDs of ingredients without nutrition data, or measured in a unit that can't be
+ * converted to grams or millilitres. These are left out of the totals.
+ */
+unknown_ingredients: Array<number>, };
+
+/**
+ * An allergen an ingredient may contain, following the UK's 14 major allergens.
+ */
+export type Allergen = "celery" | "gluten" | "crustaceans" | "egg" | "fish" | "lupin" | "dairy" | "molluscs" | "mustard" | "nuts" | "peanuts" | "sesame" | "soya" | "sulphites";
+
+/**
+ * The diet a recipe suits, derived from its ingredients.
+ */
+export type RecipeDiet = {
+/**
+ * Every allergen found in the recipe's ingredients.
+ */
+allergens: Array<Allergen>, vegetarian: boolean, vegan: boolean, };
+
+/**
+ * The diet a user follows and the allergens they avoid.
+ */
+export type DietProfile = { vegetarian?: boolean, vegan?: boolean, allergens?: Array<Allergen>, };
+
+/**
+ * A warning that a planned recipe doesn't suit the user's diet profile.
+ */
+export type DietWarning = { recipe_id: number, recipe_name: string,
+/**
+ * The allergens in the recipe that the user avoids.
+ */
+allergens: Array<Allergen>, not_vegetarian: boolean, not_vegan: boolean, };
+
+export type Day = { date: string,
+/**
+ * The recipes cooked on the day.
+ */
+recipes: Array<Recipe>,
+/**
+ * Meals eaten from recipes cooked on earlier days. These need no shopping.
+ */
+leftovers: Array<PlannedLeftovers>,
+/**
+ * Whether each of the day's recipes was cooked or skipped, for those that have happened.
+ */
+records: Array<MealRecord>,
+/**
+ * The nutrition of one portion of each of the day's recipes and leftovers.
+ */
+nutrition: Nutrition,
+/**
+ * Warnings for any of the day's recipes that don't suit the user's diet profile.
+ */
+warnings: Array<DietWarning>, };
+
+/**
+ * Defines the input for adding a recipe to a day.
+ * - `recipe_id` - the ID of the recipe to be added
+ * - `date` - a string representing the date the recipe should be added to (YYYY-MM-DD)
+ * - `portions` - the number of portions eaten at the meal, one if absent
+ * - `leftovers_from` - the date the recipe was cooked, if the meal is eaten from its leftovers
+ */
+export type DayInput = { recipe_id: number, date: string, portions?: number | null, leftovers_from?: string | null, };
+
+/**
+ * Whether a planned recipe has been cooked yet.
+ */
+export type MealStatus = "planned" | "cooked" | "skipped";
+
+/**
+ * The amount of an ingredient a cooked meal actually used.
+ * - `id` - the ID of the ingredient
+ * - `quantity` - the amount used
+ */
+export type UsedQuantity = { id: number, quantity: number, };
+
+/**
+ * What happened to a planned recipe, and any ingredients it used different amounts of.
+ */
+export type MealRecord = { recipe_id: number, status: MealStatus, used: Array<UsedQuantity>, };
+
+/**
+ * Defines the input for recording whether a planned recipe was cooked.
+ * - `status` - whether the recipe was cooked or skipped, or `planned` to clear the record
+ * - `used` - the amounts of any ingredients that differed from the recipe
+ */
+export type MealRecordInput = { status: MealStatus, used?: Array<UsedQuantity>, };
+
+/**
+ * A meal eaten from the leftovers of a recipe cooked on an earlier day.
+ */
+export type PlannedLeftovers = { recipe: Recipe,
+/**
+ * The date the recipe was cooked.
+ */
+cooked_on: string,
+/**
+ * The number of portions eaten.
+ */
+portions: number,
+/**
+ * The portions still left once this meal has been eaten.
+ */
+remaining_portions: number, };
+
+/**
+ * One change to a meal plan, made as part of a batch.
+ */
+export type PlanOperation = { "op": "add" } & DayInput | { "op": "move", recipe_id: number, from: string, to: string, } | { "op": "delete", recipe_id: number, date: string, };
+
+/**
+ * What happened to each operation in a batch.
+ */
+export type OperationResult = { "status": "ok" } | { "status": "failed", error: string, } | { "status": "skipped" };
+
+/**
+ * The outcome of a batch of changes to a meal plan.
+ */
+export type BatchResult = {
+/**
+ * True if every operation succeeded and the batch was saved.
+ */
+applied: boolean,
+/**
+ * The result of each operation, in the order they were given.
+ */
+results: Array<OperationResult>, };
+
+/**
+ * A more usable version of `ShoppingList` that is returned by the API.
+ */
+export type ShoppingList = { ingredients: Array<IngredientPurchaseQuantity>, scheduled_ingredients: Array<IngredientPurchaseQuantity>,
+/**
+ * The estimated cost of everything on the list, in pence. Unpriced items are left out.
+ */
+estimated_cost: number, };
+
+export type IngredientPurchaseQuantity = { ingredient: Ingredient, existing_surplus: number, used_quantity: number, purchase_quantity: number,
+/**
+ * The packs that make up `purchase_quantity`.
+ */
+packs: Array<PackQuantity>,
+/**
+ * The amount purchased that is expected to expire before it is used.
+ */
+projected_waste: number,
+/**
+ * The estimated cost of the packs to buy, in pence. Absent if any pack is unpriced.
+ */
+estimated_cost: number | null, };
+
+/**
+ * A recipe, along with how much of it is covered by surplus.
+ */
+export type CookableRecipe = { recipe: Recipe,
+/**
+ * The average fraction of each ingredient covered by surplus, between 0 and 1.
+ */
+coverage: number,
+/**
+ * The ingredients surplus doesn't cover, and what would need to be bought.
+ */
+missing: Array<MissingIngredient>, };
+
+/**
+ * An ingredient a recipe needs more of than is available in surplus.
+ * - `quantity` - the amount not covered by surplus
+ * - `purchase_quantity` - the amount that would need to be bought to cover it
+ */
+export type MissingIngredient = { ingredient: Ingredient, quantity: number, purchase_quantity: number, packs: Array<PackQuantity>, };
+
+/**
+ * The waste a planned recipe is projected to cause, from buying more than it needs.
+ */
+export type RecipeWaste = { recipe_id: number, name: string,
+/**
+ * The dates the recipe is planned on that cause waste
+ */
+dates: Array<string>,
+/**
+ * The wasted quantity of each ingredient
+ */
+ingredients: Array<IngredientQuantity>,
+/**
+ * The waste measured in packs, allowing ingredients with different units to be compared
+ */
+packs_wasted: number, };
+
+/**
+ * An amount of an ingredient bought on a shopping trip.
+ */
+export type Purchase = { id: number, ingredient: Ingredient, quantity: number, date: string, };
+
+/**
+ * A shopping list sent back once the shopping has been done. Takes the same shape as the
+ * `ShoppingList` it was built from, so the list can be edited and returned as is.
+ */
+export type ShoppingTrip = {
+/**
+ * The date of the trip (YYYY-MM-DD).
+ */
+date: string, ingredients?: Array<PurchasedIngredient>, scheduled_ingredients?: Array<PurchasedIngredient>, };
+
+/**
+ * An entry of a completed shopping list, with the amount that was actually bought.
+ * - `ingredient` - the ingredient bought, of which only the ID is needed
+ * - `purchase_quantity` - the total amount bought, which may differ from the amount suggested
+ * - `date` - the date it was bought, if it differs from the trip's
+ */
+export type PurchasedIngredient = { ingredient: IngredientId, purchase_quantity: number, date?: string | null, };
+
+/**
+ * Identifies an ingredient in a completed shopping list. The rest of the ingredient is ignored.
+ */
+export type IngredientId = { id: number, };
+
+/**
+ * Defines the constraints used to generate a meal plan.
+ * - `from` and `to` - the range of dates to fill (YYYY-MM-DD, inclusive)
+ * - `meals_per_day` - the number of meals each day should have, including any already planned
+ * - `no_repeat_days` - a recipe won't be planned again within this many days of its last use
+ * - `required_recipes` - recipes that must appear at least once
+ * - `excluded_recipes` - recipes that must not appear
+ * - `required_ingredients` - ingredients that at least one planned recipe must use
+ * - `excluded_ingredients` - ingredients that no planned recipe may use
+ *
+ * Ingredients match any other pack size in their family.
+ */
+export type PlanInput = { from: string, to: string, meals_per_day?: number, no_repeat_days?: number, required_recipes?: Array<number>, excluded_recipes?: Array<number>, required_ingredients?: Array<number>, excluded_ingredients?: Array<number>, };
+
+/**
+ * A generated meal plan. Nothing is saved until the draft is accepted.
+ */
+export type PlanDraft = {
+/**
+ * The recipes generated for each day. Meals that were already planned are not included.
+ */
+days: Array<Day>,
+/**
+ * The shopping list for the range if the draft is accepted, excluding scheduled ingredients.
+ */
+shopping_list: ShoppingList,
+/**
+ * Any requirements that neither the draft nor the meals already planned meet.
+ */
+unmet: UnmetRequirements, };
+
+/**
+ * The required recipes and ingredients a plan leaves out. A required recipe can't be planned
+ * if it is excluded, doesn't suit the user's diet profile or doesn't exist. A required
+ * ingredient can't be met if no recipe that can be planned uses it. Either may also be left
+ * out if there aren't enough meals to fit every requirement.
+ */
+export type UnmetRequirements = { recipes: Array<number>, ingredients: Array<number>, };
+
+/**
+ * A named set of recipes saved from a range of days, which can be planned again from any date.
+ */
+export type PlanTemplate = { id: number, name: string, days: Array<TemplateDay>, };
+
+/**
+ * A recipe in a template, planned `day_offset` days after the template's start.
+ */
+export type TemplateDay = { day_offset: number, recipe_id: number, recipe_name: string, };
+
+/**
+ * Defines the input for saving a range of days as a template.
+ * - `name` - the display name of the template
+ * - `from` and `to` - the range of days to save (YYYY-MM-DD, inclusive)
+ */
+export type TemplateInput = { name: string, from: string, to: string, };
+
+/**
+ * Defines the input for renaming a template.
+ */
+export type TemplateRename = { name: string, };
+
+/**
+ * Defines the input for applying a template, starting on `start_date` (YYYY-MM-DD).
+ */
+export type ApplyInput = { start_date: string, };
+
+/**
+ * Defines the input for copying a range of days, such as last week, to start on `start_date`.
+ */
+export type CopyInput = { from: string, to: string, start_date: string, };
+
+/**
+ * An ingredient held in surplus that will soon go off, with recipes that could use it up.
+ */
+export type ExpiringIngredient = { ingredient: Ingredient, quantity: number,
+/**
+ * The date the ingredient is expected to have gone off by.
+ */
+expires_on: string, suggested_recipes: Array<RecipeSuggestion>, };
+
+/**
+ * A recipe that uses an expiring ingredient, and how much of it the recipe uses.
+ */
+export type RecipeSuggestion = { recipe_id: number, name: string, quantity: number, };
+
+/**
+ * A user's calendar subscription token, and the path it can be fetched from without a bearer token.
+ */
+export type CalendarToken = { token: string, path: string, };
+
+/**
+ * Everything of a user's that has changed since they last synced.
+ */
+export type SyncChanges = {
+/**
+ * Pass this as `since` on the next sync to fetch only what changes after this one.
+ */
+token: string, recipes: Changes<Recipe, number>, ingredients: Changes<Ingredient, number>,
+/**
+ * Days are identified by their date.
+ */
+days: Changes<Day, string>, schedule: Changes<ScheduledIngredient, number>, };
+
+/**
+ * Items of one kind that have been created or updated, and the keys of any that were deleted.
+ */
+export type Changes<T, K> = { updated: Array<T>, deleted: Array<K>, };
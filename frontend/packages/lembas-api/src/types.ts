@@ -1,137 +1,103 @@
-export interface Recipe {
-	// The recipe's unique ID.
-	id?: number;
-	// The display name of the recipe.
-	name: string;
-	// The number of portions this recipe makes.
-	portions: number;
-	// A JSON array of strings representing the recipe's steps.
-	steps: string[];
-	// A list of the recipe's ingredients.
-	ingredients: IngredientQuantity[];
-}
-
-/**
- * These replace the core Recipe and IngredientQuantity types with string versions, 
- * that are validated before conversion to their stored types.
- */
-export type IngredientQuantityEditable = Omit<IngredientQuantity, 'quantity'> & { quantity: string; };
-export type RecipeEditable = Omit<Recipe, 'ingredients'> & { ingredients: IngredientQuantityEditable[]; };
-export type IngredientEditable =
-	Omit<Ingredient, 'minimum_quantity' | 'purchase_quantity' | 'life'>
-	& {
-		minimum_quantity: string;
-		purchase_quantity: string;
-		life: string;
-	};
-
-export interface Ingredient {
-	// The ingredient's unique ID
-	id: number;
-	// Set if this is a custom ingredient
-	user_id: number | undefined;
-	// The name of the ingredient
-	name: string;
-	// The unit the above quantity is measured in
-	unit: string | undefined;
-	// The minimum usable quantity of the ingredient
-	minimum_quantity: number;
-	// The minimum buyable quantity of the ingredient
-	purchase_quantity: number;
-	// The number of days the ingredient lasts
-	life: number;
-}
-
-/**
- * An ingredient in the context of a recipe
- */
-export interface IngredientQuantity {
-	ingredient: Ingredient;
-	quantity: number;
-}
-
-/**
- * A reminder to buy an ingredient at a set interval
- */
-export interface ScheduledIngredient {
-	// The reminder's ID.
-	id: number;
-	ingredient: Ingredient;
-	start_date: string;
-	interval: number;
-}
-
-/**
- * An ingredient in the context of a shopping list
- */
-export interface IngredientPurchaseQuantity {
-	ingredient: Ingredient;
-	existing_surplus: number;
-	used_quantity: number;
-	purchase_quantity: number;
-}
-
-export interface RecipeInput {
-	// The display name of the recipe.
-	name: string;
-	// The number of portions the recipe makes.
-	portions: number;
-	// A JSON array of strings representing the recipe's steps.
-	steps: string[];
-	// Represents the ingredients of the recipe as relations on ID.
-	ingredients: RecipeIngredientInput[];
-}
-
-/**
- * Represents a single recipe/ingredient relation, with quantity.
- */
-export interface RecipeIngredientInput {
-	// The ID of the ingredient
-	id: number;
-	// The amount of the ingredient used in whatever recipe the relation is linked to
-	quantity: number;
-}
-
-export interface IngredientInput {
-	name: string,
-	unit: string | undefined,
-	minimum_quantity: number,
-	purchase_quantity: number,
-	life: number,
-}
+import type {
+	BatchResult,
+	Changes,
+	Day,
+	DayInput,
+	ExpiringIngredient,
+	Ingredient,
+	IngredientInput,
+	IngredientPurchaseQuantity,
+	IngredientQuantity,
+	MealRecord,
+	MealRecordInput,
+	MealStatus,
+	OperationResult,
+	PlannedLeftovers,
+	PlanOperation,
+	Purchase,
+	Recipe,
+	RecipeImage,
+	RecipeIngredientInput,
+	RecipeInput,
+	RecipeMetadata,
+	RecipeSuggestion,
+	ScheduledIngredient,
+	ScheduledIngredientInput,
+	ShoppingList,
+	ShoppingTrip,
+	Step,
+	StepIngredient,
+	SubRecipe,
+	SubRecipeInput,
+	SyncChanges,
+	UsedQuantity,
+} from './generated';
 
 /**
- * The input needed to create an ingredient purchase reminder.
+ * The types sent to and returned by the API are generated from the backend's, in generated.ts.
+ * The versions of them the app builds and edits itself are written here.
  */
-export interface ScheduledIngredientInput {
-	ingredient_id: number,
-	start_date: string,
-	interval: number,
-}
+export type {
+	BatchResult,
+	Changes,
+	Day,
+	DayInput,
+	ExpiringIngredient,
+	Ingredient,
+	IngredientInput,
+	IngredientPurchaseQuantity,
+	IngredientQuantity,
+	MealRecord,
+	MealRecordInput,
+	MealStatus,
+	OperationResult,
+	PlannedLeftovers,
+	PlanOperation,
+	Purchase,
+	Recipe,
+	RecipeImage,
+	RecipeIngredientInput,
+	RecipeInput,
+	RecipeMetadata,
+	RecipeSuggestion,
+	ScheduledIngredient,
+	ScheduledIngredientInput,
+	ShoppingList,
+	ShoppingTrip,
+	Step,
+	StepIngredient,
+	SubRecipe,
+	SubRecipeInput,
+	SyncChanges,
+	UsedQuantity,
+};
 
 /**
- * Represents a calendar day with a set of recipes.
+ * The fields of a recipe that the backend fills in when it's saved, which recipes the app builds
+ * itself don't have yet.
  */
-export interface Day {
-	date: string;
-	recipes: Recipe[];
-}
+type RecipeComputed = 'id' | 'cost' | 'cost_per_portion' | 'nutrition' | 'diet' | 'images' | 'sub_recipes' | 'version';
+export type RecipeDraft = Omit<Recipe, RecipeComputed> & Partial<Pick<Recipe, RecipeComputed>>;
 
 /**
- * The input needed to add a recipe to a day.
+ * The fields of an ingredient that the app doesn't edit, so new ones don't have them yet.
  */
-export interface DayInput {
-	recipe_id: number;
-	date: string;
-}
+type IngredientComputed = 'family_id' | 'category' | 'price' | 'price_per_unit' | 'nutrition' | 'allergens' | 'vegetarian' | 'vegan';
+export type IngredientDraft = Omit<Ingredient, IngredientComputed> & Partial<Pick<Ingredient, IngredientComputed>>;
 
 /**
- * Represents a shoppings list for a given range.
+ * These replace the core Recipe and IngredientQuantity types with string versions, 
+ * that are validated before conversion to their stored types.
  */
-export interface ShoppingList {
-	ingredients: IngredientPurchaseQuantity[];
-	scheduled_ingredients: IngredientPurchaseQuantity[];
-}
+export type IngredientQuantityEditable = Omit<IngredientQuantity, 'quantity'> & { quantity: string; };
+export type RecipeEditable = Omit<RecipeDraft, 'ingredients'> & { ingredients: IngredientQuantityEditable[]; };
+export type IngredientEditable =
+	Omit<IngredientDraft, 'minimum_quantity' | 'purchase_quantity' | 'life'>
+	& {
+		minimum_quantity: string;
+		purchase_quantity: string;
+		life: string;
+	};
 
 interface ShoppingListAttributes {
 	ticked: boolean;
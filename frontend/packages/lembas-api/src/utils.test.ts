@@ -1,23 +1,28 @@
 import { describe, expect, it } from '@jest/globals';
 import { fromIngredientEditable, fromRecipeEditable, getISODateString, toIngredientEditable, toIngredientQuantity, toIngredientQuantityList, toRecipeEditable, toShoppingListEditable, toShoppingListItem } from './utils';
-import { Ingredient, IngredientEditable, IngredientPurchaseQuantity, IngredientQuantity, IngredientQuantityEditable, Recipe, RecipeEditable, ShoppingList, ShoppingListEditable, ShoppingListItem } from './types';
+import { Ingredient, IngredientEditable, IngredientPurchaseQuantity, IngredientQuantity, IngredientQuantityEditable, RecipeDraft, RecipeEditable, ShoppingList, ShoppingListEditable, ShoppingListItem } from './types';
 
 // Define some test ingredients
 const ingredient: Ingredient = {
 	id: 1,
-	user_id: undefined,
+	user_id: null,
 	name: 'Test Ingredient',
 	unit: 'g',
 	minimum_quantity: 50,
 	purchase_quantity: 50,
 	life: 7,
+	family_id: null,
+	category: null,
+	price: null,
+	price_per_unit: null,
+	nutrition: null,
+	allergens: [],
+	vegetarian: true,
+	vegan: true,
 };
 
 const ingredientEditable: IngredientEditable = {
-	id: 1,
-	user_id: undefined,
-	name: 'Test Ingredient',
-	unit: 'g',
+	...ingredient,
 	minimum_quantity: '50',
 	purchase_quantity: '50',
 	life: '7',
@@ -32,7 +37,10 @@ const ingredientPurchaseQuantity: IngredientPurchaseQuantity = {
 	ingredient,
 	existing_surplus: 5,
 	purchase_quantity: 10,
-	used_quantity: 10
+	used_quantity: 10,
+	packs: [],
+	projected_waste: 0,
+	estimated_cost: null
 };
 
 const ingredientQuantityEditable: IngredientQuantityEditable = {
@@ -48,17 +56,23 @@ describe('getISODateString', () => {
 	});
 });
 
-const recipe: Recipe = {
+const recipe: RecipeDraft = {
 	name: 'Test Recipe',
 	portions: 4,
-	steps: ['Step 1', 'Step 2'],
+	steps: [
+		{ text: 'Step 1', timer: false, ingredients: [] },
+		{ text: 'Step 2', timer: false, ingredients: [] }
+	],
 	ingredients: [ingredientQuantity],
 };
 
 const recipeEditable: RecipeEditable = {
 	name: 'Test Recipe',
 	portions: 4,
-	steps: ['Step 1', 'Step 2'],
+	steps: [
+		{ text: 'Step 1', timer: false, ingredients: [] },
+		{ text: 'Step 2', timer: false, ingredients: [] }
+	],
 	ingredients: [ingredientQuantityEditable],
 };
 
@@ -143,14 +157,12 @@ describe('toShoppingListEditable', () => {
 		],
 		scheduled_ingredients: [
 			ingredientPurchaseQuantity
-		]
+		],
+		estimated_cost: 0
 	};
 
 	const shoppingListItem: ShoppingListItem = {
-		ingredient,
-		existing_surplus: 5,
-		purchase_quantity: 10,
-		used_quantity: 10,
+		...ingredientPurchaseQuantity,
 		ticked: true
 	};
 
@@ -195,30 +207,16 @@ describe('toIngredientQuantityList', () => {
 	it('should filter out unticked items', () => {
 		const detailedList: ShoppingListEditable = {
 			ingredients: [{
-				ingredient: {
-					id: 1,
-					user_id: undefined,
-					name: 'Test Ingredient',
-					unit: 'g',
-					minimum_quantity: 50,
-					purchase_quantity: 50,
-					life: 7,
-				},
+				...ingredientPurchaseQuantity,
+				ingredient: { ...ingredient, id: 1 },
 				existing_surplus: 5,
 				purchase_quantity: 100,
 				used_quantity: 60,
 				ticked: true
 			},
 			{
-				ingredient: {
-					id: 2,
-					user_id: undefined,
-					name: 'Test Ingredient',
-					unit: 'g',
-					minimum_quantity: 50,
-					purchase_quantity: 50,
-					life: 7,
-				},
+				...ingredientPurchaseQuantity,
+				ingredient: { ...ingredient, id: 2 },
 				existing_surplus: 5,
 				purchase_quantity: 100,
 				used_quantity: 60,
@@ -239,15 +237,8 @@ describe('toIngredientQuantityList', () => {
 			ingredients: [],
 			scheduledIngredients: [],
 			checkFor: [{
-				ingredient: {
-					id: 5,
-					user_id: undefined,
-					name: 'Test Ingredient',
-					unit: 'g',
-					minimum_quantity: 50,
-					purchase_quantity: 50,
-					life: 7,
-				},
+				...ingredientPurchaseQuantity,
+				ingredient: { ...ingredient, id: 5 },
 				existing_surplus: 5,
 				purchase_quantity: 0,	// This value scaled up.
 				used_quantity: 60,
@@ -261,30 +252,16 @@ describe('toIngredientQuantityList', () => {
 	it('should merge duplicate ingredients', () => {
 		const detailedList: ShoppingListEditable = {
 			ingredients: [{
-				ingredient: {
-					id: 5,
-					user_id: undefined,
-					name: 'Test Ingredient',
-					unit: 'g',
-					minimum_quantity: 50,
-					purchase_quantity: 50,
-					life: 7,
-				},
+				...ingredientPurchaseQuantity,
+				ingredient: { ...ingredient, id: 5 },
 				existing_surplus: 5,
 				purchase_quantity: 0,
 				used_quantity: 60,
 				ticked: true
 			}],
 			scheduledIngredients: [{
-				ingredient: {
-					id: 5,
-					user_id: undefined,
-					name: 'Test Ingredient',
-					unit: 'g',
-					minimum_quantity: 50,
-					purchase_quantity: 50,
-					life: 7,
-				},
+				...ingredientPurchaseQuantity,
+				ingredient: { ...ingredient, id: 5 },
 				existing_surplus: 5,
 				purchase_quantity: 50,
 				used_quantity: 60,
@@ -300,7 +277,7 @@ describe('toIngredientQuantityList', () => {
 describe('toIngredientQuantity', () => {
 	it('should convert shopping list items to ingredient quantities', () => {
 		const item: ShoppingListItem = {
-			ingredient,
+			...ingredientPurchaseQuantity,
 			existing_surplus: 5,
 			purchase_quantity: 50,
 			used_quantity: 10,
@@ -1,4 +1,4 @@
-import { Ingredient, IngredientEditable, IngredientPurchaseQuantity, IngredientQuantity, Recipe, RecipeEditable, ShoppingList, ShoppingListEditable, ShoppingListItem } from './types';
+import { IngredientDraft, IngredientEditable, IngredientPurchaseQuantity, IngredientQuantity, RecipeDraft, RecipeEditable, ShoppingList, ShoppingListEditable, ShoppingListItem } from './types';
 
 /**
  * Returns a date into the format YYYY-MM-DD
@@ -10,7 +10,7 @@ export function getISODateString(date: Date): string {
 /**
  * Converts a recipe into an editable recipe with all string members.
  */
-export function toRecipeEditable(recipe: Recipe): RecipeEditable {
+export function toRecipeEditable(recipe: RecipeDraft): RecipeEditable {
 	return {
 		...recipe,
 		ingredients: recipe.ingredients.map(i => ({
@@ -24,7 +24,7 @@ export function toRecipeEditable(recipe: Recipe): RecipeEditable {
  * Try to convert an editable recipe back into it's fully typed equivalent.
  * Returns undefined if the conversion fails.
  */
-export function fromRecipeEditable(recipe: RecipeEditable): Recipe | undefined {
+export function fromRecipeEditable(recipe: RecipeEditable): RecipeDraft | undefined {
 	return {
 		...recipe,
 		ingredients: recipe.ingredients.map(i => ({
@@ -38,7 +38,7 @@ export function fromRecipeEditable(recipe: RecipeEditable): Recipe | undefined {
 /**
  * Converts an ingredient into an editable ingredient with all string members.
  */
-export function toIngredientEditable(ingredient: Ingredient): IngredientEditable {
+export function toIngredientEditable(ingredient: IngredientDraft): IngredientEditable {
 	return {
 		...ingredient,
 		minimum_quantity: ingredient.minimum_quantity.toString(),
@@ -51,7 +51,7 @@ export function toIngredientEditable(ingredient: Ingredient): IngredientEditable
  * Try to convert an editable ingredient back into it's fully typed equivalent.
  * Returns undefined if the conversion fails.
  */
-export function fromIngredientEditable(ingredient: IngredientEditable): Ingredient | undefined {
+export function fromIngredientEditable(ingredient: IngredientEditable): IngredientDraft | undefined {
 	if (!ingredient) {
 		return undefined;
 	}
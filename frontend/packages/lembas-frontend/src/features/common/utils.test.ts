@@ -18,12 +18,20 @@ describe('formatIngredientQuantity', () => {
 	it('should correctly format an ingredient quantity', () => {
 		const ingredient: Ingredient = {
 			id: 1,
-			user_id: undefined,
+			user_id: null,
 			name: 'Test Ingredient',
 			unit: 'g',
 			minimum_quantity: 50,
 			purchase_quantity: 50,
 			life: 7,
+			family_id: null,
+			category: null,
+			price: null,
+			price_per_unit: null,
+			nutrition: null,
+			allergens: [],
+			vegetarian: true,
+			vegan: true,
 		};
 
 		const ingredientQuantity = {
@@ -38,12 +46,20 @@ describe('formatIngredientQuantity', () => {
 	it('should correctly format an ingredient with a null unit', () => {
 		const ingredient: Ingredient = {
 			id: 1,
-			user_id: undefined,
+			user_id: null,
 			name: 'Test Ingredient',
 			unit: null,
 			minimum_quantity: 50,
 			purchase_quantity: 50,
 			life: 7,
+			family_id: null,
+			category: null,
+			price: null,
+			price_per_unit: null,
+			nutrition: null,
+			allergens: [],
+			vegetarian: true,
+			vegan: true,
 		};
 
 		const ingredientQuantity = {
@@ -100,34 +116,58 @@ describe('getPlaintextList', () => {
 		const ingredients: IngredientQuantity[] = [{
 			ingredient: {
 				id: 5,
-				user_id: undefined,
+				user_id: null,
 				name: 'Oat Drink',
 				unit: 'ml',
 				minimum_quantity: 50,
 				purchase_quantity: 50,
 				life: 7,
+				family_id: null,
+				category: null,
+				price: null,
+				price_per_unit: null,
+				nutrition: null,
+				allergens: [],
+				vegetarian: true,
+				vegan: true,
 			},
 			quantity: 50,
 		}, {
 			ingredient: {
 				id: 5,
-				user_id: undefined,
+				user_id: null,
 				name: 'Flour',
 				unit: 'g',
 				minimum_quantity: 50,
 				purchase_quantity: 50,
 				life: 7,
+				family_id: null,
+				category: null,
+				price: null,
+				price_per_unit: null,
+				nutrition: null,
+				allergens: [],
+				vegetarian: true,
+				vegan: true,
 			},
 			quantity: 500,
 		}, {
 			ingredient: {
 				id: 5,
-				user_id: undefined,
+				user_id: null,
 				name: 'Oats',
 				unit: 'g',
 				minimum_quantity: 50,
 				purchase_quantity: 50,
 				life: 7,
+				family_id: null,
+				category: null,
+				price: null,
+				price_per_unit: null,
+				nutrition: null,
+				allergens: [],
+				vegetarian: true,
+				vegan: true,
 			},
 			quantity: 400,
 		}];
@@ -25,7 +25,7 @@ const initialState: IngredientState = {
 		purchase_quantity: '1',
 		life: '7',
 		id: 0,
-		user_id: undefined
+		user_id: null
 	},
 };
 
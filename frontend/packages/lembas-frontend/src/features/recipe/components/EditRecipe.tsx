@@ -268,7 +268,7 @@ export function EditRecipe() {
 									<StepInput
 										key={ai}
 										index={ai}
-										value={recipe.steps[ai]}
+										value={recipe.steps[ai].text}
 										onChangeText={setStep}
 										onSubmit={() => { }}
 										onDelete={deleteStep}
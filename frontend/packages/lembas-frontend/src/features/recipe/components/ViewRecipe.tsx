@@ -63,10 +63,13 @@ export function ViewRecipe({ navigation }) {
 	}, [navigation, recipe]);
 
 	const steps = recipe.steps.map((step, i) => (
-		<View key={i} style={{ flexDirection: 'row', gap: 10, padding: 10, paddingLeft: 5 }}>
-			<Text style={{ flexWrap: 'wrap' }} variant='titleMedium'>{`${i + 1}.`}</Text>
-			<Text style={{ flexWrap: 'wrap' }} variant='bodyLarge'>{step}</Text>
-		</View >
+		<View key={i}>
+			{step.section && <Text style={{ padding: 10, paddingLeft: 5 }} variant='titleMedium'>{step.section}</Text>}
+			<View style={{ flexDirection: 'row', gap: 10, padding: 10, paddingLeft: 5 }}>
+				<Text style={{ flexWrap: 'wrap' }} variant='titleMedium'>{`${i + 1}.`}</Text>
+				<Text style={{ flexWrap: 'wrap' }} variant='bodyLarge'>{step.text}</Text>
+			</View>
+		</View>
 	));
 	return (
 		<View>
@@ -23,7 +23,7 @@ const initialState: EditRecipeState = {
 	recipe: {
 		name: '',
 		portions: 2,
-		steps: [''],
+		steps: [{ text: '', timer: false, ingredients: [] }],
 		ingredients: [],
 	},
 	errors: []
@@ -54,7 +54,7 @@ const editRecipeSlice = createAppSlice({
 			state.recipe.portions = action.payload;
 		}),
 		addStep: create.reducer((state) => {
-			state.recipe.steps.push('');
+			state.recipe.steps.push({ text: '', timer: false, ingredients: [] });
 		}),
 
 		// Create a prepared reducer that takes two arguments, which are put into an object before use.
@@ -67,7 +67,7 @@ const editRecipeSlice = createAppSlice({
 			}),
 			(state, action: PayloadAction<{ index: number, text: string; }>) => {
 				const index = action.payload.index;
-				state.recipe.steps[index] = action.payload.text;
+				state.recipe.steps[index].text = action.payload.text;
 			}
 		),
 		deleteStep: create.reducer((state, action: PayloadAction<number>) => {